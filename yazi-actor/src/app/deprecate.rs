@@ -19,6 +19,7 @@ impl Actor for Deprecate {
 			content: form.content.into_owned(),
 			level:   MessageLevel::Warn,
 			timeout: std::time::Duration::from_secs(20),
+			task:    None,
 		})
 	}
 }
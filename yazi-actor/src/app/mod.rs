@@ -12,6 +12,7 @@ yazi_macro::mod_flat!(
 	plugin_do
 	quit
 	reflow
+	reload
 	report
 	resize
 	resume
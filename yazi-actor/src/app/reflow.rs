@@ -20,8 +20,12 @@ impl Actor for Reflow {
 		let Some(size) = cx.term.as_ref().and_then(|t| t.size().ok()) else { succ!() };
 		let mut layout = LAYOUT.get();
 
+		// The terminal pane, if shown, takes the bottom of the screen
+		let (area, _) = cx.pane.split((Position::ORIGIN, size).into());
+		cx.pane.resize();
+
 		let result = Lives::scope(cx.core, |_| {
-			let comps = (form.reflow)((Position::ORIGIN, size).into())?;
+			let comps = (form.reflow)(area)?;
 
 			for v in comps.sequence_values::<Value>() {
 				let Value::Table(t) = v? else {
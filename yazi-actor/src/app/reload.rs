@@ -0,0 +1,32 @@
+use anyhow::Result;
+use yazi_actor::Ctx;
+use yazi_macro::{act, render, succ};
+use yazi_parser::VoidForm;
+use yazi_scheduler::{NotifyProxy, Scheduler};
+use yazi_shared::data::Data;
+
+use crate::Actor;
+
+pub struct Reload;
+
+impl Actor for Reload {
+	type Form = VoidForm;
+
+	const NAME: &str = "reload";
+
+	fn act(cx: &mut Ctx, _: Self::Form) -> Result<Data> {
+		if let Err(e) = yazi_config::reload() {
+			succ!(NotifyProxy::push_error("Config reload failed", format!("{e:#}")));
+		}
+
+		Scheduler::configure();
+		yazi_plugin::runtime::reset()?;
+
+		act!(mgr:peek, cx, true)?;
+		if cx.tab().spot.visible() {
+			act!(mgr:spot, cx, true)?;
+		}
+
+		succ!(render!());
+	}
+}
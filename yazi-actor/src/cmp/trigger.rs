@@ -1,12 +1,14 @@
-use std::{io, mem};
+use std::{collections::HashSet, env, io, mem, path::PathBuf, process::Stdio};
 
 use anyhow::Result;
-use yazi_core::cmp::{CmpItem, CmpOpt};
-use yazi_fs::{engine::{DirReader, FileHolder}, path::clean_url};
+use yazi_config::YAZI;
+use yazi_core::cmp::{CmpItem, CmpOpt, CmpSource};
+use yazi_fs::{PLACEHOLDERS, engine::{DirReader, FileHolder}, path::clean_url};
 use yazi_macro::{act, render, succ};
 use yazi_parser::cmp::TriggerForm;
 use yazi_proxy::CmpProxy;
-use yazi_shared::{AnyAsciiChar, BytePredictor, data::Data, natsort, path::{DynPath, PathBufDyn, PathLike}, spec::Spec, strand::{AsStrand, StrandLike}, url::{UrlBuf, UrlCow, UrlLike}};
+use yazi_shared::{AnyAsciiChar, BytePredictor, data::Data, natsort, path::{DynPath, PathBufDyn, PathLike}, shell::LastWord, spec::Spec, strand::{AsStrand, StrandLike}, url::{AsUrl, UrlBuf, UrlCow, UrlLike}};
+use yazi_shim::path::CROSS_SEPARATOR;
 use yazi_vfs::engine;
 
 use crate::{Actor, Ctx};
@@ -26,35 +28,35 @@ impl Actor for Trigger {
 		}
 
 		cx.cmp.handle.take().map(|h| h.abort());
-		let Some((parent, word)) = Self::split_url(&form.word) else {
+		let split = if cx.input.lock().is_some_and(|g| g.shell) {
+			Self::split_shell(&form.word)
+		} else {
+			Self::split_url(&form.word).map(|(parent, word)| (CmpSource::Dir(parent), word))
+		};
+		let Some((source, word)) = split else {
 			return act!(cmp:close, cx, false);
 		};
 
 		let ticket = cx.cmp.ticket;
-		if cx.cmp.caches.contains_key(&parent) {
-			return act!(cmp:show, cx, CmpOpt { cache: vec![], cache_name: parent, word, ticket });
+		if cx.cmp.caches.contains_key(&source) {
+			return act!(cmp:show, cx, CmpOpt { cache: vec![], cache_name: source, word, ticket });
 		}
 
+		let cwd = cx.cwd().clone();
 		cx.cmp.handle = Some(tokio::spawn(async move {
-			let mut dir = engine::read_dir(&parent).await?;
-			let mut cache = vec![];
-
-			// "/" is both a directory separator and the root directory per se
-			// As there's no parent directory for the FS root, it is a special case
-			if parent.loc() == "/" {
-				cache.push(CmpItem { name: Default::default(), is_dir: true });
-			}
-
-			while let Ok(Some(dent)) = dir.next().await {
-				if let Ok(ft) = dent.file_type().await {
-					cache.push(CmpItem { name: dent.name().into_owned(), is_dir: ft.is_dir() });
-				}
-			}
+			let mut cache = match &source {
+				CmpSource::Dir(parent) => Self::read_dir(parent).await?,
+				CmpSource::Exec => Self::executables().await,
+				CmpSource::Placeholder => Self::placeholders(),
+				CmpSource::External(line) => Self::external(line, &cwd).await?,
+			};
 
 			if !cache.is_empty() {
-				cache
-					.sort_unstable_by(|a, b| natsort(a.name.encoded_bytes(), b.name.encoded_bytes(), true));
-				CmpProxy::show(CmpOpt { cache, cache_name: parent, word, ticket });
+				if source != CmpSource::Placeholder {
+					cache
+						.sort_unstable_by(|a, b| natsort(a.name.encoded_bytes(), b.name.encoded_bytes(), true));
+				}
+				CmpProxy::show(CmpOpt { cache, cache_name: source, word, ticket });
 			}
 
 			Ok::<_, io::Error>(())
@@ -87,6 +89,107 @@ impl Trigger {
 
 		Some((clean_url(UrlCow::try_from((spec, parent)).ok()?), child))
 	}
+
+	/// Split the last word of a shell command into where its candidates come
+	/// from, and the part of it they're matched against.
+	fn split_shell(s: &str) -> Option<(CmpSource, PathBufDyn)> {
+		let word = LastWord::parse(s);
+		if word.value.is_empty() {
+			return None;
+		} else if word.quote.is_none() && word.value.starts_with('%') {
+			return Some((CmpSource::Placeholder, PathBuf::from(word.value).into()));
+		} else if word.command && !word.value.contains(CROSS_SEPARATOR) {
+			return Some((CmpSource::Exec, PathBuf::from(word.value).into()));
+		} else if word.command || YAZI.input.shell_completer.load().is_empty() {
+			return Self::split_url(&word.value).map(|(parent, child)| (CmpSource::Dir(parent), child));
+		}
+
+		let head = s[word.start..].rfind(CROSS_SEPARATOR).map_or(word.start, |i| word.start + i + 1);
+		let child = word.value.rsplit_once(CROSS_SEPARATOR).map_or(&*word.value, |(_, c)| c);
+		Some((CmpSource::External(s[..head].to_owned()), PathBuf::from(child).into()))
+	}
+
+	async fn read_dir(parent: &UrlBuf) -> io::Result<Vec<CmpItem>> {
+		let mut dir = engine::read_dir(parent).await?;
+		let mut cache = vec![];
+
+		// "/" is both a directory separator and the root directory per se
+		// As there's no parent directory for the FS root, it is a special case
+		if parent.loc() == "/" {
+			cache.push(CmpItem { name: Default::default(), is_dir: true, desc: None });
+		}
+
+		while let Ok(Some(dent)) = dir.next().await {
+			if let Ok(ft) = dent.file_type().await {
+				cache.push(CmpItem { name: dent.name().into_owned(), is_dir: ft.is_dir(), desc: None });
+			}
+		}
+		Ok(cache)
+	}
+
+	async fn executables() -> Vec<CmpItem> {
+		let (mut cache, mut seen) = (vec![], HashSet::new());
+		for dir in env::split_paths(&env::var_os("PATH").unwrap_or_default()) {
+			let Ok(mut it) = tokio::fs::read_dir(&dir).await else { continue };
+			while let Ok(Some(dent)) = it.next_entry().await {
+				if seen.contains(&dent.file_name()) {
+					continue;
+				}
+
+				let Ok(meta) = tokio::fs::metadata(dent.path()).await else { continue };
+				#[cfg(unix)]
+				let exec = std::os::unix::fs::PermissionsExt::mode(&meta.permissions()) & 0o111 != 0;
+				#[cfg(windows)]
+				let exec = true;
+
+				if meta.is_file() && exec {
+					seen.insert(dent.file_name());
+					cache.push(CmpItem { name: dent.file_name().into(), is_dir: false, desc: None });
+				}
+			}
+		}
+		cache
+	}
+
+	fn placeholders() -> Vec<CmpItem> {
+		PLACEHOLDERS
+			.iter()
+			.map(|&(name, desc)| CmpItem {
+				name:   name.into(),
+				is_dir: false,
+				desc:   Some(desc.to_owned()),
+			})
+			.collect()
+	}
+
+	/// Run the external completer, which prints a candidate per line for the
+	/// word after `line`, each optionally followed by a tab and a description.
+	async fn external(line: &str, cwd: &UrlBuf) -> io::Result<Vec<CmpItem>> {
+		let completer = YAZI.input.shell_completer.load_full();
+		let [program, args @ ..] = &completer[..] else { return Ok(vec![]) };
+
+		let mut cmd = tokio::process::Command::new(program);
+		cmd.args(args).arg(line).stdin(Stdio::null()).stderr(Stdio::null()).kill_on_drop(true);
+		if let Some(cwd) = cwd.as_url().as_local() {
+			cmd.current_dir(cwd);
+		}
+
+		// Candidates are whole words, while only their last component is completed
+		let output = cmd.output().await?;
+		let prefix = LastWord::parse(line).value;
+		Ok(
+			String::from_utf8_lossy(&output.stdout)
+				.lines()
+				.filter_map(|l| {
+					let (name, desc) = l.split_once('\t').map_or((l, None), |(n, d)| (n, Some(d.to_owned())));
+					let name = name.strip_prefix(&*prefix).unwrap_or(name);
+					let (name, is_dir) =
+						name.strip_suffix(CROSS_SEPARATOR).map_or((name, false), |n| (n, true));
+					(!name.is_empty()).then(|| CmpItem { name: name.into(), is_dir, desc })
+				})
+				.collect(),
+		)
+	}
 }
 
 #[cfg(test)]
@@ -1 +1 @@
-yazi_macro::mod_flat!(close complete escape recall remember show);
+yazi_macro::mod_flat!(close complete escape recall remember search show);
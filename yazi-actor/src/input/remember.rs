@@ -1,7 +1,7 @@
 use anyhow::Result;
 use yazi_core::input::InputMutGuard;
 use yazi_macro::succ;
-use yazi_parser::VoidForm;
+use yazi_parser::input::RememberForm;
 use yazi_shared::data::Data;
 
 use crate::{Actor, Ctx};
@@ -9,11 +9,15 @@ use crate::{Actor, Ctx};
 pub struct Remember;
 
 impl Actor for Remember {
-	type Form = VoidForm;
+	type Form = RememberForm;
 
 	const NAME: &str = "remember";
 
-	fn act(cx: &mut Ctx, _: Self::Form) -> Result<Data> {
+	fn act(cx: &mut Ctx, form: Self::Form) -> Result<Data> {
+		if let (Some(group), Some(value)) = (&form.group, &form.value) {
+			succ!(cx.input.histories.sync(group, value));
+		}
+
 		let Some(mut input) = cx.input.lock_mut() else {
 			succ!();
 		};
@@ -0,0 +1,32 @@
+use anyhow::Result;
+use yazi_core::input::InputMutGuard;
+use yazi_macro::succ;
+use yazi_parser::VoidForm;
+use yazi_shared::data::Data;
+
+use crate::{Actor, Ctx};
+
+pub struct Search;
+
+impl Actor for Search {
+	type Form = VoidForm;
+
+	const NAME: &str = "search";
+
+	fn act(cx: &mut Ctx, _: Self::Form) -> Result<Data> {
+		let Some(input) = cx.input.lock_mut() else {
+			succ!();
+		};
+
+		match input {
+			InputMutGuard::Main(input) => {
+				let entries = input.histories.get(&input.main.history.name);
+				input.main.search(entries)
+			}
+			InputMutGuard::Alt(input, mut guard) => {
+				let entries = input.histories.get(&guard.history.name);
+				guard.search(entries)
+			}
+		}
+	}
+}
@@ -1,6 +1,6 @@
 extern crate self as yazi_actor;
 
-yazi_macro::mod_pub!(app cmp confirm core help input lives mgr notify pick spot tasks which);
+yazi_macro::mod_pub!(app cmp confirm core help input lives mgr notify palette pane pick spot tasks which);
 
 yazi_macro::mod_flat!(actor context);
 
@@ -115,6 +115,16 @@ impl UserData for File {
 			}
 			Ok(if me.tab.mode.is_select() { 1u8 } else { 2u8 })
 		});
+		methods.add_method("tree", |lua, me, ()| {
+			let Some(node) = me.folder.entries.tree().node(me.idx) else { return Ok(None) };
+			let t = lua.create_table_with_capacity(node.depth, 2)?;
+			for i in 0..node.depth {
+				t.raw_push(node.rail(i))?;
+			}
+			t.raw_set("depth", node.depth)?;
+			t.raw_set("last", node.last)?;
+			Ok(Some(t))
+		});
 		methods.add_method("is_selected", |_, me, ()| Ok(me.tab.selected.contains(&me.url)));
 		methods.add_method("found", |lua, me, ()| {
 			let core: CoreRef = lua.named_registry_value("cx")?;
@@ -133,7 +143,7 @@ impl UserData for File {
 			let Some(finder) = &core.active().finder else {
 				return Ok(None);
 			};
-			if me.folder.url != me.tab.current.url {
+			if me.folder.url != me.tab.current.url || !finder.filter.admits(me) {
 				return Ok(None);
 			}
 			let Some(Some(h)) = me.url.name().map(|s| finder.filter.highlighted(s)) else {
@@ -45,6 +45,7 @@ impl Actor for BulkCreate {
 				cmd:    Splatter::new(&[file]).splat(&opener.run),
 				block:  opener.block,
 				orphan: opener.orphan,
+				remote: false,
 			})
 			.await;
 
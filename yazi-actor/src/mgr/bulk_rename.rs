@@ -63,6 +63,7 @@ impl Actor for BulkRename {
 				cmd: Splatter::new(&[rw.into_file().await?]).splat(&opener.run),
 				block: opener.block,
 				orphan: opener.orphan,
+				remote: false,
 			})
 			.await;
 
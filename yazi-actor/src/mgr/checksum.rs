@@ -0,0 +1,58 @@
+use anyhow::Result;
+use yazi_core::mgr::CdSource;
+use yazi_fs::FilesOp;
+use yazi_macro::{act, succ};
+use yazi_parser::mgr::{ChecksumForm, ChecksumVerifyForm};
+use yazi_proxy::MgrProxy;
+use yazi_scheduler::NotifyProxy;
+use yazi_shared::{data::Data, url::UrlLike};
+
+use crate::{Actor, Ctx};
+
+pub struct Checksum;
+
+impl Actor for Checksum {
+	type Form = ChecksumForm;
+
+	const NAME: &str = "checksum";
+
+	fn act(cx: &mut Ctx, form: Self::Form) -> Result<Data> {
+		act!(mgr:escape_visual, cx)?;
+
+		let targets = if form.hovered {
+			cx.hovered_url().into_iter().cloned().collect()
+		} else {
+			cx.tab().selected_or_hovered_urls().cloned().collect()
+		};
+
+		cx.tasks.checksum_compute(targets, form.algo);
+		succ!();
+	}
+}
+
+// --- Verify
+pub struct ChecksumVerify;
+
+impl Actor for ChecksumVerify {
+	type Form = ChecksumVerifyForm;
+
+	const NAME: &str = "checksum_verify";
+
+	fn act(cx: &mut Ctx, form: Self::Form) -> Result<Data> {
+		let Some(manifest) = cx.hovered_url().cloned() else { succ!() };
+		if manifest.is_search() {
+			succ!(NotifyProxy::push_warn(
+				"Verify checksums",
+				"Cannot verify a manifest in search results"
+			));
+		}
+
+		let listing = manifest.parent().and_then(|p| p.to_search("mismatches").ok()).map(|cwd| {
+			let ((), ticket) = (MgrProxy::cd(&cwd, CdSource::Search), FilesOp::prepare(&cwd));
+			(cwd, ticket)
+		});
+
+		cx.tasks.checksum_verify(manifest, form.algo, listing);
+		succ!();
+	}
+}
@@ -0,0 +1,40 @@
+use anyhow::Result;
+use yazi_macro::{act, render, succ};
+use yazi_parser::VoidForm;
+use yazi_shared::{data::Data, url::UrlLike};
+
+use crate::{Actor, Ctx};
+
+pub struct Collapse;
+
+impl Actor for Collapse {
+	type Form = VoidForm;
+
+	const NAME: &str = "collapse";
+
+	fn act(cx: &mut Ctx, _: Self::Form) -> Result<Data> {
+		let Some(hovered) = cx.hovered().map(|h| h.url.clone()) else { succ!() };
+		let tree = cx.current().entries.tree();
+
+		// Collapse the hovered directory if expanded, otherwise the one containing it
+		let dir = if tree.contains(&hovered) {
+			hovered
+		} else if let Some(parent) = hovered.parent().map(|u| u.to_owned())
+			&& tree.contains(&parent)
+		{
+			parent
+		} else {
+			succ!();
+		};
+
+		let folder = cx.current_mut();
+		folder.entries.collapse(&dir);
+		folder.hover_url(&dir);
+
+		act!(mgr:hover, cx)?;
+		act!(mgr:peek, cx)?;
+		act!(mgr:watch, cx).ok();
+		act!(mgr:update_paged, cx)?;
+		succ!(render!());
+	}
+}
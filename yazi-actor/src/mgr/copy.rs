@@ -1,4 +1,5 @@
 use anyhow::{Result, bail};
+use yazi_fs::DigestLine;
 use yazi_macro::{act, succ};
 use yazi_parser::mgr::CopyForm;
 use yazi_shared::{data::Data, strand::ToStrand, url::UrlLike};
@@ -51,6 +52,11 @@ impl Actor for Copy {
 				"name_without_ext" => {
 					s.extend_from_slice(&form.separator.transform(&f.stem().unwrap_or_default()));
 				}
+				"checksum" => {
+					let Some(hex) = cx.tasks.checksum_digest(&f.url) else { continue };
+					let name = f.name().unwrap_or_default().to_string_lossy();
+					s.extend_from_slice(DigestLine::format(&hex, &name).as_bytes());
+				}
 				_ => bail!("Unknown copy type: {}", form.r#type),
 			};
 			if it.peek().is_some() {
@@ -0,0 +1,126 @@
+use std::time::SystemTime;
+
+use anyhow::Result;
+use hashbrown::HashMap;
+use yazi_core::mgr::CdSource;
+use yazi_fs::FilesOp;
+use yazi_macro::{act, render, succ};
+use yazi_parser::{VoidForm, mgr::{DedupeForm, DedupeSelectForm, DedupeSelectKeep}};
+use yazi_proxy::MgrProxy;
+use yazi_scheduler::NotifyProxy;
+use yazi_shared::{data::Data, url::AsUrl};
+
+use crate::{Actor, Ctx};
+
+pub struct Dedupe;
+
+impl Actor for Dedupe {
+	type Form = DedupeForm;
+
+	const NAME: &str = "dedupe";
+
+	fn act(cx: &mut Ctx, form: Self::Form) -> Result<Data> {
+		let tab = cx.tab();
+		let hidden = tab.pref.show_hidden;
+
+		let r#in = form.r#in.as_ref().map_or_else(|| tab.cwd().as_url(), |u| u.as_url());
+		let Ok(cwd) = r#in.to_search("duplicates") else {
+			succ!(NotifyProxy::push_warn("Dedupe", "Only local filesystem duplicates can be listed"));
+		};
+
+		let ((), ticket) = (MgrProxy::cd(&cwd, CdSource::Search), FilesOp::prepare(&cwd));
+		cx.tasks.dedupe(cwd, hidden, ticket);
+		succ!();
+	}
+}
+
+// --- Select
+pub struct DedupeSelect;
+
+impl Actor for DedupeSelect {
+	type Form = DedupeSelectForm;
+
+	const NAME: &str = "dedupe_select";
+
+	fn act(cx: &mut Ctx, form: Self::Form) -> Result<Data> {
+		act!(mgr:escape_visual, cx)?;
+
+		let groups = cx.tasks.dedupe_groups(cx.cwd());
+		if groups.is_empty() {
+			succ!(NotifyProxy::push_warn("Dedupe", "No duplicate sets found in the current listing"));
+		}
+
+		let tab = cx.tab_mut();
+		let files: HashMap<_, _> = tab.current.entries.iter().map(|f| (&f.url, f)).collect();
+
+		let mut addition = vec![];
+		for group in &groups {
+			let members: Vec<_> = group.iter().filter_map(|u| files.get(u).copied()).collect();
+			let mtimes: Vec<_> = members.iter().map(|f| f.mtime).collect();
+			if let Some(kept) = Self::kept(form.keep, &mtimes) {
+				addition
+					.extend(members.into_iter().enumerate().filter(|&(i, _)| i != kept).map(|(_, f)| f));
+			}
+		}
+
+		let addition: Vec<_> = addition.into_iter().cloned().collect();
+		tab.selected.clear();
+		render!(tab.selected.add_many(&addition) > 0);
+		succ!();
+	}
+}
+
+impl DedupeSelect {
+	/// The member to keep, where one without a modification time is only kept if
+	/// none of them has one.
+	fn kept(keep: DedupeSelectKeep, mtimes: &[Option<SystemTime>]) -> Option<usize> {
+		let known = mtimes.iter().enumerate().filter_map(|(i, t)| Some((i, (*t)?)));
+		let kept = match keep {
+			DedupeSelectKeep::Oldest => known.min_by_key(|&(_, t)| t),
+			DedupeSelectKeep::Newest => known.max_by_key(|&(_, t)| t),
+		};
+		kept.map(|(i, _)| i).or((!mtimes.is_empty()).then_some(0))
+	}
+}
+
+// --- Link
+pub struct DedupeLink;
+
+impl Actor for DedupeLink {
+	type Form = VoidForm;
+
+	const NAME: &str = "dedupe_link";
+
+	fn act(cx: &mut Ctx, _: Self::Form) -> Result<Data> {
+		let groups = cx.tasks.dedupe_groups(cx.cwd());
+		let selected = &cx.tab().selected;
+
+		for group in groups {
+			let Some(kept) = group.iter().find(|&u| !selected.contains(u)) else {
+				NotifyProxy::push_warn("Dedupe", "Skipping a duplicate set with every file selected");
+				continue;
+			};
+			let dups = group.iter().filter(|&u| selected.contains(u)).cloned().collect();
+			cx.tasks.dedupe_link(kept.clone(), dups);
+		}
+		succ!();
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::time::Duration;
+
+	use super::*;
+
+	#[test]
+	fn test_kept() {
+		let t = |s| Some(SystemTime::UNIX_EPOCH + Duration::from_secs(s));
+		let mtimes = [t(5), None, t(1), t(9)];
+
+		assert_eq!(DedupeSelect::kept(DedupeSelectKeep::Oldest, &mtimes), Some(2));
+		assert_eq!(DedupeSelect::kept(DedupeSelectKeep::Newest, &mtimes), Some(3));
+		assert_eq!(DedupeSelect::kept(DedupeSelectKeep::Oldest, &[None, None]), Some(0));
+		assert_eq!(DedupeSelect::kept(DedupeSelectKeep::Newest, &[]), None);
+	}
+}
@@ -3,7 +3,7 @@ use std::{mem, time::{Duration, Instant}};
 use anyhow::Result;
 use futures::{StreamExt, stream::FuturesUnordered};
 use hashbrown::HashSet;
-use yazi_core::mgr::OpenOpt;
+use yazi_core::mgr::{OpenDoOpt, OpenOpt};
 use yazi_fs::{FsAuth, FsUrl, engine::{Engine, local::Local}};
 use yazi_macro::succ;
 use yazi_parser::mgr::DownloadForm;
@@ -36,6 +36,7 @@ impl Actor for Download {
 			let mut wg2 = vec![];
 			let mut urls = Vec::with_capacity(wg1.len());
 			let mut files = Vec::with_capacity(wg1.len());
+			let mut edits = vec![];
 			let mut instant = Instant::now();
 			while let Some((success, url)) = wg1.next().await {
 				if !success {
@@ -43,6 +44,9 @@ impl Actor for Download {
 				}
 
 				let Ok(f) = engine::file(&url).await else { continue };
+				if form.edit {
+					edits.push(f.clone());
+				}
 				urls.push(url);
 				files.push(f);
 
@@ -58,12 +62,15 @@ impl Actor for Download {
 			if futures::future::join_all(wg2).await.into_iter().any(|b| !b) {
 				return;
 			}
-			if form.open && !urls.is_empty() {
+			if !edits.is_empty() {
+				MgrProxy::open_do(OpenDoOpt { cwd, targets: edits, interactive: false, edit: true });
+			} else if form.open && !urls.is_empty() {
 				MgrProxy::open(OpenOpt {
 					cwd:         Some(cwd),
 					targets:     urls,
 					interactive: false,
 					hovered:     false,
+					edit:        false,
 				});
 			}
 		});
@@ -0,0 +1,42 @@
+use anyhow::Result;
+use yazi_fs::{Entries, FilesOp};
+use yazi_macro::{act, render, succ};
+use yazi_parser::mgr::ExpandForm;
+use yazi_shared::data::Data;
+use yazi_vfs::VfsEntries;
+
+use crate::{Actor, Ctx};
+
+pub struct Expand;
+
+impl Actor for Expand {
+	type Form = ExpandForm;
+
+	const NAME: &str = "expand";
+
+	fn act(cx: &mut Ctx, form: Self::Form) -> Result<Data> {
+		if !cx.tab().pref.tree {
+			succ!();
+		}
+
+		let Some(dir) = cx.hovered().filter(|h| h.is_dir()).cloned() else { succ!() };
+		if cx.current().entries.tree().contains(&dir.url) {
+			return if form.toggle { act!(mgr:collapse, cx) } else { succ!() };
+		}
+
+		if !cx.current_mut().entries.expand(dir.clone()) {
+			succ!();
+		}
+
+		tokio::spawn(async move {
+			match Entries::from_dir_bulk(&dir.url).await {
+				Ok(files) => FilesOp::Full(dir, files).emit(),
+				Err(e) => FilesOp::IOErr(dir.url, e.into()).emit(),
+			}
+		});
+
+		act!(mgr:update_paged, cx)?;
+		act!(mgr:watch, cx).ok();
+		succ!(render!());
+	}
+}
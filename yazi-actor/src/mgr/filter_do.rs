@@ -14,7 +14,21 @@ impl Actor for FilterDo {
 	const NAME: &str = "filter_do";
 
 	fn act(cx: &mut Ctx, Self::Form { opt }: Self::Form) -> Result<Data> {
-		let filter = if opt.query.is_empty() { None } else { Some(Filter::new(&opt.query, opt.case)?) };
+		let mut filter =
+			if opt.query.is_empty() { None } else { Some(Filter::new(&opt.query, opt.case)?) };
+
+		let wants_mime = filter.as_ref().is_some_and(Filter::wants_mime);
+		if let Some(f) = &mut filter
+			&& wants_mime
+		{
+			let mimetype = &cx.mgr.mimetype;
+			f.learn_mimes(
+				cx.current()
+					.entries
+					.all()
+					.filter_map(|f| Some((f.url.clone().into(), mimetype.owned(&f.url)?))),
+			);
+		}
 
 		let hovered = cx.hovered().map(|f| f.key().into());
 		cx.current_mut().entries.set_filter(filter);
@@ -25,7 +39,7 @@ impl Actor for FilterDo {
 			act!(mgr:watch, cx).ok();
 		}
 
-		if opt.done {
+		if opt.done || wants_mime {
 			act!(mgr:update_paged, cx)?;
 		}
 
@@ -18,11 +18,22 @@ impl Actor for FindDo {
 			return act!(mgr:escape_find, cx);
 		}
 
-		let finder = Finder::new(&opt.query, opt.case)?;
+		let mut finder = Finder::new(&opt.query, opt.case)?;
 		if matches!(&cx.tab().finder, Some(f) if f.filter == finder.filter) {
 			succ!();
 		}
 
+		let wants_mime = finder.filter.wants_mime();
+		if wants_mime {
+			let mimetype = &cx.mgr.mimetype;
+			finder.filter.learn_mimes(
+				cx.current()
+					.entries
+					.iter()
+					.filter_map(|f| Some((f.url.clone().into(), mimetype.owned(&f.url)?))),
+			);
+		}
+
 		let step = if opt.prev {
 			finder.prev(&cx.current().entries, cx.current().cursor, true)
 		} else {
@@ -34,6 +45,9 @@ impl Actor for FindDo {
 		}
 
 		cx.tab_mut().finder = Some(finder);
+		if wants_mime {
+			act!(mgr:update_paged, cx)?;
+		}
 		succ!(render!());
 	}
 }
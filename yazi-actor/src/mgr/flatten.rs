@@ -0,0 +1,117 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use tokio::pin;
+use tokio_stream::{StreamExt, wrappers::UnboundedReceiverStream};
+use yazi_core::{mgr::CdSource, tab};
+use yazi_fs::{FilesOp, cha::ChaType, file::File};
+use yazi_macro::succ;
+use yazi_parser::mgr::FlattenForm;
+use yazi_proxy::MgrProxy;
+use yazi_scheduler::NotifyProxy;
+use yazi_shared::{data::Data, url::{AsUrl, UrlBuf, UrlLike}};
+use yazi_vfs::Walker;
+
+use crate::{Actor, Ctx};
+
+pub struct Flatten;
+
+impl Actor for Flatten {
+	type Form = FlattenForm;
+
+	const NAME: &str = "flatten";
+
+	fn act(cx: &mut Ctx, form: Self::Form) -> Result<Data> {
+		let tab = cx.tab_mut();
+		if let Some(handle) = tab.search.take() {
+			handle.abort();
+		}
+
+		let root = form.r#in.unwrap_or_else(|| tab.cwd().clone());
+		let root = if root.is_search() { root.to_regular()? } else { root };
+		let Ok(cwd) = root.as_url().to_search("flatten") else {
+			succ!(NotifyProxy::push_warn("Flatten", "Only local filesystems can be flattened"));
+		};
+
+		let hidden = form.hidden.unwrap_or(tab.pref.show_hidden);
+		let flatten = tab::Flatten::new(root.clone(), cwd.clone(), form.depth, hidden, !form.no_ignore);
+		tab.flatten = Some(flatten.clone());
+
+		tab.search = Some(tokio::spawn(async move {
+			let rx = Self::stream(&flatten, root);
+			pin!(rx);
+
+			let ((), ticket) = (MgrProxy::cd(&cwd, CdSource::Search), FilesOp::prepare(&cwd));
+			while let Some(chunk) = rx.next().await {
+				FilesOp::Part(cwd.clone(), chunk, ticket).emit();
+			}
+			FilesOp::Done(File::from_dummy(cwd, Some(ChaType::Dir)), ticket).emit();
+
+			yazi_watcher::MgrProxy::watch();
+			Ok(())
+		}));
+
+		succ!();
+	}
+}
+
+impl Flatten {
+	/// Walk a directory that showed up under an existing listing, adding what's
+	/// found beneath it.
+	pub(super) fn walk(flatten: tab::Flatten, dir: File) {
+		tokio::spawn(async move {
+			let root = dir.url.clone();
+			flatten.accept(dir);
+
+			let rx = Self::stream(&flatten, root);
+			pin!(rx);
+
+			while let Some(chunk) = rx.next().await {
+				FilesOp::Creating(flatten.cwd.clone(), chunk).emit();
+			}
+			yazi_watcher::MgrProxy::watch();
+		});
+	}
+
+	/// Relay an op the watcher reported for one of the walked directories once
+	/// what the ignore rules rule out is dropped from it.
+	pub(super) fn sift(flatten: tab::Flatten, op: FilesOp) {
+		tokio::spawn(async move {
+			let walker = Walker { depth: None, hidden: flatten.hidden, ignore: flatten.ignore };
+			let admits = walker.admission(&flatten.root, op.cwd()).await;
+
+			let op = match op {
+				FilesOp::Creating(cwd, mut files) => {
+					files.retain(&admits);
+					FilesOp::Creating(cwd, files)
+				}
+				FilesOp::Upserting(cwd, mut map) => {
+					map.retain(|_, f| admits(f));
+					FilesOp::Upserting(cwd, map)
+				}
+				op => op,
+			};
+
+			let (relayed, walk) = flatten.relay(&op, &[]);
+			for dir in walk {
+				Self::walk(flatten.clone(), dir);
+			}
+			if let Some(op) = relayed {
+				op.emit();
+			}
+		});
+	}
+
+	fn stream(flatten: &tab::Flatten, dir: UrlBuf) -> impl tokio_stream::Stream<Item = Vec<File>> {
+		let walker = Walker {
+			depth:  flatten.depth.map(|_| flatten.remaining(&dir).unwrap_or_default()),
+			hidden: flatten.hidden,
+			ignore: flatten.ignore,
+		};
+
+		let flatten = flatten.clone();
+		UnboundedReceiverStream::new(walker.walk_within(flatten.root.clone(), dir))
+			.filter_map(move |f| flatten.accept(f))
+			.chunks_timeout(5000, Duration::from_millis(500))
+	}
+}
@@ -0,0 +1,35 @@
+use anyhow::Result;
+use yazi_config::{YAZI, mgr::MgrLayout};
+use yazi_macro::{act, render, succ};
+use yazi_parser::mgr::{LayoutForm, TabCreateForm, TabSwitchForm};
+use yazi_proxy::AppProxy;
+use yazi_shared::data::Data;
+
+use crate::{Actor, Ctx};
+
+pub struct Layout;
+
+impl Actor for Layout {
+	type Form = LayoutForm;
+
+	const NAME: &str = "layout";
+
+	fn act(cx: &mut Ctx, form: Self::Form) -> Result<Data> {
+		let old = YAZI.mgr.layout.get();
+		let new = form.new.unwrap_or(old.toggled());
+		if new == old {
+			succ!();
+		}
+
+		YAZI.mgr.layout.set(new);
+		if new == MgrLayout::Dual && cx.tabs().other().is_none() {
+			// Open the other side in the same directory, and stay on this one
+			let idx = cx.tabs().cursor;
+			act!(mgr:tab_create, cx, TabCreateForm { target: None, current: true, raw: false })?;
+			act!(mgr:tab_switch, cx, TabSwitchForm { step: idx as _, relative: false })?;
+		}
+
+		AppProxy::resize();
+		succ!(render!());
+	}
+}
@@ -2,7 +2,7 @@ use anyhow::Result;
 use yazi_core::mgr::CdSource;
 use yazi_macro::{act, succ};
 use yazi_parser::VoidForm;
-use yazi_shared::{data::Data, url::UrlLike};
+use yazi_shared::{data::Data, url::{UrlBuf, UrlLike}};
 
 use crate::{Actor, Ctx};
 
@@ -17,7 +17,7 @@ impl Actor for Leave {
 		let url = cx
 			.hovered()
 			.and_then(|h| h.url.parent())
-			.filter(|u| u != cx.cwd())
+			.filter(|u| u != cx.cwd() && !cx.current().entries.tree().contains(&UrlBuf::from(*u)))
 			.or_else(|| cx.cwd().parent());
 
 		let Some(mut url) = url else { succ!() };
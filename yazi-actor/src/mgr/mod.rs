@@ -5,24 +5,30 @@ yazi_macro::mod_flat!(
 	bulk_exit
 	bulk_rename
 	cd
+	checksum
 	close
+	collapse
 	copy
 	create
+	dedupe
 	displace
 	displace_do
 	download
 	enter
 	escape
+	expand
 	filter
 	filter_do
 	find
 	find_arrow
 	find_do
+	flatten
 	follow
 	forward
 	hardlink
 	hidden
 	hover
+	layout
 	leave
 	linemode
 	link
@@ -45,11 +51,14 @@ yazi_macro::mod_flat!(
 	suspend
 	tab_close
 	tab_create
+	tab_flip
 	tab_rename
 	tab_swap
 	tab_switch
 	toggle
 	toggle_all
+	transfer
+	tree
 	unyank
 	update_files
 	update_mimes
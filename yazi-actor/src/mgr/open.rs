@@ -1,12 +1,14 @@
+use std::mem;
+
 use anyhow::Result;
 use futures::StreamExt;
 use hashbrown::HashSet;
 use yazi_boot::ARGS;
 use yazi_core::mgr::OpenDoOpt;
 use yazi_macro::{act, succ};
-use yazi_parser::mgr::OpenForm;
+use yazi_parser::mgr::{DownloadForm, OpenForm};
 use yazi_proxy::MgrProxy;
-use yazi_shared::data::Data;
+use yazi_shared::{data::Data, url::UrlLike};
 use yazi_vfs::engine;
 
 use crate::{Actor, Ctx, mgr::Quit};
@@ -38,6 +40,15 @@ impl Actor for Open {
 				cx.tab().selected_or_hovered_urls().cloned().collect()
 			};
 		}
+		if opt.edit {
+			// Remote files are edited on a local copy, which is uploaded afterwards
+			let (urls, local): (Vec<_>, _) =
+				mem::take(&mut opt.targets).into_iter().partition(|u| u.kind().is_remote());
+			opt.targets = local;
+			if !urls.is_empty() {
+				act!(mgr:download, cx, DownloadForm { urls, open: false, edit: true })?;
+			}
+		}
 		if opt.targets.is_empty() {
 			succ!();
 		}
@@ -71,7 +82,12 @@ impl Actor for Open {
 			}
 
 			if !all.is_empty() && scheduler.fetch_mimetype(part).await {
-				MgrProxy::open_do(OpenDoOpt { cwd, targets: all, interactive: opt.interactive });
+				MgrProxy::open_do(OpenDoOpt {
+					cwd,
+					targets: all,
+					interactive: opt.interactive,
+					edit: false,
+				});
 			}
 		});
 		succ!();
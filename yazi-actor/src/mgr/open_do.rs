@@ -1,13 +1,16 @@
-use anyhow::Result;
+use std::{io, path::{Path, PathBuf}, sync::Arc};
+
+use anyhow::{Context, Result};
 use hashbrown::HashMap;
 use indexmap::IndexSet;
-use yazi_config::{YAZI, opener::OpenerRule};
-use yazi_fs::{Splatter, file::File};
+use yazi_config::{YAZI, opener::OpenerRule, popup::PickCfg};
+use yazi_fs::{Splatter, cha::Cha, engine::Attrs, file::File};
 use yazi_macro::succ;
 use yazi_parser::mgr::OpenDoForm;
-use yazi_proxy::{PickProxy, TasksProxy};
-use yazi_scheduler::process::ShellOpt;
-use yazi_shared::{data::Data, url::UrlBuf};
+use yazi_proxy::{PaneProxy, PickProxy, TasksProxy};
+use yazi_scheduler::{NotifyProxy, Scheduler, process::ShellOpt};
+use yazi_shared::{auth::AuthKind, data::Data, url::{AsUrl, UrlBuf, UrlLike}};
+use yazi_vfs::{Stamp, VfsCha, engine};
 
 use crate::{Actor, Ctx};
 
@@ -31,6 +34,8 @@ impl Actor for OpenDo {
 
 		if targets.is_empty() {
 			succ!();
+		} else if opt.edit {
+			succ!(Self::edit(cx.tasks.scheduler.clone(), targets));
 		} else if !opt.interactive {
 			succ!(Self::match_and_open(opt.cwd, targets));
 		}
@@ -69,12 +74,169 @@ impl OpenDo {
 	fn open_with(opener: &OpenerRule, cwd: &UrlBuf, files: &[File]) {
 		let size = if opener.spread { files.len().max(1) } else { 1 };
 		for files in files.chunks(size) {
-			TasksProxy::process_open(ShellOpt {
-				cwd:    cwd.clone(),
-				cmd:    Splatter::new(files).splat(&opener.run),
-				block:  opener.block,
+			// Only openers that opt in run on the remote host, internal ones such as
+			// `download` have to stay local
+			let remote = opener.remote && !opener.orphan && cwd.kind() == AuthKind::Sftp;
+			let opt = ShellOpt {
+				cwd: cwd.clone(),
+				cmd: Splatter::new(files).remote(remote).splat(&opener.run),
+				block: opener.block,
 				orphan: opener.orphan,
+				remote,
+			};
+			if opener.pane { PaneProxy::run(opt) } else { TasksProxy::process_open(opt) }
+		}
+	}
+
+	fn edit(scheduler: Arc<Scheduler>, targets: Vec<(File, &str)>) {
+		let edits: Vec<_> = targets
+			.into_iter()
+			.filter_map(|(file, mime)| {
+				let open = YAZI.open.matches(&file, mime)?;
+				Some((YAZI.opener.block(&open)?, file))
+			})
+			.collect();
+
+		tokio::spawn(async move {
+			for (opener, file) in edits {
+				if let Err(e) = Self::round_trip(&scheduler, &opener, file).await {
+					NotifyProxy::push_error("Edit remote file", e.to_string());
+				}
+			}
+		});
+	}
+
+	/// Edit the local copy of a remote file with a blocking opener, and upload it
+	/// once the opener exits if it has been modified.
+	async fn round_trip(scheduler: &Scheduler, opener: &OpenerRule, file: File) -> Result<()> {
+		let cache = file.cache().context("Cannot determine cache path")?;
+		let cwd = UrlBuf::from(cache.parent().context("Cache path has no parent")?);
+		let mtime = async || tokio::fs::metadata(&cache).await?.modified();
+
+		let before = mtime().await?;
+		scheduler
+			.process_open(ShellOpt {
+				cwd:    cwd.clone(),
+				cmd:    Splatter::new(std::slice::from_ref(&file)).splat(&opener.run),
+				block:  true,
+				orphan: false,
+				remote: false,
+			})
+			.future()
+			.await;
+
+		if mtime().await? == before {
+			return Ok(());
+		}
+
+		let differ = YAZI.opener.all(["diff"]).next();
+		let mut choices = vec![
+			"Keep mine (overwrite the remote file)".to_owned(),
+			"Keep theirs (discard my changes)".to_owned(),
+		];
+		if differ.is_some() {
+			choices.push("Show the differences".to_owned());
+		}
+
+		loop {
+			let url = &file.url;
+			let cha = Cha::from_follow(url, engine::symlink_metadata(url).await?).await;
+			if !Self::diverged(Stamp::read(url).await, cha, url) {
+				return Ok(scheduler.file_upload(file.url));
+			}
+
+			let name = file.name().map(|s| s.to_string_lossy().into_owned()).unwrap_or_default();
+			let pick = PickProxy::show(PickCfg {
+				title: format!("`{name}` has changed remotely:"),
+				..YAZI.pick.open(choices.clone())
 			});
+
+			match (pick.await, &differ) {
+				(Some(0), _) => {
+					Stamp::write(cha, url.as_url()).await?;
+					return Ok(scheduler.file_upload(file.url));
+				}
+				(Some(1), _) => return Ok(_ = scheduler.file_download(file.url)),
+				(Some(_), Some(differ)) => {
+					// Failing to show them shouldn't lose the edits, so ask again
+					if let Err(e) = Self::diff(scheduler, differ, &file, &cache, &cwd).await {
+						NotifyProxy::push_error("Edit remote file", e.to_string());
+					}
+				}
+				(Some(_), None) | (None, _) => {
+					return Ok(NotifyProxy::push_warn(
+						"Edit remote file",
+						format!("Changes to `{name}` were kept locally and not uploaded"),
+					));
+				}
+			}
 		}
 	}
+
+	/// Whether the remote file has changed since it was downloaded, according to
+	/// its `stamp`, which is assumed if there's none.
+	fn diverged(stamp: io::Result<Stamp>, cha: Cha, url: &UrlBuf) -> bool {
+		!stamp.is_ok_and(|s| s.validate(cha, url.as_url()).is_ok())
+	}
+
+	async fn diff(
+		scheduler: &Scheduler,
+		opener: &OpenerRule,
+		file: &File,
+		mine: &Path,
+		cwd: &UrlBuf,
+	) -> Result<()> {
+		let mut theirs = mine.as_os_str().to_owned();
+		theirs.push(".theirs");
+		let theirs = UrlBuf::from(PathBuf::from(theirs));
+		engine::copy(&file.url, &theirs, Attrs::default()).await?;
+
+		let files = [File::from_dummy(mine.to_owned(), None), File::from_dummy(theirs.clone(), None)];
+		scheduler
+			.process_open(ShellOpt {
+				cwd:    cwd.clone(),
+				cmd:    Splatter::new(&files).splat(&opener.run),
+				block:  true,
+				orphan: false,
+				remote: false,
+			})
+			.future()
+			.await;
+
+		engine::remove_file(&theirs).await.ok();
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::time::{Duration, UNIX_EPOCH};
+
+	use yazi_fs::FsHash128;
+	use yazi_shared::url::UrlCow;
+
+	use super::*;
+
+	fn stamp(cha: Cha, name: &str) -> io::Result<Stamp> {
+		let sig = cha.hash_u128_str(&mut [0; 26]).to_owned();
+		Stamp::try_from([sig.as_bytes(), name.as_bytes()].concat())
+	}
+
+	#[test]
+	fn test_diverged() {
+		yazi_shared::init_tests();
+		let url = UrlCow::try_from("sftp://vps//home/alice/notes.md").unwrap().into_owned();
+
+		let cha =
+			Cha { len: 10, mtime: Some(UNIX_EPOCH + Duration::from_secs(1)), ..Default::default() };
+		assert!(!OpenDo::diverged(stamp(cha, "notes.md"), cha, &url));
+
+		// Changed remotely since the download
+		let newer = Cha { mtime: Some(UNIX_EPOCH + Duration::from_secs(2)), ..cha };
+		assert!(OpenDo::diverged(stamp(cha, "notes.md"), newer, &url));
+
+		// The stamp belongs to another file, or there's none
+		assert!(OpenDo::diverged(stamp(cha, "todo.md"), cha, &url));
+		assert!(OpenDo::diverged(Err(io::ErrorKind::NotFound.into()), cha, &url));
+	}
 }
@@ -18,12 +18,19 @@ impl Actor for Paste {
 
 		let dest = tab.cwd();
 		if mgr.yanked.cut {
-			cx.core.tasks.file_move(&mgr.yanked, dest, form.force);
+			cx.core.tasks.file_move(&mgr.yanked, dest, form.force, form.verify, form.preserve);
 
 			mgr.tabs.iter_mut().for_each(|t| _ = t.selected.remove_many(mgr.yanked.urls()));
 			act!(mgr:unyank, cx)
 		} else {
-			succ!(cx.core.tasks.file_copy(&mgr.yanked, dest, form.force, form.follow));
+			succ!(cx.core.tasks.file_copy(
+				&mgr.yanked,
+				dest,
+				form.force,
+				form.follow,
+				form.verify,
+				form.preserve
+			));
 		}
 	}
 }
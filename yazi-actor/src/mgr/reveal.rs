@@ -16,6 +16,18 @@ impl Actor for Reveal {
 	fn act(cx: &mut Ctx, form: Self::Form) -> Result<Data> {
 		let Some((trail, child)) = form.target.pair() else { succ!() };
 
+		// Already visible inline in the tree, no need to cd
+		let entries = &cx.current().entries;
+		if form.target.parent().is_some_and(|p| entries.tree().contains(&p.into()))
+			&& entries.iter().any(|f| f.url == form.target)
+		{
+			render!(cx.current_mut().hover_url(&form.target));
+			act!(mgr:hover, cx)?;
+			act!(mgr:peek, cx)?;
+			act!(mgr:watch, cx).ok();
+			succ!();
+		}
+
 		// Cd to the trail directory
 		act!(mgr:cd, cx, (trail, form.source))?;
 
@@ -54,6 +54,7 @@ impl Actor for SearchDo {
 			handle.abort();
 		}
 
+		tab.flatten = None;
 		let hidden = tab.pref.show_hidden;
 		let r#in = opt.r#in.as_ref().map_or_else(|| tab.cwd().as_url(), |u| u.as_url());
 		let Ok(cwd) = r#in.to_search(&opt.subject) else {
@@ -112,6 +113,7 @@ impl Actor for SearchStop {
 			handle.abort();
 		}
 
+		tab.flatten = None;
 		if !tab.cwd().is_search() {
 			succ!();
 		}
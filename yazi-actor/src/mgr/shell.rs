@@ -6,9 +6,9 @@ use yazi_core::mgr::MgrSnap;
 use yazi_fs::Splatter;
 use yazi_macro::{act, input, succ};
 use yazi_parser::mgr::ShellForm;
-use yazi_proxy::TasksProxy;
+use yazi_proxy::{CmpProxy, PaneProxy, TasksProxy};
 use yazi_scheduler::process::ShellOpt;
-use yazi_shared::data::Data;
+use yazi_shared::{auth::AuthKind, data::Data, url::UrlLike};
 use yazi_widgets::input::InputEvent;
 
 use crate::{Actor, Ctx};
@@ -37,21 +37,30 @@ impl Actor for Shell {
 
 		tokio::spawn(async move {
 			if let Some(mut rx) = input {
-				match rx.recv().await {
-					Some(InputEvent::Submit(e)) => form.run = Cow::Owned(e),
-					_ => return,
+				loop {
+					match rx.recv().await {
+						Some(InputEvent::Submit(e)) => {
+							form.run = Cow::Owned(e);
+							break;
+						}
+						Some(InputEvent::Trigger(before, ticket)) => CmpProxy::trigger(before, ticket),
+						_ => return,
+					}
 				}
 			}
 			if form.run.is_empty() {
 				return;
 			}
 
-			TasksProxy::process_open(ShellOpt {
+			let remote = !form.orphan && cwd.kind() == AuthKind::Sftp;
+			let opt = ShellOpt {
+				cmd: Splatter::new(snap).remote(remote).splat(&*form.run),
 				cwd,
-				cmd: Splatter::new(snap).splat(&*form.run),
 				block: form.block,
 				orphan: form.orphan,
-			});
+				remote,
+			};
+			if form.pane { PaneProxy::run(opt) } else { TasksProxy::process_open(opt) }
 		});
 
 		succ!();
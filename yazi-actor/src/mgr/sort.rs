@@ -25,11 +25,16 @@ impl Actor for Sort {
 		pref.sort_fallback = form.fallback.unwrap_or(pref.sort_fallback);
 
 		let sorter = FilesSorter::from(&*pref);
+		let dedupe = cx.tasks.dedupe_target();
 		let hovered = cx.hovered().map(|f| f.key()).owned();
 		let apply = |f: &mut Folder| {
 			if f.stage == FolderStage::Loading {
 				render!();
 				false
+			} else if dedupe.as_ref() == Some(&f.url) {
+				// Keep the duplicate sets together, in the order they were found
+				f.entries.set_sorter(FilesSorter::default());
+				render_and!(f.entries.catchup_revision())
 			} else {
 				f.entries.set_sorter(sorter);
 				render_and!(f.entries.catchup_revision())
@@ -0,0 +1,28 @@
+use anyhow::Result;
+use yazi_config::{YAZI, mgr::MgrLayout};
+use yazi_macro::{act, succ};
+use yazi_parser::{VoidForm, mgr::{TabCreateForm, TabSwitchForm}};
+use yazi_shared::data::Data;
+
+use crate::{Actor, Ctx};
+
+pub struct TabFlip;
+
+impl Actor for TabFlip {
+	type Form = VoidForm;
+
+	const NAME: &str = "tab_flip";
+
+	fn act(cx: &mut Ctx, _: Self::Form) -> Result<Data> {
+		if YAZI.mgr.layout.get() != MgrLayout::Dual {
+			succ!();
+		}
+
+		let idx = cx.tabs().other_idx();
+		if idx < cx.tabs().len() {
+			act!(mgr:tab_switch, cx, TabSwitchForm { step: idx as _, relative: false })
+		} else {
+			act!(mgr:tab_create, cx, TabCreateForm { target: None, current: true, raw: false })
+		}
+	}
+}
@@ -0,0 +1,45 @@
+use anyhow::Result;
+use yazi_core::mgr::Yanked;
+use yazi_macro::{act, succ};
+use yazi_parser::mgr::TransferForm;
+use yazi_scheduler::NotifyProxy;
+use yazi_shared::data::Data;
+
+use crate::{Actor, Ctx};
+
+pub struct Transfer;
+
+impl Actor for Transfer {
+	type Form = TransferForm;
+
+	const NAME: &str = "transfer";
+
+	fn act(cx: &mut Ctx, form: Self::Form) -> Result<Data> {
+		act!(mgr:escape_visual, cx)?;
+
+		let Some(dest) = form.target.or_else(|| cx.tabs().other().map(|t| t.cwd().clone())) else {
+			succ!(NotifyProxy::push_warn(
+				"No destination",
+				"Specify a target, or switch to the dual layout to use the other side's directory."
+			));
+		};
+
+		let src = Yanked::new(form.cut, cx.tab().selected_or_hovered_files().map(Into::into).collect());
+		if src.is_empty() {
+			succ!();
+		}
+
+		if form.cut {
+			cx.tasks.file_move(&src, &dest, form.force, form.verify, form.preserve);
+
+			let mgr = &mut cx.mgr;
+			mgr.tabs.iter_mut().for_each(|t| _ = t.selected.remove_many(src.urls()));
+			mgr.yanked.remove_many(src.urls());
+			mgr.yanked.catchup_revision(false);
+		} else {
+			cx.tasks.file_copy(&src, &dest, form.force, form.follow, form.verify, form.preserve);
+		}
+
+		act!(mgr:escape_select, cx)
+	}
+}
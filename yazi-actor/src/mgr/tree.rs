@@ -0,0 +1,36 @@
+use anyhow::Result;
+use yazi_macro::{act, render, succ};
+use yazi_parser::VoidForm;
+use yazi_shared::data::Data;
+
+use crate::{Actor, Ctx};
+
+pub struct Tree;
+
+impl Actor for Tree {
+	type Form = VoidForm;
+
+	const NAME: &str = "tree";
+
+	fn act(cx: &mut Ctx, _: Self::Form) -> Result<Data> {
+		let tab = cx.tab_mut();
+		tab.pref.tree = !tab.pref.tree;
+
+		// Folders left in the history keep their expanded directories otherwise,
+		// which would show up again when entering them later.
+		if !tab.pref.tree {
+			tab.current.entries.collapse_all();
+			if let Some(p) = &mut tab.parent {
+				p.entries.collapse_all();
+			}
+			tab.history.values_mut().for_each(|f| _ = f.entries.collapse_all());
+
+			act!(mgr:hover, cx)?;
+			act!(mgr:peek, cx)?;
+			act!(mgr:watch, cx).ok();
+			act!(mgr:update_paged, cx)?;
+		}
+
+		succ!(render!());
+	}
+}
@@ -46,6 +46,7 @@ impl UpdateFiles {
 		let revision = cx.current().entries.revision;
 
 		for op in ops {
+			Self::update_flatten(cx, &op).ok();
 			Self::update_pane(cx, op).ok();
 		}
 
@@ -63,6 +64,9 @@ impl UpdateFiles {
 
 	fn update_pane(cx: &mut Ctx, op: FilesOp) -> Result<Data> {
 		let url = op.cwd();
+		if cx.current().entries.tree().contains(url) {
+			render!(cx.current_mut().entries.update_tree(op.clone()));
+		}
 
 		if url == cx.cwd() {
 			Self::update_current(cx, op)
@@ -75,6 +79,34 @@ impl UpdateFiles {
 		}
 	}
 
+	fn update_flatten(cx: &mut Ctx, op: &FilesOp) -> Result<Data> {
+		let tab = cx.tab();
+		let Some(flatten) = &tab.flatten else { succ!() };
+
+		let listed = if *tab.cwd() == flatten.cwd {
+			&tab.current.entries[..]
+		} else {
+			tab.history.get(&flatten.cwd).map_or(&[][..], |f| &f.entries[..])
+		};
+
+		if flatten.ignore
+			&& flatten.covers(op)
+			&& matches!(op, FilesOp::Creating(..) | FilesOp::Upserting(..))
+		{
+			succ!(super::Flatten::sift(flatten.clone(), op.clone()));
+		}
+
+		let (relayed, walk) = flatten.relay(op, listed);
+		for dir in walk {
+			super::Flatten::walk(flatten.clone(), dir);
+		}
+
+		match relayed {
+			Some(op) => Self::update_pane(cx, op),
+			None => succ!(),
+		}
+	}
+
 	fn update_parent(cx: &mut Ctx, op: FilesOp) -> Result<Data> {
 		let tab = cx.tab_mut();
 
@@ -1,8 +1,8 @@
 use anyhow::Result;
 use hashbrown::HashMap;
-use yazi_macro::{act, render, succ};
+use yazi_macro::{act, render, render_and, succ};
 use yazi_parser::mgr::UpdateMimesForm;
-use yazi_shared::{data::Data, pool::InternStr, url::{AsUrl, UrlCov}};
+use yazi_shared::{data::Data, pool::{InternStr, Symbol}, url::{AsUrl, UrlBufCov, UrlCov}};
 use yazi_watcher::local::LINKED;
 
 use crate::{Actor, Ctx};
@@ -42,7 +42,7 @@ impl Actor for UpdateMimes {
 			.collect();
 
 		let repeek = cx.hovered().is_some_and(|f| updates.contains_key(&UrlCov::new(&f.url)));
-		cx.mgr.mimetype.extend(updates);
+		cx.mgr.mimetype.extend(updates.clone());
 
 		if repeek {
 			act!(mgr:peek, cx)?;
@@ -50,6 +50,26 @@ impl Actor for UpdateMimes {
 		cx.tasks.fetch_paged(&affected, &cx.mgr.mimetype);
 		cx.tasks.preload_paged(&affected, &cx.mgr.mimetype);
 
+		for tab in 0..cx.tabs().len() {
+			cx.with(tab, |cx| Self::refilter(cx, &updates))?;
+		}
 		succ!(render!());
 	}
 }
+
+impl UpdateMimes {
+	/// Re-apply the filter and finder of the tab if they match by mimetype.
+	fn refilter(cx: &mut Ctx, updates: &HashMap<UrlBufCov, Symbol<str>>) -> Result<()> {
+		if let Some(finder) = &mut cx.tab_mut().finder {
+			render!(finder.update_mimes(updates));
+		}
+
+		cx.current_mut().entries.update_mimes(updates);
+		if render_and!(cx.current_mut().entries.catchup_revision()) {
+			act!(mgr:hover, cx)?;
+			act!(mgr:peek, cx)?;
+			act!(mgr:update_paged, cx)?;
+		}
+		Ok(())
+	}
+}
@@ -1,4 +1,5 @@
 use anyhow::Result;
+use yazi_fs::Filter;
 use yazi_macro::succ;
 use yazi_parser::mgr::UpdatePagedForm;
 use yazi_shared::data::Data;
@@ -22,6 +23,16 @@ impl Actor for UpdatePaged {
 			cx.tasks.fetch_paged(targets, &cx.mgr.mimetype);
 			cx.tasks.preload_paged(targets, &cx.mgr.mimetype);
 		}
+
+		// Filtering by mimetype needs them beyond the page as well
+		let tab = cx.tab();
+		if tab.current.entries.filter().is_some_and(Filter::wants_mime)
+			|| tab.finder.as_ref().is_some_and(|f| f.filter.wants_mime())
+		{
+			let unknown: Vec<_> =
+				tab.current.entries.all().filter(|f| !cx.mgr.mimetype.contains(&f.url)).cloned().collect();
+			cx.tasks.fetch_paged(&unknown, &cx.mgr.mimetype);
+		}
 		succ!();
 	}
 }
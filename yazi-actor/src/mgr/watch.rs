@@ -20,9 +20,13 @@ impl Actor for Watch {
 		}
 
 		let tab = tab!(cx);
+		let flatten = tab.flatten.as_ref().filter(|f| f.cwd == tab.current.url).map(|f| f.watches());
+
 		let it = iter::once(&tab.current.file)
 			.chain(tab.hovered_folder().map(|h| &h.file).or(tab.hovered().filter(|f| f.is_dir())))
-			.chain(tab.parent.as_ref().map(|p| &p.file));
+			.chain(tab.parent.as_ref().map(|p| &p.file))
+			.chain(tab.current.entries.tree().dirs())
+			.chain(flatten.iter().flatten());
 
 		succ!(cx.core.mgr.watcher.watch(it));
 	}
@@ -0,0 +1,24 @@
+use anyhow::Result;
+use yazi_macro::{render, succ};
+use yazi_parser::VoidForm;
+use yazi_shared::data::Data;
+
+use crate::{Actor, Ctx};
+
+pub struct Close;
+
+impl Actor for Close {
+	type Form = VoidForm;
+
+	const NAME: &str = "close";
+
+	fn act(cx: &mut Ctx, _: Self::Form) -> Result<Data> {
+		let log = &mut cx.notify.log;
+		if !log.visible {
+			succ!();
+		}
+
+		log.visible = false;
+		succ!(render!());
+	}
+}
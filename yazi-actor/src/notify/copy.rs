@@ -0,0 +1,23 @@
+use anyhow::Result;
+use yazi_macro::succ;
+use yazi_parser::VoidForm;
+use yazi_shared::data::Data;
+use yazi_widgets::CLIPBOARD;
+
+use crate::{Actor, Ctx};
+
+pub struct Copy;
+
+impl Actor for Copy {
+	type Form = VoidForm;
+
+	const NAME: &str = "copy";
+
+	fn act(cx: &mut Ctx, _: Self::Form) -> Result<Data> {
+		let Some(entry) = cx.notify.log.selected() else { succ!() };
+
+		let s = format!("{}\n{}", entry.title, entry.content);
+		futures::executor::block_on(CLIPBOARD.set(s));
+		succ!();
+	}
+}
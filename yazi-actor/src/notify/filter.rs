@@ -0,0 +1,25 @@
+use anyhow::Result;
+use yazi_macro::{render, succ};
+use yazi_parser::notify::FilterForm;
+use yazi_shared::data::Data;
+
+use crate::{Actor, Ctx};
+
+pub struct Filter;
+
+impl Actor for Filter {
+	type Form = FilterForm;
+
+	const NAME: &str = "filter";
+
+	fn act(cx: &mut Ctx, form: Self::Form) -> Result<Data> {
+		let log = &mut cx.notify.log;
+		if log.level == form.level {
+			succ!();
+		}
+
+		log.level = form.level;
+		(log.offset, log.cursor) = (0, 0);
+		succ!(render!());
+	}
+}
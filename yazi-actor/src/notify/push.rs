@@ -17,6 +17,7 @@ impl Actor for Push {
 
 	fn act(cx: &mut Ctx, form: Self::Form) -> Result<Data> {
 		let instant = Instant::now();
+		cx.notify.log.record(&form.opt);
 
 		let mut msg = Message::from(form.opt);
 		msg.timeout += instant - cx.notify.messages.first().map_or(instant, |m| m.instant);
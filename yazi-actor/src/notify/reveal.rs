@@ -0,0 +1,33 @@
+use anyhow::Result;
+use yazi_core::tasks::Tasks;
+use yazi_macro::{act, render, succ};
+use yazi_parser::VoidForm;
+use yazi_scheduler::NotifyProxy;
+use yazi_shared::data::Data;
+
+use crate::{Actor, Ctx};
+
+pub struct Reveal;
+
+impl Actor for Reveal {
+	type Form = VoidForm;
+
+	const NAME: &str = "reveal";
+
+	fn act(cx: &mut Ctx, _: Self::Form) -> Result<Data> {
+		let Some(id) = cx.notify.log.selected().and_then(|e| e.task) else {
+			succ!();
+		};
+
+		// Only the tasks on the first page are listed in the task manager
+		let idx = cx.tasks.scheduler.ongoing.lock().values().position(|t| t.id == id);
+		let Some(idx) = idx.filter(|&i| i < Tasks::limit()) else {
+			succ!(NotifyProxy::push_warn("Notification log", "The task is no longer listed"));
+		};
+
+		act!(notify:close, cx)?;
+		act!(tasks:show, cx)?;
+		cx.tasks.cursor = idx;
+		succ!(render!());
+	}
+}
@@ -0,0 +1,29 @@
+use anyhow::Result;
+use yazi_core::notify::NotifyLog;
+use yazi_macro::{render, succ};
+use yazi_parser::VoidForm;
+use yazi_shared::data::Data;
+
+use crate::{Actor, Ctx};
+
+pub struct Show;
+
+impl Actor for Show {
+	type Form = VoidForm;
+
+	const NAME: &str = "show";
+
+	fn act(cx: &mut Ctx, _: Self::Form) -> Result<Data> {
+		let area = cx.mgr.area(NotifyLog::position());
+
+		let log = &mut cx.notify.log;
+		if log.visible {
+			succ!();
+		}
+
+		log.visible = true;
+		log.height = area.height;
+		(log.offset, log.cursor) = (0, 0);
+		succ!(render!());
+	}
+}
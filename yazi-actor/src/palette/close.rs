@@ -0,0 +1,31 @@
+use std::str::FromStr;
+
+use anyhow::Result;
+use yazi_macro::{emit, render, succ};
+use yazi_parser::palette::CloseForm;
+use yazi_shared::{Source, data::Data, event::Action};
+
+use crate::{Actor, Ctx};
+
+pub struct Close;
+
+impl Actor for Close {
+	type Form = CloseForm;
+
+	const NAME: &str = "close";
+
+	fn act(cx: &mut Ctx, form: Self::Form) -> Result<Data> {
+		let palette = &mut cx.palette;
+		palette.visible = false;
+		render!();
+
+		let Some(item) = palette.selected().filter(|_| form.submit) else { succ!() };
+
+		let mut action = Action::from_str(&format!("{}{}", item.name, palette.args()))?;
+		action.layer = item.layer;
+		action.source = Source::Key;
+
+		emit!(Call(action));
+		succ!();
+	}
+}
@@ -0,0 +1,29 @@
+use anyhow::Result;
+use ratatui_core::layout::Margin;
+use yazi_macro::{render, succ};
+use yazi_parser::VoidForm;
+use yazi_shared::data::Data;
+use yazi_widgets::input::{Input, InputOpt};
+
+use crate::{Actor, Ctx};
+
+pub struct Complete;
+
+impl Actor for Complete {
+	type Form = VoidForm;
+
+	const NAME: &str = "complete";
+
+	fn act(cx: &mut Ctx, _: Self::Form) -> Result<Data> {
+		let Some(value) = cx.palette.completion() else { succ!() };
+		let area = cx.mgr.area(cx.palette.position);
+
+		let cursor = Some(value.chars().count());
+		let palette = &mut cx.palette;
+		palette.input = Input::new(InputOpt::default().with_value(value).with_cursor(cursor))?;
+		palette.input.repos(area.inner(Margin::new(1, 1)));
+
+		palette.filter_apply();
+		succ!(render!());
+	}
+}
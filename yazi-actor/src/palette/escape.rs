@@ -0,0 +1,24 @@
+use anyhow::Result;
+use yazi_macro::{act, render, succ};
+use yazi_parser::VoidForm;
+use yazi_shared::data::Data;
+use yazi_widgets::input::InputMode;
+
+use crate::{Actor, Ctx};
+
+pub struct Escape;
+
+impl Actor for Escape {
+	type Form = VoidForm;
+
+	const NAME: &str = "escape";
+
+	fn act(cx: &mut Ctx, _: Self::Form) -> Result<Data> {
+		if cx.palette.input.mode() == InputMode::Normal {
+			return act!(palette:close, cx);
+		}
+
+		act!(escape, cx.palette.input)?;
+		succ!(render!());
+	}
+}
@@ -0,0 +1 @@
+yazi_macro::mod_flat!(arrow close complete escape registry toggle);
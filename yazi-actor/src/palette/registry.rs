@@ -0,0 +1,140 @@
+use yazi_core::palette::PaletteItem;
+use yazi_macro::act;
+use yazi_parser::FormArgs;
+use yazi_shared::Layer;
+
+use crate::Actor;
+
+macro_rules! entries {
+	($($layer:ident : [$($name:ident),+ $(,)?]),+ $(,)?) => {
+		vec![$($(
+			entry::<act!($layer:$name)>(paste::paste! { Layer::[<$layer:camel>] }),
+		)+)+]
+	};
+}
+
+/// Every action that makes sense to run by hand, for the command palette to
+/// list, leaving out the ones only emitted internally.
+pub(super) fn items() -> Vec<PaletteItem> {
+	entries().into_iter().map(|(layer, name, args)| PaletteItem::new(layer, name, args)).collect()
+}
+
+fn entries() -> Vec<(Layer, &'static str, Vec<&'static str>)> {
+	let mut entries = entries!(
+		app: [plugin, lua, reload],
+		mgr: [
+			cd, refresh, quit, close, suspend, escape,
+			// Navigation
+			arrow, leave, enter, back, forward, reveal, follow, spot, seek, expand, collapse, tree,
+			// Toggle
+			toggle, toggle_all, visual_mode,
+			// Operation
+			open, yank, unyank, paste, transfer, link, hardlink, remove, create, rename, copy, shell,
+			hidden, linemode, search, flatten, dedupe, dedupe_select, dedupe_link, checksum,
+			checksum_verify, bulk_rename, bulk_create,
+			// Filter & find
+			filter, find, find_arrow,
+			// Sorting
+			sort,
+			// Tabs
+			tab_create, tab_rename, tab_close, tab_switch, tab_swap, tab_flip, layout,
+			// VFS
+			download, upload,
+		],
+		tasks: [show, close, arrow, inspect, cancel, throttle],
+		spot: [arrow, close, swipe, copy],
+		pick: [arrow, close],
+		input: [escape, close, recall],
+		confirm: [arrow, close],
+		help: [escape, arrow, close],
+		cmp: [arrow, close],
+		notify: [show, close, arrow, filter, copy, reveal],
+		pane: [toggle, focus, blur, close],
+	);
+
+	// Help is toggled by the same action on every layer
+	entries.extend(
+		[Layer::Mgr, Layer::Tasks, Layer::Spot, Layer::Pick, Layer::Input, Layer::Cmp, Layer::Notify]
+			.map(|layer| (layer, "help", vec![])),
+	);
+	entries
+}
+
+fn entry<A>(layer: Layer) -> (Layer, &'static str, Vec<&'static str>)
+where
+	A: Actor,
+	A::Form: FormArgs,
+{
+	(layer, A::NAME, A::Form::args())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// Actions the executor only runs when emitted internally.
+	const INTERNAL: &[&str] = &[
+		"app:accept_payload",
+		"app:plugin_do",
+		"app:update_progress",
+		"app:deprecate",
+		"app:passthrough",
+		"app:theme",
+		"app:stop",
+		"app:quit",
+		"mgr:update_yanked",
+		"mgr:update_files",
+		"mgr:update_mimes",
+		"mgr:update_paged",
+		"mgr:watch",
+		"mgr:peek",
+		"mgr:update_peeked",
+		"mgr:update_spotted",
+		"mgr:stash",
+		"mgr:open_do",
+		"mgr:remove_do",
+		"mgr:search_do",
+		"mgr:bulk_exit",
+		"mgr:filter_do",
+		"mgr:find_do",
+		"mgr:displace_do",
+		"tasks:output",
+		"tasks:update_succeed",
+		"tasks:spawn",
+		"tasks:process_open",
+		"pick:show",
+		"input:show",
+		"input:remember",
+		"confirm:show",
+		"cmp:trigger",
+		"cmp:show",
+		"which:activate",
+		"which:dismiss",
+		"notify:push",
+		"notify:tick",
+		"palette:escape",
+		"palette:arrow",
+		"palette:close",
+		"palette:complete",
+		"pane:run",
+		"pane:output",
+	];
+
+	#[test]
+	fn test_entries() {
+		let listed: Vec<_> = entries().into_iter().map(|(l, n, _)| format!("{l}:{n}")).collect();
+
+		let mut layer = "";
+		for line in include_str!("../../../yazi-fm/src/executor.rs").lines().map(str::trim) {
+			if let Some((name, _)) = line.strip_prefix("fn ").and_then(|s| s.split_once('(')) {
+				layer = name;
+			} else if let Some(name) = line.strip_prefix("on!(").and_then(|s| s.strip_suffix(");")) {
+				let action = format!("{layer}:{name}");
+				assert!(
+					listed.contains(&action) != INTERNAL.contains(&&*action),
+					"{action} has to be either listed or internal"
+				);
+			}
+		}
+	}
+}
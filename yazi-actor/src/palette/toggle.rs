@@ -0,0 +1,41 @@
+use anyhow::Result;
+use ratatui_core::layout::Margin;
+use yazi_config::popup::Help;
+use yazi_macro::{render, succ};
+use yazi_parser::palette::ToggleForm;
+use yazi_shared::data::Data;
+use yazi_widgets::input::Input;
+
+use super::items;
+use crate::{Actor, Ctx};
+
+pub struct Toggle;
+
+impl Actor for Toggle {
+	type Form = ToggleForm;
+
+	const NAME: &str = "toggle";
+
+	fn act(cx: &mut Ctx, form: Self::Form) -> Result<Data> {
+		let position = Help::position();
+		let area = cx.mgr.area(position);
+		let input_area = area.inner(Margin::new(1, 1));
+
+		let palette = &mut cx.palette;
+		palette.visible = true;
+		palette.layer = form.layer;
+		palette.position = position;
+		palette.height = area.height;
+		palette.items = items();
+
+		palette.input = Input::default();
+		palette.input.repos(input_area);
+
+		palette.keyword.clear();
+		palette.offset = 0;
+		palette.cursor = 0;
+		palette.filter_apply();
+
+		succ!(render!());
+	}
+}
@@ -0,0 +1,23 @@
+use anyhow::Result;
+use yazi_macro::{render, succ};
+use yazi_parser::VoidForm;
+use yazi_shared::data::Data;
+
+use crate::{Actor, Ctx};
+
+pub struct Blur;
+
+impl Actor for Blur {
+	type Form = VoidForm;
+
+	const NAME: &str = "blur";
+
+	fn act(cx: &mut Ctx, _: Self::Form) -> Result<Data> {
+		if !cx.pane.focused {
+			succ!();
+		}
+
+		cx.pane.focused = false;
+		succ!(render!());
+	}
+}
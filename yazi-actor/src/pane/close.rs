@@ -0,0 +1,27 @@
+use anyhow::Result;
+use yazi_macro::{render, succ};
+use yazi_parser::VoidForm;
+use yazi_proxy::AppProxy;
+use yazi_shared::data::Data;
+
+use crate::{Actor, Ctx};
+
+pub struct Close;
+
+impl Actor for Close {
+	type Form = VoidForm;
+
+	const NAME: &str = "close";
+
+	fn act(cx: &mut Ctx, _: Self::Form) -> Result<Data> {
+		let pane = &mut cx.pane;
+		pane.close();
+		pane.title.clear();
+
+		if pane.visible {
+			(pane.visible, pane.focused) = (false, false);
+			AppProxy::resize();
+		}
+		succ!(render!());
+	}
+}
@@ -0,0 +1,23 @@
+use anyhow::Result;
+use yazi_macro::{act, render, succ};
+use yazi_parser::VoidForm;
+use yazi_shared::data::Data;
+
+use crate::{Actor, Ctx};
+
+pub struct Focus;
+
+impl Actor for Focus {
+	type Form = VoidForm;
+
+	const NAME: &str = "focus";
+
+	fn act(cx: &mut Ctx, _: Self::Form) -> Result<Data> {
+		if !cx.pane.visible {
+			return act!(pane:toggle, cx);
+		}
+
+		cx.pane.focused = true;
+		succ!(render!());
+	}
+}
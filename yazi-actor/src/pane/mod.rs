@@ -0,0 +1 @@
+yazi_macro::mod_flat!(blur close focus output run toggle);
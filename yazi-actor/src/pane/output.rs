@@ -0,0 +1,19 @@
+use anyhow::Result;
+use yazi_macro::{render, succ};
+use yazi_parser::pane::OutputForm;
+use yazi_shared::data::Data;
+
+use crate::{Actor, Ctx};
+
+pub struct Output;
+
+impl Actor for Output {
+	type Form = OutputForm;
+
+	const NAME: &str = "output";
+
+	fn act(cx: &mut Ctx, form: Self::Form) -> Result<Data> {
+		let updated = cx.pane.output(form.id, form.out);
+		succ!(render!(updated && cx.pane.visible));
+	}
+}
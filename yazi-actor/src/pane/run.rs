@@ -0,0 +1,28 @@
+use anyhow::Result;
+use yazi_macro::{render, succ};
+use yazi_parser::pane::RunForm;
+use yazi_proxy::AppProxy;
+use yazi_scheduler::NotifyProxy;
+use yazi_shared::data::Data;
+
+use crate::{Actor, Ctx};
+
+pub struct Run;
+
+impl Actor for Run {
+	type Form = RunForm;
+
+	const NAME: &str = "run";
+
+	fn act(cx: &mut Ctx, form: Self::Form) -> Result<Data> {
+		let visible = cx.pane.visible;
+		if let Err(e) = cx.pane.run(form.opt) {
+			succ!(NotifyProxy::push_error("Terminal pane", format!("Failed to run the command:\n{e}")));
+		}
+
+		if !visible {
+			AppProxy::resize();
+		}
+		succ!(render!());
+	}
+}
@@ -0,0 +1,37 @@
+use anyhow::Result;
+use yazi_macro::{act, render, succ};
+use yazi_parser::{VoidForm, pane::RunForm};
+use yazi_proxy::AppProxy;
+use yazi_scheduler::process::ShellOpt;
+use yazi_shared::data::Data;
+
+use crate::{Actor, Ctx};
+
+pub struct Toggle;
+
+impl Actor for Toggle {
+	type Form = VoidForm;
+
+	const NAME: &str = "toggle";
+
+	fn act(cx: &mut Ctx, _: Self::Form) -> Result<Data> {
+		// Start an interactive shell if nothing has been run in the pane yet
+		if !cx.pane.visible && cx.pane.title.is_empty() {
+			let opt = ShellOpt {
+				cwd:    cx.cwd().clone(),
+				cmd:    r#"exec "${SHELL:-sh}""#.into(),
+				block:  false,
+				orphan: false,
+				remote: false,
+			};
+			return act!(pane:run, cx, RunForm { opt });
+		}
+
+		let pane = &mut cx.pane;
+		pane.visible = !pane.visible;
+		pane.focused = pane.visible;
+
+		AppProxy::resize();
+		succ!(render!());
+	}
+}
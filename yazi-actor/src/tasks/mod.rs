@@ -1 +1 @@
-yazi_macro::mod_flat!(arrow cancel close inspect output process_open show spawn update_succeed);
+yazi_macro::mod_flat!(arrow cancel close inspect output process_open show spawn throttle update_succeed);
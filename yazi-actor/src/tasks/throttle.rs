@@ -0,0 +1,23 @@
+use anyhow::Result;
+use yazi_macro::succ;
+use yazi_parser::tasks::ThrottleForm;
+use yazi_shared::data::Data;
+
+use crate::{Actor, Ctx};
+
+pub struct Throttle;
+
+impl Actor for Throttle {
+	type Form = ThrottleForm;
+
+	const NAME: &str = "throttle";
+
+	fn act(cx: &mut Ctx, form: Self::Form) -> Result<Data> {
+		let tasks = &cx.tasks;
+
+		if let Some(id) = tasks.scheduler.ongoing.lock().get_id(tasks.cursor) {
+			yazi_fs::engine::Limiter::limit(id, form.rate);
+		}
+		succ!();
+	}
+}
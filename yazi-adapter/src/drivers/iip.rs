@@ -48,7 +48,8 @@ impl Iip {
 			if img.color().has_alpha() {
 				PngEncoder::new(&mut b).write_image(&img.into_rgba8(), w, h, ExtendedColorType::Rgba8)?;
 			} else {
-				JpegEncoder::new_with_quality(&mut b, YAZI.preview.image_quality).encode_image(&img)?;
+				JpegEncoder::new_with_quality(&mut b, YAZI.preview.image_quality.get())
+					.encode_image(&img)?;
 			};
 
 			let mut buf = String::with_capacity(
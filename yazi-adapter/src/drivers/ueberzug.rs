@@ -58,8 +58,8 @@ impl Ueberzug {
 			.map(|(cw, ch)| Rect {
 				x:      max.x,
 				y:      max.y,
-				width:  max.width.min((w.min(YAZI.preview.max_width as _) as f64 / cw).ceil() as _),
-				height: max.height.min((h.min(YAZI.preview.max_height as _) as f64 / ch).ceil() as _),
+				width:  max.width.min((w.min(YAZI.preview.max_width.get() as _) as f64 / cw).ceil() as _),
+				height: max.height.min((h.min(YAZI.preview.max_height.get() as _) as f64 / ch).ceil() as _),
 			})
 			.unwrap_or(max);
 
@@ -103,8 +103,8 @@ impl Ueberzug {
 	}
 
 	fn adjust_rect(mut rect: Rect) -> Rect {
-		let scale = YAZI.preview.ueberzug_scale;
-		let (x, y, w, h) = YAZI.preview.ueberzug_offset;
+		let scale = YAZI.preview.ueberzug_scale.get();
+		let (x, y, w, h) = YAZI.preview.ueberzug_offset.get();
 
 		rect.x = 0f32.max(rect.x as f32 * scale + x) as u16;
 		rect.y = 0f32.max(rect.y as f32 * scale + y) as u16;
@@ -14,7 +14,8 @@ pub struct Image;
 impl Image {
 	pub async fn precache(src: PathBuf, cache: &Path) -> Result<()> {
 		let (mut img, orientation) = Self::decode_from(src).await?;
-		let (w, h) = Self::flip_size(orientation, (YAZI.preview.max_width, YAZI.preview.max_height));
+		let (w, h) =
+			Self::flip_size(orientation, (YAZI.preview.max_width.get(), YAZI.preview.max_height.get()));
 
 		let buf = tokio::task::spawn_blocking(move || {
 			if img.width() > w || img.height() > h {
@@ -29,7 +30,7 @@ impl Image {
 				let encoder = PngEncoder::new(&mut buf);
 				img.write_with_encoder(encoder)?;
 			} else {
-				let encoder = JpegEncoder::new_with_quality(&mut buf, YAZI.preview.image_quality);
+				let encoder = JpegEncoder::new_with_quality(&mut buf, YAZI.preview.image_quality.get());
 				img.write_with_encoder(encoder)?;
 			}
 
@@ -67,9 +68,9 @@ impl Image {
 		Dimension::cell_size()
 			.map(|(cw, ch)| {
 				let (w, h) = ((rect.width as f64 * cw) as u16, (rect.height as f64 * ch) as u16);
-				(w.min(YAZI.preview.max_width), h.min(YAZI.preview.max_height))
+				(w.min(YAZI.preview.max_width.get()), h.min(YAZI.preview.max_height.get()))
 			})
-			.unwrap_or((YAZI.preview.max_width, YAZI.preview.max_height))
+			.unwrap_or((YAZI.preview.max_width.get(), YAZI.preview.max_height.get()))
 	}
 
 	pub(super) fn pixel_area(size: (u32, u32), rect: Rect) -> Rect {
@@ -84,7 +85,7 @@ impl Image {
 	}
 
 	fn filter() -> FilterType {
-		match YAZI.preview.image_filter.as_str() {
+		match YAZI.preview.image_filter.load().as_str() {
 			"nearest" => FilterType::Nearest,
 			"triangle" => FilterType::Triangle,
 			"catmull-rom" => FilterType::CatmullRom,
@@ -96,14 +97,14 @@ impl Image {
 
 	async fn decode_from(path: PathBuf) -> Result<(DynamicImage, Orientation)> {
 		let mut limits = Limits::no_limits();
-		if YAZI.tasks.image_alloc > 0 {
-			limits.max_alloc = Some(YAZI.tasks.image_alloc as u64);
+		if YAZI.tasks.image_alloc.get() > 0 {
+			limits.max_alloc = Some(YAZI.tasks.image_alloc.get() as u64);
 		}
-		if YAZI.tasks.image_bound[0] > 0 {
-			limits.max_image_width = Some(YAZI.tasks.image_bound[0] as u32);
+		if YAZI.tasks.image_bound.get()[0] > 0 {
+			limits.max_image_width = Some(YAZI.tasks.image_bound.get()[0] as u32);
 		}
-		if YAZI.tasks.image_bound[1] > 0 {
-			limits.max_image_height = Some(YAZI.tasks.image_bound[1] as u32);
+		if YAZI.tasks.image_bound.get()[1] > 0 {
+			limits.max_image_height = Some(YAZI.tasks.image_bound.get()[1] as u32);
 		}
 
 		tokio::task::spawn_blocking(move || {
@@ -6,13 +6,14 @@ use crate::cache::Cache;
 
 impl Cache {
 	pub(crate) fn clear() -> anyhow::Result<()> {
-		if YAZI.preview.cache_dir == *Xdg::temp_dir() {
-			outln!("Clearing cache directory: \n{:?}", YAZI.preview.cache_dir)?;
-			std::fs::remove_dir_all(&YAZI.preview.cache_dir)?;
+		let cache_dir = YAZI.preview.cache_dir.load();
+		if **cache_dir == *Xdg::temp_dir() {
+			outln!("Clearing cache directory: \n{:?}", cache_dir)?;
+			std::fs::remove_dir_all(&**cache_dir)?;
 		} else {
 			outln!(
 				"You've changed the default cache directory, for your data's safety, please clear it manually: \n{:?}",
-				YAZI.preview.cache_dir
+				cache_dir
 			)?;
 		}
 
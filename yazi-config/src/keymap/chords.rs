@@ -3,12 +3,13 @@ use std::{ops::Deref, sync::Arc};
 use arc_swap::ArcSwap;
 use mlua::{ExternalError, ExternalResult, MetaMethod, Table, UserData, UserDataMethods};
 use serde::Deserialize;
+use yazi_codegen::Overlay;
 use yazi_shared::event::Actions;
 use yazi_shim::{arc_swap::{ArcSwapExt, IntoPointee}, mlua::DeserializeOverLua, vec::{IndexAtError, VecExt}};
 
 use crate::keymap::{Chord, ChordArc, ChordIter, ChordMatcher};
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Default, Deserialize, Overlay)]
 #[serde(transparent)]
 pub struct Chords(ArcSwap<Vec<ChordArc>>);
 
@@ -2,14 +2,14 @@ use std::sync::Arc;
 
 use anyhow::{Context, Result};
 use serde::Deserialize;
-use yazi_codegen::{DeserializeOver, DeserializeOver1};
+use yazi_codegen::{DeserializeOver, DeserializeOver1, Overlay};
 use yazi_fs::{Xdg, ok_or_not_found};
 use yazi_shared::Layer;
 
 use super::KeymapSection;
 use crate::keymap::ChordArc;
 
-#[derive(Deserialize, DeserializeOver, DeserializeOver1)]
+#[derive(Deserialize, DeserializeOver, DeserializeOver1, Overlay)]
 pub struct Keymap {
 	pub mgr:     KeymapSection,
 	pub tasks:   KeymapSection,
@@ -18,7 +18,10 @@ pub struct Keymap {
 	pub input:   KeymapSection,
 	pub confirm: KeymapSection,
 	pub help:    KeymapSection,
+	pub palette: KeymapSection,
 	pub cmp:     KeymapSection,
+	pub notify:  KeymapSection,
+	pub pane:    KeymapSection,
 }
 
 impl Keymap {
@@ -43,7 +46,9 @@ impl Keymap {
 			L::Help => &self.help,
 			L::Cmp => &self.cmp,
 			L::Which => None?,
-			L::Notify => None?,
+			L::Notify => &self.notify,
+			L::Palette => &self.palette,
+			L::Pane => &self.pane,
 		})
 	}
 }
@@ -3,13 +3,13 @@ use std::ops::Deref;
 use hashbrown::HashSet;
 use mlua::{UserData, UserDataFields};
 use serde::Deserialize;
-use yazi_codegen::DeserializeOver2;
+use yazi_codegen::{DeserializeOver2, Overlay};
 use yazi_shim::{mlua::UserDataFieldsExt, toml::DeserializeOverHook};
 
 use super::{Chord, Chords, Key};
 use crate::{keymap::ChordArc, mix};
 
-#[derive(Default, Deserialize, DeserializeOver2)]
+#[derive(Default, Deserialize, DeserializeOver2, Overlay)]
 pub struct KeymapSection {
 	keymap:         Chords,
 	#[serde(default)]
@@ -1,4 +1,4 @@
-yazi_macro::mod_pub!(keymap mgr open opener plugin popup preview tasks theme vfs which);
+yazi_macro::mod_pub!(keymap mgr notify open opener plugin popup preview tasks theme vfs which);
 
 yazi_macro::mod_flat!(icon inject layout mixing pattern platform preset priority selectable selector tests yazi);
 
@@ -7,7 +7,7 @@ use std::io::Write;
 use anyhow::Context;
 use yazi_fs::Xdg;
 use yazi_macro::writef;
-use yazi_shim::{cell::{RoCell, SyncCell}, toml::{DeserializeOver, DeserializeOverWith}};
+use yazi_shim::{cell::{RoCell, SyncCell}, serde::Overlay, toml::{DeserializeOver, DeserializeOverWith}};
 use yazi_term::TERM;
 use yazi_tty::{TTY, sequence::SetSgr};
 
@@ -53,6 +53,19 @@ fn try_init(merge: bool) -> anyhow::Result<()> {
 	Ok(())
 }
 
+/// Re-parse `yazi.toml`, `keymap.toml` and `vfs.toml` and apply them over the
+/// current config, which is kept as it is if any of them fails.
+pub fn reload() -> anyhow::Result<()> {
+	let yazi = parse("yazi.toml", Preset::yazi()?.deserialize_over(&yazi::Yazi::read()?))?;
+	let keymap = parse("keymap.toml", Preset::keymap()?.deserialize_over(&keymap::Keymap::read()?))?;
+	let vfs = parse("vfs.toml", Preset::vfs()?.deserialize_over(&vfs::Vfs::read()?))?;
+
+	YAZI.overlay(yazi);
+	KEYMAP.overlay(keymap);
+	VFS.overlay(vfs);
+	Ok(())
+}
+
 pub fn build_flavor(light: bool) -> anyhow::Result<Theme> {
 	let mut preset = Preset::theme(light)?;
 	let theme_str = Theme::read()?;
@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+use strum::{EnumString, IntoStaticStr};
+
+#[derive(
+	Clone, Copy, Debug, Default, Deserialize, EnumString, Eq, IntoStaticStr, PartialEq, Serialize,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum MgrLayout {
+	#[default]
+	Miller,
+	Dual,
+}
+
+impl MgrLayout {
+	pub const fn toggled(self) -> Self {
+		match self {
+			Self::Miller => Self::Dual,
+			Self::Dual => Self::Miller,
+		}
+	}
+}
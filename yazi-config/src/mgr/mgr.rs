@@ -1,15 +1,16 @@
 use anyhow::Result;
 use arc_swap::ArcSwap;
 use serde::{Deserialize, Deserializer, de};
-use yazi_codegen::{DeserializeOver, DeserializeOver2};
+use yazi_codegen::{DeserializeOver, DeserializeOver2, Overlay};
 use yazi_fs::{SortBy, SortFallback};
 use yazi_shim::{arc_swap::IntoPointee, cell::SyncCell};
 
-use super::{MgrRatio, MouseEvents};
+use super::{MgrLayout, MgrRatio, MouseEvents};
 
-#[derive(Debug, Deserialize, DeserializeOver, DeserializeOver2)]
+#[derive(Debug, Deserialize, DeserializeOver, DeserializeOver2, Overlay)]
 pub struct Mgr {
-	pub ratio: SyncCell<MgrRatio>,
+	pub layout: SyncCell<MgrLayout>,
+	pub ratio:  SyncCell<MgrRatio>,
 
 	// Sorting
 	pub sort_by:        SyncCell<SortBy>,
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+use yazi_codegen::{DeserializeOver, DeserializeOver2, Overlay};
+use yazi_shim::cell::SyncCell;
+
+#[derive(Debug, Deserialize, DeserializeOver, DeserializeOver2, Overlay, Serialize)]
+pub struct Notify {
+	/// How many of the latest messages the notification log keeps.
+	pub log_limit: SyncCell<usize>,
+}
@@ -3,14 +3,14 @@ use std::ops::Deref;
 use anyhow::Result;
 use indexmap::IndexSet;
 use serde::Deserialize;
-use yazi_codegen::DeserializeOver2;
+use yazi_codegen::{DeserializeOver2, Overlay};
 use yazi_fs::{cha::ChaType, file::File};
 use yazi_shared::url::AsUrl;
 use yazi_shim::toml::DeserializeOverHook;
 
 use crate::{mix, open::{OpenRule, OpenRuleArc, OpenRules}};
 
-#[derive(Default, Deserialize, DeserializeOver2)]
+#[derive(Default, Deserialize, DeserializeOver2, Overlay)]
 pub struct Open {
 	rules:         OpenRules,
 	#[serde(default)]
@@ -3,13 +3,14 @@ use std::{borrow::Cow, ops::Deref, sync::Arc};
 use arc_swap::ArcSwap;
 use mlua::{ExternalError, ExternalResult, MetaMethod, Table, UserData, UserDataMethods};
 use serde::Deserialize;
+use yazi_codegen::Overlay;
 use yazi_fs::file::File;
 use yazi_shim::{arc_swap::{ArcSwapExt, IntoPointee}, mlua::DeserializeOverLua, vec::{IndexAtError, VecExt}};
 
 use super::OpenRule;
 use crate::{mix, open::{OpenRuleArc, OpenRuleMatcher}};
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Default, Deserialize, Overlay)]
 pub struct OpenRules(ArcSwap<Vec<OpenRuleArc>>);
 
 impl Deref for OpenRules {
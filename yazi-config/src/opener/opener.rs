@@ -4,11 +4,12 @@ use arc_swap::ArcSwap;
 use hashbrown::HashMap;
 use mlua::{ExternalError, FromLua, IntoLua, IntoLuaMulti, LuaString, MetaMethod, UserData, UserDataMethods, Value};
 use serde::{Deserialize, Deserializer};
+use yazi_codegen::Overlay;
 use yazi_shim::{arc_swap::IntoPointee, toml::{DeserializeOverHook, DeserializeOverWith}};
 
 use crate::{open::OpenRule, opener::{OpenerRuleArc, OpenerRuleMatcher, OpenerRulesArc, OpenerRulesMatcher}};
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Overlay)]
 pub struct Opener(ArcSwap<HashMap<String, OpenerRulesArc>>);
 
 impl Deref for Opener {
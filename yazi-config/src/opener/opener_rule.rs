@@ -14,6 +14,10 @@ pub struct OpenerRule {
 	#[serde(default)]
 	pub orphan: bool,
 	#[serde(default)]
+	pub pane:   bool,
+	#[serde(default)]
+	pub remote: bool,
+	#[serde(default)]
 	pub desc:   String,
 	#[serde(default)]
 	pub r#for:  Platform,
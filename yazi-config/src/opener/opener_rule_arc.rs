@@ -38,6 +38,8 @@ impl UserData for OpenerRuleArc {
 		fields.add_cached_field("run", |lua, me| lua.create_string(&*me.run));
 		fields.add_field_method_get("block", |_, me| Ok(me.block));
 		fields.add_field_method_get("orphan", |_, me| Ok(me.orphan));
+		fields.add_field_method_get("pane", |_, me| Ok(me.pane));
+		fields.add_field_method_get("remote", |_, me| Ok(me.remote));
 		fields.add_cached_field("desc", |lua, me| lua.create_string(&*me.desc));
 	}
 }
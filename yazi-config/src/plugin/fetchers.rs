@@ -4,6 +4,7 @@ use anyhow::{Result, ensure};
 use arc_swap::ArcSwap;
 use mlua::{ExternalError, ExternalResult, MetaMethod, UserData, UserDataMethods};
 use serde::Deserialize;
+use yazi_codegen::Overlay;
 use yazi_fs::file::File;
 use yazi_macro::warn;
 use yazi_shim::{arc_swap::{ArcSwapExt, IntoPointee}, vec::VecExt};
@@ -11,7 +12,7 @@ use yazi_shim::{arc_swap::{ArcSwapExt, IntoPointee}, vec::VecExt};
 use super::{Fetcher, MAX_FETCHERS};
 use crate::{mix, plugin::{FetcherArc, FetcherMatcher, fetcher_rev}};
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Default, Deserialize, Overlay)]
 pub struct Fetchers(ArcSwap<Vec<FetcherArc>>);
 
 impl Deref for Fetchers {
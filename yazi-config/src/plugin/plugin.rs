@@ -1,12 +1,12 @@
 use anyhow::Result;
 use serde::{Deserialize, de};
-use yazi_codegen::DeserializeOver2;
+use yazi_codegen::{DeserializeOver2, Overlay};
 use yazi_shim::toml::DeserializeOverHook;
 
 use super::{Fetcher, Fetchers, Preloader, Preloaders, Previewer, Previewers, Spotter, Spotters};
 use crate::{mix, plugin::{FetcherArc, PreloaderArc, PreviewerArc, SpotterArc}};
 
-#[derive(Default, Deserialize, DeserializeOver2)]
+#[derive(Default, Deserialize, DeserializeOver2, Overlay)]
 pub struct Plugin {
 	pub fetchers:     Fetchers,
 	#[serde(default)]
@@ -4,13 +4,14 @@ use anyhow::{Result, ensure};
 use arc_swap::ArcSwap;
 use mlua::{ExternalError, ExternalResult, MetaMethod, UserData, UserDataMethods};
 use serde::Deserialize;
+use yazi_codegen::Overlay;
 use yazi_fs::file::File;
 use yazi_shim::{arc_swap::{ArcSwapExt, IntoPointee}, vec::VecExt};
 
 use super::{MAX_PRELOADERS, Preloader};
 use crate::{mix, plugin::{PreloaderArc, PreloaderMatcher, preloader_rev}};
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Default, Deserialize, Overlay)]
 pub struct Preloaders(ArcSwap<Vec<PreloaderArc>>);
 
 impl Deref for Preloaders {
@@ -3,13 +3,14 @@ use std::{borrow::Cow, ops::Deref, sync::Arc};
 use arc_swap::ArcSwap;
 use mlua::{ExternalError, ExternalResult, MetaMethod, UserData, UserDataMethods};
 use serde::Deserialize;
+use yazi_codegen::Overlay;
 use yazi_fs::file::File;
 use yazi_shim::{arc_swap::{ArcSwapExt, IntoPointee}, vec::{IndexAtError, VecExt}};
 
 use super::Previewer;
 use crate::{mix, plugin::{PreviewerArc, PreviewerMatcher}};
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Default, Deserialize, Overlay)]
 pub struct Previewers(ArcSwap<Vec<PreviewerArc>>);
 
 impl Deref for Previewers {
@@ -3,6 +3,7 @@ use std::{borrow::Cow, ops::Deref, sync::Arc};
 use arc_swap::ArcSwap;
 use mlua::{ExternalError, ExternalResult, MetaMethod, UserData, UserDataMethods};
 use serde::Deserialize;
+use yazi_codegen::Overlay;
 use yazi_fs::file::File;
 use yazi_shared::id::Id;
 use yazi_shim::{arc_swap::{ArcSwapExt, IntoPointee}, vec::{IndexAtError, VecExt}};
@@ -10,7 +11,7 @@ use yazi_shim::{arc_swap::{ArcSwapExt, IntoPointee}, vec::{IndexAtError, VecExt}
 use super::Spotter;
 use crate::{mix, plugin::{SpotterArc, SpotterMatcher}};
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Default, Deserialize, Overlay)]
 pub struct Spotters(ArcSwap<Vec<SpotterArc>>);
 
 impl Deref for Spotters {
@@ -1,48 +1,50 @@
+use arc_swap::ArcSwap;
 use serde::Deserialize;
 use yazi_binding::position::{Offset, Origin, Position};
-use yazi_codegen::{DeserializeOver, DeserializeOver2};
+use yazi_codegen::{DeserializeOver, DeserializeOver2, Overlay};
+use yazi_shim::cell::SyncCell;
 
-#[derive(Deserialize, DeserializeOver, DeserializeOver2)]
+#[derive(Deserialize, DeserializeOver, DeserializeOver2, Overlay)]
 pub struct Confirm {
 	// trash
-	pub trash_title:  String,
-	pub trash_origin: Origin,
-	pub trash_offset: Offset,
+	pub trash_title:  ArcSwap<String>,
+	pub trash_origin: SyncCell<Origin>,
+	pub trash_offset: SyncCell<Offset>,
 
 	// delete
-	pub delete_title:  String,
-	pub delete_origin: Origin,
-	pub delete_offset: Offset,
+	pub delete_title:  ArcSwap<String>,
+	pub delete_origin: SyncCell<Origin>,
+	pub delete_offset: SyncCell<Offset>,
 
 	// overwrite
-	pub overwrite_title:  String,
-	pub overwrite_body:   String,
-	pub overwrite_origin: Origin,
-	pub overwrite_offset: Offset,
+	pub overwrite_title:  ArcSwap<String>,
+	pub overwrite_body:   ArcSwap<String>,
+	pub overwrite_origin: SyncCell<Origin>,
+	pub overwrite_offset: SyncCell<Offset>,
 
 	// quit
-	pub quit_title:  String,
-	pub quit_body:   String,
-	pub quit_origin: Origin,
-	pub quit_offset: Offset,
+	pub quit_title:  ArcSwap<String>,
+	pub quit_body:   ArcSwap<String>,
+	pub quit_origin: SyncCell<Origin>,
+	pub quit_offset: SyncCell<Offset>,
 }
 
 impl Confirm {
 	pub const fn border(&self) -> u16 { 2 }
 
-	pub const fn trash_position(&self) -> Position {
-		Position::new(self.trash_origin, self.trash_offset)
+	pub fn trash_position(&self) -> Position {
+		Position::new(self.trash_origin.get(), self.trash_offset.get())
 	}
 
-	pub const fn delete_position(&self) -> Position {
-		Position::new(self.delete_origin, self.delete_offset)
+	pub fn delete_position(&self) -> Position {
+		Position::new(self.delete_origin.get(), self.delete_offset.get())
 	}
 
-	pub const fn overwrite_position(&self) -> Position {
-		Position::new(self.overwrite_origin, self.overwrite_offset)
+	pub fn overwrite_position(&self) -> Position {
+		Position::new(self.overwrite_origin.get(), self.overwrite_offset.get())
 	}
 
-	pub const fn quit_position(&self) -> Position {
-		Position::new(self.quit_origin, self.quit_offset)
+	pub fn quit_position(&self) -> Position {
+		Position::new(self.quit_origin.get(), self.quit_offset.get())
 	}
 }
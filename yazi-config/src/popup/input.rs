@@ -1,57 +1,60 @@
+use arc_swap::ArcSwap;
 use serde::Deserialize;
 use yazi_binding::position::{Offset, Origin, Position};
-use yazi_codegen::{DeserializeOver, DeserializeOver2};
+use yazi_codegen::{DeserializeOver, DeserializeOver2, Overlay};
 use yazi_shared::{spec::EncodeSpec, url::Url};
+use yazi_shim::cell::SyncCell;
 use yazi_widgets::input::InputOpt;
 
-#[derive(Deserialize, DeserializeOver, DeserializeOver2)]
+#[derive(Deserialize, DeserializeOver, DeserializeOver2, Overlay)]
 pub struct Input {
-	pub cursor_blink: bool,
+	pub cursor_blink: SyncCell<bool>,
 
 	// cd
-	pub cd_title:  String,
-	pub cd_origin: Origin,
-	pub cd_offset: Offset,
+	pub cd_title:  ArcSwap<String>,
+	pub cd_origin: SyncCell<Origin>,
+	pub cd_offset: SyncCell<Offset>,
 
 	// create
-	pub create_title:  [String; 2],
-	pub create_origin: Origin,
-	pub create_offset: Offset,
+	pub create_title:  ArcSwap<[String; 2]>,
+	pub create_origin: SyncCell<Origin>,
+	pub create_offset: SyncCell<Offset>,
 
 	// rename
-	pub rename_title:  String,
-	pub rename_origin: Origin,
-	pub rename_offset: Offset,
+	pub rename_title:  ArcSwap<String>,
+	pub rename_origin: SyncCell<Origin>,
+	pub rename_offset: SyncCell<Offset>,
 
 	// filter
-	pub filter_title:  String,
-	pub filter_origin: Origin,
-	pub filter_offset: Offset,
+	pub filter_title:  ArcSwap<String>,
+	pub filter_origin: SyncCell<Origin>,
+	pub filter_offset: SyncCell<Offset>,
 
 	// find
-	pub find_title:  [String; 2],
-	pub find_origin: Origin,
-	pub find_offset: Offset,
+	pub find_title:  ArcSwap<[String; 2]>,
+	pub find_origin: SyncCell<Origin>,
+	pub find_offset: SyncCell<Offset>,
 
 	// search
-	pub search_title:  String,
-	pub search_origin: Origin,
-	pub search_offset: Offset,
+	pub search_title:  ArcSwap<String>,
+	pub search_origin: SyncCell<Origin>,
+	pub search_offset: SyncCell<Offset>,
 
 	// shell
-	pub shell_title:  [String; 2],
-	pub shell_origin: Origin,
-	pub shell_offset: Offset,
+	pub shell_title:     ArcSwap<[String; 2]>,
+	pub shell_origin:    SyncCell<Origin>,
+	pub shell_offset:    SyncCell<Offset>,
+	pub shell_completer: ArcSwap<Vec<String>>,
 }
 
 impl Input {
 	pub fn cd(&self, cwd: Url) -> InputOpt {
 		InputOpt {
 			name: "cd".to_owned(),
-			title: self.cd_title.clone(),
+			title: (**self.cd_title.load()).clone(),
 			value: if cwd.kind().is_local() { String::new() } else { EncodeSpec(cwd).to_string() },
 			history: "shared".to_owned(),
-			position: Position::new(self.cd_origin, self.cd_offset),
+			position: Position::new(self.cd_origin.get(), self.cd_offset.get()),
 			completion: true,
 			..Default::default()
 		}
@@ -60,9 +63,9 @@ impl Input {
 	pub fn create(&self, dir: bool) -> InputOpt {
 		InputOpt {
 			name: format!("create-{}", if dir { "dir" } else { "file" }),
-			title: self.create_title[dir as usize].clone(),
+			title: self.create_title.load()[dir as usize].clone(),
 			history: "shared".to_owned(),
-			position: Position::new(self.create_origin, self.create_offset),
+			position: Position::new(self.create_origin.get(), self.create_offset.get()),
 			..Default::default()
 		}
 	}
@@ -70,9 +73,9 @@ impl Input {
 	pub fn rename(&self, is_dir: bool) -> InputOpt {
 		InputOpt {
 			name: format!("rename-{}", if is_dir { "dir" } else { "file" }),
-			title: self.rename_title.clone(),
+			title: (**self.rename_title.load()).clone(),
 			history: "shared".to_owned(),
-			position: Position::new(self.rename_origin, self.rename_offset),
+			position: Position::new(self.rename_origin.get(), self.rename_offset.get()),
 			..Default::default()
 		}
 	}
@@ -80,9 +83,9 @@ impl Input {
 	pub fn filter(&self) -> InputOpt {
 		InputOpt {
 			name: "filter".to_owned(),
-			title: self.filter_title.clone(),
+			title: (**self.filter_title.load()).clone(),
 			history: "shared".to_owned(),
-			position: Position::new(self.filter_origin, self.filter_offset),
+			position: Position::new(self.filter_origin.get(), self.filter_offset.get()),
 			realtime: true,
 			..Default::default()
 		}
@@ -91,9 +94,9 @@ impl Input {
 	pub fn find(&self, prev: bool) -> InputOpt {
 		InputOpt {
 			name: "find".to_owned(),
-			title: self.find_title[prev as usize].clone(),
+			title: self.find_title.load()[prev as usize].clone(),
 			history: "shared".to_owned(),
-			position: Position::new(self.find_origin, self.find_offset),
+			position: Position::new(self.find_origin.get(), self.find_offset.get()),
 			realtime: true,
 			..Default::default()
 		}
@@ -102,9 +105,9 @@ impl Input {
 	pub fn search(&self, name: &str) -> InputOpt {
 		InputOpt {
 			name: "search".to_owned(),
-			title: self.search_title.replace("{n}", name),
+			title: self.search_title.load().replace("{n}", name),
 			history: "shared".to_owned(),
-			position: Position::new(self.search_origin, self.search_offset),
+			position: Position::new(self.search_origin.get(), self.search_offset.get()),
 			..Default::default()
 		}
 	}
@@ -112,9 +115,11 @@ impl Input {
 	pub fn shell(&self, block: bool) -> InputOpt {
 		InputOpt {
 			name: "shell".to_owned(),
-			title: self.shell_title[block as usize].clone(),
+			title: self.shell_title.load()[block as usize].clone(),
 			history: "shared".to_owned(),
-			position: Position::new(self.shell_origin, self.shell_offset),
+			position: Position::new(self.shell_origin.get(), self.shell_offset.get()),
+			completion: true,
+			shell: true,
 			..Default::default()
 		}
 	}
@@ -47,7 +47,7 @@ impl ConfirmCfg {
 
 	pub fn trash(files: &[File]) -> Self {
 		Self::new(
-			Self::replace_number(&YAZI.confirm.trash_title, files.len()),
+			Self::replace_number(&YAZI.confirm.trash_title.load(), files.len()),
 			YAZI.confirm.trash_position(),
 			None,
 			Self::truncate_files(files, 100),
@@ -56,7 +56,7 @@ impl ConfirmCfg {
 
 	pub fn delete(files: &[File]) -> Self {
 		Self::new(
-			Self::replace_number(&YAZI.confirm.delete_title, files.len()),
+			Self::replace_number(&YAZI.confirm.delete_title.load(), files.len()),
 			YAZI.confirm.delete_position(),
 			None,
 			Self::truncate_files(files, 100),
@@ -65,18 +65,18 @@ impl ConfirmCfg {
 
 	pub fn overwrite(file: &File) -> Self {
 		Self::new(
-			YAZI.confirm.overwrite_title.clone(),
+			(**YAZI.confirm.overwrite_title.load()).clone(),
 			YAZI.confirm.overwrite_position(),
-			Some(Text::raw(&YAZI.confirm.overwrite_body)),
+			Some(Text::raw((**YAZI.confirm.overwrite_body.load()).clone())),
 			Self::truncate_files(slice::from_ref(file), 1),
 		)
 	}
 
 	pub fn quit(len: usize, names: Vec<String>) -> Self {
 		Self::new(
-			Self::replace_number(&YAZI.confirm.quit_title, len),
+			Self::replace_number(&YAZI.confirm.quit_title.load(), len),
 			YAZI.confirm.quit_position(),
-			Some(Text::raw(&YAZI.confirm.quit_body)),
+			Some(Text::raw((**YAZI.confirm.quit_body.load()).clone())),
 			Self::truncate_lines(names, len, 10),
 		)
 	}
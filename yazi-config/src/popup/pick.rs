@@ -1,30 +1,35 @@
+use arc_swap::ArcSwap;
 use serde::Deserialize;
 use yazi_binding::position::{Offset, Origin, Position};
-use yazi_codegen::{DeserializeOver, DeserializeOver2};
+use yazi_codegen::{DeserializeOver, DeserializeOver2, Overlay};
+use yazi_shim::cell::SyncCell;
 
 use crate::popup::PickCfg;
 
-#[derive(Deserialize, DeserializeOver, DeserializeOver2)]
+#[derive(Deserialize, DeserializeOver, DeserializeOver2, Overlay)]
 pub struct Pick {
 	// open
-	pub open_title:  String,
-	pub open_origin: Origin,
-	pub open_offset: Offset,
+	pub open_title:  ArcSwap<String>,
+	pub open_origin: SyncCell<Origin>,
+	pub open_offset: SyncCell<Offset>,
 }
 
 impl Pick {
 	pub const BORDER: u16 = 2;
 
 	fn max_height(&self, len: usize) -> u16 {
-		self.open_offset.height.min(Self::BORDER.saturating_add(len as u16))
+		self.open_offset.get().height.min(Self::BORDER.saturating_add(len as u16))
 	}
 
 	pub fn open(&self, items: Vec<String>) -> PickCfg {
 		let max_height = self.max_height(items.len());
 		PickCfg {
-			title: self.open_title.clone(),
+			title: (**self.open_title.load()).clone(),
 			items,
-			position: Position::new(self.open_origin, Offset { height: max_height, ..self.open_offset }),
+			position: Position::new(self.open_origin.get(), Offset {
+				height: max_height,
+				..self.open_offset.get()
+			}),
 		}
 	}
 }
@@ -1,94 +1,93 @@
 use std::path::PathBuf;
 
 use anyhow::{Context, Result};
+use arc_swap::ArcSwap;
 use serde::{Deserialize, Deserializer, Serialize};
-use yazi_codegen::DeserializeOver2;
+use yazi_codegen::{DeserializeOver2, Overlay};
 use yazi_fs::{Xdg, create_owned_dir_blocking, path::sanitize_path};
 use yazi_shared::timestamp_us;
-use yazi_shim::{SStr, toml::DeserializeOverHook};
+use yazi_shim::{SStr, arc_swap::IntoPointee, cell::SyncCell, toml::DeserializeOverHook};
 
 use super::PreviewWrap;
 
-#[derive(Debug, Deserialize, DeserializeOver2, Serialize)]
+#[derive(Debug, Deserialize, DeserializeOver2, Overlay, Serialize)]
 pub struct Preview {
-	pub wrap:       PreviewWrap,
-	pub tab_size:   u8,
-	pub max_width:  u16,
-	pub max_height: u16,
+	pub wrap:       SyncCell<PreviewWrap>,
+	pub tab_size:   SyncCell<u8>,
+	pub max_width:  SyncCell<u16>,
+	pub max_height: SyncCell<u16>,
 
 	#[serde(deserialize_with = "deserialize_cache_dir")]
-	pub cache_dir: PathBuf,
+	pub cache_dir: ArcSwap<PathBuf>,
 
 	#[serde(deserialize_with = "deserialize_image_delay")]
-	pub image_delay:   u8,
-	pub image_filter:  String,
+	pub image_delay:   SyncCell<u8>,
+	pub image_filter:  ArcSwap<String>,
 	#[serde(deserialize_with = "deserialize_image_quality")]
-	pub image_quality: u8,
+	pub image_quality: SyncCell<u8>,
 
-	pub ueberzug_scale:  f32,
-	pub ueberzug_offset: (f32, f32, f32, f32),
+	pub ueberzug_scale:  SyncCell<f32>,
+	pub ueberzug_offset: SyncCell<(f32, f32, f32, f32)>,
 }
 
 impl Preview {
 	pub fn tmpfile(&self, prefix: &str) -> PathBuf {
-		self.cache_dir.join(format!("{prefix}-{}", timestamp_us()))
+		self.cache_dir.load().join(format!("{prefix}-{}", timestamp_us()))
 	}
 
 	pub fn indent(&self) -> SStr {
 		#[rustfmt::skip]
 		const TABS: &[&str] = &["", " ", "  ", "   ", "    ", "     ", "      ", "       ", "        ", "         ", "          ", "           ", "            ", "             ", "              ", "               ", "                "];
 
-		if let Some(&s) = TABS.get(self.tab_size as usize) {
-			s.into()
-		} else {
-			" ".repeat(self.tab_size as usize).into()
-		}
+		let size = self.tab_size.get() as usize;
+		if let Some(&s) = TABS.get(size) { s.into() } else { " ".repeat(size).into() }
 	}
 }
 
 impl DeserializeOverHook for Preview {
 	fn deserialize_over_hook(self) -> Result<Self, toml::de::Error> {
-		create_owned_dir_blocking(&self.cache_dir)
-			.context(format!("Failed to create cache directory: {}", self.cache_dir.display()))
+		let cache_dir = self.cache_dir.load();
+		create_owned_dir_blocking(&cache_dir)
+			.context(format!("Failed to create cache directory: {}", cache_dir.display()))
 			.map_err(|err| serde::de::Error::custom(format!("{err:#}")))?;
 
 		Ok(self)
 	}
 }
 
-fn deserialize_cache_dir<'de, D>(deserializer: D) -> Result<PathBuf, D::Error>
+fn deserialize_cache_dir<'de, D>(deserializer: D) -> Result<ArcSwap<PathBuf>, D::Error>
 where
 	D: Deserializer<'de>,
 {
 	let path = PathBuf::deserialize(deserializer)?;
 	if path.as_os_str().is_empty() {
-		Ok(Xdg::temp_dir().to_owned())
+		Ok(Xdg::temp_dir().to_owned().into_pointee())
 	} else {
-		sanitize_path(path).ok_or_else(|| {
+		sanitize_path(path).map(IntoPointee::into_pointee).ok_or_else(|| {
 			serde::de::Error::custom("cache_dir must be either empty or an absolute path.")
 		})
 	}
 }
 
-fn deserialize_image_delay<'de, D>(deserializer: D) -> Result<u8, D::Error>
+fn deserialize_image_delay<'de, D>(deserializer: D) -> Result<SyncCell<u8>, D::Error>
 where
 	D: Deserializer<'de>,
 {
 	let value = u8::deserialize(deserializer)?;
 	if value <= 100 {
-		Ok(value)
+		Ok(value.into())
 	} else {
 		Err(serde::de::Error::custom("image_delay must be between 0 and 100."))
 	}
 }
 
-fn deserialize_image_quality<'de, D>(deserializer: D) -> Result<u8, D::Error>
+fn deserialize_image_quality<'de, D>(deserializer: D) -> Result<SyncCell<u8>, D::Error>
 where
 	D: Deserializer<'de>,
 {
 	let value = u8::deserialize(deserializer)?;
 	if (50..=90).contains(&value) {
-		Ok(value)
+		Ok(value.into())
 	} else {
 		Err(serde::de::Error::custom("image_quality must be between 50 and 90."))
 	}
@@ -2,6 +2,8 @@ use std::num::NonZeroU8;
 
 use serde::Deserialize;
 use yazi_codegen::{DeserializeOver, DeserializeOver2};
+use yazi_fs::engine::Reflink;
+use yazi_shim::{cell::SyncCell, serde::Overlay};
 
 #[derive(Debug, Deserialize, DeserializeOver, DeserializeOver2)]
 pub struct Tasks {
@@ -11,10 +13,29 @@ pub struct Tasks {
 	pub preload_workers: NonZeroU8,
 	pub process_workers: NonZeroU8,
 
-	pub bizarre_retry: NonZeroU8,
+	pub bizarre_retry: SyncCell<NonZeroU8>,
+	pub verify:        SyncCell<bool>,
+	pub reflink:       SyncCell<Reflink>,
+	pub throttle:      SyncCell<u64>,
 
-	pub image_alloc: u32,
-	pub image_bound: [u16; 2],
+	pub image_alloc: SyncCell<u32>,
+	pub image_bound: SyncCell<[u16; 2]>,
 
-	pub suppress_preload: bool,
+	pub suppress_preload: SyncCell<bool>,
+}
+
+impl Overlay for Tasks {
+	/// The number of workers is fixed once they're spawned, so only the options
+	/// read by each task are overlaid.
+	fn overlay(&self, new: Self) {
+		self.bizarre_retry.overlay(new.bizarre_retry);
+		self.verify.overlay(new.verify);
+		self.reflink.overlay(new.reflink);
+		self.throttle.overlay(new.throttle);
+
+		self.image_alloc.overlay(new.image_alloc);
+		self.image_bound.overlay(new.image_bound);
+
+		self.suppress_preload.overlay(new.suppress_preload);
+	}
 }
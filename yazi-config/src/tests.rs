@@ -1,9 +1,14 @@
 use std::sync::OnceLock;
 
-use crate::{Preset, VFS};
+use yazi_shim::toml::DeserializeOver;
+
+use crate::{Preset, VFS, YAZI};
 
 pub fn init_tests() {
 	static INIT: OnceLock<()> = OnceLock::new();
 
-	INIT.get_or_init(|| VFS.init(Preset::vfs().unwrap()));
+	INIT.get_or_init(|| {
+		YAZI.init(Preset::yazi().unwrap().deserialize_over("").unwrap());
+		VFS.init(Preset::vfs().unwrap());
+	});
 }
@@ -237,7 +237,7 @@ impl From<&Input> for yazi_widgets::input::InputStyles {
 		Self {
 			normal:   Some(input.value.get().into()),
 			selected: Some(input.selected.get().into()),
-			blink:    Some(YAZI.input.cursor_blink),
+			blink:    Some(YAZI.input.cursor_blink.get()),
 		}
 	}
 }
@@ -1,18 +1,26 @@
 use std::sync::Arc;
 
+use arc_swap::ArcSwap;
 use hashbrown::HashMap;
 use serde::{Deserialize, Deserializer, de::{MapAccess, Visitor}};
+use yazi_codegen::Overlay;
 use yazi_shared::auth::{Auth, Domain, Scheme};
-use yazi_shim::toml::DeserializeOverWith;
+use yazi_shim::{arc_swap::IntoPointee, toml::DeserializeOverWith};
 
 use super::{DomainSeed, Domains};
 use crate::vfs::Service;
 
-pub struct Authorities(HashMap<Scheme, Domains>);
+#[derive(Overlay)]
+pub struct Authorities(ArcSwap<HashMap<Scheme, Domains>>);
 
 impl Authorities {
-	pub fn service(&self, scheme: &Scheme, domain: &Domain<'_>) -> Option<&Service> {
-		self.0.get(scheme)?.get(domain)
+	pub fn service(&self, scheme: &Scheme, domain: &Domain<'_>) -> Option<&'static Service> {
+		self.0.load().get(scheme)?.get(domain)
+	}
+
+	/// Services of all schemes configured for a specific domain.
+	pub fn services(&self) -> Vec<&'static Service> {
+		self.0.load().values().flat_map(Domains::exact).collect()
 	}
 
 	pub fn auth(&self, scheme: &Scheme, domain: &Domain<'_>) -> Option<Arc<Auth>> {
@@ -42,7 +50,7 @@ impl<'de> Deserialize<'de> for Authorities {
 					let domains = map.next_value_seed(DomainSeed(&scheme))?;
 					authorities.insert(scheme, domains);
 				}
-				Ok(Authorities(authorities))
+				Ok(Authorities(authorities.into_pointee()))
 			}
 		}
 
@@ -51,10 +59,11 @@ impl<'de> Deserialize<'de> for Authorities {
 }
 
 impl DeserializeOverWith for Authorities {
-	fn deserialize_over_with<'de, D: Deserializer<'de>>(mut self, de: D) -> Result<Self, D::Error> {
-		for (scheme, domains) in Self::deserialize(de)?.0 {
-			self.0.entry(scheme).or_default().extend(domains);
+	fn deserialize_over_with<'de, D: Deserializer<'de>>(self, de: D) -> Result<Self, D::Error> {
+		let mut authorities = HashMap::clone(&self.0.load());
+		for (scheme, domains) in Self::deserialize(de)?.0.load().iter() {
+			authorities.entry(scheme.clone()).or_default().extend(domains.clone());
 		}
-		Ok(self)
+		Ok(Self(authorities.into_pointee()))
 	}
 }
@@ -1,22 +1,34 @@
-use std::sync::Arc;
+use std::sync::{Arc, LazyLock, Mutex};
 
 use hashbrown::HashMap;
 use serde::{Deserialize, Deserializer, de::{self, DeserializeSeed, Error}};
 use yazi_shared::auth::{AuthKind, Domain, Scheme};
 
-use super::{Service, ServiceSftp};
+use super::{Service, ServiceFtp, ServiceS3, ServiceSftp, ServiceWebdav};
 
-#[derive(Default)]
+/// Services handed out so far, by scheme and domain.
+static INTERNED: LazyLock<Mutex<HashMap<(Scheme, Domain<'static>), &'static Service>>> =
+	LazyLock::new(Default::default);
+
+/// Services of a scheme, by domain.
+///
+/// Services are leaked since engines hold them as `&'static` for their
+/// connection pools. A reload reuses the ones that didn't change, so only those
+/// replaced by an edit stay around.
+#[derive(Clone, Default)]
 pub struct Domains {
-	exact:    HashMap<Domain<'static>, Service>,
-	catchall: Option<Service>,
+	exact:    HashMap<Domain<'static>, &'static Service>,
+	catchall: Option<&'static Service>,
 }
 
 impl Domains {
-	pub fn get(&self, domain: &Domain<'_>) -> Option<&Service> {
-		self.exact.get(domain.as_ref()).or(self.catchall.as_ref())
+	pub fn get(&self, domain: &Domain<'_>) -> Option<&'static Service> {
+		self.exact.get(domain.as_ref()).or(self.catchall.as_ref()).copied()
 	}
 
+	/// Services configured for a specific domain, leaving out the catch-all.
+	pub fn exact(&self) -> impl Iterator<Item = &'static Service> { self.exact.values().copied() }
+
 	pub fn extend(&mut self, other: Self) {
 		self.exact.extend(other.exact);
 		if other.catchall.is_some() {
@@ -24,44 +36,45 @@ impl Domains {
 		}
 	}
 
-	fn init(&mut self, scheme: &Scheme) {
-		for (domain, service) in &mut self.exact {
-			let kind = service.kind();
-			let auth = Arc::get_mut(service.auth_mut()).expect("unique auth arc");
-
-			auth.kind = kind;
-			auth.scheme = scheme.clone();
-			auth.domain = domain.clone();
-		}
+	fn from_map<E>(scheme: &Scheme, map: HashMap<Domain<'static>, Service>) -> Result<Self, E>
+	where
+		E: de::Error,
+	{
+		let mut domains = Self::default();
+		for (domain, mut service) in map {
+			if !domain.is_catchall() && service.kind() == AuthKind::Hub {
+				return Err(E::custom("Hub services require a `*` catch-all domain"));
+			}
 
-		if let Some(service) = &mut self.catchall {
 			let kind = service.kind();
 			let auth = Arc::get_mut(service.auth_mut()).expect("unique auth arc");
-
 			auth.kind = kind;
 			auth.scheme = scheme.clone();
-			auth.domain = Domain::CATCHALL;
-		}
-	}
+			auth.domain = if domain.is_catchall() { Domain::CATCHALL } else { domain.clone() };
 
-	fn from_map<E>(map: HashMap<Domain<'static>, Service>) -> Result<Self, E>
-	where
-		E: de::Error,
-	{
-		let mut domains = Self::default();
-		for (domain, service) in map {
+			let service = Self::intern(scheme, &domain, service);
 			if domain.is_catchall() {
 				domains.catchall = Some(service);
-				continue;
+			} else {
+				domains.exact.insert(domain, service);
 			}
-
-			if service.kind() == AuthKind::Hub {
-				return Err(E::custom("Hub services require a `*` catch-all domain"));
-			}
-			domains.exact.insert(domain, service);
 		}
 		Ok(domains)
 	}
+
+	fn intern(scheme: &Scheme, domain: &Domain<'static>, service: Service) -> &'static Service {
+		let mut interned = INTERNED.lock().unwrap_or_else(|e| e.into_inner());
+		let key = (scheme.clone(), domain.clone());
+		if let Some(&old) = interned.get(&key)
+			&& *old == service
+		{
+			return old;
+		}
+
+		let service = Box::leak(Box::new(service));
+		interned.insert(key, service);
+		service
+	}
 }
 
 // --- DomainSeed
@@ -71,26 +84,51 @@ impl<'de> DeserializeSeed<'de> for DomainSeed<'_> {
 	type Value = Domains;
 
 	fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
-		let mut domains = match self.0 {
+		Ok(match self.0 {
 			Scheme::Regular | Scheme::Search => {
 				return Err(D::Error::custom("scheme cannot be configured"));
 			}
 			Scheme::Sftp => {
 				let map = HashMap::<Domain<'static>, ServiceSftp>::deserialize(deserializer)?;
 				Domains::from_map(
+					self.0,
 					map.into_iter().map(|(domain, service)| (domain, Service::Sftp(service))).collect(),
 				)?
 			}
+			Scheme::Webdav => {
+				let map = HashMap::<Domain<'static>, ServiceWebdav>::deserialize(deserializer)?;
+				Domains::from_map(
+					self.0,
+					map.into_iter().map(|(domain, service)| (domain, Service::Webdav(service))).collect(),
+				)?
+			}
+			Scheme::S3 => {
+				let map = HashMap::<Domain<'static>, ServiceS3>::deserialize(deserializer)?;
+				Domains::from_map(
+					self.0,
+					map.into_iter().map(|(domain, service)| (domain, Service::S3(service))).collect(),
+				)?
+			}
+			Scheme::Ftp => {
+				let map = HashMap::<Domain<'static>, ServiceFtp>::deserialize(deserializer)?;
+				Domains::from_map(
+					self.0,
+					map.into_iter().map(|(domain, service)| (domain, Service::Ftp(service))).collect(),
+				)?
+			}
 			Scheme::Custom(_) => {
 				let map = HashMap::<Domain<'static>, Service>::deserialize(deserializer)?;
 				if map.values().any(|service| matches!(service, Service::Sftp(_))) {
 					return Err(D::Error::custom("SFTP services must use the `sftp` scheme"));
+				} else if map.values().any(|service| matches!(service, Service::Webdav(_))) {
+					return Err(D::Error::custom("WebDAV services must use the `webdav` scheme"));
+				} else if map.values().any(|service| matches!(service, Service::S3(_))) {
+					return Err(D::Error::custom("S3 services must use the `s3` scheme"));
+				} else if map.values().any(|service| matches!(service, Service::Ftp(_))) {
+					return Err(D::Error::custom("FTP services must use the `ftp` scheme"));
 				}
-				Domains::from_map(map)?
+				Domains::from_map(self.0, map)?
 			}
-		};
-
-		domains.init(self.0);
-		Ok(domains)
+		})
 	}
 }
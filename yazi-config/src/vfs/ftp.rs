@@ -0,0 +1,57 @@
+use std::{ops::Deref, sync::Arc};
+
+use serde::{Deserialize, Serialize};
+use yazi_shared::auth::Auth;
+
+#[derive(Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ServiceFtp {
+	#[serde(skip, default)]
+	pub auth:           Arc<Auth>,
+	pub host:           String,
+	#[serde(default)]
+	pub port:           u16,
+	#[serde(default)]
+	pub user:           String,
+	pub password:       Option<String>,
+	#[serde(default)]
+	pub tls:            FtpTls,
+	#[serde(default)]
+	pub active:         bool,
+	#[serde(default)]
+	pub no_cert_verify: bool,
+	#[serde(default)]
+	pub throttle:       u64,
+}
+
+impl Deref for ServiceFtp {
+	type Target = Auth;
+
+	fn deref(&self) -> &Self::Target { &self.auth }
+}
+
+impl ServiceFtp {
+	/// The configured port, or the default one of the TLS mode.
+	pub fn port(&self) -> u16 {
+		match (self.port, self.tls) {
+			(0, FtpTls::Implicit) => 990,
+			(0, _) => 21,
+			(port, _) => port,
+		}
+	}
+
+	/// The configured user, or `anonymous` if there's none.
+	pub fn user(&self) -> &str { if self.user.is_empty() { "anonymous" } else { &self.user } }
+}
+
+// --- FtpTls
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum FtpTls {
+	/// Plain FTP.
+	#[default]
+	None,
+	/// Upgrade the connection with `AUTH TLS` after connecting.
+	Explicit,
+	/// Speak TLS from the start, usually on port 990.
+	Implicit,
+}
@@ -1 +1 @@
-yazi_macro::mod_flat!(authorities domains lua service sftp vfs);
+yazi_macro::mod_flat!(authorities domains ftp lua s3 service sftp vfs webdav);
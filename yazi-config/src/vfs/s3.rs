@@ -0,0 +1,58 @@
+use std::{ops::Deref, sync::Arc};
+
+use serde::{Deserialize, Deserializer, Serialize, de};
+use yazi_shared::auth::Auth;
+
+#[derive(Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ServiceS3 {
+	#[serde(skip, default)]
+	pub auth:           Arc<Auth>,
+	#[serde(deserialize_with = "deserialize_endpoint")]
+	pub endpoint:       String,
+	#[serde(default = "default_region")]
+	pub region:         String,
+	#[serde(deserialize_with = "deserialize_bucket")]
+	pub bucket:         String,
+	pub access_key:     Option<String>,
+	pub secret_key:     Option<String>,
+	pub session_token:  Option<String>,
+	pub profile:        Option<String>,
+	#[serde(default)]
+	pub virtual_host:   bool,
+	#[serde(default)]
+	pub no_cert_verify: bool,
+	#[serde(default)]
+	pub throttle:       u64,
+}
+
+impl Deref for ServiceS3 {
+	type Target = Auth;
+
+	fn deref(&self) -> &Self::Target { &self.auth }
+}
+
+fn deserialize_endpoint<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	let endpoint = String::deserialize(deserializer)?;
+	if !endpoint.starts_with("http://") && !endpoint.starts_with("https://") {
+		return Err(de::Error::custom("endpoint must start with `http://` or `https://`"));
+	}
+
+	Ok(endpoint.trim_end_matches('/').to_owned())
+}
+
+fn default_region() -> String { "us-east-1".to_owned() }
+
+fn deserialize_bucket<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	let bucket = String::deserialize(deserializer)?;
+	if bucket.is_empty() || bucket.contains('/') {
+		return Err(de::Error::custom("bucket must be a non-empty name without `/`"));
+	}
+
+	Ok(bucket)
+}
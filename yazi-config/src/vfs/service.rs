@@ -3,23 +3,42 @@ use std::sync::Arc;
 use serde::Deserialize;
 use yazi_shared::auth::{Auth, AuthKind};
 
-use super::{ServiceLua, ServiceSftp};
+use super::{ServiceFtp, ServiceLua, ServiceS3, ServiceSftp, ServiceWebdav};
 
 #[derive(Deserialize)]
 #[serde(tag = "kind", rename_all = "kebab-case")]
 pub enum Service {
 	Sftp(ServiceSftp),
+	Webdav(ServiceWebdav),
+	S3(ServiceS3),
+	Ftp(ServiceFtp),
 	Mount(ServiceLua),
 	Hub(ServiceLua),
 	Scope(ServiceLua),
 }
 
+impl PartialEq for Service {
+	// Custom services hold no connections, and their commands can't be compared
+	fn eq(&self, other: &Self) -> bool {
+		match (self, other) {
+			(Self::Sftp(a), Self::Sftp(b)) => a == b,
+			(Self::Webdav(a), Self::Webdav(b)) => a == b,
+			(Self::S3(a), Self::S3(b)) => a == b,
+			(Self::Ftp(a), Self::Ftp(b)) => a == b,
+			_ => false,
+		}
+	}
+}
+
 impl TryFrom<&'static Service> for &'static ServiceSftp {
 	type Error = &'static str;
 
 	fn try_from(value: &'static Service) -> Result<Self, Self::Error> {
 		match value {
 			Service::Sftp(p) => Ok(p),
+			Service::Webdav(_) => Err("expected an SFTP service, got a WebDAV service"),
+			Service::S3(_) => Err("expected an SFTP service, got an S3 service"),
+			Service::Ftp(_) => Err("expected an SFTP service, got an FTP service"),
 			Service::Mount(_) | Service::Hub(_) | Service::Scope(_) => {
 				Err("expected an SFTP service, got a custom VFS service")
 			}
@@ -27,12 +46,63 @@ impl TryFrom<&'static Service> for &'static ServiceSftp {
 	}
 }
 
+impl TryFrom<&'static Service> for &'static ServiceWebdav {
+	type Error = &'static str;
+
+	fn try_from(value: &'static Service) -> Result<Self, Self::Error> {
+		match value {
+			Service::Webdav(p) => Ok(p),
+			Service::Sftp(_) => Err("expected a WebDAV service, got an SFTP service"),
+			Service::S3(_) => Err("expected a WebDAV service, got an S3 service"),
+			Service::Ftp(_) => Err("expected a WebDAV service, got an FTP service"),
+			Service::Mount(_) | Service::Hub(_) | Service::Scope(_) => {
+				Err("expected a WebDAV service, got a custom VFS service")
+			}
+		}
+	}
+}
+
+impl TryFrom<&'static Service> for &'static ServiceS3 {
+	type Error = &'static str;
+
+	fn try_from(value: &'static Service) -> Result<Self, Self::Error> {
+		match value {
+			Service::S3(p) => Ok(p),
+			Service::Sftp(_) => Err("expected an S3 service, got an SFTP service"),
+			Service::Webdav(_) => Err("expected an S3 service, got a WebDAV service"),
+			Service::Ftp(_) => Err("expected an S3 service, got an FTP service"),
+			Service::Mount(_) | Service::Hub(_) | Service::Scope(_) => {
+				Err("expected an S3 service, got a custom VFS service")
+			}
+		}
+	}
+}
+
+impl TryFrom<&'static Service> for &'static ServiceFtp {
+	type Error = &'static str;
+
+	fn try_from(value: &'static Service) -> Result<Self, Self::Error> {
+		match value {
+			Service::Ftp(p) => Ok(p),
+			Service::Sftp(_) => Err("expected an FTP service, got an SFTP service"),
+			Service::Webdav(_) => Err("expected an FTP service, got a WebDAV service"),
+			Service::S3(_) => Err("expected an FTP service, got an S3 service"),
+			Service::Mount(_) | Service::Hub(_) | Service::Scope(_) => {
+				Err("expected an FTP service, got a custom VFS service")
+			}
+		}
+	}
+}
+
 impl TryFrom<&'static Service> for &'static ServiceLua {
 	type Error = &'static str;
 
 	fn try_from(value: &'static Service) -> Result<Self, Self::Error> {
 		match value {
 			Service::Sftp(_) => Err("expected a custom VFS service, got an SFTP service"),
+			Service::Webdav(_) => Err("expected a custom VFS service, got a WebDAV service"),
+			Service::S3(_) => Err("expected a custom VFS service, got an S3 service"),
+			Service::Ftp(_) => Err("expected a custom VFS service, got an FTP service"),
 			Service::Mount(lua) | Service::Hub(lua) | Service::Scope(lua) => Ok(lua),
 		}
 	}
@@ -42,15 +112,32 @@ impl Service {
 	pub fn kind(&self) -> AuthKind {
 		match self {
 			Self::Sftp(_) => AuthKind::Sftp,
+			Self::Webdav(_) => AuthKind::Webdav,
+			Self::S3(_) => AuthKind::S3,
+			Self::Ftp(_) => AuthKind::Ftp,
 			Self::Mount(_) => AuthKind::Mount,
 			Self::Hub(_) => AuthKind::Hub,
 			Self::Scope(_) => AuthKind::Scope,
 		}
 	}
 
+	/// The bandwidth limit of the service in bytes per second, or 0 if none.
+	pub fn throttle(&self) -> u64 {
+		match self {
+			Self::Sftp(sftp) => sftp.throttle,
+			Self::Webdav(webdav) => webdav.throttle,
+			Self::S3(s3) => s3.throttle,
+			Self::Ftp(ftp) => ftp.throttle,
+			Self::Mount(_) | Self::Hub(_) | Self::Scope(_) => 0,
+		}
+	}
+
 	pub fn auth(&self) -> &Arc<Auth> {
 		match self {
 			Self::Sftp(sftp) => &sftp.auth,
+			Self::Webdav(webdav) => &webdav.auth,
+			Self::S3(s3) => &s3.auth,
+			Self::Ftp(ftp) => &ftp.auth,
 			Self::Mount(lua) => &lua.auth,
 			Self::Hub(lua) => &lua.auth,
 			Self::Scope(lua) => &lua.auth,
@@ -60,6 +147,9 @@ impl Service {
 	pub fn auth_mut(&mut self) -> &mut Arc<Auth> {
 		match self {
 			Self::Sftp(sftp) => &mut sftp.auth,
+			Self::Webdav(webdav) => &mut webdav.auth,
+			Self::S3(s3) => &mut s3.auth,
+			Self::Ftp(ftp) => &mut ftp.auth,
 			Self::Mount(lua) => &mut lua.auth,
 			Self::Hub(lua) => &mut lua.auth,
 			Self::Scope(lua) => &mut lua.auth,
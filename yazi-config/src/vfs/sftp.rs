@@ -21,6 +21,10 @@ pub struct ServiceSftp {
 	pub no_cert_verify: bool,
 	#[serde(default = "default_identity_agent", deserialize_with = "deserialize_identity_agent")]
 	pub identity_agent: PathBuf,
+	#[serde(default)]
+	pub allow_exec:     bool,
+	#[serde(default)]
+	pub throttle:       u64,
 }
 
 impl Deref for ServiceSftp {
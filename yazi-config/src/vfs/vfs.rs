@@ -2,7 +2,7 @@ use std::io;
 
 use anyhow::{Context, Result};
 use serde::{Deserialize, Deserializer};
-use yazi_codegen::DeserializeOver;
+use yazi_codegen::{DeserializeOver, Overlay};
 use yazi_fs::{Xdg, ok_or_not_found};
 use yazi_shared::auth::{Auth, AuthInventory};
 use yazi_shim::toml::DeserializeOverWith;
@@ -10,7 +10,7 @@ use yazi_shim::toml::DeserializeOverWith;
 use super::{Authorities, Service};
 use crate::VFS;
 
-#[derive(Deserialize, DeserializeOver)]
+#[derive(Deserialize, DeserializeOver, Overlay)]
 pub struct Vfs {
 	#[serde(flatten)]
 	pub authorities: Authorities,
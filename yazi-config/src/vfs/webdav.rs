@@ -0,0 +1,52 @@
+use std::{ops::Deref, sync::Arc};
+
+use serde::{Deserialize, Deserializer, Serialize, de};
+use yazi_shared::auth::Auth;
+
+#[derive(Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ServiceWebdav {
+	#[serde(skip, default)]
+	pub auth:           Arc<Auth>,
+	#[serde(deserialize_with = "deserialize_url")]
+	pub url:            String,
+	#[serde(default)]
+	pub user:           String,
+	pub password:       Option<String>,
+	#[serde(default)]
+	pub no_cert_verify: bool,
+	#[serde(default)]
+	pub throttle:       u64,
+}
+
+impl Deref for ServiceWebdav {
+	type Target = Auth;
+
+	fn deref(&self) -> &Self::Target { &self.auth }
+}
+
+fn deserialize_url<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	let url = String::deserialize(deserializer)?;
+	if !url.starts_with("http://") && !url.starts_with("https://") {
+		return Err(de::Error::custom("url must start with `http://` or `https://`"));
+	}
+
+	Ok(url.trim_end_matches('/').to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_url() {
+		let url = |s: &str| toml::from_str::<ServiceWebdav>(&format!("url = {s:?}")).map(|c| c.url);
+
+		assert_eq!(url("https://example.com/dav/").unwrap(), "https://example.com/dav");
+		assert_eq!(url("https://example.com//").unwrap(), "https://example.com");
+		assert_eq!(url("http://example.com").unwrap(), "http://example.com");
+		assert!(url("ftp://example.com").is_err());
+	}
+}
@@ -1,13 +1,14 @@
 use serde::{Deserialize, Serialize};
-use yazi_codegen::{DeserializeOver, DeserializeOver2};
+use yazi_codegen::{DeserializeOver, DeserializeOver2, Overlay};
+use yazi_shim::cell::SyncCell;
 
 use super::SortBy;
 
-#[derive(Debug, Deserialize, DeserializeOver, DeserializeOver2, Serialize)]
+#[derive(Debug, Deserialize, DeserializeOver, DeserializeOver2, Overlay, Serialize)]
 pub struct Which {
 	// Sorting
-	pub sort_by:        SortBy,
-	pub sort_sensitive: bool,
-	pub sort_reverse:   bool,
-	pub sort_translit:  bool,
+	pub sort_by:        SyncCell<SortBy>,
+	pub sort_sensitive: SyncCell<bool>,
+	pub sort_reverse:   SyncCell<bool>,
+	pub sort_translit:  SyncCell<bool>,
 }
@@ -2,8 +2,9 @@ use anyhow::{Context, Result};
 use serde::Deserialize;
 use yazi_codegen::{DeserializeOver, DeserializeOver1};
 use yazi_fs::{Xdg, ok_or_not_found};
+use yazi_shim::serde::Overlay;
 
-use crate::{mgr, open, opener, plugin, popup, preview, tasks, which};
+use crate::{mgr, notify, open, opener, plugin, popup, preview, tasks, which};
 
 #[derive(Deserialize, DeserializeOver, DeserializeOver1)]
 pub struct Yazi {
@@ -17,6 +18,7 @@ pub struct Yazi {
 	pub confirm: popup::Confirm,
 	pub pick:    popup::Pick,
 	pub which:   which::Which,
+	pub notify:  notify::Notify,
 }
 
 impl Yazi {
@@ -26,3 +28,59 @@ impl Yazi {
 			.with_context(|| format!("Failed to read config {p:?}"))
 	}
 }
+
+impl Overlay for Yazi {
+	fn overlay(&self, new: Self) {
+		self.mgr.overlay(new.mgr);
+		self.preview.overlay(new.preview);
+		self.opener.overlay(new.opener);
+		self.open.overlay(new.open);
+		self.tasks.overlay(new.tasks);
+		self.plugin.overlay(new.plugin);
+		self.input.overlay(new.input);
+		self.confirm.overlay(new.confirm);
+		self.pick.overlay(new.pick);
+		self.which.overlay(new.which);
+		self.notify.overlay(new.notify);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use yazi_shim::toml::DeserializeOver;
+
+	use super::*;
+	use crate::Preset;
+
+	#[test]
+	fn test_overlay() {
+		yazi_shared::init_tests();
+
+		let yazi = Preset::yazi().unwrap().deserialize_over("").unwrap();
+		let new = Preset::yazi()
+			.unwrap()
+			.deserialize_over(
+				r#"
+				[preview]
+				tab_size = 8
+				[tasks]
+				throttle = 1024
+				file_workers = 9
+				[input]
+				cd_title = "Go to:"
+				[which]
+				sort_reverse = true
+				"#,
+			)
+			.unwrap();
+
+		yazi.overlay(new);
+		assert_eq!(yazi.preview.tab_size.get(), 8);
+		assert_eq!(yazi.tasks.throttle.get(), 1024);
+		assert_eq!(**yazi.input.cd_title.load(), "Go to:");
+		assert!(yazi.which.sort_reverse.get());
+
+		// Workers are already spawned, so their number is kept
+		assert_ne!(yazi.tasks.file_workers.get(), 9);
+	}
+}
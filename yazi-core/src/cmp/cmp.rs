@@ -2,14 +2,14 @@ use std::io;
 
 use hashbrown::HashMap;
 use tokio::task::JoinHandle;
-use yazi_shared::{id::Id, url::UrlBuf};
+use yazi_shared::id::Id;
 use yazi_widgets::Scrollable;
 
-use crate::cmp::CmpItem;
+use crate::cmp::{CmpItem, CmpSource};
 
 #[derive(Default)]
 pub struct Cmp {
-	pub caches:  HashMap<UrlBuf, Vec<CmpItem>>,
+	pub caches:  HashMap<CmpSource, Vec<CmpItem>>,
 	pub matches: Vec<CmpItem>,
 	pub offset:  usize,
 	pub cursor:  usize,
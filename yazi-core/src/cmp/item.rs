@@ -4,4 +4,5 @@ use yazi_shared::strand::StrandBuf;
 pub struct CmpItem {
 	pub name:   StrandBuf,
 	pub is_dir: bool,
+	pub desc:   Option<String>,
 }
@@ -1 +1 @@
-yazi_macro::mod_flat!(cmp item option);
+yazi_macro::mod_flat!(cmp item option source);
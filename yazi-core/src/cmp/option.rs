@@ -1,12 +1,12 @@
 use yazi_macro::impl_data_any;
-use yazi_shared::{id::Id, path::PathBufDyn, url::UrlBuf};
+use yazi_shared::{id::Id, path::PathBufDyn};
 
-use crate::cmp::CmpItem;
+use crate::cmp::{CmpItem, CmpSource};
 
 #[derive(Clone, Debug)]
 pub struct CmpOpt {
 	pub cache:      Vec<CmpItem>,
-	pub cache_name: UrlBuf,
+	pub cache_name: CmpSource,
 	pub word:       PathBufDyn,
 	pub ticket:     Id,
 }
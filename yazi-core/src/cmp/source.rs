@@ -0,0 +1,14 @@
+use yazi_shared::url::UrlBuf;
+
+/// Where the candidates come from, which the completion caches them by.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum CmpSource {
+	/// Entries of a directory.
+	Dir(UrlBuf),
+	/// Executables in `$PATH`.
+	Exec,
+	/// Placeholders of a shell command.
+	Placeholder,
+	/// The external completer, given the command line up to the word.
+	External(String),
+}
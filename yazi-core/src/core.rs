@@ -3,7 +3,7 @@ use yazi_shared::Layer;
 use yazi_shim::ratatui::Padable;
 use yazi_tty::sequence::SetCursorStyle;
 
-use crate::{cmp::Cmp, confirm::Confirm, help::Help, input::{Input, InputGuard}, mgr::Mgr, notify::Notify, pick::Pick, tab::{Folder, Tab}, tasks::Tasks, which::Which};
+use crate::{cmp::Cmp, confirm::Confirm, help::Help, input::{Input, InputGuard}, mgr::Mgr, notify::Notify, palette::Palette, pane::Pane, pick::Pick, tab::{Folder, Tab}, tasks::Tasks, which::Which};
 
 pub struct Core {
 	pub mgr:     Mgr,
@@ -12,9 +12,11 @@ pub struct Core {
 	pub input:   Input,
 	pub confirm: Confirm,
 	pub help:    Help,
+	pub palette: Palette,
 	pub cmp:     Cmp,
 	pub which:   Which,
 	pub notify:  Notify,
+	pub pane:    Pane,
 }
 
 impl Core {
@@ -26,9 +28,11 @@ impl Core {
 			input:   Default::default(),
 			confirm: Default::default(),
 			help:    Default::default(),
+			palette: Default::default(),
 			cmp:     Default::default(),
 			which:   Default::default(),
 			notify:  Default::default(),
+			pane:    Default::default(),
 		}
 	}
 
@@ -38,6 +42,11 @@ impl Core {
 			return Some((Position { x: x + cursor, y }, self.help.cursor_shape()?));
 		}
 
+		if let Some(cursor) = self.palette.cursor() {
+			let Rect { x, y, .. } = self.mgr.area(self.palette.position).padding(self.palette.padding());
+			return Some((Position { x: x + cursor, y }, self.palette.cursor_shape()?));
+		}
+
 		if let Some(guard) = self.input.lock() {
 			let Rect { x, y, .. } = match &guard {
 				InputGuard::Main(_) => self.mgr.area(self.input.position()?).padding(self.input.padding()),
@@ -46,6 +55,13 @@ impl Core {
 			return Some((Position { x: x + guard.cursor(), y }, guard.cursor_shape()));
 		}
 
+		if self.pane.focused
+			&& let Some(Rect { x, y, .. }) = self.pane.inner()
+			&& let Some((cx, cy)) = self.pane.screen.cursor()
+		{
+			return Some((Position { x: x + cx, y: y + cy }, SetCursorStyle::Default));
+		}
+
 		None
 	}
 
@@ -54,12 +70,18 @@ impl Core {
 			Layer::Which
 		} else if self.cmp.visible {
 			Layer::Cmp
+		} else if self.palette.visible {
+			Layer::Palette
 		} else if self.help.visible {
 			Layer::Help
 		} else if self.confirm.visible {
 			Layer::Confirm
 		} else if self.input.focus() {
 			Layer::Input
+		} else if self.pane.focused {
+			Layer::Pane
+		} else if self.notify.log.visible {
+			Layer::Notify
 		} else if self.pick.visible {
 			Layer::Pick
 		} else if self.active().spot.visible() {
@@ -95,11 +95,11 @@ impl Highlighter {
 	}
 
 	fn process_plain(&self, buf: &[u8], i: &mut usize, lines: &mut Vec<Line>) -> Result<bool> {
-		let b = replace_to_printable(buf, true, YAZI.preview.tab_size, false);
+		let b = replace_to_printable(buf, true, YAZI.preview.tab_size.get(), false);
 		let s = String::from_utf8_lossy(&b);
 
-		let mut it = LineIter::source(&s, YAZI.preview.tab_size);
-		if let Some(wrap) = YAZI.preview.wrap.into() {
+		let mut it = LineIter::source(&s, YAZI.preview.tab_size.get());
+		if let Some(wrap) = YAZI.preview.wrap.get().into() {
 			it = it.wrapped(wrap, self.size.width);
 		}
 
@@ -126,8 +126,8 @@ impl Highlighter {
 		let s = String::from_utf8_lossy(buf);
 		let line = [Self::to_line_widget(h.highlight_line(&s, self.syntaxes)?)];
 
-		let mut it = LineIter::parsed(&line, YAZI.preview.tab_size);
-		if let Some(wrap) = YAZI.preview.wrap.into() {
+		let mut it = LineIter::parsed(&line, YAZI.preview.tab_size.get());
+		if let Some(wrap) = YAZI.preview.wrap.get().into() {
 			it = it.wrapped(wrap, self.size.width);
 		}
 
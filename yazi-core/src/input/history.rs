@@ -1,31 +1,199 @@
-use hashbrown::HashMap;
+use std::{io, mem, path::PathBuf};
 
+use hashbrown::{HashMap, HashSet};
+use tokio::{io::AsyncWriteExt, sync::oneshot::{self, error::TryRecvError}};
+use yazi_dds::Pubsub;
+use yazi_fs::{Xdg, engine::{Engine, FileBuilder, local::{Demand, Local}}};
+use yazi_macro::log_if_err;
+
+const MAX_ENTRIES: usize = 1000;
+
+/// Histories of the input, by group, each saved to a file in the state dir
+/// with one entry per line, oldest first.
+///
+/// New entries are appended to the file, so that concurrent instances don't
+/// overwrite each other, and duplicates are dropped the next time it's loaded.
 #[derive(Default)]
 pub struct InputHistories {
-	inner: HashMap<String, Vec<String>>,
+	inner: HashMap<String, Group>,
+}
+
+struct Group {
+	entries: Vec<String>,
+	// The entries of the file, while it's being read in the background
+	loading: Option<oneshot::Receiver<Vec<String>>>,
 }
 
 impl InputHistories {
-	pub fn get(&self, group: &str) -> &[String] {
-		self.inner.get(group).map(Vec::as_slice).unwrap_or(&[])
+	pub fn get(&mut self, group: &str) -> &[String] {
+		if group.is_empty() { &[] } else { self.entries(group) }
+	}
+
+	/// Start reading the history of `group` in the background, so it's ready by
+	/// the time it's needed.
+	pub fn preload(&mut self, group: &str) {
+		if !group.is_empty() {
+			self.entries(group);
+		}
 	}
 
+	/// Remember a value submitted in this instance, saving it and sharing it
+	/// with the other instances.
 	pub fn remember(&mut self, group: &str, value: &str) -> bool {
+		if !self.sync(group, value) {
+			return false;
+		}
+
+		Self::save(group, value);
+		log_if_err!(Pubsub::pub_after_history(group, value));
+		true
+	}
+
+	/// Remember a value submitted in another instance, which has saved it
+	/// already.
+	pub fn sync(&mut self, group: &str, value: &str) -> bool {
 		if group.is_empty() || value.is_empty() {
 			return false;
 		}
 
-		let entries = self.inner.entry_ref(group).or_default();
+		let entries = self.entries(group);
 		if entries.last().is_some_and(|last| last == value) {
 			return false;
 		}
 
-		entries.retain(|entry| entry != value);
-		entries.push(value.to_owned());
-		if entries.len() > 20 {
-			entries.drain(..entries.len() - 20);
+		push(entries, value.to_owned());
+		true
+	}
+
+	fn entries(&mut self, group: &str) -> &mut Vec<String> {
+		let group = self
+			.inner
+			.entry_ref(group)
+			.or_insert_with(|| Group { entries: vec![], loading: Some(Self::load(group)) });
+
+		// Entries remembered while loading are newer than those of the file
+		match group.loading.as_mut().map(|rx| rx.try_recv()) {
+			Some(Ok(loaded)) => {
+				for entry in mem::replace(&mut group.entries, loaded) {
+					push(&mut group.entries, entry);
+				}
+				group.loading = None;
+			}
+			Some(Err(TryRecvError::Closed)) => group.loading = None,
+			Some(Err(TryRecvError::Empty)) | None => {}
 		}
+		&mut group.entries
+	}
 
-		true
+	fn path(group: &str) -> Option<PathBuf> {
+		// The group names the file, so only the ones that are safe to are saved
+		group
+			.bytes()
+			.all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_'))
+			.then(|| Xdg::state_dir().join("history").join(group))
+	}
+
+	fn load(group: &str) -> oneshot::Receiver<Vec<String>> {
+		let (tx, rx) = oneshot::channel();
+		let Some(path) = Self::path(group) else { return rx };
+
+		tokio::spawn(async move {
+			let Ok(content) = Local::regular(&path).read_to_string().await else { return };
+
+			let (entries, stale) = parse(&content);
+			if stale {
+				let new: String = entries.iter().flat_map(|e| [e, "\n"]).collect();
+				log_if_err!(Self::rewrite(path, content.len(), new).await);
+			}
+			tx.send(entries).ok();
+		});
+		rx
+	}
+
+	/// Replace the file at `path` with `content`, through a temporary file so
+	/// it's never seen half-written, unless it's no longer `len` bytes long, as
+	/// entries appended by other instances in the meantime would be lost.
+	async fn rewrite(path: PathBuf, len: usize, content: String) -> io::Result<()> {
+		let tmp = path.with_extension(format!("{}.tmp", std::process::id()));
+		Local::regular(&tmp).write(content).await?;
+
+		if Local::regular(&path).metadata().await?.len != len as u64 {
+			return Local::regular(&tmp).remove_file().await;
+		}
+		Local::regular(&tmp).rename(path).await
+	}
+
+	fn save(group: &str, value: &str) {
+		let Some(path) = Self::path(group) else { return };
+		if value.contains('\n') {
+			return;
+		}
+
+		let line = format!("{value}\n");
+		tokio::spawn(async move {
+			let dir = path.parent().unwrap();
+			Local::regular(dir).create_dir_all().await?;
+
+			let mut file = Demand::default().append(true).create(true).open(&path).await?;
+			file.write_all(line.as_bytes()).await
+		});
+	}
+}
+
+/// Add `entry` as the latest, dropping its older duplicate and the oldest
+/// entries past [`MAX_ENTRIES`].
+fn push(entries: &mut Vec<String>, entry: String) {
+	entries.retain(|e| *e != entry);
+	entries.push(entry);
+	if entries.len() > MAX_ENTRIES {
+		entries.drain(..entries.len() - MAX_ENTRIES);
+	}
+}
+
+/// The last [`MAX_ENTRIES`] distinct lines of a history file, and whether the
+/// file has grown enough with duplicates and older entries to be rewritten.
+fn parse(content: &str) -> (Vec<String>, bool) {
+	let lines: Vec<_> = content.lines().filter(|l| !l.is_empty()).collect();
+
+	let mut seen = HashSet::new();
+	let mut entries: Vec<_> = lines
+		.iter()
+		.rev()
+		.filter(|&&l| seen.insert(l))
+		.take(MAX_ENTRIES)
+		.map(|&l| l.to_owned())
+		.collect();
+
+	entries.reverse();
+	let stale = lines.len() > entries.len() * 2 && lines.len() > MAX_ENTRIES;
+	(entries, stale)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{MAX_ENTRIES, parse, push};
+
+	#[test]
+	fn test_parse() {
+		let (entries, stale) = parse("a\nb\n\na\nc\nb\n");
+		assert_eq!(entries, ["a", "c", "b"]);
+		assert!(!stale);
+
+		let content: String = (0..MAX_ENTRIES * 3).map(|i| format!("{}\n", i % 10)).collect();
+		let (entries, stale) = parse(&content);
+		assert_eq!(entries.len(), 10);
+		assert!(stale);
+	}
+
+	#[test]
+	fn test_push() {
+		let mut entries = vec!["a".to_owned(), "b".to_owned(), "c".to_owned()];
+		push(&mut entries, "a".to_owned());
+		assert_eq!(entries, ["b", "c", "a"]);
+
+		let mut entries: Vec<_> = (0..MAX_ENTRIES).map(|i| i.to_string()).collect();
+		push(&mut entries, "new".to_owned());
+		assert_eq!(entries.len(), MAX_ENTRIES);
+		assert_eq!((entries[0].as_str(), entries.last().unwrap().as_str()), ("1", "new"));
 	}
 }
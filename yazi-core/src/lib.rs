@@ -1,3 +1,3 @@
-yazi_macro::mod_pub!(app cmp confirm help input mgr notify pick spot tab tasks which);
+yazi_macro::mod_pub!(app cmp confirm help input mgr notify palette pane pick spot tab tasks which);
 
 yazi_macro::mod_flat!(core highlighter invalidator proxy reconciler);
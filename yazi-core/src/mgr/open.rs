@@ -9,6 +9,7 @@ pub struct OpenOpt {
 	pub targets:     Vec<UrlBuf>,
 	pub interactive: bool,
 	pub hovered:     bool,
+	pub edit:        bool,
 }
 
 impl_data_any!(OpenOpt);
@@ -22,6 +23,7 @@ impl TryFrom<ActionCow> for OpenOpt {
 			targets:     a.take_seq(),
 			interactive: a.bool("interactive"),
 			hovered:     a.bool("hovered"),
+			edit:        a.bool("edit"),
 		})
 	}
 }
@@ -32,6 +34,7 @@ pub struct OpenDoOpt {
 	pub cwd:         UrlBuf,
 	pub targets:     Vec<File>,
 	pub interactive: bool,
+	pub edit:        bool,
 }
 
 impl_data_any!(OpenDoOpt);
@@ -1,5 +1,6 @@
 use std::ops::{Deref, DerefMut};
 
+use yazi_config::{YAZI, mgr::MgrLayout};
 use yazi_dds::Pubsub;
 use yazi_fs::file::File;
 use yazi_macro::log_if_err;
@@ -40,6 +41,13 @@ impl Tabs {
 		log_if_err!(Pubsub::pub_after_tab(self.active().id));
 	}
 
+	/// Index of the tab shown beside the active one in the dual layout.
+	///
+	/// Tabs are paired up in order, so the first and second share the screen,
+	/// the third and fourth, and so on.
+	#[inline]
+	pub fn other_idx(&self) -> usize { self.cursor ^ 1 }
+
 	pub fn indices_or_active(&self, ids: Vec<Id>) -> Vec<usize> {
 		if ids.is_empty() {
 			vec![self.cursor]
@@ -56,6 +64,10 @@ impl Tabs {
 	#[inline]
 	pub(super) fn active_mut(&mut self) -> &mut Tab { &mut self.items[self.cursor] }
 
+	pub fn other(&self) -> Option<&Tab> {
+		if YAZI.mgr.layout.get() == MgrLayout::Dual { self.items.get(self.other_idx()) } else { None }
+	}
+
 	#[inline]
 	pub fn parent(&self) -> Option<&Folder> { self.active().parent.as_ref() }
 
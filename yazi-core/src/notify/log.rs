@@ -0,0 +1,128 @@
+use std::{collections::VecDeque, time::SystemTime};
+
+use yazi_binding::position::{Offset, Origin, Position};
+use yazi_config::YAZI;
+use yazi_shared::id::Id;
+use yazi_widgets::Scrollable;
+
+use crate::notify::{MessageLevel, MessageOpt};
+
+/// Every message pushed during the session, newest first, kept after it has
+/// faded away from the screen.
+#[derive(Default)]
+pub struct NotifyLog {
+	pub visible: bool,
+	pub entries: VecDeque<LogEntry>,
+	pub level:   Option<MessageLevel>,
+
+	pub offset: usize,
+	pub cursor: usize,
+	pub height: u16,
+}
+
+pub struct LogEntry {
+	pub title:   String,
+	pub content: String,
+	pub level:   MessageLevel,
+	pub time:    SystemTime,
+	pub task:    Option<Id>,
+}
+
+impl From<&MessageOpt> for LogEntry {
+	fn from(opt: &MessageOpt) -> Self {
+		Self {
+			title:   opt.title.lines().next().unwrap_or_default().to_owned(),
+			content: opt.content.clone(),
+			level:   opt.level,
+			time:    SystemTime::now(),
+			task:    opt.task,
+		}
+	}
+}
+
+impl NotifyLog {
+	pub fn record(&mut self, opt: &MessageOpt) {
+		self.entries.push_front(opt.into());
+		self.entries.truncate(YAZI.notify.log_limit.get());
+
+		// Keep an older entry selected as the new one comes in above it
+		if self.visible && self.cursor > 0 && self.level.is_none_or(|l| l == opt.level) {
+			self.next((self.cursor + 1).min(self.total() - 1));
+		}
+	}
+
+	pub fn position() -> Position {
+		Position::new(Origin::Center, Offset { x: 0, y: 0, width: 100, height: 25 })
+	}
+
+	// --- Entries
+	pub fn filtered(&self) -> impl Iterator<Item = &LogEntry> {
+		self.entries.iter().filter(|e| self.level.is_none_or(|l| l == e.level))
+	}
+
+	pub fn window(&self) -> impl Iterator<Item = &LogEntry> {
+		self.filtered().skip(self.offset).take(self.limit())
+	}
+
+	pub fn selected(&self) -> Option<&LogEntry> { self.filtered().nth(self.cursor) }
+
+	// --- Cursor
+	pub fn rel_cursor(&self) -> usize { self.cursor - self.offset }
+}
+
+impl Scrollable for NotifyLog {
+	fn total(&self) -> usize { self.filtered().count() }
+
+	fn limit(&self) -> usize {
+		self.height.saturating_sub(/* border */ 2 + /* preview */ 6) as usize
+	}
+
+	fn cursor_mut(&mut self) -> &mut usize { &mut self.cursor }
+
+	fn offset_mut(&mut self) -> &mut usize { &mut self.offset }
+}
+
+#[cfg(test)]
+mod tests {
+	use std::time::Duration;
+
+	use super::*;
+
+	fn opt(content: &str, level: MessageLevel) -> MessageOpt {
+		MessageOpt {
+			content: content.to_owned(),
+			title: "Title\nignored".to_owned(),
+			level,
+			timeout: Duration::ZERO,
+			task: None,
+		}
+	}
+
+	#[test]
+	fn test_record() {
+		yazi_shared::init_tests();
+		yazi_config::init_tests();
+		let limit = YAZI.notify.log_limit.get();
+
+		let mut log = NotifyLog { visible: true, height: 20, ..Default::default() };
+		for i in 0..limit + 2 {
+			let level = if i % 2 == 0 { MessageLevel::Info } else { MessageLevel::Error };
+			log.record(&opt(&i.to_string(), level));
+		}
+
+		assert_eq!(log.entries.len(), limit);
+		assert_eq!(log.entries[0].title, "Title");
+		assert_eq!(log.selected().unwrap().content, (limit + 1).to_string());
+
+		log.level = Some(MessageLevel::Info);
+		assert_eq!(log.total(), limit / 2);
+		assert_eq!(log.selected().unwrap().content, limit.to_string());
+
+		// An older entry stays selected as new ones come in
+		log.cursor = 2;
+		log.record(&opt("new", MessageLevel::Info));
+		assert_eq!(log.cursor, 3);
+		log.record(&opt("new", MessageLevel::Warn));
+		assert_eq!(log.cursor, 3);
+	}
+}
@@ -1,4 +1,4 @@
-yazi_macro::mod_flat!(level message notify option);
+yazi_macro::mod_flat!(level log message notify option);
 
 pub const NOTIFY_BORDER: u16 = 2;
 pub const NOTIFY_SPACING: u16 = 1;
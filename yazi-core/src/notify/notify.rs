@@ -3,12 +3,13 @@ use std::ops::ControlFlow;
 use ratatui_core::layout::{Constraint, Layout, Rect};
 use tokio::task::JoinHandle;
 
-use super::{Message, NOTIFY_SPACING};
+use super::{Message, NOTIFY_SPACING, NotifyLog};
 
 #[derive(Default)]
 pub struct Notify {
 	pub ticker:   Option<JoinHandle<()>>,
 	pub messages: Vec<Message>,
+	pub log:      NotifyLog,
 }
 
 impl Notify {
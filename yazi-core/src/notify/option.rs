@@ -4,7 +4,7 @@ use mlua::{FromLua, IntoLua, Lua, LuaSerdeExt, Value};
 use serde::{Deserialize, Serialize};
 use serde_with::{DurationSecondsWithFrac, serde_as};
 use yazi_macro::impl_data_any;
-use yazi_shared::event::ActionCow;
+use yazi_shared::{event::ActionCow, id::Id};
 use yazi_shim::mlua::SER_OPT;
 
 use crate::notify::MessageLevel;
@@ -20,6 +20,8 @@ pub struct MessageOpt {
 	pub level:   MessageLevel,
 	#[serde_as(as = "DurationSecondsWithFrac<f64>")]
 	pub timeout: Duration,
+	#[serde(default)]
+	pub task:    Option<Id>,
 }
 
 impl_data_any!(MessageOpt);
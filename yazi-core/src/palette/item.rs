@@ -0,0 +1,36 @@
+use yazi_config::KEYMAP;
+use yazi_shared::Layer;
+
+#[derive(Clone, Debug)]
+pub struct PaletteItem {
+	pub layer: Layer,
+	pub name:  &'static str,
+	pub args:  Vec<&'static str>,
+	pub keys:  Vec<String>,
+}
+
+impl PaletteItem {
+	pub fn new(layer: Layer, name: &'static str, args: Vec<&'static str>) -> Self {
+		Self { layer, name, args, keys: Self::keys(layer, name) }
+	}
+
+	/// Chords of every keymap section that run this action alone.
+	fn keys(layer: Layer, name: &str) -> Vec<String> {
+		(0..)
+			.map_while(Layer::from_repr)
+			.flat_map(|section| {
+				KEYMAP
+					.chords(section)
+					.iter()
+					.filter(|c| {
+						let [action] = &c.run[..] else { return false };
+						action.name == name && action.layer.or(section) == layer
+					})
+					.map(|c| c.on())
+					.collect::<Vec<_>>()
+			})
+			.collect()
+	}
+
+	pub fn title(&self) -> String { format!("{}:{}", self.layer, self.name) }
+}
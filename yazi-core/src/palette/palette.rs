@@ -0,0 +1,200 @@
+use std::cmp::Reverse;
+
+use anyhow::Result;
+use ratatui_widgets::block::Padding;
+use yazi_binding::position::Position;
+use yazi_macro::render;
+use yazi_shared::Layer;
+use yazi_term::event::KeyEvent;
+use yazi_tty::sequence::SetCursorStyle;
+use yazi_widgets::{Scrollable, input::Input};
+
+use super::PaletteItem;
+
+#[derive(Default)]
+pub struct Palette {
+	pub visible:  bool,
+	pub layer:    Layer,
+	pub position: Position,
+	pub items:    Vec<PaletteItem>,
+	pub matches:  Vec<usize>,
+
+	// Filter
+	pub input:   Input,
+	pub keyword: String,
+
+	pub offset: usize,
+	pub cursor: usize,
+	pub height: u16,
+}
+
+impl Palette {
+	pub fn r#type(&mut self, key: &KeyEvent) -> Result<bool> {
+		if !self.input.r#type(key)? {
+			return Ok(false);
+		}
+
+		self.filter_apply();
+		Ok(true)
+	}
+
+	/// Filter the actions by the first word of the input, the rest being the
+	/// arguments to run the selected one with.
+	pub fn filter_apply(&mut self) {
+		let kw = self.input.value().split_whitespace().next().unwrap_or_default();
+
+		if kw.is_empty() {
+			self.keyword.clear();
+			self.matches = (0..self.items.len()).collect();
+			self.matches.sort_by_key(|&i| self.items[i].layer != self.layer);
+		} else if self.keyword != kw {
+			self.keyword = kw.to_owned();
+			self.matches = self.filter_items(kw);
+			(self.offset, self.cursor) = (0, 0);
+		}
+
+		render!(self.scroll(0));
+	}
+
+	fn filter_items(&self, kw: &str) -> Vec<usize> {
+		let mut scored: Vec<_> = self
+			.items
+			.iter()
+			.enumerate()
+			.filter_map(|(i, item)| {
+				let score = if kw.contains(':') { fuzzy(kw, &item.title()) } else { fuzzy(kw, item.name) };
+				Some((Reverse(score?), item.layer != self.layer, i))
+			})
+			.collect();
+
+		scored.sort_unstable();
+		scored.into_iter().map(|(.., i)| i).collect()
+	}
+
+	pub fn selected(&self) -> Option<&PaletteItem> {
+		self.matches.get(self.cursor).map(|&i| &self.items[i])
+	}
+
+	/// Everything typed after the action name.
+	pub fn args(&self) -> &str {
+		let value = self.input.value().trim_start();
+		value.find(char::is_whitespace).map_or("", |i| &value[i..])
+	}
+
+	/// The input with its last word completed, which is either the name of the
+	/// selected action, or one of its arguments not given yet.
+	pub fn completion(&self) -> Option<String> {
+		let item = self.selected()?;
+		let value = self.input.value();
+		if !value.trim_start().contains(char::is_whitespace) {
+			return Some(format!("{} ", item.name));
+		}
+
+		let (head, word) = value.rsplit_once(char::is_whitespace)?;
+		let prefix = word.strip_prefix("--").or(word.is_empty().then_some(""))?;
+
+		let typed: Vec<_> = head.split_whitespace().filter_map(|w| w.strip_prefix("--")).collect();
+		let candidates: Vec<_> = item
+			.args
+			.iter()
+			.copied()
+			.filter(|&a| a.starts_with(prefix) && !typed.iter().any(|t| t.split('=').next() == Some(a)))
+			.collect();
+
+		let common = candidates.iter().skip(1).fold(*candidates.first()?, |acc, a| {
+			let n = acc.bytes().zip(a.bytes()).take_while(|(a, b)| a == b).count();
+			&acc[..n]
+		});
+
+		Some(if candidates.len() == 1 {
+			format!("{head} --{common} ")
+		} else if common.len() > prefix.len() {
+			format!("{head} --{common}")
+		} else {
+			format!("{head} --{}", candidates[0])
+		})
+	}
+}
+
+impl Palette {
+	pub fn padding(&self) -> Padding { Padding::new(1, 1, 1, 1) }
+
+	// --- Items
+	pub fn window(&self) -> impl Iterator<Item = &PaletteItem> {
+		let end = (self.offset + self.limit()).min(self.matches.len());
+		self.matches[self.offset..end].iter().map(|&i| &self.items[i])
+	}
+
+	// --- Cursor
+	pub fn cursor(&self) -> Option<u16> { self.visible.then_some(self.input.cursor()) }
+
+	pub fn rel_cursor(&self) -> usize { self.cursor - self.offset }
+
+	pub fn cursor_shape(&self) -> Option<SetCursorStyle> {
+		self.visible.then_some(self.input.cursor_shape())
+	}
+}
+
+impl Scrollable for Palette {
+	fn total(&self) -> usize { self.matches.len() }
+
+	fn limit(&self) -> usize {
+		let p = self.padding();
+		self.height.saturating_sub(p.top + /* input */ 1 + /* divider */ 1 + p.bottom) as usize
+	}
+
+	fn cursor_mut(&mut self) -> &mut usize { &mut self.cursor }
+
+	fn offset_mut(&mut self) -> &mut usize { &mut self.offset }
+}
+
+/// Score `haystack` for containing the characters of `kw` in order,
+/// ignoring case. Consecutive characters and ones at the start of a word score
+/// higher.
+fn fuzzy(kw: &str, haystack: &str) -> Option<u32> {
+	let mut needle = kw.chars().map(|c| c.to_ascii_lowercase()).peekable();
+	let (mut score, mut last, mut prev) = (0, None, None);
+
+	for (i, c) in haystack.chars().enumerate() {
+		let Some(&n) = needle.peek() else { break };
+		if c.to_ascii_lowercase() == n {
+			needle.next();
+			score += 1;
+			if i == 0 || matches!(prev, Some('_' | '-' | ':')) {
+				score += 3;
+			}
+			if last.is_some_and(|l| l + 1 == i) {
+				score += 4;
+			}
+			last = Some(i);
+		}
+		prev = Some(c);
+	}
+
+	if needle.peek().is_some() {
+		None
+	} else if kw.eq_ignore_ascii_case(haystack) {
+		Some(score + 10)
+	} else {
+		Some(score)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::fuzzy;
+
+	#[test]
+	fn test_fuzzy() {
+		assert_eq!(fuzzy("xyz", "hardlink"), None);
+		assert_eq!(fuzzy("HL", "hardlink"), fuzzy("hl", "hardlink"));
+		assert!(fuzzy("hl", "hardlink").is_some());
+
+		// Word starts beat scattered matches
+		assert!(fuzzy("ts", "tab_swap") > fuzzy("ts", "toggles"));
+		// Runs beat gaps
+		assert!(fuzzy("tab", "tab_close") > fuzzy("tab", "toggle_all_b"));
+		// Exact names come first
+		assert!(fuzzy("copy", "copy") > fuzzy("copy", "copy_cell"));
+	}
+}
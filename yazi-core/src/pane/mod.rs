@@ -0,0 +1,3 @@
+yazi_macro::mod_flat!(pane pty screen);
+
+pub const PANE_PERCENT: u16 = 40;
@@ -0,0 +1,170 @@
+use std::mem;
+
+use anstyle_parse::Parser;
+use anyhow::Result;
+use ratatui_core::layout::Rect;
+use yazi_scheduler::process::ShellOpt;
+use yazi_shared::id::{Id, Ids};
+use yazi_term::{TERM, event::{KeyCode, KeyEvent, Modifiers}};
+
+use super::{PANE_PERCENT, Pty, PtyOut, Screen};
+
+#[derive(Default)]
+pub struct Pane {
+	pub visible: bool,
+	pub focused: bool,
+
+	pub title:   String,
+	pub screen:  Screen,
+	pub running: bool,
+	pub code:    Option<i32>,
+
+	id:     Id,
+	pty:    Option<Pty>,
+	parser: Parser,
+}
+
+impl Pane {
+	/// Split the area into the file manager above and the pane below.
+	pub fn split(&self, area: Rect) -> (Rect, Option<Rect>) {
+		if !self.visible {
+			return (area, None);
+		}
+
+		let height = (area.height * PANE_PERCENT / 100).clamp(3.min(area.height), area.height);
+		let top = Rect { height: area.height - height, ..area };
+		(top, Some(Rect { y: top.bottom(), height, ..area }))
+	}
+
+	/// The area the screen is drawn in, below the title line of the pane.
+	pub fn inner(&self) -> Option<Rect> {
+		let (width, height) = TERM.dimension().area();
+		let area = self.split(Rect { width, height, ..Default::default() }).1?;
+		Some(Rect { y: area.y + 1, height: area.height - 1, ..area })
+	}
+
+	pub fn run(&mut self, opt: ShellOpt) -> Result<()> {
+		static IDS: Ids = Ids::new();
+
+		self.close();
+		let visible = mem::replace(&mut self.visible, true);
+
+		let inner = self.inner().unwrap_or_default();
+		let (id, title) = (IDS.next(), opt.cmd.to_string_lossy().into_owned());
+		let pty = match Pty::spawn(id, opt, inner.height.max(1), inner.width.max(1)) {
+			Ok(pty) => pty,
+			Err(e) => {
+				self.visible = visible;
+				return Err(e);
+			}
+		};
+
+		(self.id, self.title, self.pty) = (id, title, Some(pty));
+		self.screen = Screen::new(inner.height, inner.width);
+		self.parser = Default::default();
+
+		self.focused = true;
+		(self.running, self.code) = (true, None);
+		Ok(())
+	}
+
+	pub fn output(&mut self, id: Id, out: PtyOut) -> bool {
+		if id != self.id {
+			return false;
+		}
+
+		match out {
+			PtyOut::Data(bytes) => {
+				bytes.into_iter().for_each(|b| self.parser.advance(&mut self.screen, b));
+				if let Some(pty) = &self.pty
+					&& !self.screen.replies.is_empty()
+				{
+					pty.write(mem::take(&mut self.screen.replies)).ok();
+				}
+			}
+			PtyOut::Exit(code) => (self.running, self.code) = (false, code),
+		}
+		true
+	}
+
+	pub fn resize(&mut self) {
+		let Some(inner) = self.inner() else { return };
+		if (inner.height, inner.width) == (self.screen.rows(), self.screen.cols()) {
+			return;
+		}
+
+		self.screen.resize(inner.height, inner.width);
+		if let Some(pty) = &self.pty {
+			pty.resize(self.screen.rows(), self.screen.cols());
+		}
+	}
+
+	pub fn close(&mut self) {
+		if let Some(pty) = self.pty.take()
+			&& self.running
+		{
+			pty.kill();
+		}
+		self.running = false;
+	}
+
+	pub fn r#type(&mut self, key: &KeyEvent) -> bool {
+		let Some(bytes) = Self::encode(key, self.screen.app_cursor) else { return false };
+		self.paste(&bytes)
+	}
+
+	pub fn paste(&mut self, s: &str) -> bool {
+		match &self.pty {
+			Some(pty) if self.running => pty.write(s.as_bytes().to_vec()).is_ok(),
+			_ => false,
+		}
+	}
+}
+
+impl Pane {
+	fn encode(key: &KeyEvent, app_cursor: bool) -> Option<String> {
+		let (ctrl, alt) =
+			(key.modifiers.contains(Modifiers::CONTROL), key.modifiers.contains(Modifiers::ALT));
+		let cursor = |c: char| if app_cursor { format!("\x1bO{c}") } else { format!("\x1b[{c}") };
+
+		let s = match key.code {
+			KeyCode::Char(c) if ctrl => match c.to_ascii_lowercase() {
+				c @ 'a'..='z' => ((c as u8 - b'a' + 1) as char).to_string(),
+				' ' | '@' | '2' => "\0".to_owned(),
+				'[' | '3' => "\x1b".to_owned(),
+				'\\' | '4' => "\x1c".to_owned(),
+				']' | '5' => "\x1d".to_owned(),
+				'^' | '6' => "\x1e".to_owned(),
+				'_' | '/' | '7' => "\x1f".to_owned(),
+				_ => None?,
+			},
+			KeyCode::Char(c) if key.text.is_empty() => c.to_string(),
+			KeyCode::Char(_) => key.text.to_string(),
+
+			KeyCode::Enter => "\r".to_owned(),
+			KeyCode::Backspace => "\x7f".to_owned(),
+			KeyCode::Tab if key.modifiers.contains(Modifiers::SHIFT) => "\x1b[Z".to_owned(),
+			KeyCode::Tab => "\t".to_owned(),
+			KeyCode::Escape => "\x1b".to_owned(),
+
+			KeyCode::Up => cursor('A'),
+			KeyCode::Down => cursor('B'),
+			KeyCode::Right => cursor('C'),
+			KeyCode::Left => cursor('D'),
+			KeyCode::Home => cursor('H'),
+			KeyCode::End => cursor('F'),
+			KeyCode::Insert => "\x1b[2~".to_owned(),
+			KeyCode::Delete => "\x1b[3~".to_owned(),
+			KeyCode::PageUp => "\x1b[5~".to_owned(),
+			KeyCode::PageDown => "\x1b[6~".to_owned(),
+
+			KeyCode::Fn(n @ 1..=4) => format!("\x1bO{}", (b'P' + n - 1) as char),
+			KeyCode::Fn(n @ 5..=12) => {
+				format!("\x1b[{}~", [15, 17, 18, 19, 20, 21, 23, 24][n as usize - 5])
+			}
+			_ => None?,
+		};
+
+		Some(if alt { format!("\x1b{s}") } else { s })
+	}
+}
@@ -0,0 +1,200 @@
+use std::{fs::File, io::{self, Read, Write}, process::Child};
+
+use anyhow::Result;
+use tokio::{select, sync::{mpsc, watch}, task::JoinHandle};
+use yazi_macro::impl_data_any;
+use yazi_scheduler::process::ShellOpt;
+use yazi_shared::{id::Id, url::AsUrl};
+use yazi_vfs::engine::sftp::{RemoteOutput, RemoteProcess};
+
+use crate::PaneProxy;
+
+#[derive(Clone, Debug)]
+pub enum PtyOut {
+	Data(Vec<u8>),
+	Exit(Option<i32>),
+}
+
+impl_data_any!(PtyOut);
+
+pub(super) struct Pty {
+	writer:  mpsc::UnboundedSender<Vec<u8>>,
+	backend: Backend,
+}
+
+enum Backend {
+	Local { master: File, pid: u32 },
+	Remote { size: watch::Sender<(u16, u16)>, handle: JoinHandle<()> },
+}
+
+impl Pty {
+	/// Run the command in a local pty, or on the remote host over SSH if it's
+	/// meant to run there.
+	pub(super) fn spawn(id: Id, opt: ShellOpt, rows: u16, cols: u16) -> Result<Self> {
+		if opt.remote {
+			return Ok(Self::spawn_remote(id, opt, rows, cols));
+		}
+		Self::spawn_local(id, opt, rows, cols)
+	}
+
+	#[cfg(unix)]
+	fn spawn_local(id: Id, opt: ShellOpt, rows: u16, cols: u16) -> Result<Self> {
+		use std::{os::{fd::{AsRawFd, FromRawFd, OwnedFd}, unix::process::CommandExt}, process::Command, ptr};
+
+		use yazi_fs::Cwd;
+
+		let (mut master, mut slave) = (-1, -1);
+		let mut size = libc::winsize { ws_row: rows, ws_col: cols, ws_xpixel: 0, ws_ypixel: 0 };
+		if unsafe {
+			libc::openpty(&mut master, &mut slave, ptr::null_mut(), ptr::null_mut(), &raw mut size)
+		} != 0
+		{
+			return Err(io::Error::last_os_error().into());
+		}
+
+		let (master, slave) = unsafe { (OwnedFd::from_raw_fd(master), OwnedFd::from_raw_fd(slave)) };
+		unsafe { libc::fcntl(master.as_raw_fd(), libc::F_SETFD, libc::FD_CLOEXEC) };
+
+		let child = unsafe {
+			Command::new("sh")
+				.arg("-c")
+				.arg(opt.cmd)
+				.current_dir(Cwd::ensure(opt.cwd.as_url()))
+				.env("TERM", "xterm-256color")
+				.stdin(slave.try_clone()?)
+				.stdout(slave.try_clone()?)
+				.stderr(slave)
+				.pre_exec(|| {
+					// Become the session leader, with the pty as the controlling terminal
+					if libc::setsid() < 0 || libc::ioctl(0, libc::TIOCSCTTY as _, 0) < 0 {
+						return Err(io::Error::last_os_error());
+					}
+					Ok(())
+				})
+				.spawn()?
+		};
+
+		let pid = child.id();
+		let reader = File::from(master.try_clone()?);
+		std::thread::spawn(move || Self::read(id, reader, child));
+
+		// Writes block once the program stops reading, so keep them off the caller
+		let (writer, rx) = mpsc::unbounded_channel();
+		let file = File::from(master.try_clone()?);
+		std::thread::spawn(move || Self::write_all(file, rx));
+
+		Ok(Self { writer, backend: Backend::Local { master: master.into(), pid } })
+	}
+
+	#[cfg(windows)]
+	fn spawn_local(_: Id, _: ShellOpt, _: u16, _: u16) -> Result<Self> {
+		anyhow::bail!("The terminal pane is not supported on Windows")
+	}
+
+	fn spawn_remote(id: Id, opt: ShellOpt, rows: u16, cols: u16) -> Self {
+		let (writer, rx) = mpsc::unbounded_channel();
+		let (size, size_rx) = watch::channel((rows, cols));
+
+		let handle = tokio::spawn(async move {
+			let code = match Self::remote(id, opt, rx, size_rx).await {
+				Ok(code) => code.map(|c| c as i32),
+				Err(e) => {
+					PaneProxy::output(id, PtyOut::Data(format!("Failed to run remotely: {e}\r\n").into()));
+					None
+				}
+			};
+			PaneProxy::output(id, PtyOut::Exit(code));
+		});
+
+		Self { writer, backend: Backend::Remote { size, handle } }
+	}
+
+	fn read(id: Id, mut reader: File, mut child: Child) {
+		let mut buf = vec![0; 8192];
+		loop {
+			match reader.read(&mut buf) {
+				Ok(0) => break,
+				Ok(n) => PaneProxy::output(id, PtyOut::Data(buf[..n].to_vec())),
+				Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+				Err(_) => break,
+			}
+		}
+
+		let code = child.wait().ok().and_then(|s| s.code());
+		PaneProxy::output(id, PtyOut::Exit(code));
+	}
+
+	async fn remote(
+		id: Id,
+		opt: ShellOpt,
+		mut rx: mpsc::UnboundedReceiver<Vec<u8>>,
+		mut size: watch::Receiver<(u16, u16)>,
+	) -> io::Result<Option<u32>> {
+		let (rows, cols) = *size.borrow_and_update();
+		let mut proc =
+			RemoteProcess::spawn(opt.cwd.as_url(), opt.cmd.as_encoded_bytes(), Some((cols, rows)))
+				.await?;
+
+		let mut code = None;
+		loop {
+			select! {
+				out = proc.next() => match out? {
+					Some(RemoteOutput::Stdout(b) | RemoteOutput::Stderr(b)) => {
+						PaneProxy::output(id, PtyOut::Data(b));
+					}
+					Some(RemoteOutput::Exit(c)) => code = c,
+					None => break,
+				},
+				Some(b) = rx.recv() => proc.write(&b).await?,
+				Ok(()) = size.changed() => {
+					let (rows, cols) = *size.borrow_and_update();
+					proc.resize(cols, rows).await.ok();
+				}
+			}
+		}
+		Ok(code)
+	}
+
+	fn write_all(mut file: File, mut rx: mpsc::UnboundedReceiver<Vec<u8>>) {
+		while let Some(bytes) = rx.blocking_recv() {
+			if file.write_all(&bytes).is_err() {
+				break;
+			}
+		}
+	}
+
+	/// Queue `bytes` to be written to the program, without waiting for it to
+	/// read them.
+	pub(super) fn write(&self, bytes: Vec<u8>) -> io::Result<()> {
+		self.writer.send(bytes).map_err(|_| io::ErrorKind::BrokenPipe.into())
+	}
+
+	pub(super) fn resize(&self, rows: u16, cols: u16) {
+		match &self.backend {
+			#[cfg(unix)]
+			Backend::Local { master, .. } => unsafe {
+				use std::os::fd::AsRawFd;
+
+				let size = libc::winsize { ws_row: rows, ws_col: cols, ws_xpixel: 0, ws_ypixel: 0 };
+				libc::ioctl(master.as_raw_fd(), libc::TIOCSWINSZ, &size);
+			},
+			#[cfg(windows)]
+			Backend::Local { .. } => {}
+			Backend::Remote { size, .. } => _ = size.send((rows, cols)),
+		}
+	}
+
+	/// Hang up the whole session of the program, as closing a terminal would.
+	pub(super) fn kill(&self) {
+		match &self.backend {
+			#[cfg(unix)]
+			Backend::Local { pid, .. } => unsafe {
+				libc::kill(-(*pid as libc::pid_t), libc::SIGHUP);
+			},
+			#[cfg(windows)]
+			Backend::Local { .. } => {}
+			// Dropping the exec channel closes it on the remote host
+			Backend::Remote { handle, .. } => handle.abort(),
+		}
+	}
+}
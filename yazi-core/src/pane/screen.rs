@@ -0,0 +1,439 @@
+use std::mem;
+
+use anstyle_parse::{Params, Perform};
+use ratatui_core::{buffer::Cell, style::{Color, Modifier, Style}};
+use unicode_width::UnicodeWidthChar;
+
+/// A minimal VT100/xterm screen, covering what shells, build tools and most
+/// full-screen programs rely on: cursor movement, erasing, scroll regions,
+/// SGR attributes and the alternate screen.
+pub struct Screen {
+	lines:  Vec<Vec<Cell>>,
+	main:   Option<Vec<Vec<Cell>>>,
+	cols:   u16,
+	region: (u16, u16),
+
+	cursor: (u16, u16),
+	saved:  ((u16, u16), Style),
+	style:  Style,
+	wrap:   bool,
+
+	pub cursor_visible: bool,
+	pub app_cursor:     bool,
+	pub(super) replies: Vec<u8>,
+}
+
+impl Default for Screen {
+	fn default() -> Self { Self::new(24, 80) }
+}
+
+impl Screen {
+	pub fn new(rows: u16, cols: u16) -> Self {
+		let (rows, cols) = (rows.max(1), cols.max(1));
+		Self {
+			lines: vec![vec![Cell::default(); cols as usize]; rows as usize],
+			main: None,
+			cols,
+			region: (0, rows - 1),
+
+			cursor: (0, 0),
+			saved: Default::default(),
+			style: Style::default(),
+			wrap: false,
+
+			cursor_visible: true,
+			app_cursor: false,
+			replies: Vec::new(),
+		}
+	}
+
+	pub fn resize(&mut self, rows: u16, cols: u16) {
+		let (rows, cols) = (rows.max(1), cols.max(1));
+		if rows == self.rows() && cols == self.cols {
+			return;
+		}
+
+		// Drop lines from the top to keep the cursor on the screen
+		let overflow = (self.cursor.1 + 1).saturating_sub(rows) as usize;
+		let blank = self.blank();
+		for lines in [Some(&mut self.lines), self.main.as_mut()].into_iter().flatten() {
+			lines.drain(..overflow.min(lines.len()));
+			lines.resize(rows as usize, vec![blank.clone(); cols as usize]);
+			lines.iter_mut().for_each(|l| l.resize(cols as usize, blank.clone()));
+		}
+
+		self.cols = cols;
+		self.region = (0, rows - 1);
+		// The saved cursor is restored later, so it has to stay on the screen too
+		let clamp =
+			|(x, y): (u16, u16)| (x.min(cols - 1), y.saturating_sub(overflow as u16).min(rows - 1));
+		self.cursor = clamp(self.cursor);
+		self.saved.0 = clamp(self.saved.0);
+		self.wrap = false;
+	}
+
+	#[inline]
+	pub fn lines(&self) -> &[Vec<Cell>] { &self.lines }
+
+	#[inline]
+	pub fn rows(&self) -> u16 { self.lines.len() as u16 }
+
+	#[inline]
+	pub fn cols(&self) -> u16 { self.cols }
+
+	pub fn cursor(&self) -> Option<(u16, u16)> { self.cursor_visible.then_some(self.cursor) }
+
+	fn blank(&self) -> Cell {
+		let mut cell = Cell::default();
+		cell.set_bg(self.style.bg.unwrap_or(Color::Reset));
+		cell
+	}
+
+	fn blank_line(&self) -> Vec<Cell> { vec![self.blank(); self.cols as usize] }
+}
+
+impl Screen {
+	fn goto(&mut self, x: u16, y: u16) {
+		self.cursor = (x.min(self.cols - 1), y.min(self.rows() - 1));
+	}
+
+	fn line_feed(&mut self) {
+		if self.cursor.1 == self.region.1 {
+			self.scroll_up(1);
+		} else if self.cursor.1 < self.rows() - 1 {
+			self.cursor.1 += 1;
+		}
+	}
+
+	fn reverse_index(&mut self) {
+		if self.cursor.1 == self.region.0 {
+			self.scroll_down(1);
+		} else {
+			self.cursor.1 = self.cursor.1.saturating_sub(1);
+		}
+	}
+
+	fn scroll_up(&mut self, n: u16) { self.shift_lines(self.region.0, n, true) }
+
+	fn scroll_down(&mut self, n: u16) { self.shift_lines(self.region.0, n, false) }
+
+	/// Shift the lines between `top` and the bottom of the scroll region by `n`,
+	/// filling the vacated ones with blanks.
+	fn shift_lines(&mut self, top: u16, n: u16, up: bool) {
+		let (top, bottom) = (top as usize, self.region.1 as usize);
+		if top > bottom {
+			return;
+		}
+
+		let n = (n as usize).min(bottom - top + 1);
+		let blank = self.blank_line();
+		let lines = &mut self.lines[top..=bottom];
+		if up {
+			lines.rotate_left(n);
+			lines[bottom - top + 1 - n..].fill(blank);
+		} else {
+			lines.rotate_right(n);
+			lines[..n].fill(blank);
+		}
+	}
+
+	fn erase(&mut self, y: u16, from: u16, to: u16) {
+		let blank = self.blank();
+		let line = &mut self.lines[y as usize];
+		line[from as usize..to.min(self.cols) as usize].fill(blank);
+	}
+
+	fn erase_display(&mut self, mode: u16) {
+		let (x, y) = self.cursor;
+		let rows = match mode {
+			0 => {
+				self.erase(y, x, self.cols);
+				y + 1..self.rows()
+			}
+			1 => {
+				self.erase(y, 0, x + 1);
+				0..y
+			}
+			_ => 0..self.rows(),
+		};
+		rows.for_each(|y| self.erase(y, 0, self.cols));
+	}
+
+	fn erase_line(&mut self, mode: u16) {
+		let (x, y) = self.cursor;
+		match mode {
+			0 => self.erase(y, x, self.cols),
+			1 => self.erase(y, 0, x + 1),
+			_ => self.erase(y, 0, self.cols),
+		}
+	}
+
+	fn shift_chars(&mut self, n: u16, insert: bool) {
+		let (x, y) = (self.cursor.0 as usize, self.cursor.1 as usize);
+		let len = self.cols as usize - x;
+		let n = (n as usize).min(len);
+
+		let blank = self.blank();
+		let line = &mut self.lines[y][x..];
+		if insert {
+			line.rotate_right(n);
+			line[..n].fill(blank);
+		} else {
+			line.rotate_left(n);
+			line[len - n..].fill(blank);
+		}
+	}
+
+	fn alternate(&mut self, on: bool) {
+		if on && self.main.is_none() {
+			self.saved = (self.cursor, self.style);
+			let blank = vec![self.blank_line(); self.rows() as usize];
+			self.main = Some(mem::replace(&mut self.lines, blank));
+		} else if !on && let Some(main) = self.main.take() {
+			self.lines = main;
+			(self.cursor, self.style) = self.saved;
+		}
+	}
+
+	fn sgr(&mut self, params: &Params) {
+		if params.is_empty() {
+			self.style = Style::default();
+		}
+
+		let mut it = params.iter();
+		while let Some(param) = it.next() {
+			let s = self.style;
+			self.style = match param[0] {
+				0 => Style::default(),
+				1 => s.add_modifier(Modifier::BOLD),
+				2 => s.add_modifier(Modifier::DIM),
+				3 => s.add_modifier(Modifier::ITALIC),
+				4 => s.add_modifier(Modifier::UNDERLINED),
+				5 | 6 => s.add_modifier(Modifier::SLOW_BLINK),
+				7 => s.add_modifier(Modifier::REVERSED),
+				8 => s.add_modifier(Modifier::HIDDEN),
+				9 => s.add_modifier(Modifier::CROSSED_OUT),
+				21 | 22 => s.remove_modifier(Modifier::BOLD | Modifier::DIM),
+				23 => s.remove_modifier(Modifier::ITALIC),
+				24 => s.remove_modifier(Modifier::UNDERLINED),
+				25 => s.remove_modifier(Modifier::SLOW_BLINK),
+				27 => s.remove_modifier(Modifier::REVERSED),
+				28 => s.remove_modifier(Modifier::HIDDEN),
+				29 => s.remove_modifier(Modifier::CROSSED_OUT),
+
+				n @ 30..=37 => s.fg(Color::Indexed(n as u8 - 30)),
+				38 => Self::extended(param, &mut it).map_or(s, |c| s.fg(c)),
+				39 => Style { fg: None, ..s },
+				n @ 40..=47 => s.bg(Color::Indexed(n as u8 - 40)),
+				48 => Self::extended(param, &mut it).map_or(s, |c| s.bg(c)),
+				49 => Style { bg: None, ..s },
+				n @ 90..=97 => s.fg(Color::Indexed(n as u8 - 90 + 8)),
+				n @ 100..=107 => s.bg(Color::Indexed(n as u8 - 100 + 8)),
+				_ => s,
+			};
+		}
+	}
+
+	/// Parse a 256-color or true color, given either as subparameters
+	/// (`38:5:n`) or as the parameters that follow (`38;5;n`).
+	fn extended<'a>(param: &[u16], it: &mut impl Iterator<Item = &'a [u16]>) -> Option<Color> {
+		let mut args = param[1..].to_vec();
+		if args.is_empty() {
+			args.push(it.next()?[0]);
+			for _ in 0..if args[0] == 2 { 3 } else { 1 } {
+				args.push(it.next()?[0]);
+			}
+		}
+
+		match *args.as_slice() {
+			[5, n, ..] => Some(Color::Indexed(n as u8)),
+			[2, _, r, g, b] | [2, r, g, b] => Some(Color::Rgb(r as u8, g as u8, b as u8)),
+			_ => None,
+		}
+	}
+}
+
+impl Perform for Screen {
+	fn print(&mut self, c: char) {
+		let width = c.width().unwrap_or(0) as u16;
+		if width == 0 || width > self.cols {
+			return;
+		}
+
+		if self.wrap || self.cursor.0 + width > self.cols {
+			self.wrap = false;
+			self.cursor.0 = 0;
+			self.line_feed();
+		}
+
+		let (x, y) = (self.cursor.0 as usize, self.cursor.1 as usize);
+		let line = &mut self.lines[y];
+		line[x].reset();
+		line[x].set_char(c).set_style(self.style);
+		if width == 2 {
+			line[x + 1].reset();
+			line[x + 1].set_style(self.style);
+		}
+
+		self.cursor.0 += width;
+		if self.cursor.0 >= self.cols {
+			self.cursor.0 = self.cols - 1;
+			self.wrap = true;
+		}
+	}
+
+	fn execute(&mut self, byte: u8) {
+		match byte {
+			b'\x08' => self.cursor.0 = self.cursor.0.saturating_sub(1),
+			b'\t' => self.cursor.0 = ((self.cursor.0 / 8 + 1) * 8).min(self.cols - 1),
+			b'\n' | b'\x0b' | b'\x0c' => self.line_feed(),
+			b'\r' => self.cursor.0 = 0,
+			_ => return,
+		}
+		self.wrap = false;
+	}
+
+	fn csi_dispatch(&mut self, params: &Params, intermediates: &[u8], ignore: bool, action: u8) {
+		if ignore {
+			return;
+		}
+
+		let args: Vec<_> = params.iter().map(|p| p[0]).collect();
+		let arg = |i: usize, default: u16| args.get(i).copied().filter(|&n| n != 0).unwrap_or(default);
+		let (x, y) = self.cursor;
+
+		match (intermediates, action) {
+			([], b'm') => return self.sgr(params),
+			([], b'A') => self.cursor.1 = y.saturating_sub(arg(0, 1)),
+			([], b'B' | b'e') => self.goto(x, y.saturating_add(arg(0, 1))),
+			([], b'C' | b'a') => self.goto(x.saturating_add(arg(0, 1)), y),
+			([], b'D') => self.cursor.0 = x.saturating_sub(arg(0, 1)),
+			([], b'E') => self.goto(0, y.saturating_add(arg(0, 1))),
+			([], b'F') => self.goto(0, y.saturating_sub(arg(0, 1))),
+			([], b'G' | b'`') => self.goto(arg(0, 1) - 1, y),
+			([], b'd') => self.goto(x, arg(0, 1) - 1),
+			([], b'H' | b'f') => self.goto(arg(1, 1) - 1, arg(0, 1) - 1),
+
+			([] | [b'?'], b'J') => self.erase_display(args.first().copied().unwrap_or(0)),
+			([] | [b'?'], b'K') => self.erase_line(args.first().copied().unwrap_or(0)),
+			([], b'X') => self.erase(y, x, x.saturating_add(arg(0, 1))),
+			([], b'P') => self.shift_chars(arg(0, 1), false),
+			([], b'@') => self.shift_chars(arg(0, 1), true),
+			([], b'L') if (self.region.0..=self.region.1).contains(&y) => {
+				self.shift_lines(y, arg(0, 1), false);
+			}
+			([], b'M') if (self.region.0..=self.region.1).contains(&y) => {
+				self.shift_lines(y, arg(0, 1), true);
+			}
+			([], b'S') => self.scroll_up(arg(0, 1)),
+			([], b'T') => self.scroll_down(arg(0, 1)),
+
+			([], b'r') => {
+				let (top, bottom) = (arg(0, 1) - 1, arg(1, self.rows()).min(self.rows()) - 1);
+				if top < bottom {
+					self.region = (top, bottom);
+					self.goto(0, 0);
+				}
+			}
+			([], b's') => self.saved = (self.cursor, self.style),
+			([], b'u') => (self.cursor, self.style) = self.saved,
+
+			([b'?'], b'h' | b'l') => {
+				for &mode in &args {
+					match mode {
+						1 => self.app_cursor = action == b'h',
+						25 => self.cursor_visible = action == b'h',
+						47 | 1047 | 1049 => self.alternate(action == b'h'),
+						_ => {}
+					}
+				}
+			}
+
+			([], b'n') if args.first() == Some(&5) => self.replies.extend_from_slice(b"\x1b[0n"),
+			([], b'n') if args.first() == Some(&6) => {
+				self.replies.extend(format!("\x1b[{};{}R", y + 1, x + 1).bytes());
+			}
+			([], b'c') if arg(0, 0) == 0 => self.replies.extend_from_slice(b"\x1b[?1;2c"),
+			_ => return,
+		}
+		self.wrap = false;
+	}
+
+	fn esc_dispatch(&mut self, intermediates: &[u8], ignore: bool, byte: u8) {
+		if ignore || !intermediates.is_empty() {
+			return;
+		}
+
+		match byte {
+			b'7' => self.saved = (self.cursor, self.style),
+			b'8' => (self.cursor, self.style) = self.saved,
+			b'D' => self.line_feed(),
+			b'E' => {
+				self.cursor.0 = 0;
+				self.line_feed();
+			}
+			b'M' => self.reverse_index(),
+			b'c' => *self = Self::new(self.rows(), self.cols),
+			_ => return,
+		}
+		self.wrap = false;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use anstyle_parse::Parser;
+
+	use super::*;
+
+	fn feed(screen: &mut Screen, s: &str) {
+		let mut parser: Parser = Default::default();
+		s.bytes().for_each(|b| parser.advance(screen, b));
+	}
+
+	fn text(screen: &Screen) -> Vec<String> {
+		let line =
+			|l: &Vec<Cell>| l.iter().map(|c| c.symbol()).collect::<String>().trim_end().to_owned();
+		screen.lines().iter().map(line).collect()
+	}
+
+	#[test]
+	fn test_screen() {
+		let mut s = Screen::new(3, 5);
+		feed(&mut s, "abcdefg\r\n\x1b[31mred\x1b[0m.");
+		assert_eq!(text(&s), ["abcde", "fg", "red."]);
+		assert_eq!(s.lines()[2][0].fg, Color::Indexed(1));
+		assert_eq!(s.lines()[2][3].fg, Color::Reset);
+
+		// Scroll once the bottom is reached
+		feed(&mut s, "\r\n1\r\n2");
+		assert_eq!(text(&s), ["red.", "1", "2"]);
+
+		// Erase, move and overwrite
+		feed(&mut s, "\x1b[2J\x1b[2;3Hx\x1b[1;1H\x1b[38;5;208my");
+		assert_eq!(text(&s), ["y", "  x", ""]);
+		assert_eq!(s.lines()[0][0].fg, Color::Indexed(208));
+
+		// The alternate screen keeps the main one intact
+		feed(&mut s, "\x1b[?1049h\x1b[2Jz\x1b[?1049l");
+		assert_eq!(text(&s), ["y", "  x", ""]);
+
+		// Cursor position report
+		feed(&mut s, "\x1b[6n");
+		assert_eq!(s.replies, b"\x1b[1;2R");
+
+		s.resize(2, 3);
+		assert_eq!(text(&s), ["y", "  x"]);
+	}
+
+	#[test]
+	fn test_resize_alternate() {
+		let mut s = Screen::new(3, 5);
+		feed(&mut s, "\x1b[3;5Ha\x1b[?1049h\x1b[1;1Hb");
+
+		// The cursor saved on the main screen is out of range after the shrink
+		s.resize(2, 3);
+		feed(&mut s, "\x1b[?1049lc\x1b8d");
+		assert_eq!(text(&s), ["", "  d"]);
+	}
+}
@@ -4,11 +4,11 @@ use yazi_config::LAYOUT;
 use yazi_macro::{emit, relay};
 use yazi_runner::previewer::PeekJob;
 use yazi_scheduler::TaskSummary;
-use yazi_shared::url::AsUrl;
+use yazi_shared::{id::Id, url::AsUrl};
 use yazi_shim::fs::Error;
 use yazi_widgets::Renderable;
 
-use crate::{app::PluginOpt, tab::PreviewLock};
+use crate::{app::PluginOpt, pane::PtyOut, tab::PreviewLock};
 
 pub struct AppProxy;
 
@@ -63,3 +63,12 @@ impl MgrProxy {
 		);
 	}
 }
+
+// --- Pane
+pub struct PaneProxy;
+
+impl PaneProxy {
+	pub fn output(id: Id, out: PtyOut) {
+		emit!(Call(relay!(pane:output).with("id", id).with_any("out", out)));
+	}
+}
@@ -1,7 +1,7 @@
 use anyhow::Result;
 use hashbrown::HashMap;
-use yazi_fs::{Entries, Filter, FilterCase};
-use yazi_shared::{path::{DynPath, PathBufDyn}, url::UrlBuf};
+use yazi_fs::{Entries, Filter, FilterCase, file::File};
+use yazi_shared::{path::{DynPath, PathBufDyn}, pool::Symbol, url::{UrlBuf, UrlBufCov}};
 
 use crate::tab::Folder;
 
@@ -29,9 +29,7 @@ impl Finder {
 	pub fn prev(&self, entries: &Entries, cursor: usize, include: bool) -> Option<isize> {
 		for i in !include as usize..entries.len() {
 			let idx = (cursor + entries.len() - i) % entries.len();
-			if let Some(s) = entries[idx].name()
-				&& self.filter.matches(s)
-			{
+			if self.matches(&entries[idx]) {
 				return Some(idx as isize - cursor as isize);
 			}
 		}
@@ -41,9 +39,7 @@ impl Finder {
 	pub fn next(&self, entries: &Entries, cursor: usize, include: bool) -> Option<isize> {
 		for i in !include as usize..entries.len() {
 			let idx = (cursor + i) % entries.len();
-			if let Some(s) = entries[idx].name()
-				&& self.filter.matches(s)
-			{
+			if self.matches(&entries[idx]) {
 				return Some(idx as isize - cursor as isize);
 			}
 		}
@@ -58,7 +54,7 @@ impl Finder {
 
 		let mut i = 0u8;
 		for file in folder.entries.iter() {
-			if file.name().is_none_or(|s| !self.filter.matches(s)) {
+			if !self.matches(file) {
 				continue;
 			}
 
@@ -73,6 +69,21 @@ impl Finder {
 		self.lock = folder.into();
 		true
 	}
+
+	/// Take in newly fetched mimetypes, redoing the matches on the next catchup
+	/// if the query depends on them.
+	pub fn update_mimes(&mut self, mimes: &HashMap<UrlBufCov, Symbol<str>>) -> bool {
+		let b = self.filter.learn_mimes(mimes.iter().map(|(u, m)| (u.clone(), m.clone())));
+		if b {
+			self.lock = Default::default();
+		}
+		b
+	}
+
+	#[inline]
+	fn matches(&self, file: &File) -> bool {
+		file.name().is_some_and(|s| self.filter.matches_name(s)) && self.filter.admits(file)
+	}
 }
 
 impl Finder {
@@ -0,0 +1,238 @@
+use std::sync::Arc;
+
+use hashbrown::{HashMap, HashSet};
+use parking_lot::RwLock;
+use yazi_fs::{FilesOp, file::File};
+use yazi_shared::{path::PathBufDyn, url::{UrlBuf, UrlLike}};
+
+/// Most walked directories to watch, as each takes up one of the inotify
+/// watches the system allows.
+const MAX_WATCHES: usize = 2048;
+
+/// A flat listing of every file under `root`, shown in the tab as the
+/// `search://` URL `cwd` with paths relative to `root` as names.
+#[derive(Clone)]
+pub struct Flatten {
+	pub root:   UrlBuf,
+	pub cwd:    UrlBuf,
+	pub depth:  Option<usize>,
+	pub hidden: bool,
+	pub ignore: bool,
+
+	/// Directories walked so far, the shallowest of which the watcher keeps an
+	/// eye on.
+	pub dirs: Arc<RwLock<HashMap<UrlBuf, File>>>,
+}
+
+impl Flatten {
+	pub fn new(root: UrlBuf, cwd: UrlBuf, depth: Option<usize>, hidden: bool, ignore: bool) -> Self {
+		let dirs = HashMap::from_iter([(root.clone(), File::from_dummy(&root, None))]);
+		Self { root, cwd, depth, hidden, ignore, dirs: Arc::new(RwLock::new(dirs)) }
+	}
+
+	/// How deep the URL is under the root, `0` for the root itself.
+	pub fn level(&self, url: &UrlBuf) -> Option<usize> {
+		let root = self.root.as_local()?;
+		Some(url.as_local()?.strip_prefix(root).ok()?.components().count())
+	}
+
+	/// How many levels are left to walk beneath the directory.
+	pub fn remaining(&self, dir: &UrlBuf) -> Option<usize> {
+		let level = self.level(dir)?;
+		match self.depth {
+			Some(d) if level < d => Some(d - level),
+			Some(_) => None,
+			None => Some(usize::MAX),
+		}
+	}
+
+	/// Directories to watch, the shallowest first and at most [`MAX_WATCHES`] of
+	/// them.
+	pub fn watches(&self) -> Vec<File> {
+		let mut dirs: Vec<_> = self.dirs.read().values().cloned().collect();
+		if dirs.len() > MAX_WATCHES {
+			dirs.sort_unstable_by_key(|f| self.level(&f.url));
+			dirs.truncate(MAX_WATCHES);
+		}
+		dirs
+	}
+
+	/// Whether an op the watcher reported concerns one of the walked
+	/// directories.
+	pub fn covers(&self, op: &FilesOp) -> bool { self.dirs.read().contains_key(op.cwd()) }
+
+	/// Take in a file found by a walk, remembering the directories to watch
+	/// and turning the rest into entries of the listing.
+	pub fn accept(&self, file: File) -> Option<File> {
+		if !file.is_dir() {
+			return self.relocate(file);
+		}
+
+		if !file.is_link() && self.remaining(&file.url).is_some() {
+			self.dirs.write().insert(file.url.clone(), file);
+		}
+		None
+	}
+
+	/// Translate an op the watcher reported for one of the walked directories
+	/// into one on the listing, along with new directories yet to be walked.
+	///
+	/// Only hidden files are left out here, files showing up later have to be
+	/// checked against the ignore rules before the op gets relayed.
+	pub fn relay(&self, op: &FilesOp, listed: &[File]) -> (Option<FilesOp>, Vec<File>) {
+		if !self.covers(op) {
+			return Default::default();
+		}
+
+		let mut walk = vec![];
+		let mut accept = |file: &File| {
+			if !self.hidden && file.is_hidden() {
+				None
+			} else if file.is_dir() {
+				if !self.dirs.read().contains_key(&file.url) && self.remaining(&file.url).is_some() {
+					walk.push(file.clone());
+				}
+				None
+			} else {
+				self.relocate(file.clone())
+			}
+		};
+
+		let op = match op {
+			FilesOp::Creating(_, files) => {
+				let files: Vec<_> = files.iter().filter_map(accept).collect();
+				(!files.is_empty()).then(|| FilesOp::Creating(self.cwd.clone(), files))
+			}
+			FilesOp::Updating(_, map) | FilesOp::Upserting(_, map) => {
+				let map: HashMap<_, _> =
+					map.iter().filter_map(|(k, f)| Some((self.key(op.cwd(), k)?, accept(f)?))).collect();
+
+				match op {
+					_ if map.is_empty() => None,
+					FilesOp::Updating(..) => Some(FilesOp::Updating(self.cwd.clone(), map)),
+					_ => Some(FilesOp::Upserting(self.cwd.clone(), map)),
+				}
+			}
+			FilesOp::Deleting(_, keys) => self.delete(op.cwd(), keys, listed),
+			_ => None,
+		};
+
+		(op, walk)
+	}
+
+	fn delete(&self, dir: &UrlBuf, keys: &HashSet<PathBufDyn>, listed: &[File]) -> Option<FilesOp> {
+		let mut removed = vec![];
+		let mut dirs = self.dirs.write();
+		for key in keys {
+			let Ok(url) = dir.try_join(key) else { continue };
+			let Some(path) = url.as_local() else { continue };
+
+			let len = dirs.len();
+			dirs.retain(|u, _| !u.as_local().is_some_and(|p| p.starts_with(path)));
+			if dirs.len() != len {
+				removed.push(path.to_owned());
+			}
+		}
+		drop(dirs);
+
+		// Files beneath removed directories may never get their own report
+		let under = listed
+			.iter()
+			.filter(|f| f.url.as_local().is_some_and(|p| removed.iter().any(|r| p.starts_with(r))));
+
+		let keys: HashSet<_> = keys
+			.iter()
+			.filter_map(|k| self.key(dir, k))
+			.chain(under.map(|f| f.key().to_owned()))
+			.collect();
+		(!keys.is_empty()).then(|| FilesOp::Deleting(self.cwd.clone(), keys))
+	}
+
+	fn relocate(&self, file: File) -> Option<File> {
+		let rel = file.url.as_local()?.strip_prefix(self.root.as_local()?).ok()?;
+		Some(File { url: self.cwd.try_join(rel).ok()?, ..file })
+	}
+
+	fn key(&self, dir: &UrlBuf, key: &PathBufDyn) -> Option<PathBufDyn> {
+		let url = dir.try_join(key).ok()?;
+		let rel = url.as_local()?.strip_prefix(self.root.as_local()?).ok()?;
+		Some(self.cwd.try_join(rel).ok()?.key().to_owned())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::path::Path;
+
+	use yazi_fs::cha::ChaType;
+	use yazi_shared::url::AsUrl;
+
+	use super::*;
+
+	fn flatten() -> Flatten {
+		let root = UrlBuf::from(Path::new("/r"));
+		let cwd = root.as_url().to_search("flatten").unwrap();
+		Flatten::new(root, cwd, Some(2), false, true)
+	}
+
+	fn file(path: &str, kind: ChaType) -> File { File::from_dummy(Path::new(path), Some(kind)) }
+
+	#[test]
+	fn test_accept() {
+		let f = flatten();
+
+		let entry = f.accept(file("/r/a/b.txt", ChaType::File)).unwrap();
+		assert!(entry.url.is_search());
+		assert_eq!(entry.key().to_string_lossy(), "a/b.txt");
+
+		assert!(f.accept(file("/r/a", ChaType::Dir)).is_none());
+		assert!(f.accept(file("/r/a/deep", ChaType::Dir)).is_none());
+		assert!(f.dirs.read().contains_key(&UrlBuf::from(Path::new("/r/a"))));
+		assert!(!f.dirs.read().contains_key(&UrlBuf::from(Path::new("/r/a/deep"))));
+	}
+
+	#[test]
+	fn test_relay() {
+		let f = flatten();
+		f.accept(file("/r/a", ChaType::Dir));
+		let listed = [f.accept(file("/r/a/x", ChaType::File)).unwrap()];
+
+		// Unrelated directories are left alone
+		let op = FilesOp::Creating(Path::new("/elsewhere").into(), vec![]);
+		assert!(f.relay(&op, &listed).0.is_none());
+
+		// New directories are to be walked, and hidden files are skipped
+		let op = FilesOp::Creating(Path::new("/r").into(), vec![
+			file("/r/n", ChaType::Dir),
+			file("/r/.h", ChaType::File),
+			file("/r/y", ChaType::File),
+		]);
+		let (op, walk) = f.relay(&op, &listed);
+		assert!(matches!(op, Some(FilesOp::Creating(u, files)) if u == f.cwd && files.len() == 1));
+		assert_eq!(walk.len(), 1);
+
+		// Removing a directory takes every file beneath it
+		let op = FilesOp::Deleting(Path::new("/r").into(), [PathBufDyn::from(Path::new("a"))].into());
+		let Some(FilesOp::Deleting(_, keys)) = f.relay(&op, &listed).0 else { panic!() };
+		assert!(keys.contains(&PathBufDyn::from(Path::new("a/x"))));
+		assert!(!f.dirs.read().contains_key(&UrlBuf::from(Path::new("/r/a"))));
+	}
+
+	#[test]
+	fn test_watches() {
+		let root = UrlBuf::from(Path::new("/r"));
+		let f =
+			Flatten::new(root.clone(), root.as_url().to_search("flatten").unwrap(), None, false, true);
+
+		f.accept(file("/r/a/b", ChaType::Dir));
+		for i in 0..MAX_WATCHES {
+			f.accept(file(&format!("/r/{i}"), ChaType::Dir));
+		}
+
+		// The deepest ones are left out
+		let watches = f.watches();
+		assert_eq!(watches.len(), MAX_WATCHES);
+		assert!(watches.iter().any(|d| d.url == root));
+		assert!(!watches.iter().any(|d| d.url == UrlBuf::from(Path::new("/r/a/b"))));
+	}
+}
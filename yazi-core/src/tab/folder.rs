@@ -128,6 +128,16 @@ impl Folder {
 		b
 	}
 
+	/// Hover over the file by its full URL, which unlike its key stays unique
+	/// across the levels of an expanded tree.
+	pub fn hover_url(&mut self, url: &UrlBuf) -> bool {
+		let Some(new) = self.entries.iter().position(|f| f.url == *url) else { return false };
+		let b = self.arrow(new as isize - self.cursor as isize);
+
+		self.retrace();
+		b
+	}
+
 	pub fn repos(&mut self, key: Option<PathDyn>) -> bool {
 		if let Some(k) = key {
 			self.hover(k)
@@ -17,6 +17,8 @@ impl History {
 
 	pub fn get_mut(&mut self, url: &UrlBuf) -> Option<&mut Folder> { self.entries.get_mut(url) }
 
+	pub fn values_mut(&mut self) -> impl Iterator<Item = &mut Folder> { self.entries.values_mut() }
+
 	pub fn ensure(&mut self, url: &UrlBuf) -> (&mut Folder, Option<Folder>) {
 		let evicted =
 			if self.entries.contains_key(url) { None } else { self.insert(Folder::from(url)) };
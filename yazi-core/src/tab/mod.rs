@@ -1 +1 @@
-yazi_macro::mod_flat!(backstack finder folder history mode preference preview preview_lock preview_sig selected snap tab visual);
+yazi_macro::mod_flat!(backstack finder flatten folder history mode preference preview preview_lock preview_sig selected snap tab visual);
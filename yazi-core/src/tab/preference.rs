@@ -9,6 +9,7 @@ pub struct Preference {
 	pub name:        String,
 	pub linemode:    Arc<String>,
 	pub show_hidden: bool,
+	pub tree:        bool,
 
 	// Sorting
 	pub sort_by:        SortBy,
@@ -26,6 +27,7 @@ impl Default for Preference {
 			name:        String::new(),
 			linemode:    YAZI.mgr.linemode.load_full(),
 			show_hidden: YAZI.mgr.show_hidden.get(),
+			tree:        false,
 
 			// Sorting
 			sort_by:        YAZI.mgr.sort_by.get(),
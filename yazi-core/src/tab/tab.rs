@@ -9,7 +9,7 @@ use yazi_fs::file::File;
 use yazi_shared::{id::{Id, Ids}, url::{UrlBuf, UrlLike}};
 use yazi_term::TERM;
 
-use super::{Backstack, Finder, Folder, History, Mode, Preference, Preview};
+use super::{Backstack, Finder, Flatten, Folder, History, Mode, Preference, Preview};
 use crate::{spot::Spot, tab::Selected};
 
 pub struct Tab {
@@ -27,6 +27,7 @@ pub struct Tab {
 	pub preview: Preview,
 	pub finder:  Option<Finder>,
 	pub search:  Option<JoinHandle<Result<()>>>,
+	pub flatten: Option<Flatten>,
 }
 
 impl Default for Tab {
@@ -48,6 +49,7 @@ impl Default for Tab {
 			preview: Default::default(),
 			finder:  Default::default(),
 			search:  Default::default(),
+			flatten: Default::default(),
 		}
 	}
 }
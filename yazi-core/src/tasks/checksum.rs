@@ -0,0 +1,25 @@
+use yazi_fs::Digest;
+use yazi_shared::{id::Id, url::UrlBuf};
+
+use super::Tasks;
+
+impl Tasks {
+	pub fn checksum_compute(&self, targets: Vec<UrlBuf>, digest: Digest) {
+		if !targets.is_empty() {
+			self.scheduler.checksum_compute(targets, digest);
+		}
+	}
+
+	pub fn checksum_verify(
+		&self,
+		manifest: UrlBuf,
+		digest: Option<Digest>,
+		listing: Option<(UrlBuf, Id)>,
+	) {
+		self.scheduler.checksum_verify(manifest, digest, listing);
+	}
+
+	pub fn checksum_digest(&self, url: &UrlBuf) -> Option<String> {
+		self.scheduler.checksum.digests.read().get(url).map(|(_, hex)| hex.clone())
+	}
+}
@@ -0,0 +1,42 @@
+use yazi_scheduler::{NotifyProxy, dedupe::Dedupe};
+use yazi_shared::{id::Id, url::UrlBuf};
+
+use super::Tasks;
+
+impl Tasks {
+	pub fn dedupe(&self, target: UrlBuf, hidden: bool, ticket: Id) {
+		self.scheduler.behavior.reset();
+		self.scheduler.dedupe(target, hidden, ticket);
+	}
+
+	pub fn dedupe_groups(&self, target: &UrlBuf) -> Vec<Vec<UrlBuf>> {
+		self.scheduler.dedupe.groups.read().get(target).cloned().unwrap_or_default()
+	}
+
+	/// The listing of the latest run, which keeps its duplicate sets in the order
+	/// they were found.
+	pub fn dedupe_target(&self) -> Option<UrlBuf> {
+		self.scheduler.dedupe.groups.read().keys().next().cloned()
+	}
+
+	/// Replace the duplicates with hard links to the kept file, each only once
+	/// it's confirmed to be still identical, as the files may have changed since
+	/// they were found.
+	pub fn dedupe_link(&self, kept: UrlBuf, dups: Vec<UrlBuf>) {
+		let scheduler = self.scheduler.clone();
+		tokio::spawn(async move {
+			for dup in dups {
+				match Dedupe::same(&kept, &dup).await {
+					Ok(true) => scheduler.file_hardlink(kept.clone(), dup, true, false),
+					Ok(false) => NotifyProxy::push_warn(
+						"Dedupe",
+						format!("Skipping {dup:?}, which no longer matches {kept:?}"),
+					),
+					Err(e) => {
+						NotifyProxy::push_warn("Dedupe", format!("Cannot compare {dup:?} with {kept:?}: {e}"))
+					}
+				}
+			}
+		});
+	}
+}
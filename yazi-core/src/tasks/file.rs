@@ -1,3 +1,4 @@
+use yazi_fs::engine::Preserve;
 use yazi_macro::debug;
 use yazi_scheduler::file::{FileInCopy, FileInLink, FileInMove};
 use yazi_shared::url::{UrlBuf, UrlLike};
@@ -6,7 +7,14 @@ use super::Tasks;
 use crate::mgr::Yanked;
 
 impl Tasks {
-	pub fn file_move(&self, src: &Yanked, dest: &UrlBuf, force: bool) {
+	pub fn file_move(
+		&self,
+		src: &Yanked,
+		dest: &UrlBuf,
+		force: bool,
+		verify: bool,
+		preserve: Preserve,
+	) {
 		self.scheduler.behavior.reset();
 
 		for u in src.urls() {
@@ -17,12 +25,22 @@ impl Tasks {
 			if force && u == to {
 				debug!("file_move: same file, skip {to:?}");
 			} else {
-				self.scheduler.file_move(FileInMove::new(u.clone(), to, force));
+				self.scheduler.file_move(
+					FileInMove::new(u.clone(), to, force).with_verify(verify).with_preserve(preserve),
+				);
 			}
 		}
 	}
 
-	pub fn file_copy(&self, src: &Yanked, dest: &UrlBuf, force: bool, follow: bool) {
+	pub fn file_copy(
+		&self,
+		src: &Yanked,
+		dest: &UrlBuf,
+		force: bool,
+		follow: bool,
+		verify: bool,
+		preserve: Preserve,
+	) {
 		self.scheduler.behavior.reset();
 
 		for u in src.urls() {
@@ -33,7 +51,9 @@ impl Tasks {
 			if force && u == to {
 				debug!("file_copy: same file, skip {to:?}");
 			} else {
-				self.scheduler.file_copy(FileInCopy::new(u.clone(), to, force, follow));
+				self.scheduler.file_copy(
+					FileInCopy::new(u.clone(), to, force, follow).with_verify(verify).with_preserve(preserve),
+				);
 			}
 		}
 	}
@@ -14,10 +14,10 @@ pub struct WhichSorter {
 impl Default for WhichSorter {
 	fn default() -> Self {
 		Self {
-			by:        YAZI.which.sort_by,
-			sensitive: YAZI.which.sort_sensitive,
-			reverse:   YAZI.which.sort_reverse,
-			translit:  YAZI.which.sort_translit,
+			by:        YAZI.which.sort_by.get(),
+			sensitive: YAZI.which.sort_sensitive.get(),
+			reverse:   YAZI.which.sort_reverse.get(),
+			translit:  YAZI.which.sort_translit.get(),
 		}
 	}
 }
@@ -1,3 +1,3 @@
 yazi_macro::mod_flat!(
-	bulk_rename bye cd custom delete download duplicate ember hey hi hover input load mount r#move rename tab theme trash yank
+	bulk_rename bye cd custom delete download duplicate ember hey hi history hover input load mount r#move rename tab theme trash yank
 );
@@ -28,7 +28,9 @@ impl Widget for Cmp<'_> {
 				let icon = if x.is_dir { &THEME.cmp.icon_folder } else { &THEME.cmp.icon_file };
 				let slash = if x.is_dir { MAIN_SEPARATOR_STR } else { "" };
 
-				let mut item = ListItem::new(format!(" {icon} {}{slash}", x.name.display()));
+				let desc = x.desc.as_ref().map(|d| format!("  {d}")).unwrap_or_default();
+
+				let mut item = ListItem::new(format!(" {icon} {}{slash}{desc}", x.name.display()));
 				if i == self.core.cmp.rel_cursor() {
 					item = item.style(THEME.cmp.active.get());
 				} else {
@@ -1,7 +1,7 @@
 use anyhow::Result;
 use yazi_actor::Ctx;
 use yazi_macro::{act, emit, warn};
-use yazi_shared::event::{ActionCow, Event};
+use yazi_shared::{Layer, event::{ActionCow, Event}};
 use yazi_term::event::{ClipboardEvent, DndEvent, Event as TermEvent, KeyEvent, MouseEvent};
 
 use crate::{Executor, Router, app::App};
@@ -77,6 +77,8 @@ impl<'a> Dispatcher<'a> {
 	fn dispatch_paste(&mut self, str: String) -> Result<()> {
 		if let Some(mut guard) = self.app.core.input.lock_mut() {
 			guard.feed(str.into())?;
+		} else if self.app.core.layer() == Layer::Pane {
+			self.app.core.pane.paste(&str);
 		}
 		Ok(())
 	}
@@ -28,6 +28,8 @@ impl<'a> Executor<'a> {
 			Layer::Cmp => self.cmp(action),
 			Layer::Which => self.which(action),
 			Layer::Notify => self.notify(action),
+			Layer::Palette => self.palette(action),
+			Layer::Pane => self.pane(action),
 		}
 	}
 
@@ -52,6 +54,7 @@ impl<'a> Executor<'a> {
 		on!(deprecate);
 		on!(passthrough);
 		on!(theme);
+		on!(reload);
 		on!(stop);
 		on!(quit);
 
@@ -100,6 +103,9 @@ impl<'a> Executor<'a> {
 		on!(reveal);
 		on!(follow);
 		on!(stash);
+		on!(expand);
+		on!(collapse);
+		on!(tree);
 
 		// Toggle
 		on!(toggle);
@@ -112,6 +118,7 @@ impl<'a> Executor<'a> {
 		on!(yank);
 		on!(unyank);
 		on!(paste);
+		on!(transfer);
 		on!(link);
 		on!(hardlink);
 		on!(remove);
@@ -124,6 +131,12 @@ impl<'a> Executor<'a> {
 		on!(linemode);
 		on!(search);
 		on!(search_do);
+		on!(flatten);
+		on!(dedupe);
+		on!(dedupe_select);
+		on!(dedupe_link);
+		on!(checksum);
+		on!(checksum_verify);
 		on!(bulk_exit);
 		on!(bulk_rename);
 		on!(bulk_create);
@@ -146,6 +159,8 @@ impl<'a> Executor<'a> {
 		on!(tab_close);
 		on!(tab_switch);
 		on!(tab_swap);
+		on!(tab_flip);
+		on!(layout);
 
 		// VFS
 		on!(download);
@@ -155,6 +170,8 @@ impl<'a> Executor<'a> {
 		match action.name.as_ref() {
 			// Help
 			"help" => act!(help:toggle, cx, Layer::Mgr),
+			// Palette
+			"palette" => act!(palette:toggle, cx, Layer::Mgr),
 			// Plugin
 			"plugin" => act!(app:plugin, cx, action),
 			// Lua
@@ -183,11 +200,14 @@ impl<'a> Executor<'a> {
 		on!(arrow);
 		on!(inspect);
 		on!(cancel);
+		on!(throttle);
 		on!(process_open);
 
 		match action.name.as_ref() {
 			// Help
 			"help" => act!(help:toggle, cx, Layer::Tasks),
+			// Palette
+			"palette" => act!(palette:toggle, cx, Layer::Tasks),
 			// Plugin
 			"plugin" => act!(app:plugin, cx, action),
 			// Lua
@@ -215,6 +235,8 @@ impl<'a> Executor<'a> {
 		match action.name.as_ref() {
 			// Help
 			"help" => act!(help:toggle, cx, Layer::Spot),
+			// Palette
+			"palette" => act!(palette:toggle, cx, Layer::Spot),
 			// Plugin
 			"plugin" => act!(app:plugin, cx, action),
 			// Lua
@@ -241,6 +263,8 @@ impl<'a> Executor<'a> {
 		match action.name.as_ref() {
 			// Help
 			"help" => act!(help:toggle, cx, Layer::Pick),
+			// Palette
+			"palette" => act!(palette:toggle, cx, Layer::Pick),
 			// Plugin
 			"plugin" => act!(app:plugin, cx, action),
 			// Lua
@@ -285,6 +309,7 @@ impl<'a> Executor<'a> {
 			},
 			InputMode::Insert => match action.name.as_ref() {
 				"complete" => on!(input:complete, action),
+				"search" => on!(input:search, action),
 				_ => {}
 			},
 			InputMode::Replace => {}
@@ -339,6 +364,35 @@ impl<'a> Executor<'a> {
 		}
 	}
 
+	fn palette(&mut self, action: ActionCow) -> Result<Data> {
+		let cx = &mut Ctx::new(&action, &mut self.app.core, &mut self.app.term)?;
+
+		macro_rules! on {
+			($name:ident) => {
+				if action.name == stringify!($name) {
+					return act!(palette:$name, cx, action);
+				}
+			};
+		}
+
+		on!(escape);
+		on!(arrow);
+		on!(close);
+		on!(complete);
+
+		match action.name.as_ref() {
+			// Plugin
+			"plugin" => act!(app:plugin, cx, action),
+			// Lua
+			"lua" => act!(app:lua, cx, action),
+			_ => {
+				cx.palette.input.execute(action)?;
+				cx.palette.filter_apply();
+				succ!()
+			}
+		}
+	}
+
 	fn cmp(&mut self, action: ActionCow) -> Result<Data> {
 		let cx = &mut Ctx::new(&action, &mut self.app.core, &mut self.app.term)?;
 
@@ -397,6 +451,50 @@ impl<'a> Executor<'a> {
 		on!(push);
 		on!(tick);
 
-		succ!();
+		on!(show);
+		on!(close);
+		on!(arrow);
+		on!(filter);
+		on!(copy);
+		on!(reveal);
+
+		match action.name.as_ref() {
+			// Help
+			"help" => act!(help:toggle, cx, Layer::Notify),
+			// Plugin
+			"plugin" => act!(app:plugin, cx, action),
+			// Lua
+			"lua" => act!(app:lua, cx, action),
+			_ => succ!(),
+		}
+	}
+
+	fn pane(&mut self, action: ActionCow) -> Result<Data> {
+		let cx = &mut Ctx::new(&action, &mut self.app.core, &mut self.app.term)?;
+
+		macro_rules! on {
+			($name:ident) => {
+				if action.name == stringify!($name) {
+					return act!(pane:$name, cx, action);
+				}
+			};
+		}
+
+		on!(run);
+		on!(output);
+		on!(toggle);
+		on!(focus);
+		on!(blur);
+		on!(close);
+
+		match action.name.as_ref() {
+			// Help
+			"help" => act!(help:toggle, cx, Layer::Pane),
+			// Plugin
+			"plugin" => act!(app:plugin, cx, action),
+			// Lua
+			"lua" => act!(app:lua, cx, action),
+			_ => succ!(),
+		}
 	}
 }
@@ -1,4 +1,4 @@
-use std::path::Path;
+use std::{borrow::Cow, path::Path};
 
 use ratatui_core::{buffer::Buffer, layout::{Margin, Rect}, text::Line, widgets::Widget};
 use ratatui_widgets::{block::Block, borders::BorderType};
@@ -46,10 +46,15 @@ impl Widget for Input<'_> {
 		let outer = self.core.mgr.area(self.core.input.main.position);
 		yazi_widgets::clear::Clear::default().render(outer, buf);
 
+		let title: Cow<_> = match self.core.input.main.history.query() {
+			Some(query) => format!("Search history: {query}").into(),
+			None => self.core.input.main.title.as_str().into(),
+		};
+
 		let mut block = Block::bordered()
 			.border_type(BorderType::Rounded)
 			.border_style(THEME.input.border.get())
-			.title(Line::styled(&self.core.input.main.title, THEME.input.title.get()));
+			.title(Line::styled(title, THEME.input.title.get()));
 
 		if let Some(i) = self.icon() {
 			block = block.title_bottom(Line::raw(format!("{} ", i.text)).style(i.style).right_aligned());
@@ -4,7 +4,7 @@ static GLOBAL: tikv_jemallocator::Jemalloc = tikv_jemallocator::Jemalloc;
 
 extern crate self as yazi_fm;
 
-yazi_macro::mod_pub!(app cmp confirm help input mgr notify pick spot tasks which);
+yazi_macro::mod_pub!(app cmp confirm help input mgr notify palette pane pick spot tasks which);
 
 yazi_macro::mod_flat!(dispatcher executor logs panic renderer root router signals);
 
@@ -54,6 +54,8 @@ async fn serve() -> anyhow::Result<()> {
 
 	yazi_dds::serve();
 
+	yazi_watcher::serve();
+
 	yazi_plugin::setup()?;
 
 	yazi_shared::LOCAL_SET.run_until(app::App::serve()).await
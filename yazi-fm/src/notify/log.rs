@@ -0,0 +1,78 @@
+use chrono::{DateTime, Local};
+use ratatui_core::{buffer::Buffer, layout::{Alignment, Constraint, Layout, Rect}, symbols::merge::MergeStrategy, text::{Line, Span}, widgets::Widget};
+use ratatui_widgets::{block::{Block, Padding}, borders::BorderType, list::{List, ListItem}, paragraph::{Paragraph, Wrap}};
+use yazi_config::THEME;
+use yazi_core::{Core, notify::NotifyLog};
+use yazi_shim::ratatui::Padable;
+
+pub(crate) struct Log<'a> {
+	core: &'a Core,
+}
+
+impl<'a> Log<'a> {
+	pub(crate) fn new(core: &'a Core) -> Self { Self { core } }
+}
+
+impl Widget for Log<'_> {
+	fn render(self, _: Rect, buf: &mut Buffer) {
+		let log = &self.core.notify.log;
+		let area = self.core.mgr.area(NotifyLog::position());
+
+		yazi_widgets::clear::Clear::default().render(area, buf);
+
+		let title = match log.level {
+			Some(level) => format!("notifications ({level:?})").to_lowercase(),
+			None => "notifications".to_owned(),
+		};
+		Block::bordered()
+			.title(title)
+			.title_alignment(Alignment::Center)
+			.border_type(BorderType::Rounded)
+			.border_style(THEME.help.border.get())
+			.render(area, buf);
+
+		let chunks =
+			Layout::vertical([Constraint::Fill(1), Constraint::Length(1), Constraint::Length(5)])
+				.split(area.padding(Padding::horizontal(1)).padding(Padding::vertical(1)));
+
+		// Entries
+		let items: Vec<_> = log
+			.window()
+			.map(|e| {
+				let time = DateTime::<Local>::from(e.time).format("%H:%M:%S").to_string();
+				let mut spans = vec![
+					Span::styled(time, THEME.help.chord.get()),
+					Span::raw(" "),
+					Span::styled(format!("{} {}", e.level.icon(), e.title), e.level.style()),
+					Span::raw(" "),
+					Span::raw(e.content.lines().next().unwrap_or_default()),
+				];
+				if e.task.is_some() {
+					spans.insert(3, Span::styled(" [task]", THEME.help.chord.get()));
+				}
+				ListItem::new(Line::from(spans))
+			})
+			.collect();
+
+		if !items.is_empty() {
+			let cursor = log.rel_cursor() as u16;
+			buf.set_style(
+				Rect { y: chunks[0].y + cursor, height: 1, ..chunks[0] },
+				THEME.help.hovered.get(),
+			);
+		}
+		List::new(items).render(chunks[0], buf);
+
+		// Divider
+		Block::bordered()
+			.border_type(BorderType::Rounded)
+			.border_style(THEME.help.border.get())
+			.merge_borders(MergeStrategy::Fuzzy)
+			.render(Rect { x: area.x, width: area.width, ..chunks[1] }, buf);
+
+		// Content of the selected entry
+		if let Some(e) = log.selected() {
+			Paragraph::new(e.content.as_str()).wrap(Wrap { trim: false }).render(chunks[2], buf);
+		}
+	}
+}
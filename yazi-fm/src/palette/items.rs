@@ -0,0 +1,52 @@
+use ratatui_core::{buffer::Buffer, layout::{self, Constraint, Rect}, text::{Line, Span}, widgets::Widget};
+use ratatui_widgets::list::{List, ListItem};
+use yazi_config::THEME;
+use yazi_core::Core;
+
+pub(super) struct Items<'a> {
+	core: &'a Core,
+}
+
+impl<'a> Items<'a> {
+	pub(super) fn new(core: &'a Core) -> Self { Self { core } }
+}
+
+impl Widget for Items<'_> {
+	fn render(self, area: Rect, buf: &mut Buffer) {
+		let palette = &self.core.palette;
+		let items: Vec<_> = palette.window().collect();
+		if items.is_empty() {
+			return;
+		}
+
+		// Keys
+		let col1: Vec<_> = items
+			.iter()
+			.map(|item| ListItem::new(item.keys.join(" ")).style(THEME.help.chord.get()))
+			.collect();
+
+		// Action and its arguments
+		let col2: Vec<_> = items
+			.iter()
+			.map(|item| {
+				let mut spans = vec![Span::styled(item.title(), THEME.help.action.get())];
+				spans.extend(
+					item.args.iter().map(|a| Span::styled(format!(" --{a}"), THEME.help.chord.get())),
+				);
+				ListItem::new(Line::from(spans))
+			})
+			.collect();
+
+		let chunks =
+			layout::Layout::horizontal([Constraint::Length(20), Constraint::Fill(1)]).split(area);
+
+		let cursor = palette.rel_cursor() as u16;
+		buf.set_style(
+			Rect { x: area.x, y: area.y + cursor, width: area.width, height: 1 },
+			THEME.help.hovered.get(),
+		);
+
+		List::new(col1).render(chunks[0], buf);
+		List::new(col2).render(chunks[1], buf);
+	}
+}
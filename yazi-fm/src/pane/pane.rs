@@ -0,0 +1,42 @@
+use ratatui_core::{buffer::Buffer, layout::Rect, text::Line, widgets::Widget};
+use ratatui_widgets::{block::Block, borders::Borders};
+use yazi_config::THEME;
+use yazi_core::Core;
+
+pub(crate) struct Pane<'a> {
+	core: &'a Core,
+}
+
+impl<'a> Pane<'a> {
+	pub(crate) fn new(core: &'a Core) -> Self { Self { core } }
+}
+
+impl Widget for Pane<'_> {
+	fn render(self, area: Rect, buf: &mut Buffer) {
+		let pane = &self.core.pane;
+		let Some(area) = pane.split(area).1 else { return };
+
+		yazi_widgets::clear::Clear::default().render(area, buf);
+
+		let status = match (pane.running, pane.code) {
+			(true, _) => String::new(),
+			(false, Some(code)) => format!(" (exited with {code})"),
+			(false, None) => " (terminated)".to_owned(),
+		};
+		let block = Block::new()
+			.borders(Borders::TOP)
+			.title(Line::styled(format!(" {}{status} ", pane.title), THEME.tasks.title.get()))
+			.border_style(if pane.focused { THEME.tasks.title.get() } else { THEME.tasks.border.get() });
+
+		let inner = block.inner(area);
+		block.render(area, buf);
+
+		for (y, line) in (inner.y..inner.bottom()).zip(pane.screen.lines()) {
+			for (x, cell) in (inner.x..inner.right()).zip(line) {
+				if let Some(c) = buf.cell_mut((x, y)) {
+					*c = cell.clone();
+				}
+			}
+		}
+	}
+}
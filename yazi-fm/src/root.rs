@@ -4,7 +4,7 @@ use yazi_core::Core;
 use yazi_macro::error;
 use yazi_plugin::LUA;
 
-use super::{cmp, confirm, help, input, mgr, pick, spot, tasks, which};
+use super::{cmp, confirm, help, input, mgr, notify, palette, pane, pick, spot, tasks, which};
 use crate::Renderer;
 
 pub(super) struct Root<'a> {
@@ -23,13 +23,18 @@ impl<'a> Root<'a> {
 
 impl Widget for Root<'_> {
 	fn render(self, area: Rect, buf: &mut Buffer) {
-		if let Err(e) = Renderer::new(self.core, "Root").render(area, buf) {
+		let (top, _) = self.core.pane.split(area);
+		if let Err(e) = Renderer::new(self.core, "Root").render(top, buf) {
 			error!("Failed to redraw the `Root` component:\n{e}");
 		}
 
 		mgr::Preview::new(self.core).render(area, buf);
 		mgr::Modal::new(self.core).render(area, buf);
 
+		if self.core.pane.visible {
+			pane::Pane::new(self.core).render(area, buf);
+		}
+
 		if self.core.tasks.visible {
 			tasks::Tasks::new(self.core).render(area, buf);
 		}
@@ -42,6 +47,10 @@ impl Widget for Root<'_> {
 			pick::Pick::new(self.core).render(area, buf);
 		}
 
+		if self.core.notify.log.visible {
+			notify::Log::new(self.core).render(area, buf);
+		}
+
 		if self.core.input.main.visible {
 			input::Input::new(self.core).render(area, buf);
 		}
@@ -54,6 +63,10 @@ impl Widget for Root<'_> {
 			help::Help::new(self.core).render(area, buf);
 		}
 
+		if self.core.palette.visible {
+			palette::Palette::new(self.core).render(area, buf);
+		}
+
 		if self.core.cmp.visible {
 			cmp::Cmp::new(self.core).render(area, buf);
 		}
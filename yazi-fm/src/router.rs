@@ -23,6 +23,10 @@ impl<'a> Router<'a> {
 			return Ok(true);
 		}
 
+		if core.palette.visible && core.palette.r#type(&key)? {
+			return Ok(true);
+		}
+
 		if let Some(mut guard) = core.input.lock_mut()
 			&& guard.r#type(&key)?
 		{
@@ -30,13 +34,22 @@ impl<'a> Router<'a> {
 		}
 
 		let layer = core.layer();
+		if layer == L::Pane {
+			return Ok(
+				self.matches(L::Pane, L::Pane, Key::from(key.clone())) || self.app.core.pane.r#type(&key),
+			);
+		}
+
 		let key = Key::from(key);
 		Ok(match layer {
-			L::Null | L::App | L::Notify => unreachable!(),
-			L::Mgr | L::Tasks | L::Spot | L::Pick | L::Input | L::Confirm => {
+			L::Null | L::App | L::Pane => unreachable!(),
+			L::Mgr | L::Tasks | L::Spot | L::Pick | L::Input | L::Confirm | L::Notify => {
 				self.matches(layer, layer, key)
 			}
 			L::Help => self.matches(L::Help, L::Help, key) || self.matches(L::Input, L::Help, key),
+			L::Palette => {
+				self.matches(L::Palette, L::Palette, key) || self.matches(L::Input, L::Palette, key)
+			}
 			L::Cmp => self.matches(L::Cmp, L::Cmp, key) || self.matches(L::Input, L::Input, key),
 			L::Which => core.which.r#type(key),
 		})
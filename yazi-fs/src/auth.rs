@@ -20,14 +20,18 @@ impl FsAuth for Auth {
 	fn cache_root(&self) -> Option<PathBuf> {
 		match self.kind {
 			AuthKind::Regular | AuthKind::Search => None,
-			AuthKind::Mount | AuthKind::Hub | AuthKind::Scope | AuthKind::Sftp => {
-				Some(Xdg::temp_dir().join(format!(
-					"{}_{}_{}",
-					self.kind.into_str(),
-					self.scheme,
-					self.domain.hash_u128_str(&mut [0; 26])
-				)))
-			}
+			AuthKind::Mount
+			| AuthKind::Hub
+			| AuthKind::Scope
+			| AuthKind::Sftp
+			| AuthKind::Webdav
+			| AuthKind::S3
+			| AuthKind::Ftp => Some(Xdg::temp_dir().join(format!(
+				"{}_{}_{}",
+				self.kind.into_str(),
+				self.scheme,
+				self.domain.hash_u128_str(&mut [0; 26])
+			))),
 		}
 	}
 }
@@ -0,0 +1,262 @@
+use std::{borrow::Cow, io};
+
+use data_encoding::HEXLOWER;
+use serde::{Deserialize, Serialize};
+use sha2::Digest as _;
+use strum::{EnumString, IntoStaticStr};
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+// --- Digest
+#[derive(
+	Clone,
+	Copy,
+	Debug,
+	Default,
+	Deserialize,
+	EnumString,
+	Eq,
+	Hash,
+	IntoStaticStr,
+	PartialEq,
+	Serialize,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum Digest {
+	#[default]
+	Sha256,
+	Sha1,
+	Md5,
+	Blake3,
+}
+
+impl Digest {
+	pub fn hasher(self) -> Digester {
+		match self {
+			Self::Sha256 => Digester::Sha256(Default::default()),
+			Self::Sha1 => Digester::Sha1(Default::default()),
+			Self::Md5 => Digester::Md5(Default::default()),
+			Self::Blake3 => Digester::Blake3(Default::default()),
+		}
+	}
+
+	pub async fn read<R>(self, mut reader: R) -> io::Result<String>
+	where
+		R: AsyncRead + Unpin,
+	{
+		let mut hasher = self.hasher();
+		let mut buf = vec![0; 64 * 1024];
+
+		loop {
+			match reader.read(&mut buf).await? {
+				0 => break,
+				n => hasher.update(&buf[..n]),
+			}
+		}
+		Ok(hasher.finalize())
+	}
+
+	/// Name of the algorithm as used by the SFTP `check-file` extension.
+	pub fn sftp_name(self) -> Option<&'static str> {
+		match self {
+			Self::Sha256 => Some("sha256"),
+			Self::Sha1 => Some("sha1"),
+			Self::Md5 => Some("md5"),
+			Self::Blake3 => None,
+		}
+	}
+
+	pub fn encode(bytes: &[u8]) -> String { HEXLOWER.encode(bytes) }
+
+	/// Guess the algorithm from a manifest name, e.g. `SHA256SUMS` or
+	/// `archive.tar.gz.md5`.
+	pub fn from_manifest(name: &str) -> Option<Self> {
+		let name = name.to_ascii_lowercase();
+		if name.contains("sha256") {
+			Some(Self::Sha256)
+		} else if name.contains("sha1") {
+			Some(Self::Sha1)
+		} else if name.contains("md5") {
+			Some(Self::Md5)
+		} else if name.contains("b3") || name.contains("blake3") {
+			Some(Self::Blake3)
+		} else {
+			None
+		}
+	}
+
+	/// Guess the algorithm from the length of a hex digest. BLAKE3 and SHA-256
+	/// share the same length, in which case SHA-256 wins.
+	pub fn from_hex_len(len: usize) -> Option<Self> {
+		match len {
+			64 => Some(Self::Sha256),
+			40 => Some(Self::Sha1),
+			32 => Some(Self::Md5),
+			_ => None,
+		}
+	}
+}
+
+// --- Digester
+pub enum Digester {
+	Sha256(sha2::Sha256),
+	Sha1(sha1::Sha1),
+	Md5(md5::Md5),
+	Blake3(Box<blake3::Hasher>),
+}
+
+impl Digester {
+	pub fn update(&mut self, data: &[u8]) {
+		match self {
+			Self::Sha256(h) => h.update(data),
+			Self::Sha1(h) => h.update(data),
+			Self::Md5(h) => h.update(data),
+			Self::Blake3(h) => _ = h.update(data),
+		}
+	}
+
+	pub fn finalize(self) -> String {
+		match self {
+			Self::Sha256(h) => HEXLOWER.encode(&h.finalize()),
+			Self::Sha1(h) => HEXLOWER.encode(&h.finalize()),
+			Self::Md5(h) => HEXLOWER.encode(&h.finalize()),
+			Self::Blake3(h) => h.finalize().to_hex().to_string(),
+		}
+	}
+}
+
+// --- Manifest
+#[derive(Debug, Eq, PartialEq)]
+pub struct DigestLine<'a> {
+	pub digest: &'a str,
+	pub name:   Cow<'a, str>,
+}
+
+impl<'a> DigestLine<'a> {
+	/// Parse one line of a `sha256sum`-style manifest, in either text
+	/// (`<digest>  <name>`) or binary (`<digest> *<name>`) mode, including the
+	/// `\`-prefixed form GNU tools use for names with backslashes or newlines.
+	pub fn parse(line: &'a str) -> Option<Self> {
+		let line = line.trim_end_matches(['\r', '\n']);
+		if line.starts_with('#') {
+			return None;
+		}
+
+		let (escaped, line) = match line.strip_prefix('\\') {
+			Some(rest) => (true, rest),
+			None => (false, line),
+		};
+
+		let (digest, rest) = line.split_once(' ')?;
+		let name = rest.strip_prefix([' ', '*'])?;
+		if name.is_empty() || !digest.bytes().all(|b| b.is_ascii_hexdigit()) {
+			return None;
+		}
+
+		let name = if escaped { Self::unescape(name)?.into() } else { name.into() };
+		Some(Self { digest, name })
+	}
+
+	pub fn format(digest: &str, name: &str) -> String {
+		if name.contains(['\\', '\n', '\r']) {
+			let name = name.replace('\\', "\\\\").replace('\n', "\\n").replace('\r', "\\r");
+			format!("\\{digest}  {name}")
+		} else {
+			format!("{digest}  {name}")
+		}
+	}
+
+	/// Whether the name stays within the directory of the manifest, i.e. it's
+	/// relative and never goes up with `..`.
+	pub fn is_confined(&self) -> bool {
+		let b = self.name.as_bytes();
+		if b.starts_with(b"/") || b.starts_with(b"\\") {
+			return false;
+		} else if b.len() > 1 && b[0].is_ascii_alphabetic() && b[1] == b':' {
+			return false;
+		}
+		!self.name.split(['/', '\\']).any(|c| c == "..")
+	}
+
+	fn unescape(s: &str) -> Option<String> {
+		let mut out = String::with_capacity(s.len());
+		let mut it = s.chars();
+		while let Some(c) = it.next() {
+			out.push(match c {
+				'\\' => match it.next()? {
+					'\\' => '\\',
+					'n' => '\n',
+					'r' => '\r',
+					_ => return None,
+				},
+				c => c,
+			});
+		}
+		Some(out)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn hex(digest: Digest, data: &[u8]) -> String {
+		let mut h = digest.hasher();
+		h.update(data);
+		h.finalize()
+	}
+
+	#[test]
+	fn test_digest() {
+		assert_eq!(
+			hex(Digest::Sha256, b"abc"),
+			"ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+		);
+		assert_eq!(hex(Digest::Sha1, b"abc"), "a9993e364706816aba3e25717850c26c9cd0d89d");
+		assert_eq!(hex(Digest::Md5, b"abc"), "900150983cd24fb0d6963f7d28e17f72");
+		assert_eq!(
+			hex(Digest::Blake3, b"abc"),
+			"6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85"
+		);
+	}
+
+	#[test]
+	fn test_guess() {
+		assert_eq!(Digest::from_manifest("SHA256SUMS"), Some(Digest::Sha256));
+		assert_eq!(Digest::from_manifest("SHA1SUMS"), Some(Digest::Sha1));
+		assert_eq!(Digest::from_manifest("foo.iso.md5"), Some(Digest::Md5));
+		assert_eq!(Digest::from_manifest("B3SUMS"), Some(Digest::Blake3));
+		assert_eq!(Digest::from_manifest("CHECKSUMS"), None);
+
+		assert_eq!(Digest::from_hex_len(40), Some(Digest::Sha1));
+		assert_eq!(Digest::from_hex_len(12), None);
+	}
+
+	#[test]
+	fn test_parse() {
+		let parse = DigestLine::parse;
+		let line = |digest, name: &str| Some(DigestLine { digest, name: name.to_owned().into() });
+		assert_eq!(parse("abc123  foo.txt"), line("abc123", "foo.txt"));
+		assert_eq!(parse("abc123 *dir/a b\r\n"), line("abc123", "dir/a b"));
+		assert_eq!(parse("# comment"), None);
+		assert_eq!(parse("abc123 foo.txt"), None);
+		assert_eq!(parse("xyz  foo.txt"), None);
+		assert_eq!(parse("abc123  "), None);
+
+		// Escaped names of GNU tools
+		assert_eq!(parse("\\abc123  a\\nb\\\\c"), line("abc123", "a\nb\\c"));
+		assert_eq!(parse("\\abc123  a\\x"), None);
+		assert_eq!(DigestLine::format("abc123", "a\nb\\c"), "\\abc123  a\\nb\\\\c");
+	}
+
+	#[test]
+	fn test_confined() {
+		let confined = |s| DigestLine::parse(s).unwrap().is_confined();
+		assert!(confined("abc123  a/b..c/d"));
+		assert!(!confined("abc123  ../etc/passwd"));
+		assert!(!confined("abc123  a/../../b"));
+		assert!(!confined("abc123  /etc/passwd"));
+		assert!(!confined("abc123  C:\\Windows"));
+		assert!(!confined("abc123  a\\..\\b"));
+	}
+}
@@ -1,8 +1,9 @@
-use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::{io, time::{Duration, SystemTime, UNIX_EPOCH}};
 
 use mlua::{IntoLua, Lua, Value};
+use yazi_shared::id::Id;
 
-use crate::cha::{Cha, ChaMode};
+use crate::{cha::{Cha, ChaMode}, engine::{Limiter, Preserve}};
 
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub struct Attrs {
@@ -10,11 +11,25 @@ pub struct Attrs {
 	pub atime: Option<SystemTime>,
 	pub btime: Option<SystemTime>,
 	pub mtime: Option<SystemTime>,
+
+	pub uid:      Option<u32>,
+	pub gid:      Option<u32>,
+	pub preserve: Preserve,
+	pub throttle: Limiter,
+	/// The task the transfer belongs to, where what can't be preserved is
+	/// reported.
+	pub task:     Option<Id>,
 }
 
 impl From<Cha> for Attrs {
 	fn from(value: Cha) -> Self {
-		Self { mode: Some(value.mode), atime: value.atime, btime: value.btime, mtime: value.mtime }
+		Self {
+			mode: Some(value.mode),
+			atime: value.atime,
+			btime: value.btime,
+			mtime: value.mtime,
+			..Default::default()
+		}
 	}
 }
 
@@ -67,6 +82,20 @@ impl TryFrom<Attrs> for std::fs::Permissions {
 impl Attrs {
 	pub fn mode(mode: ChaMode) -> Self { Self { mode: Some(mode), ..Default::default() } }
 
+	/// Attributes of `cha`, including its ownership if `preserve` asks for it.
+	pub fn preserving(cha: Cha, preserve: Preserve) -> Self {
+		let owned = preserve.contains(Preserve::OWNERSHIP);
+		Self { uid: owned.then_some(cha.uid), gid: owned.then_some(cha.gid), preserve, ..cha.into() }
+	}
+
+	/// Record that `what` couldn't be preserved, to be reported in the log of
+	/// the task the transfer belongs to.
+	pub fn lapse(self, what: Preserve, err: io::Error) {
+		if let Some(id) = self.task {
+			what.lapse(id, err);
+		}
+	}
+
 	pub fn has_times(self) -> bool {
 		self.atime.is_some() || self.btime.is_some() || self.mtime.is_some()
 	}
@@ -86,6 +115,8 @@ impl IntoLua for Attrs {
 				("atime", self.atime_dur().map(|d| d.as_secs_f64()).into_lua(lua)?),
 				("btime", self.btime_dur().map(|d| d.as_secs_f64()).into_lua(lua)?),
 				("mtime", self.mtime_dur().map(|d| d.as_secs_f64()).into_lua(lua)?),
+				("uid", self.uid.into_lua(lua)?),
+				("gid", self.gid.into_lua(lua)?),
 			])?
 			.into_lua(lua)
 	}
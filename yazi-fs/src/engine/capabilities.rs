@@ -1,11 +1,17 @@
 use mlua::{FromLua, Lua, Table, Value};
 
+use crate::engine::Preserve;
+
 #[derive(Clone, Copy, Debug, Default)]
 pub struct Capabilities {
 	pub symlink:          bool,
 	pub hard_link:        bool,
 	pub trash:            bool,
 	pub copy_progressive: bool,
+	pub server_copy:      bool,
+	pub ownership:        bool,
+	pub xattrs:           bool,
+	pub acls:             bool,
 }
 
 impl FromLua for Capabilities {
@@ -17,6 +23,21 @@ impl FromLua for Capabilities {
 			hard_link:        t.raw_get("hard_link")?,
 			trash:            t.raw_get("trash")?,
 			copy_progressive: t.raw_get("copy_progressive")?,
+			server_copy:      t.raw_get("server_copy")?,
+			ownership:        t.raw_get("ownership")?,
+			xattrs:           t.raw_get("xattrs")?,
+			acls:             t.raw_get("acls")?,
 		})
 	}
 }
+
+impl Capabilities {
+	/// The subset of `preserve` that this engine is able to carry over.
+	pub fn preservable(self, preserve: Preserve) -> Preserve {
+		let mut me = Preserve::empty();
+		me.set(Preserve::OWNERSHIP, self.ownership);
+		me.set(Preserve::XATTRS, self.xattrs);
+		me.set(Preserve::ACLS, self.acls);
+		me & preserve
+	}
+}
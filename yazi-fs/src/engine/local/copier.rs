@@ -1,40 +1,39 @@
 use std::{io, path::PathBuf};
 
-use tokio::{select, sync::{mpsc, oneshot}};
+use tokio::sync::mpsc;
 
 use crate::engine::Attrs;
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+use crate::engine::{REFLINK, Reflink};
 
 pub(super) async fn copy_impl(from: PathBuf, to: PathBuf, attrs: Attrs) -> io::Result<u64> {
 	#[cfg(any(target_os = "linux", target_os = "android"))]
 	{
-		use std::os::unix::fs::OpenOptionsExt;
-
-		tokio::task::spawn_blocking(move || {
-			let mut opts = std::fs::OpenOptions::new();
-			if let Some(mode) = attrs.mode {
-				opts.mode(mode.bits() as _);
-			}
-
-			let mut reader = std::fs::File::open(from)?;
-			let mut writer = opts.write(true).create(true).truncate(true).open(to)?;
-			let written = std::io::copy(&mut reader, &mut writer)?;
-
-			if let Some(mode) = attrs.mode {
-				writer.set_permissions(mode.into()).ok();
-			}
-			if let Ok(times) = attrs.try_into() {
-				writer.set_times(times).ok();
-			}
-
-			Ok(written)
-		})
-		.await?
+		tokio::task::spawn_blocking(move || linux::copy(from, to, attrs, |_| true)).await?
 	}
 
 	#[cfg(not(any(target_os = "linux", target_os = "android")))]
 	{
+		if REFLINK.get() == Reflink::Always {
+			return Err(io::Error::new(
+				io::ErrorKind::Unsupported,
+				"Reflink is not supported on this platform",
+			));
+		}
+
 		tokio::task::spawn_blocking(move || {
-			let written = std::fs::copy(from, &to)?;
+			let written = if attrs.throttle.is_limited() {
+				throttled(&from, &to, attrs)?
+			} else {
+				std::fs::copy(from, &to)?
+			};
+
+			#[cfg(unix)]
+			if (attrs.uid.is_some() || attrs.gid.is_some())
+				&& let Err(e) = std::os::unix::fs::chown(&to, attrs.uid, attrs.gid)
+			{
+				attrs.lapse(crate::engine::Preserve::OWNERSHIP, e);
+			}
 
 			if let Ok(times) = attrs.try_into()
 				&& let Ok(file) = std::fs::File::options().write(true).open(to)
@@ -54,47 +53,357 @@ pub(super) fn copy_progressive_impl(
 	attrs: Attrs,
 ) -> mpsc::Receiver<Result<u64, io::Error>> {
 	let (prog_tx, prog_rx) = mpsc::channel(20);
-	let (done_tx, mut done_rx) = oneshot::channel();
 
-	tokio::spawn({
-		let to = to.clone();
-		async move {
-			done_tx.send(copy_impl(from, to, attrs).await).ok();
-		}
+	#[cfg(any(target_os = "linux", target_os = "android"))]
+	tokio::task::spawn_blocking(move || {
+		match linux::copy(from, to, attrs, |n| prog_tx.blocking_send(Ok(n)).is_ok()) {
+			Ok(_) => prog_tx.blocking_send(Ok(0)).ok(),
+			Err(e) => prog_tx.blocking_send(Err(e)).ok(),
+		};
 	});
 
-	tokio::spawn(async move {
-		let mut last = 0;
-		let mut done = None;
-		loop {
-			select! {
-				res = &mut done_rx => done = Some(res.unwrap()),
-				_ = prog_tx.closed() => break,
-				_ = tokio::time::sleep(std::time::Duration::from_secs(3)) => {},
+	#[cfg(not(any(target_os = "linux", target_os = "android")))]
+	{
+		let (done_tx, mut done_rx) = tokio::sync::oneshot::channel();
+
+		tokio::spawn({
+			let to = to.clone();
+			async move {
+				done_tx.send(copy_impl(from, to, attrs).await).ok();
 			}
+		});
+
+		tokio::spawn(async move {
+			let mut last = 0;
+			let mut done = None;
+			loop {
+				tokio::select! {
+					res = &mut done_rx => done = Some(res.unwrap()),
+					_ = prog_tx.closed() => break,
+					_ = tokio::time::sleep(std::time::Duration::from_secs(3)) => {},
+				}
 
-			match done {
-				Some(Ok(len)) => {
-					if len > last {
-						prog_tx.send(Ok(len - last)).await.ok();
+				match done {
+					Some(Ok(len)) => {
+						if len > last {
+							prog_tx.send(Ok(len - last)).await.ok();
+						}
+						prog_tx.send(Ok(0)).await.ok();
+						break;
 					}
-					prog_tx.send(Ok(0)).await.ok();
-					break;
+					Some(Err(e)) => {
+						prog_tx.send(Err(e)).await.ok();
+						break;
+					}
+					None => {}
 				}
-				Some(Err(e)) => {
-					prog_tx.send(Err(e)).await.ok();
-					break;
+
+				let len = tokio::fs::symlink_metadata(&to).await.map(|m| m.len()).unwrap_or(0);
+				if len > last {
+					prog_tx.send(Ok(len - last)).await.ok();
+					last = len;
 				}
-				None => {}
 			}
+		});
+	}
+
+	prog_rx
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+fn throttled(from: &std::path::Path, to: &std::path::Path, attrs: Attrs) -> io::Result<u64> {
+	use std::io::{Read, Write};
+
+	let mut reader = std::fs::File::open(from)?;
+	let mut writer = std::fs::File::create(to)?;
+
+	// Like `std::fs::copy`, unless a mode is given
+	let perm = match attrs.mode {
+		#[cfg(unix)]
+		Some(mode) => Ok(std::os::unix::fs::PermissionsExt::from_mode(mode.bits() as _)),
+		_ => reader.metadata().map(|m| m.permissions()),
+	};
+	if let Ok(perm) = perm {
+		writer.set_permissions(perm).ok();
+	}
+
+	let (mut buf, mut written) = (vec![0; 256 * 1024], 0);
+	loop {
+		let n = reader.read(&mut buf)?;
+		if n == 0 {
+			break;
+		}
+		writer.write_all(&buf[..n])?;
+		written += n as u64;
+		attrs.throttle.wait_blocking(n as u64);
+	}
+	Ok(written)
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod linux {
+	use std::{ffi::CString, fs::File, io, os::{fd::AsRawFd, unix::fs::{FileExt, MetadataExt, OpenOptionsExt}}, path::PathBuf};
+
+	use crate::engine::{Attrs, Limiter, Preserve, REFLINK, Reflink};
 
-			let len = tokio::fs::symlink_metadata(&to).await.map(|m| m.len()).unwrap_or(0);
-			if len > last {
-				prog_tx.send(Ok(len - last)).await.ok();
-				last = len;
+	const CHUNK: u64 = 4 * 1024 * 1024;
+
+	// Smaller chunks keep the progress of rate-limited copies smooth
+	const THROTTLED_CHUNK: u64 = 256 * 1024;
+
+	/// Copy `from` to `to`, reporting the length of each copied chunk to
+	/// `progress`. The copy is aborted if `progress` returns `false`.
+	///
+	/// A copy-on-write clone is tried first according to [`REFLINK`], then
+	/// `copy_file_range(2)`, and finally a plain read/write loop. Holes in sparse
+	/// files are preserved, and the copy is rate-limited by `attrs.throttle`.
+	pub(super) fn copy<F>(
+		from: PathBuf,
+		to: PathBuf,
+		attrs: Attrs,
+		mut progress: F,
+	) -> io::Result<u64>
+	where
+		F: FnMut(u64) -> bool,
+	{
+		let mut opts = std::fs::OpenOptions::new();
+		if let Some(mode) = attrs.mode {
+			opts.mode(mode.bits() as _);
+		}
+
+		let reader = File::open(from)?;
+		let writer = opts.write(true).create(true).truncate(true).open(to)?;
+
+		let meta = reader.metadata()?;
+		let cloned = match REFLINK.get() {
+			Reflink::Auto => clone(&reader, &writer).is_ok(),
+			Reflink::Always => clone(&reader, &writer).map(|()| true)?,
+			Reflink::Never => false,
+		};
+
+		let written = if cloned {
+			if meta.len() > 0 {
+				progress(meta.len());
 			}
+			meta.len()
+		} else if meta.blocks() * 512 < meta.len() {
+			sparse(&reader, &writer, meta.len(), attrs.throttle, &mut progress)?
+		} else {
+			Copier::new(attrs.throttle).range(&reader, &writer, 0, u64::MAX, &mut progress)?
+		};
+
+		if (attrs.uid.is_some() || attrs.gid.is_some())
+			&& let Err(e) = std::os::unix::fs::fchown(&writer, attrs.uid, attrs.gid)
+		{
+			attrs.lapse(Preserve::OWNERSHIP, e);
+		}
+		if attrs.preserve.intersects(Preserve::XATTRS | Preserve::ACLS) {
+			xattrs(&reader, &writer, attrs);
+		}
+		if let Some(mode) = attrs.mode {
+			writer.set_permissions(mode.into()).ok();
+		}
+		if let Ok(times) = attrs.try_into() {
+			writer.set_times(times).ok();
 		}
-	});
 
-	prog_rx
+		Ok(written)
+	}
+
+	/// Copy extended attributes on a best-effort basis, recording the ones that
+	/// can't be set as lapses. POSIX ACLs are stored as `system.posix_acl_*`
+	/// attributes, so they're copied the same way.
+	fn xattrs(reader: &File, writer: &File, attrs: Attrs) {
+		let (rfd, wfd) = (reader.as_raw_fd(), writer.as_raw_fd());
+		let Ok(names) =
+			xattr_read(|buf| unsafe { libc::flistxattr(rfd, buf.as_mut_ptr().cast(), buf.len()) })
+		else {
+			return;
+		};
+
+		for name in names.split(|&b| b == 0).filter(|n| !n.is_empty()) {
+			let flag =
+				if name.starts_with(b"system.posix_acl_") { Preserve::ACLS } else { Preserve::XATTRS };
+			let Ok(name) = CString::new(name) else { continue };
+			if !attrs.preserve.contains(flag) {
+				continue;
+			}
+
+			let Ok(value) = xattr_read(|buf| unsafe {
+				libc::fgetxattr(rfd, name.as_ptr(), buf.as_mut_ptr().cast(), buf.len())
+			}) else {
+				continue;
+			};
+			if unsafe { libc::fsetxattr(wfd, name.as_ptr(), value.as_ptr().cast(), value.len(), 0) } != 0
+			{
+				attrs.lapse(flag, io::Error::last_os_error());
+			}
+		}
+	}
+
+	fn xattr_read<F>(f: F) -> io::Result<Vec<u8>>
+	where
+		F: Fn(&mut [u8]) -> isize,
+	{
+		let mut buf = vec![];
+		loop {
+			match f(&mut buf) {
+				-1 => match io::Error::last_os_error() {
+					e if e.raw_os_error() == Some(libc::ERANGE) => buf.clear(),
+					e => return Err(e),
+				},
+				n if n as usize <= buf.len() => {
+					buf.truncate(n as usize);
+					return Ok(buf);
+				}
+				n => buf.resize(n as usize, 0),
+			}
+		}
+	}
+
+	fn clone(reader: &File, writer: &File) -> io::Result<()> {
+		match unsafe { libc::ioctl(writer.as_raw_fd(), libc::FICLONE, reader.as_raw_fd()) } {
+			0 => Ok(()),
+			_ => Err(io::Error::last_os_error()),
+		}
+	}
+
+	fn sparse<F>(
+		reader: &File,
+		writer: &File,
+		len: u64,
+		throttle: Limiter,
+		progress: &mut F,
+	) -> io::Result<u64>
+	where
+		F: FnMut(u64) -> bool,
+	{
+		let mut copier = Copier::new(throttle);
+		let mut pos = 0;
+
+		while pos < len {
+			let Some(data) = seek(reader, pos, libc::SEEK_DATA)? else { break };
+			let hole = seek(reader, data, libc::SEEK_HOLE)?.unwrap_or(len).min(len);
+
+			if data > pos && !progress(data - pos) {
+				return Err(io::ErrorKind::Interrupted.into());
+			}
+
+			copier.range(reader, writer, data, hole, progress)?;
+			pos = hole;
+		}
+
+		if len > pos && !progress(len - pos) {
+			return Err(io::ErrorKind::Interrupted.into());
+		}
+
+		writer.set_len(len)?;
+		Ok(len)
+	}
+
+	fn seek(file: &File, offset: u64, whence: i32) -> io::Result<Option<u64>> {
+		match unsafe { libc::lseek64(file.as_raw_fd(), offset as _, whence) } {
+			-1 => match io::Error::last_os_error() {
+				e if e.raw_os_error() == Some(libc::ENXIO) => Ok(None),
+				e => Err(e),
+			},
+			n => Ok(Some(n as u64)),
+		}
+	}
+
+	struct Copier {
+		fallback: bool,
+		buf:      Vec<u8>,
+		throttle: Limiter,
+		chunk:    u64,
+	}
+
+	impl Copier {
+		fn new(throttle: Limiter) -> Self {
+			let chunk = if throttle.is_limited() { THROTTLED_CHUNK } else { CHUNK };
+			Self { fallback: false, buf: vec![], throttle, chunk }
+		}
+
+		/// Copy the bytes in `start..end` at the same offset, stopping early at
+		/// EOF.
+		fn range<F>(
+			&mut self,
+			reader: &File,
+			writer: &File,
+			start: u64,
+			end: u64,
+			progress: &mut F,
+		) -> io::Result<u64>
+		where
+			F: FnMut(u64) -> bool,
+		{
+			let mut pos = start;
+			while pos < end {
+				let want = (end - pos).min(self.chunk) as usize;
+				let n = if self.fallback {
+					self.read_write(reader, writer, pos, want)?
+				} else {
+					match copy_file_range(reader, writer, pos, want) {
+						Ok(n) => n,
+						Err(e) if pos == start && Self::unsupported(&e) => {
+							self.fallback = true;
+							continue;
+						}
+						Err(e) => return Err(e),
+					}
+				};
+
+				if n == 0 {
+					break;
+				} else if !progress(n as u64) {
+					return Err(io::ErrorKind::Interrupted.into());
+				}
+				pos += n as u64;
+				self.throttle.wait_blocking(n as u64);
+			}
+			Ok(pos - start)
+		}
+
+		fn read_write(
+			&mut self,
+			reader: &File,
+			writer: &File,
+			pos: u64,
+			want: usize,
+		) -> io::Result<usize> {
+			self.buf.resize(want, 0);
+			let n = reader.read_at(&mut self.buf, pos)?;
+			writer.write_all_at(&self.buf[..n], pos)?;
+			Ok(n)
+		}
+
+		fn unsupported(e: &io::Error) -> bool {
+			matches!(
+				e.raw_os_error(),
+				Some(libc::EXDEV | libc::ENOSYS | libc::EINVAL | libc::EOPNOTSUPP | libc::EPERM)
+			)
+		}
+	}
+
+	#[cfg(target_os = "linux")]
+	fn copy_file_range(reader: &File, writer: &File, pos: u64, len: usize) -> io::Result<usize> {
+		let (mut off_in, mut off_out) = (pos as libc::loff_t, pos as libc::loff_t);
+		let n = unsafe {
+			libc::copy_file_range(
+				reader.as_raw_fd(),
+				&mut off_in,
+				writer.as_raw_fd(),
+				&mut off_out,
+				len,
+				0,
+			)
+		};
+
+		if n < 0 { Err(io::Error::last_os_error()) } else { Ok(n as usize) }
+	}
+
+	#[cfg(target_os = "android")]
+	fn copy_file_range(_: &File, _: &File, _: u64, _: usize) -> io::Result<usize> {
+		Err(io::Error::from_raw_os_error(libc::ENOSYS))
+	}
 }
@@ -35,6 +35,10 @@ impl<'a> Engine for Local<'a> {
 			hard_link:        true,
 			trash:            true,
 			copy_progressive: true,
+			server_copy:      false,
+			ownership:        cfg!(unix),
+			xattrs:           cfg!(any(target_os = "linux", target_os = "android")),
+			acls:             cfg!(target_os = "linux"),
 		})
 	}
 
@@ -87,7 +91,13 @@ impl<'a> Engine for Local<'a> {
 	async fn new<'b>(url: Url<'b>) -> io::Result<Self::Me<'b>> {
 		match url {
 			Url::Regular(loc) | Url::Search { loc, .. } => Ok(Self::Me { url, path: loc.as_inner() }),
-			Url::Mount { .. } | Url::Hub { .. } | Url::Scope { .. } | Url::Sftp { .. } => {
+			Url::Mount { .. }
+			| Url::Hub { .. }
+			| Url::Scope { .. }
+			| Url::Sftp { .. }
+			| Url::Webdav { .. }
+			| Url::S3 { .. }
+			| Url::Ftp { .. } => {
 				Err(io::Error::new(io::ErrorKind::InvalidInput, format!("Not a local URL: {url:?}")))
 			}
 		}
@@ -101,7 +111,13 @@ impl<'a> Engine for Local<'a> {
 				reader: tokio::fs::read_dir(self.path).await?,
 				dir:    Arc::new(self.url.to_owned()),
 			},
-			AuthKind::Mount | AuthKind::Hub | AuthKind::Scope | AuthKind::Sftp => Err(io::Error::new(
+			AuthKind::Mount
+			| AuthKind::Hub
+			| AuthKind::Scope
+			| AuthKind::Sftp
+			| AuthKind::Webdav
+			| AuthKind::S3
+			| AuthKind::Ftp => Err(io::Error::new(
 				io::ErrorKind::InvalidInput,
 				format!("Not a local URL: {:?}", self.url),
 			))?,
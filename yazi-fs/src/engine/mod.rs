@@ -1,3 +1,3 @@
 yazi_macro::mod_pub!(local);
 
-yazi_macro::mod_flat!(attrs capabilities demand traits);
+yazi_macro::mod_flat!(attrs capabilities demand preserve reflink throttle traits);
@@ -0,0 +1,123 @@
+use std::{fmt, io, mem, str::FromStr, sync::LazyLock};
+
+use anyhow::bail;
+use bitflags::bitflags;
+use hashbrown::HashMap;
+use parking_lot::Mutex;
+use serde::Deserialize;
+use yazi_shared::id::Id;
+
+/// Metadata that failed to be preserved for a task, and the errors that haven't
+/// been reported yet.
+type Lapses = (Preserve, Vec<(Preserve, io::Error)>);
+
+static LAPSES: LazyLock<Mutex<HashMap<Id, Lapses>>> = LazyLock::new(Default::default);
+
+bitflags! {
+	/// Metadata to carry over from the source when copying, on top of the
+	/// permissions and timestamps that are always preserved.
+	#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq)]
+	#[serde(try_from = "String")]
+	pub struct Preserve: u8 {
+		const OWNERSHIP = 0b001;
+		const XATTRS    = 0b010;
+		const ACLS      = 0b100;
+	}
+}
+
+impl Preserve {
+	/// Record that `self` couldn't be preserved for a transfer of task `id`.
+	/// Only the first failure of each kind is kept, so a task that copies many
+	/// files reports it once.
+	pub fn lapse(self, id: Id, err: io::Error) {
+		let mut lapses = LAPSES.lock();
+		let (seen, pending) = lapses.entry(id).or_default();
+		if !seen.contains(self) {
+			*seen |= self;
+			pending.push((self, err));
+		}
+	}
+
+	/// Take the failures of task `id` that haven't been reported yet.
+	pub fn lapses(id: Id) -> Vec<(Self, io::Error)> {
+		LAPSES.lock().get_mut(&id).map(|(_, pending)| mem::take(pending)).unwrap_or_default()
+	}
+
+	/// Forget the failures of task `id` once it's done.
+	pub fn forget(id: Id) { LAPSES.lock().remove(&id); }
+}
+
+impl FromStr for Preserve {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let mut me = Self::empty();
+		for part in s.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+			me |= match part {
+				"all" => Self::all(),
+				"ownership" => Self::OWNERSHIP,
+				"xattrs" => Self::XATTRS,
+				"acls" => Self::ACLS,
+				_ => bail!("invalid preserve flag: {part}"),
+			};
+		}
+		Ok(me)
+	}
+}
+
+impl TryFrom<String> for Preserve {
+	type Error = anyhow::Error;
+
+	fn try_from(value: String) -> Result<Self, Self::Error> { value.parse() }
+}
+
+impl fmt::Display for Preserve {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let names: Vec<_> =
+			[(Self::OWNERSHIP, "ownership"), (Self::XATTRS, "xattrs"), (Self::ACLS, "acls")]
+				.into_iter()
+				.filter(|&(flag, _)| self.contains(flag))
+				.map(|(_, name)| name)
+				.collect();
+
+		f.write_str(&names.join(","))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse() {
+		assert_eq!("".parse::<Preserve>().unwrap(), Preserve::empty());
+		assert_eq!("all".parse::<Preserve>().unwrap(), Preserve::all());
+		assert_eq!("xattrs, acls".parse::<Preserve>().unwrap(), Preserve::XATTRS | Preserve::ACLS);
+		assert!("owner".parse::<Preserve>().is_err());
+
+		assert_eq!((Preserve::OWNERSHIP | Preserve::ACLS).to_string(), "ownership,acls");
+	}
+
+	#[test]
+	fn test_lapses() {
+		let id = Id::from(u64::MAX);
+		Preserve::OWNERSHIP.lapse(id, io::ErrorKind::PermissionDenied.into());
+		Preserve::OWNERSHIP.lapse(id, io::ErrorKind::Other.into());
+		Preserve::XATTRS.lapse(id, io::ErrorKind::Unsupported.into());
+
+		let lapses: Vec<_> = Preserve::lapses(id).into_iter().map(|(p, e)| (p, e.kind())).collect();
+		assert_eq!(lapses, [
+			(Preserve::OWNERSHIP, io::ErrorKind::PermissionDenied),
+			(Preserve::XATTRS, io::ErrorKind::Unsupported)
+		]);
+
+		// Reported ones don't come back, nor do repeated kinds
+		Preserve::XATTRS.lapse(id, io::ErrorKind::Other.into());
+		assert!(Preserve::lapses(id).is_empty());
+
+		Preserve::forget(id);
+		Preserve::XATTRS.lapse(id, io::ErrorKind::Other.into());
+		assert_eq!(Preserve::lapses(id).len(), 1);
+		Preserve::forget(id);
+	}
+}
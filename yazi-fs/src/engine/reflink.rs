@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+use yazi_shim::cell::SyncCell;
+
+pub static REFLINK: SyncCell<Reflink> = SyncCell::new(Reflink::Auto);
+
+/// Whether local copies should try a copy-on-write clone first.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Reflink {
+	/// Clone when the file system supports it, and fall back to a regular copy
+	/// otherwise.
+	#[default]
+	Auto,
+	/// Always clone, and fail if the file system doesn't support it.
+	Always,
+	/// Never clone.
+	Never,
+}
@@ -0,0 +1,125 @@
+use std::{sync::{Arc, LazyLock, atomic::{AtomicU64, Ordering}}, time::{Duration, Instant}};
+
+use hashbrown::HashMap;
+use parking_lot::Mutex;
+use yazi_shared::id::Id;
+
+/// The bandwidth limit of all transfers together.
+pub static BANDWIDTH: Bucket = Bucket::new(0);
+
+static TASKS: LazyLock<Mutex<HashMap<Id, Arc<Bucket>>>> = LazyLock::new(Default::default);
+
+/// A token bucket limiting the bytes that go through it to `rate` per second,
+/// with bursts of up to one second worth of bytes. A rate of 0 means no limit.
+#[derive(Debug)]
+pub struct Bucket {
+	rate:  AtomicU64,
+	state: Mutex<(f64, Option<Instant>)>,
+}
+
+impl Bucket {
+	pub const fn new(rate: u64) -> Self {
+		Self { rate: AtomicU64::new(rate), state: Mutex::new((0.0, None)) }
+	}
+
+	#[inline]
+	pub fn rate(&self) -> u64 { self.rate.load(Ordering::Relaxed) }
+
+	#[inline]
+	pub fn set_rate(&self, rate: u64) { self.rate.store(rate, Ordering::Relaxed); }
+
+	/// Take `n` bytes worth of tokens, returning how long the caller has to wait
+	/// until they have been paid off.
+	pub fn take(&self, n: u64) -> Duration {
+		let rate = self.rate() as f64;
+		if rate == 0.0 {
+			return Duration::ZERO;
+		}
+
+		let now = Instant::now();
+		let mut state = self.state.lock();
+		let (tokens, last) = &mut *state;
+
+		*tokens = match last {
+			Some(last) => (*tokens + now.duration_since(*last).as_secs_f64() * rate).min(rate),
+			None => rate,
+		};
+		*tokens -= n as f64;
+		*last = Some(now);
+
+		if *tokens >= 0.0 { Duration::ZERO } else { Duration::from_secs_f64(-*tokens / rate) }
+	}
+}
+
+/// The bandwidth limits a transfer is subject to: [`BANDWIDTH`], and the limit
+/// of the task it belongs to, if any.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Limiter(Option<Id>);
+
+impl Limiter {
+	pub fn task(id: Id) -> Self { Self(Some(id)) }
+
+	/// Limit the transfers of task `id` to `rate` bytes per second, or lift the
+	/// limit if `rate` is 0.
+	pub fn limit(id: Id, rate: u64) {
+		let mut tasks = TASKS.lock();
+		match tasks.get(&id) {
+			Some(bucket) => bucket.set_rate(rate),
+			None if rate == 0 => {}
+			None => _ = tasks.insert(id, Arc::new(Bucket::new(rate))),
+		}
+	}
+
+	/// Forget the limit of task `id` once it's done.
+	pub fn forget(id: Id) { TASKS.lock().remove(&id); }
+
+	pub fn is_limited(self) -> bool { BANDWIDTH.rate() > 0 || self.bucket().is_some() }
+
+	/// Account for `n` transferred bytes, returning how long to wait before
+	/// transferring more.
+	pub fn take(self, n: u64) -> Duration {
+		let task = self.bucket().map(|b| b.take(n)).unwrap_or_default();
+		BANDWIDTH.take(n).max(task)
+	}
+
+	/// Account for `n` transferred bytes, and sleep until more can be
+	/// transferred.
+	pub fn wait_blocking(self, n: u64) {
+		let d = self.take(n);
+		if !d.is_zero() {
+			std::thread::sleep(d);
+		}
+	}
+
+	/// Like [`Self::wait_blocking`], but also subject to the limits of `extra`,
+	/// e.g. those of the services involved in the transfer.
+	pub async fn wait<'a>(self, n: u64, extra: impl IntoIterator<Item = &'a Bucket>) {
+		let d = extra.into_iter().map(|b| b.take(n)).fold(self.take(n), Duration::max);
+		if !d.is_zero() {
+			tokio::time::sleep(d).await;
+		}
+	}
+
+	fn bucket(self) -> Option<Arc<Bucket>> { TASKS.lock().get(&self.0?).cloned() }
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_bucket() {
+		let bucket = Bucket::new(0);
+		assert_eq!(bucket.take(u64::MAX), Duration::ZERO);
+
+		// Starts with a full second worth of tokens
+		bucket.set_rate(1000);
+		assert_eq!(bucket.take(1000), Duration::ZERO);
+
+		// Then goes into debt, which takes time to pay off
+		let d = bucket.take(500);
+		assert!(d > Duration::from_millis(490) && d <= Duration::from_millis(500), "{d:?}");
+		let d = bucket.take(500);
+		assert!(d > Duration::from_millis(990) && d <= Duration::from_millis(1000), "{d:?}");
+	}
+}
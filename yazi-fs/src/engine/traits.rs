@@ -4,7 +4,7 @@ use tokio::{io::{AsyncRead, AsyncSeek, AsyncWrite, AsyncWriteExt}, sync::mpsc};
 use yazi_macro::ok_or_not_found;
 use yazi_shared::{path::{DynPath, PathBufDyn}, strand::{AsStrand, StrandCow}, url::{AsUrl, Url, UrlBuf}};
 
-use crate::{cha::{Cha, ChaType}, engine::{Attrs, Capabilities}, file::{File, FileExtra}};
+use crate::{Digest, cha::{Cha, ChaType}, engine::{Attrs, Capabilities}, file::{File, FileExtra}};
 
 pub trait Engine: Sized {
 	type File: AsyncRead + AsyncSeek + AsyncWrite + Unpin;
@@ -21,6 +21,10 @@ pub trait Engine: Sized {
 
 	fn casefold(&self) -> impl Future<Output = io::Result<UrlBuf>>;
 
+	fn checksum(&self, digest: Digest) -> impl Future<Output = io::Result<String>> {
+		async move { digest.read(self.open().await?).await }
+	}
+
 	fn copy<P>(&self, to: P, attrs: Attrs) -> impl Future<Output = io::Result<u64>>
 	where
 		P: DynPath;
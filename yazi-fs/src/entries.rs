@@ -1,10 +1,10 @@
 use std::{mem, ops::{Deref, DerefMut, Not}};
 
 use hashbrown::{HashMap, HashSet};
-use yazi_shared::{id::Id, path::{PathBufDyn, PathDyn, PathLike}};
+use yazi_shared::{id::Id, path::{PathBufDyn, PathDyn, PathLike}, pool::Symbol, url::{UrlBuf, UrlBufCov}};
 
-use super::{FilesSorter, Filter};
-use crate::{FILES_TICKET, SortBy, file::File};
+use super::{FilesSorter, Filter, Tree};
+use crate::{FILES_TICKET, FilesOp, SortBy, file::File};
 
 #[derive(Default)]
 pub struct Entries {
@@ -19,16 +19,22 @@ pub struct Entries {
 	sorter:      FilesSorter,
 	filter:      Option<Filter>,
 	show_hidden: bool,
+
+	tree: Tree,
 }
 
 impl Deref for Entries {
 	type Target = Vec<File>;
 
-	fn deref(&self) -> &Self::Target { &self.items }
+	fn deref(&self) -> &Self::Target {
+		if self.tree.is_empty() { &self.items } else { self.tree.view() }
+	}
 }
 
 impl DerefMut for Entries {
-	fn deref_mut(&mut self) -> &mut Self::Target { &mut self.items }
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		if self.tree.is_empty() { &mut self.items } else { self.tree.view_mut() }
+	}
 }
 
 impl Entries {
@@ -173,7 +179,7 @@ impl Entries {
 		let (mut hidden, mut items) = if let Some(filter) = &self.filter {
 			files
 				.into_iter()
-				.partition(|(_, f)| (f.is_hidden() && !self.show_hidden) || !filter.matches(f.urn()))
+				.partition(|(_, f)| (f.is_hidden() && !self.show_hidden) || !filter.matches(f))
 		} else if self.show_hidden {
 			(HashMap::new(), files)
 		} else {
@@ -214,19 +220,23 @@ impl Entries {
 	}
 
 	pub fn catchup_revision(&mut self) -> bool {
-		if self.version == self.revision {
+		let nested = self.tree.catchup_revision();
+		if self.version == self.revision && !nested {
 			return false;
 		}
 
-		self.version = self.revision;
-		self.sorter.sort(&mut self.items, &self.sizes);
+		if self.version != self.revision {
+			self.version = self.revision;
+			self.sorter.sort(&mut self.items, &self.sizes);
+		}
+		self.tree.rebuild(&self.items);
 		true
 	}
 
 	fn split_files(&self, files: impl IntoIterator<Item = File>) -> (Vec<File>, Vec<File>) {
 		let files = files.into_iter().filter(|f| !f.key().is_empty());
 		if let Some(filter) = &self.filter {
-			files.partition(|f| (f.is_hidden() && !self.show_hidden) || !filter.matches(f.urn()))
+			files.partition(|f| (f.is_hidden() && !self.show_hidden) || !filter.matches(f))
 		} else if self.show_hidden {
 			(vec![], files.collect())
 		} else {
@@ -242,6 +252,9 @@ impl Entries {
 		if key.is_empty() { None } else { self.iter().position(|f| f.key() == key) }
 	}
 
+	/// Every file at the top level, including the hidden and filtered out ones.
+	pub fn all(&self) -> impl Iterator<Item = &File> { self.items.iter().chain(&self.hidden) }
+
 	// --- Ticket
 	#[inline]
 	pub fn ticket(&self) -> Id { self.ticket }
@@ -251,6 +264,7 @@ impl Entries {
 	pub fn sorter(&self) -> &FilesSorter { &self.sorter }
 
 	pub fn set_sorter(&mut self, sorter: FilesSorter) {
+		self.tree.entries_mut().for_each(|e| e.set_sorter(sorter));
 		if self.sorter != sorter {
 			self.sorter = sorter;
 			self.revision += 1;
@@ -266,6 +280,7 @@ impl Entries {
 			return false;
 		}
 
+		self.tree.entries_mut().for_each(|e| _ = e.set_filter(filter.clone()));
 		self.filter = filter;
 		if self.filter.is_none() {
 			let take = mem::take(&mut self.hidden);
@@ -276,17 +291,33 @@ impl Entries {
 				self.items.extend(items);
 				self.sorter.sort(&mut self.items, &self.sizes);
 			}
-			return true;
+		} else {
+			let it = mem::take(&mut self.items).into_iter().chain(mem::take(&mut self.hidden));
+			(self.hidden, self.items) = self.split_files(it);
+			self.sorter.sort(&mut self.items, &self.sizes);
+		}
+
+		self.tree.rebuild(&self.items);
+		true
+	}
+
+	/// Let the filter know about newly fetched mimetypes, moving files in or out
+	/// of view if it has terms depending on them.
+	pub fn update_mimes(&mut self, mimes: &HashMap<UrlBufCov, Symbol<str>>) {
+		self.tree.entries_mut().for_each(|e| e.update_mimes(mimes));
+		let mimes = mimes.iter().map(|(u, m)| (u.clone(), m.clone()));
+		if !self.filter.as_mut().is_some_and(|f| f.learn_mimes(mimes)) {
+			return;
 		}
 
 		let it = mem::take(&mut self.items).into_iter().chain(mem::take(&mut self.hidden));
 		(self.hidden, self.items) = self.split_files(it);
-		self.sorter.sort(&mut self.items, &self.sizes);
-		true
+		self.revision += 1;
 	}
 
 	// --- Show hidden
 	pub fn set_show_hidden(&mut self, state: bool) {
+		self.tree.entries_mut().for_each(|e| e.set_show_hidden(state));
 		if mem::replace(&mut self.show_hidden, state) == state {
 			return;
 		}
@@ -304,3 +335,51 @@ impl Entries {
 		self.revision += (self.items.len() != len) as u64;
 	}
 }
+
+impl Entries {
+	// --- Tree
+	#[inline]
+	pub fn tree(&self) -> &Tree { &self.tree }
+
+	/// Expand the directory inline, with its children to be filled in later by
+	/// a [`FilesOp`] for it.
+	pub fn expand(&mut self, dir: File) -> bool {
+		if self.tree.contains(&dir.url) {
+			return false;
+		}
+
+		let mut level = Self::new(self.show_hidden);
+		(level.sorter, level.filter) = (self.sorter, self.filter.clone());
+
+		self.tree.insert(dir, level);
+		self.tree.rebuild(&self.items);
+		self.revision += 1;
+		true
+	}
+
+	pub fn collapse(&mut self, url: &UrlBuf) -> bool {
+		if !self.tree.remove(url) {
+			return false;
+		}
+
+		self.tree.rebuild(&self.items);
+		self.revision += 1;
+		true
+	}
+
+	pub fn collapse_all(&mut self) -> bool {
+		if self.tree.is_empty() {
+			return false;
+		}
+
+		self.tree.clear();
+		self.revision += 1;
+		true
+	}
+
+	pub fn update_tree(&mut self, op: FilesOp) -> bool {
+		let b = self.tree.update(op);
+		self.revision += b as u64;
+		b
+	}
+}
@@ -1,41 +1,110 @@
 use std::{fmt::Display, ops::Range};
 
 use anyhow::Result;
+use hashbrown::HashMap;
 use regex::bytes::{Regex, RegexBuilder};
-use yazi_shared::{event::Action, strand::AsStrand};
+use yazi_shared::{event::Action, pool::Symbol, strand::AsStrand, url::{UrlBufCov, UrlCov}};
 
-use super::Normalizer;
+use super::{FilterTerm, Normalizer};
+use crate::file::File;
 
+/// A query of whitespace-separated attribute terms like `size>10M` or
+/// `type:dir`, with whatever is left matched against the name as a regex.
+///
+/// A token made of a known attribute followed by `:`, `<`, `>` or `=` is always
+/// taken as a term, so a name pattern like `size=1` is rejected as an invalid
+/// term, and has to be written as `(size=1)` to be matched against the name.
+#[derive(Clone)]
 pub struct Filter {
 	raw:   String,
-	regex: Regex,
+	regex: Option<Regex>,
+	terms: Vec<FilterTerm>,
+	mimes: HashMap<UrlBufCov, Symbol<str>>,
 }
 
 impl Filter {
 	pub fn new(s: &str, case: FilterCase) -> Result<Self> {
-		let pat = Normalizer::normalize(s)?;
-		let regex = match case {
-			FilterCase::Smart => {
-				let uppercase = pat.chars().any(|c| c.is_uppercase());
-				RegexBuilder::new(&pat).case_insensitive(!uppercase).build()?
+		let smart = |s: &str| !s.chars().any(|c| c.is_uppercase());
+		let mut terms = vec![];
+		let mut rest = vec![];
+		for token in s.split_whitespace() {
+			let insensitive = match case {
+				FilterCase::Smart => smart(token),
+				FilterCase::Sensitive => false,
+				FilterCase::Insensitive => true,
+			};
+			match FilterTerm::parse(token, insensitive) {
+				Some(term) => terms.push(term?),
+				None => rest.push(token),
 			}
-			FilterCase::Sensitive => Regex::new(&pat)?,
-			FilterCase::Insensitive => RegexBuilder::new(&pat).case_insensitive(true).build()?,
+		}
+
+		let regex = if rest.is_empty() {
+			None
+		} else {
+			let pat = if terms.is_empty() { s.to_owned() } else { rest.join(" ") };
+			let pat = Normalizer::normalize(&pat)?;
+			Some(match case {
+				FilterCase::Smart => RegexBuilder::new(&pat).case_insensitive(smart(&pat)).build()?,
+				FilterCase::Sensitive => Regex::new(&pat)?,
+				FilterCase::Insensitive => RegexBuilder::new(&pat).case_insensitive(true).build()?,
+			})
 		};
-		Ok(Self { raw: s.to_owned(), regex })
+
+		Ok(Self { raw: s.to_owned(), regex, terms, mimes: Default::default() })
 	}
 
+	/// Whether the file matches both the name and the attribute terms.
+	#[inline]
+	pub fn matches(&self, file: &File) -> bool { self.matches_name(file.urn()) && self.admits(file) }
+
 	#[inline]
 	#[allow(private_bounds)]
-	pub fn matches<T>(&self, name: T) -> bool
+	pub fn matches_name<T>(&self, name: T) -> bool
 	where
 		T: AsStrand,
 	{
-		self.regex.is_match(name.as_strand().encoded_bytes())
+		self.regex.as_ref().is_none_or(|r| r.is_match(name.as_strand().encoded_bytes()))
+	}
+
+	/// Whether the file satisfies every attribute term, where one depending on a
+	/// mimetype not known yet counts as unsatisfied.
+	pub fn admits(&self, file: &File) -> bool {
+		if self.terms.is_empty() {
+			return true;
+		}
+
+		let mime = self.mimes.get(&UrlCov::new(&file.url)).map(AsRef::as_ref);
+		self.terms.iter().all(|t| t.eval(file, mime) == Some(true))
 	}
 
 	pub fn highlighted(&self, name: impl AsStrand) -> Option<Vec<Range<usize>>> {
-		self.regex.find(name.as_strand().encoded_bytes()).map(|m| vec![m.range()])
+		let name = name.as_strand().encoded_bytes();
+		let mut ranges = match &self.regex {
+			Some(r) => vec![r.find(name)?.range()],
+			None => vec![],
+		};
+
+		ranges.extend(self.terms.iter().filter_map(|t| t.highlighted(name)));
+		if ranges.is_empty() { None } else { Some(ranges) }
+	}
+
+	// --- Mimetype
+	#[inline]
+	pub fn wants_mime(&self) -> bool { self.terms.iter().any(FilterTerm::wants_mime) }
+
+	/// Remember the mimetypes for the terms to match against, returning whether
+	/// any of them was new.
+	pub fn learn_mimes(&mut self, mimes: impl IntoIterator<Item = (UrlBufCov, Symbol<str>)>) -> bool {
+		if !self.wants_mime() {
+			return false;
+		}
+
+		let mut changed = false;
+		for (url, mime) in mimes {
+			changed |= self.mimes.insert(url, mime.clone()).is_none_or(|old| old != mime);
+		}
+		changed
 	}
 }
 
@@ -65,3 +134,64 @@ impl From<&Action> for FilterCase {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use std::path::Path;
+
+	use yazi_shared::pool::InternStr;
+
+	use super::*;
+	use crate::cha::{ChaMode, ChaType};
+
+	fn file(path: &str, r#type: ChaType, len: u64) -> File {
+		let mut f = File::from_dummy(Path::new(path), Some(r#type));
+		f.cha.len = len;
+		f.cha.kind = Default::default();
+		f
+	}
+
+	#[test]
+	fn test_terms() {
+		let filter = Filter::new("size>1K !type:dir ext:{rs,toml} ma", FilterCase::Smart).unwrap();
+		assert!(filter.matches(&file("/main.RS", ChaType::File, 2048)));
+		assert!(!filter.matches(&file("/main.rs", ChaType::File, 10)));
+		assert!(!filter.matches(&file("/main.rs", ChaType::Dir, 2048)));
+		assert!(!filter.matches(&file("/lib.rs", ChaType::File, 2048)));
+
+		// The name and the extension are highlighted
+		assert_eq!(filter.highlighted("main.rs"), Some(vec![0..2, 5..7]));
+
+		let mut f = file("/x", ChaType::File, 0);
+		f.cha.mode |= ChaMode::U_EXEC;
+		assert!(Filter::new("perm:x", FilterCase::Smart).unwrap().matches(&f));
+		assert!(!Filter::new("perm:g:x", FilterCase::Smart).unwrap().matches(&f));
+
+		// Setuid and setgid are told apart by the scope
+		f.cha.mode |= ChaMode::S_SUID;
+		assert!(Filter::new("perm:s", FilterCase::Smart).unwrap().matches(&f));
+		assert!(Filter::new("perm:u:s", FilterCase::Smart).unwrap().matches(&f));
+		assert!(!Filter::new("perm:g:s", FilterCase::Smart).unwrap().matches(&f));
+		assert!(Filter::new("perm:o:s", FilterCase::Smart).is_err());
+
+		// Unknown attributes are left to the name regex, invalid values are errors
+		assert!(Filter::new("foo:bar", FilterCase::Smart).unwrap().matches_name("foo:bar"));
+		assert!(Filter::new("(size=1)", FilterCase::Smart).unwrap().matches_name("size=1"));
+		assert!(Filter::new("size>ten", FilterCase::Smart).is_err());
+		assert!(Filter::new("type>dir", FilterCase::Smart).is_err());
+		assert!(Filter::new("mtime<99999999999999999999y", FilterCase::Smart).is_err());
+	}
+
+	#[test]
+	fn test_mime() {
+		yazi_shared::init_tests();
+		let mut filter = Filter::new("mime:image/*", FilterCase::Smart).unwrap();
+		let f = file("/a.png", ChaType::File, 0);
+		assert!(filter.wants_mime());
+		assert!(!filter.matches(&f));
+
+		assert!(filter.learn_mimes([(f.url.clone().into(), "image/png".intern())]));
+		assert!(!filter.learn_mimes([(f.url.clone().into(), "image/png".intern())]));
+		assert!(filter.matches(&f));
+	}
+}
@@ -0,0 +1,300 @@
+use std::time::{Duration, SystemTime};
+
+use anyhow::{Result, bail};
+use globset::{GlobBuilder, GlobMatcher};
+use yazi_shared::{path::PathLike, url::UrlLike};
+
+use crate::{cha::{ChaMode, ChaType}, file::File};
+
+/// An attribute term of a filter query, like `size>10M` or `!type:dir`.
+#[derive(Clone, Debug)]
+pub(super) struct FilterTerm {
+	negated: bool,
+	kind:    TermKind,
+}
+
+#[derive(Clone, Debug)]
+enum TermKind {
+	Size(TermOp, u64),
+	Age(TermTime, TermOp, Duration),
+	Type(TermType),
+	Perm(ChaMode),
+	Owner(u32),
+	Group(u32),
+	Mime(GlobMatcher),
+	Ext(GlobMatcher),
+	Link(GlobMatcher),
+}
+
+#[derive(Clone, Copy, Debug)]
+enum TermOp {
+	Lt,
+	Le,
+	Eq,
+	Ge,
+	Gt,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum TermTime {
+	Atime,
+	Btime,
+	Ctime,
+	Mtime,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum TermType {
+	File,
+	Dir,
+	Link,
+	Orphan,
+	Exec,
+	Hidden,
+	Dummy,
+	Bare(ChaType),
+}
+
+impl FilterTerm {
+	/// Parse a whitespace-free token, or `None` if it isn't an attribute term and
+	/// should be matched against the name instead.
+	pub(super) fn parse(s: &str, insensitive: bool) -> Option<Result<Self>> {
+		let (negated, rest) = match s.strip_prefix('!') {
+			Some(rest) => (true, rest),
+			None => (false, s),
+		};
+
+		let at = rest.find([':', '<', '>', '='])?;
+		let (key, tail) = rest.split_at(at);
+		let (op, value) = if let Some(v) = tail.strip_prefix(':') {
+			(TermOp::Eq, v)
+		} else if let Some(v) = tail.strip_prefix("<=") {
+			(TermOp::Le, v)
+		} else if let Some(v) = tail.strip_prefix(">=") {
+			(TermOp::Ge, v)
+		} else if let Some(v) = tail.strip_prefix('<') {
+			(TermOp::Lt, v)
+		} else if let Some(v) = tail.strip_prefix('>') {
+			(TermOp::Gt, v)
+		} else {
+			(TermOp::Eq, &tail[1..])
+		};
+
+		let exact = |kind: TermKind| match op {
+			TermOp::Eq => Ok(kind),
+			_ => bail!("`{key}` only supports `:`"),
+		};
+
+		let kind = match key {
+			"size" => Self::parse_size(value).map(|n| TermKind::Size(op, n)),
+			"atime" | "btime" | "ctime" | "mtime" => Self::parse_age(value).map(|d| {
+				let time = match key {
+					"atime" => TermTime::Atime,
+					"btime" => TermTime::Btime,
+					"ctime" => TermTime::Ctime,
+					_ => TermTime::Mtime,
+				};
+				TermKind::Age(time, op, d)
+			}),
+			"type" => Self::parse_type(value).and_then(|t| exact(TermKind::Type(t))),
+			"perm" => Self::parse_perm(value).and_then(|m| exact(TermKind::Perm(m))),
+			"owner" => Self::parse_owner(value).and_then(|u| exact(TermKind::Owner(u))),
+			"group" => Self::parse_group(value).and_then(|g| exact(TermKind::Group(g))),
+			"mime" => Self::parse_glob(value, true).and_then(|g| exact(TermKind::Mime(g))),
+			"ext" => Self::parse_glob(value, insensitive).and_then(|g| exact(TermKind::Ext(g))),
+			"link" => Self::parse_glob(value, false).and_then(|g| exact(TermKind::Link(g))),
+			_ => return None,
+		};
+
+		Some(kind.map(|kind| Self { negated, kind }))
+	}
+
+	/// Whether the file satisfies the term, or `None` if that can't be told yet,
+	/// e.g. its mimetype hasn't been fetched.
+	pub(super) fn eval(&self, file: &File, mime: Option<&str>) -> Option<bool> {
+		let b = match &self.kind {
+			TermKind::Size(op, n) => op.test(file.len, *n),
+			TermKind::Age(time, op, d) => {
+				let t = match time {
+					TermTime::Atime => file.atime,
+					TermTime::Btime => file.btime,
+					TermTime::Ctime => file.ctime,
+					TermTime::Mtime => file.mtime,
+				}?;
+				op.test(SystemTime::now().duration_since(t).unwrap_or_default(), *d)
+			}
+			TermKind::Type(t) => match t {
+				TermType::File => file.is_file(),
+				TermType::Dir => file.is_dir(),
+				TermType::Link => file.is_link(),
+				TermType::Orphan => file.is_orphan(),
+				TermType::Exec => file.is_file() && file.is_exec(),
+				TermType::Hidden => file.is_hidden(),
+				TermType::Dummy => file.is_dummy(),
+				TermType::Bare(t) => *file.mode == *t,
+			},
+			TermKind::Perm(m) => file.mode.contains(*m),
+			TermKind::Owner(uid) => file.uid == *uid && !file.is_dummy(),
+			TermKind::Group(gid) => file.gid == *gid && !file.is_dummy(),
+			TermKind::Mime(g) => g.is_match(mime?),
+			TermKind::Ext(g) => file.url.ext().is_some_and(|e| g.is_match(e.to_string_lossy().as_ref())),
+			TermKind::Link(g) => {
+				file.extra.link_to().is_some_and(|p| g.is_match(p.to_string_lossy().as_ref()))
+			}
+		};
+		Some(b != self.negated)
+	}
+
+	#[inline]
+	pub(super) fn wants_mime(&self) -> bool { matches!(self.kind, TermKind::Mime(_)) }
+
+	/// The part of the name the term matches, as a byte range.
+	pub(super) fn highlighted(&self, name: &[u8]) -> Option<std::ops::Range<usize>> {
+		let TermKind::Ext(g) = &self.kind else { return None };
+		if self.negated {
+			return None;
+		}
+
+		let dot = name.iter().rposition(|&b| b == b'.').filter(|&i| i > 0)?;
+		let ext = String::from_utf8_lossy(&name[dot + 1..]);
+		g.is_match(ext.as_ref()).then_some(dot + 1..name.len())
+	}
+
+	fn parse_size(s: &str) -> Result<u64> {
+		let s = s.to_ascii_uppercase();
+		let s = s.strip_suffix("IB").or_else(|| s.strip_suffix('B')).unwrap_or(&s);
+
+		let (num, exp) = match s.as_bytes().last() {
+			Some(b'K') => (&s[..s.len() - 1], 1),
+			Some(b'M') => (&s[..s.len() - 1], 2),
+			Some(b'G') => (&s[..s.len() - 1], 3),
+			Some(b'T') => (&s[..s.len() - 1], 4),
+			Some(b'P') => (&s[..s.len() - 1], 5),
+			_ => (s, 0),
+		};
+
+		match num.parse::<f64>() {
+			Ok(n) if n >= 0.0 => Ok((n * 1024f64.powi(exp)) as u64),
+			_ => bail!("invalid size: {s:?}"),
+		}
+	}
+
+	fn parse_age(s: &str) -> Result<Duration> {
+		let i = s.find(|c: char| !c.is_ascii_digit() && c != '.').unwrap_or(s.len());
+		let secs = match &s[i..] {
+			"" | "s" => 1,
+			"m" => 60,
+			"h" => 3600,
+			"d" => 86400,
+			"w" => 604800,
+			"y" => 31536000,
+			u => bail!("invalid time unit: {u:?}"),
+		};
+
+		match s[..i].parse::<f64>().map(|n| Duration::try_from_secs_f64(n * secs as f64)) {
+			Ok(Ok(d)) => Ok(d),
+			_ => bail!("invalid duration: {s:?}"),
+		}
+	}
+
+	fn parse_type(s: &str) -> Result<TermType> {
+		Ok(match s {
+			"file" | "f" => TermType::File,
+			"dir" | "d" => TermType::Dir,
+			"link" | "l" => TermType::Link,
+			"orphan" => TermType::Orphan,
+			"exec" | "x" => TermType::Exec,
+			"hidden" => TermType::Hidden,
+			"dummy" => TermType::Dummy,
+			"block" => TermType::Bare(ChaType::Block),
+			"char" => TermType::Bare(ChaType::Char),
+			"sock" => TermType::Bare(ChaType::Sock),
+			"fifo" => TermType::Bare(ChaType::FIFO),
+			_ => bail!("invalid file type: {s:?}"),
+		})
+	}
+
+	/// Permission bits like `rw` for the owner, or scoped as `g:w`, `o:r`.
+	fn parse_perm(s: &str) -> Result<ChaMode> {
+		let (scope, bits) = match s.split_once(':') {
+			Some((scope, bits)) => (scope, bits),
+			None => ("u", s),
+		};
+
+		let mut mode = ChaMode::empty();
+		for c in scope.chars() {
+			for b in bits.chars() {
+				mode |= match (c, b) {
+					('u', 'r') => ChaMode::U_READ,
+					('u', 'w') => ChaMode::U_WRITE,
+					('u', 'x') => ChaMode::U_EXEC,
+					('g', 'r') => ChaMode::G_READ,
+					('g', 'w') => ChaMode::G_WRITE,
+					('g', 'x') => ChaMode::G_EXEC,
+					('o', 'r') => ChaMode::O_READ,
+					('o', 'w') => ChaMode::O_WRITE,
+					('o', 'x') => ChaMode::O_EXEC,
+					('u', 's') => ChaMode::S_SUID,
+					('g', 's') => ChaMode::S_SGID,
+					(_, 't') => ChaMode::S_STICKY,
+					_ => bail!("invalid permission: {s:?}"),
+				};
+			}
+		}
+
+		if mode.is_empty() {
+			bail!("invalid permission: {s:?}");
+		}
+		Ok(mode)
+	}
+
+	fn parse_owner(s: &str) -> Result<u32> {
+		if let Ok(uid) = s.parse() {
+			return Ok(uid);
+		}
+
+		#[cfg(unix)]
+		{
+			use uzers::Users;
+			if let Some(user) = yazi_shared::USERS_CACHE.get_user_by_name(s) {
+				return Ok(user.uid());
+			}
+		}
+		bail!("unknown user: {s:?}")
+	}
+
+	fn parse_group(s: &str) -> Result<u32> {
+		if let Ok(gid) = s.parse() {
+			return Ok(gid);
+		}
+
+		#[cfg(unix)]
+		{
+			use uzers::Groups;
+			if let Some(group) = yazi_shared::USERS_CACHE.get_group_by_name(s) {
+				return Ok(group.gid());
+			}
+		}
+		bail!("unknown group: {s:?}")
+	}
+
+	fn parse_glob(s: &str, insensitive: bool) -> Result<GlobMatcher> {
+		if s.is_empty() {
+			bail!("empty pattern");
+		}
+		Ok(GlobBuilder::new(s).case_insensitive(insensitive).build()?.compile_matcher())
+	}
+}
+
+impl TermOp {
+	#[inline]
+	fn test<T: Ord>(self, a: T, b: T) -> bool {
+		match self {
+			Self::Lt => a < b,
+			Self::Le => a <= b,
+			Self::Eq => a == b,
+			Self::Ge => a >= b,
+			Self::Gt => a > b,
+		}
+	}
+}
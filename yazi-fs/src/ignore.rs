@@ -0,0 +1,96 @@
+use std::path::Path;
+
+use globset::{Candidate, GlobBuilder, GlobSet, GlobSetBuilder};
+
+/// Rules from a `.gitignore` or `.ignore` file, covering the common subset of
+/// the syntax: comments, negation, anchoring and directory-only patterns.
+#[derive(Debug, Default)]
+pub struct Ignore {
+	set:   GlobSet,
+	rules: Vec<IgnoreRule>,
+}
+
+#[derive(Debug)]
+struct IgnoreRule {
+	negated:  bool,
+	dir_only: bool,
+}
+
+impl Ignore {
+	pub const FILES: [&str; 2] = [".gitignore", ".ignore"];
+
+	pub fn parse(s: &str) -> Self {
+		let mut builder = GlobSetBuilder::new();
+		let mut rules = vec![];
+
+		for line in s.lines() {
+			let line = line.trim_end();
+			if line.is_empty() || line.starts_with('#') {
+				continue;
+			}
+
+			let (negated, line) = match line.strip_prefix('!') {
+				Some(rest) => (true, rest),
+				None => (false, line.strip_prefix('\\').unwrap_or(line)),
+			};
+
+			let pat = line.trim_end_matches('/');
+			let dir_only = pat.len() < line.len();
+
+			// A slash other than the trailing one anchors it to the directory of the file
+			let glob = if pat.contains('/') {
+				pat.trim_start_matches('/').to_owned()
+			} else {
+				format!("**/{pat}")
+			};
+
+			let Ok(glob) = GlobBuilder::new(&glob).literal_separator(true).build() else {
+				continue;
+			};
+
+			builder.add(glob);
+			rules.push(IgnoreRule { negated, dir_only });
+		}
+
+		Self { set: builder.build().unwrap_or_else(|_| GlobSet::empty()), rules }
+	}
+
+	#[inline]
+	pub fn is_empty(&self) -> bool { self.rules.is_empty() }
+
+	/// Whether the path, relative to the directory of the ignore file, is
+	/// ignored, or `None` if no rule applies to it.
+	pub fn matched(&self, path: &Path, is_dir: bool) -> Option<bool> {
+		let candidate = Candidate::new(path);
+		self
+			.set
+			.matches_candidate(&candidate)
+			.into_iter()
+			.rev()
+			.map(|i| &self.rules[i])
+			.find(|r| is_dir || !r.dir_only)
+			.map(|r| !r.negated)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_matched() {
+		let ignore = Ignore::parse("# build\n/target\n*.log\n!keep.log\nnode_modules/\ndocs/*.md\n");
+
+		assert_eq!(ignore.matched(Path::new("target"), true), Some(true));
+		assert_eq!(ignore.matched(Path::new("sub/target"), true), None);
+
+		assert_eq!(ignore.matched(Path::new("a/b/c.log"), false), Some(true));
+		assert_eq!(ignore.matched(Path::new("a/keep.log"), false), Some(false));
+
+		assert_eq!(ignore.matched(Path::new("x/node_modules"), true), Some(true));
+		assert_eq!(ignore.matched(Path::new("x/node_modules"), false), None);
+
+		assert_eq!(ignore.matched(Path::new("docs/a.md"), false), Some(true));
+		assert_eq!(ignore.matched(Path::new("docs/sub/a.md"), false), None);
+	}
+}
@@ -2,7 +2,7 @@ extern crate self as yazi_fs;
 
 yazi_macro::mod_pub!(cha file mounts path engine trash);
 
-yazi_macro::mod_flat!(auth cwd entries filter fns hash normalizer op sorter sorting splatter stage url xdg);
+yazi_macro::mod_flat!(auth cwd digest entries filter filter_term fns hash ignore normalizer op sorter sorting splatter stage tree url xdg);
 
 pub fn init() {
 	CWD.init(<_>::default());
@@ -61,6 +61,18 @@ fn expand_url_impl(url: UrlCow) -> UrlCow {
 			loc:  LocBuf::<typed_path::UnixPathBuf>::with(path.into_unix().unwrap(), uri, urn).unwrap(),
 			auth: auth.clone(),
 		},
+		Url::Webdav { auth, .. } => UrlBuf::Webdav {
+			loc:  LocBuf::<typed_path::UnixPathBuf>::with(path.into_unix().unwrap(), uri, urn).unwrap(),
+			auth: auth.clone(),
+		},
+		Url::S3 { auth, .. } => UrlBuf::S3 {
+			loc:  LocBuf::<typed_path::UnixPathBuf>::with(path.into_unix().unwrap(), uri, urn).unwrap(),
+			auth: auth.clone(),
+		},
+		Url::Ftp { auth, .. } => UrlBuf::Ftp {
+			loc:  LocBuf::<typed_path::UnixPathBuf>::with(path.into_unix().unwrap(), uri, urn).unwrap(),
+			auth: auth.clone(),
+		},
 	}
 	.into()
 }
@@ -4,7 +4,7 @@ use std::os::unix::ffi::{OsStrExt, OsStringExt};
 use std::os::windows::ffi::{OsStrExt, OsStringExt};
 use std::{cell::Cell, ffi::{OsStr, OsString}, iter::{self, Peekable}, mem, path::Path};
 
-use yazi_shared::url::UrlLike;
+use yazi_shared::{strand::AsStrand, url::UrlLike};
 
 use crate::file::File;
 
@@ -18,10 +18,26 @@ type Buf = Vec<u8>;
 #[cfg(windows)]
 type Buf = Vec<u16>;
 
+/// The placeholders [`Splatter`] expands, with what each is replaced with.
+pub const PLACEHOLDERS: [(&str, &str); 11] = [
+	("%s", "Selected files, or the hovered one"),
+	("%S", "Selected files, as URLs"),
+	("%h", "Hovered file"),
+	("%H", "Hovered file, as a URL"),
+	("%d", "Parent directories of the selected files"),
+	("%D", "Parent directories of the selected files, as URLs"),
+	("%y", "Yanked files"),
+	("%Y", "Yanked files, as URLs"),
+	("%t", "Prefix, for the next tab"),
+	("%T", "Prefix, for the previous tab"),
+	("%%", "A literal %"),
+];
+
 #[derive(Clone, Copy)]
 pub struct Splatter<T> {
-	src: T,
-	tab: usize,
+	src:    T,
+	tab:    usize,
+	remote: bool,
 }
 
 pub trait Splatable {
@@ -46,11 +62,26 @@ fn cue(buf: &mut Buf, s: impl AsRef<OsStr>) {
 	buf.extend(yazi_shared::shell::escape_os_str(s.as_ref()).encode_wide());
 }
 
+fn cue_remote(buf: &mut Buf, b: &[u8]) {
+	let b = yazi_shared::shell::unix::escape_os_bytes(b);
+	#[cfg(unix)]
+	buf.extend_from_slice(&b);
+	#[cfg(windows)]
+	buf.extend(OsStr::new(&*String::from_utf8_lossy(&b)).encode_wide());
+}
+
 impl<T> Splatter<T>
 where
 	T: Splatable,
 {
-	pub fn new(src: T) -> Self { Self { tab: src.tab(), src } }
+	pub fn new(src: T) -> Self { Self { tab: src.tab(), src, remote: false } }
+
+	/// Splat the paths of remote files as they are on the remote host, rather
+	/// than their local cache, for commands that run there.
+	pub fn remote(mut self, remote: bool) -> Self {
+		self.remote = remote;
+		self
+	}
 
 	pub fn splat(mut self, cmd: impl AsRef<OsStr>) -> OsString {
 		#[cfg(unix)]
@@ -99,7 +130,7 @@ where
 			if c == Some('S') {
 				cue(buf, file.url.os_str());
 			} else {
-				cue(buf, &*file.content_path());
+				self.cue_path(buf, file);
 			}
 		}
 		if first && idx.is_some() {
@@ -109,9 +140,10 @@ where
 
 	fn visit_hovered(&mut self, it: &mut Iter, buf: &mut Buf) {
 		match it.next().and_then(b2c) {
-			Some('h') => {
-				cue(buf, &*self.src.hovered(self.tab).map(|f| f.content_path()).unwrap_or_default());
-			}
+			Some('h') => match self.src.hovered(self.tab) {
+				Some(file) => self.cue_path(buf, file),
+				None => cue(buf, ""),
+			},
 			Some('H') => {
 				cue(buf, self.src.hovered(self.tab).map(|f| f.url.os_str()).unwrap_or_default());
 			}
@@ -131,6 +163,9 @@ where
 
 			if c == Some('D') {
 				cue(buf, file.url.parent().map(|p| p.os_str()).unwrap_or_default());
+			} else if self.remote && file.url.kind().is_remote() {
+				let parent = file.url.loc().parent();
+				cue_remote(buf, parent.as_ref().map(|p| p.as_strand().encoded_bytes()).unwrap_or_default());
 			} else {
 				cue(buf, file.content_path().parent().unwrap_or(Path::new("")));
 			}
@@ -165,7 +200,7 @@ where
 			if c == Some('Y') {
 				cue(buf, file.url.os_str());
 			} else {
-				cue(buf, &*file.content_path());
+				self.cue_path(buf, file);
 			}
 		}
 		if first && idx.is_some() {
@@ -173,6 +208,14 @@ where
 		}
 	}
 
+	fn cue_path(&self, buf: &mut Buf, file: &File) {
+		if self.remote && file.url.kind().is_remote() {
+			cue_remote(buf, file.url.loc().as_strand().encoded_bytes());
+		} else {
+			cue(buf, &*file.content_path());
+		}
+	}
+
 	fn visit_escape(&mut self, it: &mut Iter, buf: &mut Buf) { buf.push(it.next().unwrap()); }
 
 	fn visit_unknown(&mut self, it: &mut Iter, buf: &mut Buf) {
@@ -217,7 +260,7 @@ impl<T> Splatter<T> {
 		}
 
 		let src = Source(Cell::new(false));
-		Splatter { src: &src, tab: 1 }.splat(cmd.as_ref());
+		Splatter { src: &src, tab: 1, remote: false }.splat(cmd.as_ref());
 		src.0.get()
 	}
 }
@@ -343,4 +386,16 @@ mod tests {
 		let s = Splatter::new(&[file]).splat(OsStr::new("%s %S %d %D"));
 		assert_eq!(s, OsStr::new("/real/file /logical/file /real /logical"));
 	}
+
+	#[test]
+	#[cfg(unix)]
+	fn test_remote() {
+		use yazi_shared::url::UrlCow;
+
+		let url = UrlCow::try_from("sftp://vps//home/me/a b".as_bytes()).unwrap().into_owned();
+		let file = File { url, cha: Default::default(), extra: Default::default() };
+
+		let s = Splatter::new(&[file]).remote(true).splat(OsStr::new("%s %S %d"));
+		assert_eq!(s, OsStr::new("'/home/me/a b' 'sftp://vps//home/me/a b' /home/me"));
+	}
 }
@@ -0,0 +1,189 @@
+use hashbrown::HashMap;
+use yazi_shared::url::{UrlBuf, UrlLike};
+
+use crate::{Entries, FilesOp, file::File};
+
+/// Where a file sits in the flattened tree, for drawing the indentation guides.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct TreeNode {
+	pub depth: usize,
+	/// Whether it's the last one among its siblings.
+	pub last:  bool,
+	/// Bit `n` is set if the ancestor at depth `n` has more siblings below,
+	/// i.e. a vertical guide runs through this row at that depth.
+	pub rails: u64,
+}
+
+impl TreeNode {
+	#[inline]
+	pub fn rail(self, depth: usize) -> bool { depth < 64 && self.rails & (1 << depth) != 0 }
+}
+
+/// Directories expanded inline under the top level of [`Entries`].
+#[derive(Default)]
+pub struct Tree {
+	levels: HashMap<UrlBuf, Level>,
+	view:   Vec<File>,
+	nodes:  Vec<TreeNode>,
+}
+
+struct Level {
+	dir:     File,
+	entries: Entries,
+}
+
+impl Tree {
+	#[inline]
+	pub fn is_empty(&self) -> bool { self.levels.is_empty() }
+
+	#[inline]
+	pub fn contains(&self, url: &UrlBuf) -> bool { self.levels.contains_key(url) }
+
+	/// The expanded directories, in no particular order.
+	pub fn dirs(&self) -> impl Iterator<Item = &File> { self.levels.values().map(|l| &l.dir) }
+
+	#[inline]
+	pub fn node(&self, idx: usize) -> Option<TreeNode> { self.nodes.get(idx).copied() }
+
+	#[inline]
+	pub(super) fn view(&self) -> &Vec<File> { &self.view }
+
+	#[inline]
+	pub(super) fn view_mut(&mut self) -> &mut Vec<File> { &mut self.view }
+
+	pub(super) fn insert(&mut self, dir: File, entries: Entries) {
+		self.levels.insert(dir.url.clone(), Level { dir, entries });
+	}
+
+	/// Remove the directory, along with every directory expanded beneath it.
+	pub(super) fn remove(&mut self, url: &UrlBuf) -> bool {
+		let len = self.levels.len();
+		self.levels.retain(|k, _| !k.try_starts_with(url).unwrap_or(false));
+		self.levels.len() != len
+	}
+
+	pub(super) fn clear(&mut self) {
+		self.levels.clear();
+		self.view.clear();
+		self.nodes.clear();
+	}
+
+	pub(super) fn entries_mut(&mut self) -> impl Iterator<Item = &mut Entries> {
+		self.levels.values_mut().map(|l| &mut l.entries)
+	}
+
+	pub(super) fn update(&mut self, op: FilesOp) -> bool {
+		let Some(level) = self.levels.get_mut(op.cwd()) else { return false };
+
+		let entries = &mut level.entries;
+		let revision = entries.revision;
+		match op {
+			FilesOp::Full(dir, files) => {
+				level.dir = dir;
+				entries.update_full(files);
+			}
+			FilesOp::Part(_, files, ticket) => entries.update_part(files, ticket),
+			FilesOp::Done(..) => {}
+			FilesOp::Size(_, sizes) => entries.update_size(sizes),
+			FilesOp::IOErr(..) => entries.update_ioerr(),
+
+			FilesOp::Creating(_, files) => entries.update_creating(files),
+			FilesOp::Deleting(_, keys) => _ = entries.update_deleting(keys),
+			FilesOp::Updating(_, files) => _ = entries.update_updating(files),
+			FilesOp::Upserting(_, files) => entries.update_upserting(files),
+		}
+		revision != entries.revision
+	}
+
+	pub(super) fn catchup_revision(&mut self) -> bool {
+		self.entries_mut().fold(false, |b, e| e.catchup_revision() | b)
+	}
+
+	/// Interleave the expanded levels with the top level, depth first.
+	pub(super) fn rebuild(&mut self, top: &[File]) {
+		self.view.clear();
+		self.nodes.clear();
+		if !self.levels.is_empty() {
+			Self::walk(&self.levels, top, 0, 0, &mut self.view, &mut self.nodes);
+		}
+	}
+
+	fn walk(
+		levels: &HashMap<UrlBuf, Level>,
+		files: &[File],
+		depth: usize,
+		rails: u64,
+		view: &mut Vec<File>,
+		nodes: &mut Vec<TreeNode>,
+	) {
+		for (i, file) in files.iter().enumerate() {
+			let last = i + 1 == files.len();
+			view.push(file.clone());
+			nodes.push(TreeNode { depth, last, rails });
+
+			if let Some(level) = levels.get(&file.url) {
+				let rails = if last { rails } else { rails | 1u64.checked_shl(depth as u32).unwrap_or(0) };
+				Self::walk(levels, &level.entries, depth + 1, rails, view, nodes);
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::path::Path;
+
+	use super::*;
+	use crate::{FilesSorter, SortBy, cha::ChaType};
+
+	fn dir(path: &str) -> File { File::from_dummy(Path::new(path), Some(ChaType::Dir)) }
+
+	fn file(path: &str) -> File { File::from_dummy(Path::new(path), Some(ChaType::File)) }
+
+	fn names(entries: &Entries) -> Vec<String> {
+		entries.iter().map(|f| f.url.loc().to_string_lossy().into_owned()).collect()
+	}
+
+	#[test]
+	fn test_expand_collapse() {
+		let mut entries = Entries::new(false);
+		entries.set_sorter(FilesSorter { by: SortBy::Alphabetical, ..Default::default() });
+		entries.update_full(vec![file("/r/z"), dir("/r/b"), dir("/r/a")]);
+		entries.catchup_revision();
+
+		assert!(entries.expand(dir("/r/a")));
+		assert!(entries.update_tree(FilesOp::Full(dir("/r/a"), vec![file("/r/a/y"), dir("/r/a/x")])));
+		assert!(entries.expand(dir("/r/a/x")));
+		assert!(entries.update_tree(FilesOp::Full(dir("/r/a/x"), vec![file("/r/a/x/1")])));
+		entries.catchup_revision();
+
+		// Each level is sorted on its own, and placed right below its directory
+		assert_eq!(names(&entries), ["/r/a", "/r/a/x", "/r/a/x/1", "/r/a/y", "/r/b", "/r/z"]);
+
+		let tree = entries.tree();
+		assert_eq!(tree.node(0), Some(TreeNode { depth: 0, last: false, rails: 0 }));
+		assert_eq!(tree.node(1), Some(TreeNode { depth: 1, last: false, rails: 0b1 }));
+		assert_eq!(tree.node(2), Some(TreeNode { depth: 2, last: true, rails: 0b11 }));
+		assert_eq!(tree.node(3), Some(TreeNode { depth: 1, last: true, rails: 0b1 }));
+		assert_eq!(tree.node(5), Some(TreeNode { depth: 0, last: true, rails: 0 }));
+
+		// Collapsing a directory also collapses the ones expanded beneath it
+		assert!(entries.collapse(&dir("/r/a").url));
+		assert!(!entries.tree().contains(&dir("/r/a/x").url));
+		assert_eq!(names(&entries), ["/r/a", "/r/b", "/r/z"]);
+	}
+
+	#[test]
+	fn test_hidden_per_level() {
+		let mut entries = Entries::new(false);
+		entries.update_full(vec![dir("/r/a")]);
+		entries.expand(dir("/r/a"));
+		entries.update_tree(FilesOp::Full(dir("/r/a"), vec![file("/r/a/.h"), file("/r/a/v")]));
+		entries.catchup_revision();
+		assert_eq!(names(&entries), ["/r/a", "/r/a/v"]);
+
+		entries.set_show_hidden(true);
+		entries.catchup_revision();
+		assert_eq!(names(&entries).len(), 3);
+	}
+}
@@ -43,7 +43,13 @@ impl<'a> FsUrl<'a> for Url<'a> {
 	fn working_path(self) -> Cow<'a, Path> {
 		match self {
 			Self::Regular(loc) | Self::Search { loc, .. } => loc.as_inner().into(),
-			Self::Mount { .. } | Self::Hub { .. } | Self::Scope { .. } | Self::Sftp { .. } => {
+			Self::Mount { .. }
+			| Self::Hub { .. }
+			| Self::Scope { .. }
+			| Self::Sftp { .. }
+			| Self::Webdav { .. }
+			| Self::S3 { .. }
+			| Self::Ftp { .. } => {
 				self.cache_bucket().expect("non-local URL should have a cache path").into()
 			}
 		}
@@ -60,7 +66,13 @@ impl FsUrl<'_> for UrlBuf {
 	fn working_path(self) -> Cow<'static, Path> {
 		match self {
 			Self::Regular(loc) | Self::Search { loc, .. } => loc.into_inner().into(),
-			Self::Mount { .. } | Self::Hub { .. } | Self::Scope { .. } | Self::Sftp { .. } => {
+			Self::Mount { .. }
+			| Self::Hub { .. }
+			| Self::Scope { .. }
+			| Self::Sftp { .. }
+			| Self::Webdav { .. }
+			| Self::S3 { .. }
+			| Self::Ftp { .. } => {
 				self.cache_bucket().expect("non-local URL should have a cache path").into()
 			}
 		}
@@ -77,7 +89,13 @@ impl<'a> FsUrl<'a> for UrlCow<'a> {
 	fn working_path(self) -> Cow<'a, Path> {
 		match self {
 			Self::Regular(loc) | Self::Search { loc, .. } => loc.into_inner(),
-			Self::Mount { .. } | Self::Hub { .. } | Self::Scope { .. } | Self::Sftp { .. } => {
+			Self::Mount { .. }
+			| Self::Hub { .. }
+			| Self::Scope { .. }
+			| Self::Sftp { .. }
+			| Self::Webdav { .. }
+			| Self::S3 { .. }
+			| Self::Ftp { .. } => {
 				self.cache_bucket().expect("non-local URL should have a cache path").into()
 			}
 		}
@@ -4,6 +4,8 @@ use mlua::{ExternalError, FromLua, IntoLua, Lua, Value};
 use yazi_core::app::PluginOpt;
 use yazi_shared::event::ActionCow;
 
+use crate::FormArgs;
+
 #[derive(Clone, Debug, Default)]
 pub struct PluginForm {
 	pub opt: PluginOpt,
@@ -21,6 +23,10 @@ impl TryFrom<ActionCow> for PluginForm {
 	}
 }
 
+impl FormArgs for PluginForm {
+	fn args() -> Vec<&'static str> { vec![] }
+}
+
 impl FromLua for PluginForm {
 	fn from_lua(_: Value, _: &Lua) -> mlua::Result<Self> { Err("unsupported".into_lua_err()) }
 }
@@ -0,0 +1,72 @@
+use std::fmt;
+
+use serde::{Deserializer, de::{self, DeserializeOwned, Visitor}};
+
+/// Names of the arguments a form accepts, for completing them in the command
+/// palette.
+///
+/// Forms that derive `Deserialize` get theirs from the field names, while the
+/// ones parsed by hand list them themselves.
+pub trait FormArgs {
+	fn args() -> Vec<&'static str>;
+}
+
+impl<T: DeserializeOwned> FormArgs for T {
+	/// Positional aliases like `0` are left out, and so are the snake_case names
+	/// of fields that have a kebab-case alias.
+	fn args() -> Vec<&'static str> {
+		let Err(Probed::Fields(fields)) = T::deserialize(Probe) else {
+			return vec![];
+		};
+
+		fields
+			.iter()
+			.copied()
+			.filter(|s| !s.starts_with(|c: char| c.is_ascii_digit()))
+			.filter(|s| !s.contains('_') || !fields.contains(&&*s.replace('_', "-")))
+			.collect()
+	}
+}
+
+// --- Probe
+/// A deserializer that only records the fields of the struct asked for.
+struct Probe;
+
+#[derive(Debug)]
+enum Probed {
+	Fields(&'static [&'static str]),
+	Other,
+}
+
+impl fmt::Display for Probed {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str("probed") }
+}
+
+impl std::error::Error for Probed {}
+
+impl de::Error for Probed {
+	fn custom<T: fmt::Display>(_: T) -> Self { Self::Other }
+}
+
+impl<'de> Deserializer<'de> for Probe {
+	type Error = Probed;
+
+	serde::forward_to_deserialize_any! {
+		bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+		option unit unit_struct newtype_struct seq tuple tuple_struct map enum identifier
+		ignored_any
+	}
+
+	fn deserialize_any<V: Visitor<'de>>(self, _: V) -> Result<V::Value, Self::Error> {
+		Err(Probed::Other)
+	}
+
+	fn deserialize_struct<V: Visitor<'de>>(
+		self,
+		_: &'static str,
+		fields: &'static [&'static str],
+		_: V,
+	) -> Result<V::Value, Self::Error> {
+		Err(Probed::Fields(fields))
+	}
+}
@@ -1,6 +1,8 @@
 use mlua::{ExternalError, FromLua, IntoLua, Lua, Value};
 use yazi_shared::event::ActionCow;
 
+use crate::FormArgs;
+
 #[derive(Debug, Default)]
 pub struct CloseForm {
 	pub submit: bool,
@@ -14,6 +16,10 @@ impl From<bool> for CloseForm {
 	fn from(submit: bool) -> Self { Self { submit } }
 }
 
+impl FormArgs for CloseForm {
+	fn args() -> Vec<&'static str> { vec!["submit"] }
+}
+
 impl FromLua for CloseForm {
 	fn from_lua(_: Value, _: &Lua) -> mlua::Result<Self> { Err("unsupported".into_lua_err()) }
 }
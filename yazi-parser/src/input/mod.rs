@@ -1 +1 @@
-yazi_macro::mod_flat!(close recall show);
+yazi_macro::mod_flat!(close recall remember show);
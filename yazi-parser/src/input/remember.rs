@@ -0,0 +1,26 @@
+use mlua::{FromLua, IntoLua, Lua, LuaSerdeExt, Value};
+use serde::{Deserialize, Serialize};
+use yazi_shared::event::ActionCow;
+use yazi_shim::mlua::SER_OPT;
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct RememberForm {
+	/// A value submitted in another instance, to sync into its history group
+	/// instead of remembering the current input.
+	pub group: Option<String>,
+	pub value: Option<String>,
+}
+
+impl TryFrom<ActionCow> for RememberForm {
+	type Error = anyhow::Error;
+
+	fn try_from(a: ActionCow) -> Result<Self, Self::Error> { Ok(a.deserialize()?) }
+}
+
+impl FromLua for RememberForm {
+	fn from_lua(value: Value, lua: &Lua) -> mlua::Result<Self> { lua.from_value(value) }
+}
+
+impl IntoLua for RememberForm {
+	fn into_lua(self, lua: &Lua) -> mlua::Result<Value> { lua.to_value_with(&self, SER_OPT) }
+}
@@ -1,5 +1,5 @@
 mod macros;
 
-yazi_macro::mod_pub!(app cmp confirm help input mgr notify pick spark spot tasks which);
+yazi_macro::mod_pub!(app cmp confirm help input mgr notify palette pane pick spark spot tasks which);
 
-yazi_macro::mod_flat!(arrow void);
+yazi_macro::mod_flat!(args arrow void);
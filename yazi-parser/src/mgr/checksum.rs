@@ -0,0 +1,26 @@
+use mlua::{ExternalError, FromLua, IntoLua, Lua, Value};
+use serde::Deserialize;
+use yazi_fs::Digest;
+use yazi_shared::event::ActionCow;
+
+#[derive(Debug, Deserialize)]
+pub struct ChecksumForm {
+	#[serde(default)]
+	pub algo:    Digest,
+	#[serde(default)]
+	pub hovered: bool,
+}
+
+impl TryFrom<ActionCow> for ChecksumForm {
+	type Error = anyhow::Error;
+
+	fn try_from(a: ActionCow) -> Result<Self, Self::Error> { Ok(a.deserialize()?) }
+}
+
+impl FromLua for ChecksumForm {
+	fn from_lua(_: Value, _: &Lua) -> mlua::Result<Self> { Err("unsupported".into_lua_err()) }
+}
+
+impl IntoLua for ChecksumForm {
+	fn into_lua(self, _: &Lua) -> mlua::Result<Value> { Err("unsupported".into_lua_err()) }
+}
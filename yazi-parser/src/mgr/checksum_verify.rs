@@ -0,0 +1,24 @@
+use mlua::{ExternalError, FromLua, IntoLua, Lua, Value};
+use serde::Deserialize;
+use yazi_fs::Digest;
+use yazi_shared::event::ActionCow;
+
+#[derive(Debug, Deserialize)]
+pub struct ChecksumVerifyForm {
+	#[serde(default)]
+	pub algo: Option<Digest>,
+}
+
+impl TryFrom<ActionCow> for ChecksumVerifyForm {
+	type Error = anyhow::Error;
+
+	fn try_from(a: ActionCow) -> Result<Self, Self::Error> { Ok(a.deserialize()?) }
+}
+
+impl FromLua for ChecksumVerifyForm {
+	fn from_lua(_: Value, _: &Lua) -> mlua::Result<Self> { Err("unsupported".into_lua_err()) }
+}
+
+impl IntoLua for ChecksumVerifyForm {
+	fn into_lua(self, _: &Lua) -> mlua::Result<Value> { Err("unsupported".into_lua_err()) }
+}
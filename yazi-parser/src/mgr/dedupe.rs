@@ -0,0 +1,37 @@
+use anyhow::bail;
+use mlua::{ExternalError, FromLua, IntoLua, Lua, Value};
+use yazi_shared::{event::ActionCow, url::{UrlBuf, UrlLike}};
+
+use crate::FormArgs;
+
+#[derive(Debug)]
+pub struct DedupeForm {
+	pub r#in: Option<UrlBuf>,
+}
+
+impl TryFrom<ActionCow> for DedupeForm {
+	type Error = anyhow::Error;
+
+	fn try_from(mut a: ActionCow) -> Result<Self, Self::Error> {
+		let r#in = a.take::<UrlBuf>("in").ok();
+		if let Some(u) = &r#in
+			&& (!u.is_absolute() || u.is_search())
+		{
+			bail!("invalid 'in' in DedupeForm");
+		}
+
+		Ok(Self { r#in })
+	}
+}
+
+impl FormArgs for DedupeForm {
+	fn args() -> Vec<&'static str> { vec!["in"] }
+}
+
+impl FromLua for DedupeForm {
+	fn from_lua(_: Value, _: &Lua) -> mlua::Result<Self> { Err("unsupported".into_lua_err()) }
+}
+
+impl IntoLua for DedupeForm {
+	fn into_lua(self, _: &Lua) -> mlua::Result<Value> { Err("unsupported".into_lua_err()) }
+}
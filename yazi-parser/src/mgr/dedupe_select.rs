@@ -0,0 +1,32 @@
+use mlua::{ExternalError, FromLua, IntoLua, Lua, Value};
+use serde::Deserialize;
+use yazi_shared::event::ActionCow;
+
+#[derive(Debug, Deserialize)]
+pub struct DedupeSelectForm {
+	#[serde(default)]
+	pub keep: DedupeSelectKeep,
+}
+
+impl TryFrom<ActionCow> for DedupeSelectForm {
+	type Error = anyhow::Error;
+
+	fn try_from(a: ActionCow) -> Result<Self, Self::Error> { Ok(a.deserialize()?) }
+}
+
+impl FromLua for DedupeSelectForm {
+	fn from_lua(_: Value, _: &Lua) -> mlua::Result<Self> { Err("unsupported".into_lua_err()) }
+}
+
+impl IntoLua for DedupeSelectForm {
+	fn into_lua(self, _: &Lua) -> mlua::Result<Value> { Err("unsupported".into_lua_err()) }
+}
+
+// --- Keep
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum DedupeSelectKeep {
+	#[default]
+	Oldest,
+	Newest,
+}
@@ -1,14 +1,23 @@
 use mlua::{ExternalError, FromLua, IntoLua, Lua, Value};
 use yazi_shared::{event::ActionCow, url::UrlBuf};
 
+use crate::FormArgs;
+
 #[derive(Debug, Default)]
 pub struct DownloadForm {
 	pub urls: Vec<UrlBuf>,
 	pub open: bool,
+	pub edit: bool,
 }
 
 impl From<ActionCow> for DownloadForm {
-	fn from(mut a: ActionCow) -> Self { Self { urls: a.take_seq(), open: a.bool("open") } }
+	fn from(mut a: ActionCow) -> Self {
+		Self { urls: a.take_seq(), open: a.bool("open"), edit: a.bool("edit") }
+	}
+}
+
+impl FormArgs for DownloadForm {
+	fn args() -> Vec<&'static str> { vec!["open", "edit"] }
 }
 
 impl FromLua for DownloadForm {
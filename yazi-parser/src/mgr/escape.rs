@@ -2,6 +2,8 @@ use bitflags::bitflags;
 use mlua::{ExternalError, FromLua, IntoLua, Lua, Value};
 use yazi_shared::event::ActionCow;
 
+use crate::FormArgs;
+
 bitflags! {
 	#[derive(Debug)]
 	pub struct EscapeForm: u8 {
@@ -29,6 +31,10 @@ impl From<ActionCow> for EscapeForm {
 	}
 }
 
+impl FormArgs for EscapeForm {
+	fn args() -> Vec<&'static str> { vec!["all", "find", "visual", "filter", "select", "search"] }
+}
+
 impl FromLua for EscapeForm {
 	fn from_lua(_: Value, _: &Lua) -> mlua::Result<Self> { Err("unsupported".into_lua_err()) }
 }
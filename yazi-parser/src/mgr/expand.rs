@@ -0,0 +1,25 @@
+use mlua::{FromLua, IntoLua, Lua, LuaSerdeExt, Value};
+use serde::{Deserialize, Serialize};
+use yazi_shared::event::ActionCow;
+use yazi_shim::mlua::SER_OPT;
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct ExpandForm {
+	/// Collapse the directory instead if it's already expanded.
+	#[serde(default)]
+	pub toggle: bool,
+}
+
+impl TryFrom<ActionCow> for ExpandForm {
+	type Error = anyhow::Error;
+
+	fn try_from(a: ActionCow) -> Result<Self, Self::Error> { Ok(a.deserialize()?) }
+}
+
+impl FromLua for ExpandForm {
+	fn from_lua(value: Value, lua: &Lua) -> mlua::Result<Self> { lua.from_value(value) }
+}
+
+impl IntoLua for ExpandForm {
+	fn into_lua(self, lua: &Lua) -> mlua::Result<Value> { lua.to_value_with(&self, SER_OPT) }
+}
@@ -2,6 +2,8 @@ use mlua::{ExternalError, FromLua, IntoLua, Lua, Value};
 use yazi_core::mgr::FilterOpt;
 use yazi_shared::event::ActionCow;
 
+use crate::FormArgs;
+
 #[derive(Clone, Debug, Default)]
 pub struct FilterForm {
 	pub opt: FilterOpt,
@@ -15,6 +17,10 @@ impl TryFrom<ActionCow> for FilterForm {
 	}
 }
 
+impl FormArgs for FilterForm {
+	fn args() -> Vec<&'static str> { vec!["smart", "insensitive", "done"] }
+}
+
 impl FromLua for FilterForm {
 	fn from_lua(_: Value, _: &Lua) -> mlua::Result<Self> { Err("unsupported".into_lua_err()) }
 }
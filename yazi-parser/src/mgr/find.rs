@@ -2,6 +2,8 @@ use mlua::{ExternalError, FromLua, IntoLua, Lua, Value};
 use yazi_fs::FilterCase;
 use yazi_shared::event::ActionCow;
 
+use crate::FormArgs;
+
 #[derive(Debug)]
 pub struct FindForm {
 	pub prev: bool,
@@ -16,6 +18,10 @@ impl TryFrom<ActionCow> for FindForm {
 	}
 }
 
+impl FormArgs for FindForm {
+	fn args() -> Vec<&'static str> { vec!["previous", "smart", "insensitive"] }
+}
+
 impl FromLua for FindForm {
 	fn from_lua(_: Value, _: &Lua) -> mlua::Result<Self> { Err("unsupported".into_lua_err()) }
 }
@@ -1,6 +1,8 @@
 use mlua::{ExternalError, FromLua, IntoLua, Lua, Value};
 use yazi_shared::event::ActionCow;
 
+use crate::FormArgs;
+
 #[derive(Debug)]
 pub struct FindArrowForm {
 	pub prev: bool,
@@ -10,6 +12,10 @@ impl From<ActionCow> for FindArrowForm {
 	fn from(a: ActionCow) -> Self { Self { prev: a.bool("previous") } }
 }
 
+impl FormArgs for FindArrowForm {
+	fn args() -> Vec<&'static str> { vec!["previous"] }
+}
+
 impl FromLua for FindArrowForm {
 	fn from_lua(_: Value, _: &Lua) -> mlua::Result<Self> { Err("unsupported".into_lua_err()) }
 }
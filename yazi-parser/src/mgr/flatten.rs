@@ -0,0 +1,33 @@
+use mlua::{ExternalError, FromLua, IntoLua, Lua, Value};
+use serde::Deserialize;
+use yazi_shared::{event::ActionCow, url::{UrlBuf, UrlLike}};
+
+#[derive(Debug, Deserialize)]
+pub struct FlattenForm {
+	#[serde(rename = "in", alias = "0")]
+	pub r#in:      Option<UrlBuf>,
+	pub depth:     Option<usize>,
+	pub hidden:    Option<bool>,
+	#[serde(alias = "no-ignore", default)]
+	pub no_ignore: bool,
+}
+
+impl TryFrom<ActionCow> for FlattenForm {
+	type Error = anyhow::Error;
+
+	fn try_from(a: ActionCow) -> Result<Self, Self::Error> {
+		let me: Self = a.deserialize()?;
+		if me.r#in.as_ref().is_some_and(|u| !u.is_absolute() || u.is_search()) {
+			anyhow::bail!("invalid 'in' in FlattenForm");
+		}
+		Ok(me)
+	}
+}
+
+impl FromLua for FlattenForm {
+	fn from_lua(_: Value, _: &Lua) -> mlua::Result<Self> { Err("unsupported".into_lua_err()) }
+}
+
+impl IntoLua for FlattenForm {
+	fn into_lua(self, _: &Lua) -> mlua::Result<Value> { Err("unsupported".into_lua_err()) }
+}
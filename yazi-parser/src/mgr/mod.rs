@@ -1,19 +1,26 @@
 yazi_macro::mod_flat!(
 	bulk_exit
 	cd
+	checksum
+	checksum_verify
 	close
 	copy
 	create
+	dedupe
+	dedupe_select
 	displace_do
 	download
 	escape
+	expand
 	filter
 	find
 	find_arrow
 	find_do
+	flatten
 	hardlink
 	hidden
 	hover
+	layout
 	linemode
 	link
 	open
@@ -36,6 +43,7 @@ yazi_macro::mod_flat!(
 	tab_switch
 	toggle
 	toggle_all
+	transfer
 	update_files
 	update_mimes
 	update_paged
@@ -2,6 +2,8 @@ use mlua::{ExternalError, FromLua, IntoLua, Lua, Value};
 use yazi_core::mgr::OpenOpt;
 use yazi_shared::event::ActionCow;
 
+use crate::FormArgs;
+
 #[derive(Clone, Debug)]
 pub struct OpenForm {
 	pub opt: OpenOpt,
@@ -19,6 +21,10 @@ impl TryFrom<ActionCow> for OpenForm {
 	}
 }
 
+impl FormArgs for OpenForm {
+	fn args() -> Vec<&'static str> { vec!["cwd", "interactive", "hovered", "edit"] }
+}
+
 impl FromLua for OpenForm {
 	fn from_lua(_: Value, _: &Lua) -> mlua::Result<Self> { Err("unsupported".into_lua_err()) }
 }
@@ -1,13 +1,18 @@
 use mlua::{ExternalError, FromLua, IntoLua, Lua, Value};
 use serde::Deserialize;
+use yazi_fs::engine::Preserve;
 use yazi_shared::event::ActionCow;
 
 #[derive(Debug, Deserialize)]
 pub struct PasteForm {
 	#[serde(default)]
-	pub force:  bool,
+	pub force:    bool,
 	#[serde(default)]
-	pub follow: bool,
+	pub follow:   bool,
+	#[serde(default)]
+	pub verify:   bool,
+	#[serde(default)]
+	pub preserve: Preserve,
 }
 
 impl TryFrom<ActionCow> for PasteForm {
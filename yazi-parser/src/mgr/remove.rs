@@ -1,6 +1,8 @@
 use mlua::{ExternalError, FromLua, IntoLua, Lua, Value};
 use yazi_shared::event::ActionCow;
 
+use crate::FormArgs;
+
 #[derive(Debug)]
 pub struct RemoveForm {
 	pub force:       bool,
@@ -18,6 +20,10 @@ impl From<ActionCow> for RemoveForm {
 	}
 }
 
+impl FormArgs for RemoveForm {
+	fn args() -> Vec<&'static str> { vec!["force", "permanently", "hovered"] }
+}
+
 impl FromLua for RemoveForm {
 	fn from_lua(_: Value, _: &Lua) -> mlua::Result<Self> { Err("unsupported".into_lua_err()) }
 }
@@ -2,6 +2,8 @@ use mlua::{ExternalError, FromLua, IntoLua, Lua, Value};
 use yazi_shared::event::ActionCow;
 use yazi_shim::SStr;
 
+use crate::FormArgs;
+
 #[derive(Debug)]
 pub struct RenameForm {
 	pub hovered: bool,
@@ -21,6 +23,10 @@ impl From<ActionCow> for RenameForm {
 	}
 }
 
+impl FormArgs for RenameForm {
+	fn args() -> Vec<&'static str> { vec!["hovered", "force", "empty", "cursor"] }
+}
+
 impl FromLua for RenameForm {
 	fn from_lua(_: Value, _: &Lua) -> mlua::Result<Self> { Err("unsupported".into_lua_err()) }
 }
@@ -2,6 +2,8 @@ use mlua::{ExternalError, FromLua, IntoLua, Lua, Value};
 use yazi_core::mgr::SearchOpt;
 use yazi_shared::event::ActionCow;
 
+use crate::FormArgs;
+
 #[derive(Clone, Debug)]
 pub struct SearchForm {
 	pub opt: SearchOpt,
@@ -15,6 +17,10 @@ impl TryFrom<ActionCow> for SearchForm {
 	}
 }
 
+impl FormArgs for SearchForm {
+	fn args() -> Vec<&'static str> { vec!["via", "args", "in"] }
+}
+
 impl FromLua for SearchForm {
 	fn from_lua(_: Value, _: &Lua) -> mlua::Result<Self> { Err("unsupported".into_lua_err()) }
 }
@@ -15,6 +15,8 @@ pub struct ShellForm {
 	#[serde(default)]
 	pub orphan:      bool,
 	#[serde(default)]
+	pub pane:        bool,
+	#[serde(default)]
 	pub interactive: bool,
 
 	pub cursor: Option<usize>,
@@ -2,6 +2,8 @@ use mlua::{ExternalError, FromLua, IntoLua, Lua, Value};
 use yazi_fs::file::File;
 use yazi_shared::event::ActionCow;
 
+use crate::FormArgs;
+
 #[derive(Debug)]
 pub struct ToggleForm {
 	pub file:  Option<File>,
@@ -21,6 +23,10 @@ impl From<ActionCow> for ToggleForm {
 	}
 }
 
+impl FormArgs for ToggleForm {
+	fn args() -> Vec<&'static str> { vec!["state"] }
+}
+
 impl FromLua for ToggleForm {
 	fn from_lua(_: Value, _: &Lua) -> mlua::Result<Self> { Err("unsupported".into_lua_err()) }
 }
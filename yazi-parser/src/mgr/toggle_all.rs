@@ -2,6 +2,8 @@ use mlua::{ExternalError, FromLua, IntoLua, Lua, Value};
 use yazi_fs::file::File;
 use yazi_shared::event::ActionCow;
 
+use crate::FormArgs;
+
 #[derive(Debug)]
 pub struct ToggleAllForm {
 	pub files: Vec<File>,
@@ -25,6 +27,10 @@ impl From<Option<bool>> for ToggleAllForm {
 	fn from(state: Option<bool>) -> Self { Self { files: vec![], state } }
 }
 
+impl FormArgs for ToggleAllForm {
+	fn args() -> Vec<&'static str> { vec!["state"] }
+}
+
 impl FromLua for ToggleAllForm {
 	fn from_lua(_: Value, _: &Lua) -> mlua::Result<Self> { Err("unsupported".into_lua_err()) }
 }
@@ -0,0 +1,49 @@
+use mlua::{ExternalError, FromLua, IntoLua, Lua, Value};
+use serde::Deserialize;
+use yazi_fs::{engine::Preserve, path::{clean_url, expand_url}};
+use yazi_shared::{event::ActionCow, url::UrlBuf};
+use yazi_vfs::engine;
+
+#[derive(Debug, Deserialize)]
+pub struct TransferForm {
+	#[serde(alias = "0")]
+	pub target:   Option<UrlBuf>,
+	#[serde(default)]
+	pub cut:      bool,
+	#[serde(default)]
+	pub force:    bool,
+	#[serde(default)]
+	pub follow:   bool,
+	#[serde(default)]
+	pub verify:   bool,
+	#[serde(default)]
+	pub preserve: Preserve,
+}
+
+impl TryFrom<ActionCow> for TransferForm {
+	type Error = anyhow::Error;
+
+	fn try_from(a: ActionCow) -> Result<Self, Self::Error> {
+		let mut me: Self = a.deserialize()?;
+
+		if let Some(mut target) = me.target.take() {
+			target = expand_url(target).into_owned();
+			if let Some(u) = engine::try_absolute(&target)
+				&& u.is_owned()
+			{
+				target = u.into_owned();
+			}
+			me.target = Some(clean_url(target));
+		}
+
+		Ok(me)
+	}
+}
+
+impl FromLua for TransferForm {
+	fn from_lua(_: Value, _: &Lua) -> mlua::Result<Self> { Err("unsupported".into_lua_err()) }
+}
+
+impl IntoLua for TransferForm {
+	fn into_lua(self, _: &Lua) -> mlua::Result<Value> { Err("unsupported".into_lua_err()) }
+}
@@ -1,6 +1,8 @@
 use mlua::{ExternalError, FromLua, IntoLua, Lua, Value};
 use yazi_shared::{event::ActionCow, url::UrlBuf};
 
+use crate::FormArgs;
+
 #[derive(Debug, Default)]
 pub struct UploadForm {
 	pub urls: Vec<UrlBuf>,
@@ -10,6 +12,10 @@ impl From<ActionCow> for UploadForm {
 	fn from(mut a: ActionCow) -> Self { Self { urls: a.take_seq() } }
 }
 
+impl FormArgs for UploadForm {
+	fn args() -> Vec<&'static str> { vec![] }
+}
+
 impl FromLua for UploadForm {
 	fn from_lua(_: Value, _: &Lua) -> mlua::Result<Self> { Err("unsupported".into_lua_err()) }
 }
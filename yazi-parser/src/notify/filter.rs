@@ -0,0 +1,24 @@
+use mlua::{ExternalError, FromLua, IntoLua, Lua, Value};
+use serde::Deserialize;
+use yazi_core::notify::MessageLevel;
+use yazi_shared::event::ActionCow;
+
+#[derive(Debug, Default, Deserialize)]
+pub struct FilterForm {
+	#[serde(alias = "0")]
+	pub level: Option<MessageLevel>,
+}
+
+impl TryFrom<ActionCow> for FilterForm {
+	type Error = anyhow::Error;
+
+	fn try_from(a: ActionCow) -> Result<Self, Self::Error> { Ok(a.deserialize()?) }
+}
+
+impl FromLua for FilterForm {
+	fn from_lua(_: Value, _: &Lua) -> mlua::Result<Self> { Err("unsupported".into_lua_err()) }
+}
+
+impl IntoLua for FilterForm {
+	fn into_lua(self, _: &Lua) -> mlua::Result<Value> { Err("unsupported".into_lua_err()) }
+}
@@ -0,0 +1,25 @@
+use anyhow::anyhow;
+use mlua::{ExternalError, FromLua, IntoLua, Lua, Value};
+use yazi_scheduler::process::ShellOpt;
+use yazi_shared::event::ActionCow;
+
+#[derive(Clone, Debug)]
+pub struct RunForm {
+	pub opt: ShellOpt,
+}
+
+impl TryFrom<ActionCow> for RunForm {
+	type Error = anyhow::Error;
+
+	fn try_from(mut a: ActionCow) -> Result<Self, Self::Error> {
+		Ok(Self { opt: a.take_any("opt").ok_or_else(|| anyhow!("Invalid 'opt' in RunForm"))? })
+	}
+}
+
+impl FromLua for RunForm {
+	fn from_lua(_: Value, _: &Lua) -> mlua::Result<Self> { Err("unsupported".into_lua_err()) }
+}
+
+impl IntoLua for RunForm {
+	fn into_lua(self, _: &Lua) -> mlua::Result<Value> { Err("unsupported".into_lua_err()) }
+}
@@ -22,6 +22,7 @@ pub enum Spark<'a> {
 	AppQuit(crate::app::QuitForm),
 	AppReport(yazi_term::event::Report),
 	AppReflow(crate::app::ReflowForm),
+	AppReload(crate::VoidForm),
 	AppResize(crate::app::ReflowForm),
 	AppResume(crate::app::ReflowForm),
 	AppStop(crate::app::StopForm),
@@ -36,9 +37,15 @@ pub enum Spark<'a> {
 	BulkExit(crate::mgr::BulkExitForm),
 	BulkRename(crate::VoidForm),
 	Cd(crate::mgr::CdForm),
+	Checksum(crate::mgr::ChecksumForm),
+	ChecksumVerify(crate::mgr::ChecksumVerifyForm),
 	Close(crate::mgr::CloseForm),
+	Collapse(crate::VoidForm),
 	Copy(crate::mgr::CopyForm),
 	Create(crate::mgr::CreateForm),
+	Dedupe(crate::mgr::DedupeForm),
+	DedupeLink(crate::VoidForm),
+	DedupeSelect(crate::mgr::DedupeSelectForm),
 	Displace(crate::VoidForm),
 	DisplaceDo(crate::mgr::DisplaceDoForm),
 	Download(crate::mgr::DownloadForm),
@@ -49,16 +56,19 @@ pub enum Spark<'a> {
 	EscapeSearch(crate::VoidForm),
 	EscapeSelect(crate::VoidForm),
 	EscapeVisual(crate::VoidForm),
+	Expand(crate::mgr::ExpandForm),
 	Filter(crate::mgr::FilterForm),
 	FilterDo(crate::mgr::FilterForm),
 	Find(crate::mgr::FindForm),
 	FindArrow(crate::mgr::FindArrowForm),
 	FindDo(crate::mgr::FindDoForm),
+	Flatten(crate::mgr::FlattenForm),
 	Follow(crate::VoidForm),
 	Forward(crate::VoidForm),
 	Hardlink(crate::mgr::HardlinkForm),
 	Hidden(crate::mgr::HiddenForm),
 	Hover(crate::mgr::HoverForm),
+	Layout(crate::mgr::LayoutForm),
 	Leave(crate::VoidForm),
 	Linemode(crate::mgr::LinemodeForm),
 	Link(crate::mgr::LinkForm),
@@ -83,11 +93,14 @@ pub enum Spark<'a> {
 	Suspend(crate::VoidForm),
 	TabClose(crate::mgr::TabCloseForm),
 	TabCreate(crate::mgr::TabCreateForm),
+	TabFlip(crate::VoidForm),
 	TabRename(crate::mgr::TabRenameForm),
 	TabSwap(crate::ArrowForm),
 	TabSwitch(crate::mgr::TabSwitchForm),
 	Toggle(crate::mgr::ToggleForm),
 	ToggleAll(crate::mgr::ToggleAllForm),
+	Transfer(crate::mgr::TransferForm),
+	Tree(crate::VoidForm),
 	Unyank(crate::VoidForm),
 	UpdateFiles(crate::mgr::UpdateFilesForm),
 	UpdateMimes(crate::mgr::UpdateMimesForm),
@@ -130,13 +143,35 @@ pub enum Spark<'a> {
 	InputMove(yazi_widgets::input::parser::MoveOpt),
 	InputPaste(yazi_widgets::input::parser::PasteOpt),
 	InputRecall(crate::input::RecallForm),
-	InputRemember(crate::VoidForm),
+	InputRemember(crate::input::RememberForm),
+	InputSearch(crate::VoidForm),
 	InputShow(crate::input::ShowForm),
 
 	// Notify
+	NotifyArrow(crate::ArrowForm),
+	NotifyClose(crate::VoidForm),
+	NotifyCopy(crate::VoidForm),
+	NotifyFilter(crate::notify::FilterForm),
 	NotifyPush(crate::notify::PushForm),
+	NotifyReveal(crate::VoidForm),
+	NotifyShow(crate::VoidForm),
 	NotifyTick(crate::notify::TickForm),
 
+	// Palette
+	PaletteArrow(crate::ArrowForm),
+	PaletteClose(crate::palette::CloseForm),
+	PaletteComplete(crate::VoidForm),
+	PaletteEscape(crate::VoidForm),
+	PaletteToggle(crate::palette::ToggleForm),
+
+	// Pane
+	PaneBlur(crate::VoidForm),
+	PaneClose(crate::VoidForm),
+	PaneFocus(crate::VoidForm),
+	PaneOutput(crate::pane::OutputForm),
+	PaneRun(crate::pane::RunForm),
+	PaneToggle(crate::VoidForm),
+
 	// Pick
 	PickArrow(crate::ArrowForm),
 	PickClose(crate::pick::CloseForm),
@@ -157,6 +192,7 @@ pub enum Spark<'a> {
 	TasksProcessOpen(crate::tasks::ProcessOpenForm),
 	TasksShow(crate::VoidForm),
 	TasksSpawn(crate::tasks::SpawnForm),
+	TasksThrottle(crate::tasks::ThrottleForm),
 	TasksOutput(crate::tasks::OutputForm),
 	TasksUpdateSucceed(crate::tasks::UpdateSucceedForm),
 
@@ -225,6 +261,7 @@ impl<'a> IntoLua for Spark<'a> {
 			Self::AppQuit(b) => b.into_lua(lua),
 			Self::AppReport(b) => b.into_lua(lua),
 			Self::AppReflow(b) => b.into_lua(lua),
+			Self::AppReload(b) => b.into_lua(lua),
 			Self::AppResize(b) => b.into_lua(lua),
 			Self::AppResume(b) => b.into_lua(lua),
 			Self::AppStop(b) => b.into_lua(lua),
@@ -239,9 +276,15 @@ impl<'a> IntoLua for Spark<'a> {
 			Self::BulkExit(b) => b.into_lua(lua),
 			Self::BulkRename(b) => b.into_lua(lua),
 			Self::Cd(b) => b.into_lua(lua),
+			Self::Checksum(b) => b.into_lua(lua),
+			Self::ChecksumVerify(b) => b.into_lua(lua),
 			Self::Close(b) => b.into_lua(lua),
+			Self::Collapse(b) => b.into_lua(lua),
 			Self::Copy(b) => b.into_lua(lua),
 			Self::Create(b) => b.into_lua(lua),
+			Self::Dedupe(b) => b.into_lua(lua),
+			Self::DedupeLink(b) => b.into_lua(lua),
+			Self::DedupeSelect(b) => b.into_lua(lua),
 			Self::Displace(b) => b.into_lua(lua),
 			Self::DisplaceDo(b) => b.into_lua(lua),
 			Self::Download(b) => b.into_lua(lua),
@@ -252,16 +295,19 @@ impl<'a> IntoLua for Spark<'a> {
 			Self::EscapeSearch(b) => b.into_lua(lua),
 			Self::EscapeSelect(b) => b.into_lua(lua),
 			Self::EscapeVisual(b) => b.into_lua(lua),
+			Self::Expand(b) => b.into_lua(lua),
 			Self::Filter(b) => b.into_lua(lua),
 			Self::FilterDo(b) => b.into_lua(lua),
 			Self::Find(b) => b.into_lua(lua),
 			Self::FindArrow(b) => b.into_lua(lua),
 			Self::FindDo(b) => b.into_lua(lua),
+			Self::Flatten(b) => b.into_lua(lua),
 			Self::Follow(b) => b.into_lua(lua),
 			Self::Forward(b) => b.into_lua(lua),
 			Self::Hardlink(b) => b.into_lua(lua),
 			Self::Hidden(b) => b.into_lua(lua),
 			Self::Hover(b) => b.into_lua(lua),
+			Self::Layout(b) => b.into_lua(lua),
 			Self::Leave(b) => b.into_lua(lua),
 			Self::Linemode(b) => b.into_lua(lua),
 			Self::Link(b) => b.into_lua(lua),
@@ -286,11 +332,14 @@ impl<'a> IntoLua for Spark<'a> {
 			Self::Suspend(b) => b.into_lua(lua),
 			Self::TabClose(b) => b.into_lua(lua),
 			Self::TabCreate(b) => b.into_lua(lua),
+			Self::TabFlip(b) => b.into_lua(lua),
 			Self::TabRename(b) => b.into_lua(lua),
 			Self::TabSwap(b) => b.into_lua(lua),
 			Self::TabSwitch(b) => b.into_lua(lua),
 			Self::Toggle(b) => b.into_lua(lua),
 			Self::ToggleAll(b) => b.into_lua(lua),
+			Self::Transfer(b) => b.into_lua(lua),
+			Self::Tree(b) => b.into_lua(lua),
 			Self::Unyank(b) => b.into_lua(lua),
 			Self::UpdateFiles(b) => b.into_lua(lua),
 			Self::UpdateMimes(b) => b.into_lua(lua),
@@ -334,12 +383,34 @@ impl<'a> IntoLua for Spark<'a> {
 			Self::InputPaste(b) => b.into_lua(lua),
 			Self::InputRecall(b) => b.into_lua(lua),
 			Self::InputRemember(b) => b.into_lua(lua),
+			Self::InputSearch(b) => b.into_lua(lua),
 			Self::InputShow(b) => b.into_lua(lua),
 
 			// Notify
+			Self::NotifyArrow(b) => b.into_lua(lua),
+			Self::NotifyClose(b) => b.into_lua(lua),
+			Self::NotifyCopy(b) => b.into_lua(lua),
+			Self::NotifyFilter(b) => b.into_lua(lua),
 			Self::NotifyPush(b) => b.into_lua(lua),
+			Self::NotifyReveal(b) => b.into_lua(lua),
+			Self::NotifyShow(b) => b.into_lua(lua),
 			Self::NotifyTick(b) => b.into_lua(lua),
 
+			// Palette
+			Self::PaletteArrow(b) => b.into_lua(lua),
+			Self::PaletteClose(b) => b.into_lua(lua),
+			Self::PaletteComplete(b) => b.into_lua(lua),
+			Self::PaletteEscape(b) => b.into_lua(lua),
+			Self::PaletteToggle(b) => b.into_lua(lua),
+
+			// Pane
+			Self::PaneBlur(b) => b.into_lua(lua),
+			Self::PaneClose(b) => b.into_lua(lua),
+			Self::PaneFocus(b) => b.into_lua(lua),
+			Self::PaneOutput(b) => b.into_lua(lua),
+			Self::PaneRun(b) => b.into_lua(lua),
+			Self::PaneToggle(b) => b.into_lua(lua),
+
 			// Pick
 			Self::PickArrow(b) => b.into_lua(lua),
 			Self::PickClose(b) => b.into_lua(lua),
@@ -360,6 +431,7 @@ impl<'a> IntoLua for Spark<'a> {
 			Self::TasksProcessOpen(b) => b.into_lua(lua),
 			Self::TasksShow(b) => b.into_lua(lua),
 			Self::TasksSpawn(b) => b.into_lua(lua),
+			Self::TasksThrottle(b) => b.into_lua(lua),
 			Self::TasksOutput(b) => b.into_lua(lua),
 			Self::TasksUpdateSucceed(b) => b.into_lua(lua),
 
@@ -374,9 +446,12 @@ try_from_spark!(
 	crate::VoidForm,
 	app:bootstrap,
 	app:focus,
+	app:reload,
 	app:theme,
 	mgr:back,
 	mgr:bulk_rename,
+	mgr:collapse,
+	mgr:dedupe_link,
 	mgr:enter,
 	mgr:escape_filter,
 	mgr:escape_find,
@@ -389,8 +464,18 @@ try_from_spark!(
 	mgr:refresh,
 	mgr:search_stop,
 	mgr:suspend,
+	mgr:tab_flip,
+	mgr:tree,
 	mgr:unyank,
-	input:remember,
+	input:search,
+	notify:close,
+	notify:copy,
+	notify:reveal,
+	notify:show,
+	pane:blur,
+	pane:close,
+	pane:focus,
+	pane:toggle,
 	which:dismiss
 );
 
@@ -416,22 +501,31 @@ try_from_spark!(crate::confirm::CloseForm, confirm:close);
 try_from_spark!(crate::confirm::ShowForm, confirm:show);
 try_from_spark!(crate::help::CloseForm, help:close);
 try_from_spark!(crate::help::ToggleForm, help:toggle);
+try_from_spark!(crate::palette::CloseForm, palette:close);
+try_from_spark!(crate::palette::ToggleForm, palette:toggle);
 try_from_spark!(crate::input::CloseForm, input:close);
 try_from_spark!(crate::mgr::BulkExitForm, mgr:bulk_exit);
 try_from_spark!(crate::mgr::CdForm, mgr:cd);
+try_from_spark!(crate::mgr::ChecksumForm, mgr:checksum);
+try_from_spark!(crate::mgr::ChecksumVerifyForm, mgr:checksum_verify);
 try_from_spark!(crate::mgr::CloseForm, mgr:close);
 try_from_spark!(crate::mgr::CopyForm, mgr:copy);
 try_from_spark!(crate::mgr::CreateForm, mgr:create);
+try_from_spark!(crate::mgr::DedupeForm, mgr:dedupe);
+try_from_spark!(crate::mgr::DedupeSelectForm, mgr:dedupe_select);
 try_from_spark!(crate::mgr::DisplaceDoForm, mgr:displace_do);
 try_from_spark!(crate::mgr::DownloadForm, mgr:download);
 try_from_spark!(crate::mgr::EscapeForm, mgr:escape);
+try_from_spark!(crate::mgr::ExpandForm, mgr:expand);
 try_from_spark!(crate::mgr::FilterForm, mgr:filter, mgr:filter_do);
 try_from_spark!(crate::mgr::FindArrowForm, mgr:find_arrow);
 try_from_spark!(crate::mgr::FindDoForm, mgr:find_do);
 try_from_spark!(crate::mgr::FindForm, mgr:find);
+try_from_spark!(crate::mgr::FlattenForm, mgr:flatten);
 try_from_spark!(crate::mgr::HardlinkForm, mgr:hardlink);
 try_from_spark!(crate::mgr::HiddenForm, mgr:hidden);
 try_from_spark!(crate::mgr::HoverForm, mgr:hover);
+try_from_spark!(crate::mgr::LayoutForm, mgr:layout);
 try_from_spark!(crate::mgr::LinemodeForm, mgr:linemode);
 try_from_spark!(crate::mgr::LinkForm, mgr:link);
 try_from_spark!(crate::mgr::OpenDoForm, mgr:open_do);
@@ -453,6 +547,7 @@ try_from_spark!(crate::mgr::TabCreateForm, mgr:tab_create);
 try_from_spark!(crate::mgr::TabRenameForm, mgr:tab_rename);
 try_from_spark!(crate::mgr::TabSwitchForm, mgr:tab_switch);
 try_from_spark!(crate::mgr::ToggleAllForm, mgr:toggle_all);
+try_from_spark!(crate::mgr::TransferForm, mgr:transfer);
 try_from_spark!(crate::mgr::ToggleForm, mgr:toggle);
 try_from_spark!(crate::mgr::UpdateFilesForm, mgr:update_files);
 try_from_spark!(crate::mgr::UpdateMimesForm, mgr:update_mimes);
@@ -464,13 +559,17 @@ try_from_spark!(crate::mgr::UploadForm, mgr:upload);
 try_from_spark!(crate::mgr::VisualModeForm, mgr:visual_mode);
 try_from_spark!(crate::mgr::WatchForm, mgr:watch);
 try_from_spark!(crate::mgr::YankForm, mgr:yank);
+try_from_spark!(crate::notify::FilterForm, notify:filter);
 try_from_spark!(crate::notify::PushForm, notify:push);
 try_from_spark!(crate::notify::TickForm, notify:tick);
+try_from_spark!(crate::pane::OutputForm, pane:output);
+try_from_spark!(crate::pane::RunForm, pane:run);
 try_from_spark!(crate::pick::CloseForm, pick:close);
 try_from_spark!(crate::pick::ShowForm, pick:show);
 try_from_spark!(crate::spot::CopyForm, spot:copy);
 try_from_spark!(crate::tasks::ProcessOpenForm, tasks:process_open);
 try_from_spark!(crate::tasks::SpawnForm, tasks:spawn);
+try_from_spark!(crate::tasks::ThrottleForm, tasks:throttle);
 try_from_spark!(crate::tasks::OutputForm, tasks:output);
 try_from_spark!(crate::tasks::UpdateSucceedForm, tasks:update_succeed);
 try_from_spark!(crate::which::ActivateForm, which:activate);
@@ -486,3 +585,4 @@ try_from_spark!(yazi_widgets::input::parser::KillOpt, input:kill);
 try_from_spark!(yazi_widgets::input::parser::MoveOpt, input:move);
 try_from_spark!(yazi_widgets::input::parser::PasteOpt, input:paste);
 try_from_spark!(crate::input::RecallForm, input:recall);
+try_from_spark!(crate::input::RememberForm, input:remember);
@@ -2,6 +2,8 @@ use mlua::{ExternalError, FromLua, IntoLua, Lua, Value};
 use yazi_shared::event::ActionCow;
 use yazi_shim::SStr;
 
+use crate::FormArgs;
+
 #[derive(Debug)]
 pub struct CopyForm {
 	pub r#type: SStr,
@@ -11,6 +13,10 @@ impl From<ActionCow> for CopyForm {
 	fn from(mut a: ActionCow) -> Self { Self { r#type: a.take_first().unwrap_or_default() } }
 }
 
+impl FormArgs for CopyForm {
+	fn args() -> Vec<&'static str> { vec![] }
+}
+
 impl FromLua for CopyForm {
 	fn from_lua(_: Value, _: &Lua) -> mlua::Result<Self> { Err("unsupported".into_lua_err()) }
 }
@@ -1 +1 @@
-yazi_macro::mod_flat!(output process_open spawn update_succeed);
+yazi_macro::mod_flat!(output process_open spawn throttle update_succeed);
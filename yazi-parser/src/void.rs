@@ -1,6 +1,8 @@
 use mlua::{FromLua, IntoLua, Lua, Value};
 use yazi_shared::event::ActionCow;
 
+use crate::FormArgs;
+
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub struct VoidForm;
 
@@ -12,6 +14,10 @@ impl From<()> for VoidForm {
 	fn from(_: ()) -> Self { Self }
 }
 
+impl FormArgs for VoidForm {
+	fn args() -> Vec<&'static str> { vec![] }
+}
+
 impl FromLua for VoidForm {
 	fn from_lua(_: Value, _: &Lua) -> mlua::Result<Self> { Ok(Self) }
 }
@@ -49,19 +49,30 @@ function Current:redraw()
 	}
 end
 
+-- Focus the other side of the dual layout if this folder belongs to it
+function Current:focus()
+	if self._tab.id.value ~= cx.active.id.value then
+		ya.emit("tab_flip", {})
+	end
+end
+
 -- Mouse events
 function Current:click(event, up)
 	if up or event.is_middle then
 		return
 	end
 
+	self:focus()
 	local y = event.y - self._area.y + 1
 	if self._folder.window[y] then
 		Entity:new(self._folder.window[y]):click(event, up)
 	end
 end
 
-function Current:scroll(event, step) ya.emit("arrow", { step }) end
+function Current:scroll(event, step)
+	self:focus()
+	ya.emit("arrow", { step })
+end
 
 function Current:touch(event, step) end
 
@@ -0,0 +1,56 @@
+Dual = {
+	_id = "dual",
+	_ratio = { 0, 1, 0 },
+}
+
+function Dual:new(area, tab, other)
+	local me = setmetatable({ _area = area, _tab = tab, _other = other }, { __index = self })
+	me:layout()
+	me:build()
+	return me
+end
+
+function Dual:layout()
+	self._chunks = ui.Layout()
+		:direction(ui.Layout.HORIZONTAL)
+		:constraints({
+			ui.Constraint.Percentage(50),
+			ui.Constraint.Percentage(50),
+		})
+		:split(self._area)
+end
+
+function Dual:build()
+	-- Tabs are paired in order, with the first of each pair on the left
+	local c, left = self._chunks, cx.tabs.idx % 2 == 1
+	local active = Tab:new(left and c[1] or c[2], self._tab, self._ratio)
+	local other = Tab:new(left and c[2] or c[1], self._other, self._ratio)
+
+	-- The active side goes last, so its areas are the ones that end up in the layout
+	self._children = { other, active }
+end
+
+function Dual:reflow()
+	local components = { self }
+	for _, child in ipairs(self._children) do
+		components = ya.list_merge(components, child:reflow())
+	end
+	return components
+end
+
+function Dual:redraw()
+	local elements = {
+		ui.Bar(ui.Edge.LEFT):area(self._chunks[2]):symbol(th.mgr.border_symbol):style(th.mgr.border_style),
+	}
+	for _, child in ipairs(self._children) do
+		elements = ya.list_merge(elements, ui.redraw(child))
+	end
+	return elements
+end
+
+-- Mouse events
+function Dual:click(event, up) end
+
+function Dual:scroll(event, step) end
+
+function Dual:touch(event, step) end
@@ -2,6 +2,7 @@ Entity = {
 	_inc = 1000,
 	_children = {
 		{ "padding", id = 1, order = 1000 },
+		{ "indent", id = 7, order = 1500 },
 		{ "icon", id = 2, order = 2000 },
 		{ "prefix", id = 3, order = 3000 },
 		{ "highlights", id = 4, order = 4000 },
@@ -25,6 +26,20 @@ function Entity:padding()
 	end
 end
 
+function Entity:indent()
+	local node = self._file:tree()
+	if not node or node.depth == 0 then
+		return ""
+	end
+
+	local guides = {}
+	for i = 2, node.depth do
+		guides[#guides + 1] = node[i] and "│  " or "   "
+	end
+	guides[#guides + 1] = node.last and "└─ " or "├─ "
+	return ui.Span(table.concat(guides)):style(th.mgr.border_style)
+end
+
 function Entity:icon()
 	local icon = th.icon:match(self._file, { hovered = self._file.is_hovered })
 	if not icon then
@@ -24,11 +24,12 @@ function Root:layout()
 end
 
 function Root:build()
+	local other = cx.tabs.other
 	self._children = {
 		Backdrop:new(self._area),
 		Header:new(self._chunks[1], cx.active),
 		Tabs:new(self._chunks[2]),
-		Tab:new(self._chunks[3], cx.active),
+		other and Dual:new(self._chunks[3], cx.active, other) or Tab:new(self._chunks[3], cx.active),
 		Status:new(self._chunks[4], cx.active),
 		Modal:new(self._area),
 	}
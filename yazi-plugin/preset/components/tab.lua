@@ -2,15 +2,15 @@ Tab = {
 	_id = "tab",
 }
 
-function Tab:new(area, tab)
-	local me = setmetatable({ _area = area, _tab = tab }, { __index = self })
+function Tab:new(area, tab, ratio)
+	local me = setmetatable({ _area = area, _tab = tab, _ratio = ratio }, { __index = self })
 	me:layout()
 	me:build()
 	return me
 end
 
 function Tab:layout()
-	local ratio = rt.mgr.ratio
+	local ratio = self._ratio or rt.mgr.ratio
 	local all = ratio[1] + ratio[2] + ratio[3]
 	self._chunks = ui.Layout()
 		:direction(ui.Layout.HORIZONTAL)
@@ -67,6 +67,10 @@ function Tasks:icon(snap)
 		return "  "
 	elseif snap.prog.kind == "FileUpload" then
 		return "  "
+	elseif snap.prog.kind == "Dedupe" then
+		return "  "
+	elseif snap.prog.kind == "Checksum" then
+		return "  "
 	else
 		return "  "
 	end
@@ -76,7 +80,11 @@ function Tasks:progress(snap)
 	local p = snap.prog
 
 	local label, count, failed
-	if p.total_bytes then
+	if p.kind == "Dedupe" then
+		label = string.format("%3d%% - %s / %s", math.floor(snap.percent), ya.readable_size(p.processed_bytes), ya.readable_size(p.total_bytes))
+		count = string.format("%d sets, %d files", p.groups, p.duplicates)
+		failed = snap.failed
+	elseif p.total_bytes then
 		local percent = snap.running and snap.cooked and "Cleaning…" or string.format("%3d%%", math.floor(snap.percent))
 		label = string.format("%s - %s / %s", percent, ya.readable_size(p.processed_bytes), ya.readable_size(p.total_bytes))
 		count = string.format("%d/%d", p.success_files, p.total_files)
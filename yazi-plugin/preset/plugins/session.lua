@@ -2,6 +2,9 @@ local function setup(_, opts)
 	if opts.sync_yanked then
 		ps.sub_remote("@yank", function(state) ya.emit("update_yanked", { state }) end)
 	end
+	if opts.sync_history then
+		ps.sub_remote("history", function(t) ya.emit("input:remember", { group = t.group, value = t.value }) end)
+	end
 end
 
 return { setup = setup }
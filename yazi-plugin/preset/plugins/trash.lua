@@ -9,12 +9,15 @@ local function top(url)
 	return url.name and url or nil
 end
 
+-- Items of remote trashes are keyed by the URL of their `.trashinfo`
+local function backend(key) return key:find("^[%w-]+://") and fs.remote_trash or fs.trash end
+
 local function entry(url)
 	local top = top(url)
 	if not top then
 		return -- We are at the root, no entry to return
 	end
-	return fs.trash.entry {
+	return backend(top.spec.domain).entry {
 		top = top.spec.domain,
 		rel = url:strip_prefix(top) or Path.os(""),
 	}
@@ -66,7 +69,7 @@ end
 local restore_recursively
 local function restore(ent)
 	local id = { top = ent.top, rel = ent.rel }
-	local ok, err = fs.trash.restore { ent }
+	local ok, err = backend(ent.top).restore { ent }
 	if ok then
 		return true, 0
 	elseif err.kind ~= "AlreadyExists" then
@@ -76,7 +79,8 @@ local function restore(ent)
 end
 
 restore_recursively = function(id, collision)
-	local ent, err = fs.trash.entry(id)
+	local trash = backend(id.top)
+	local ent, err = trash.entry(id)
 	if not ent then
 		return false, 1, err
 	elseif not ent.cha.is_dir or ent.cha.is_indirect then
@@ -88,7 +92,7 @@ restore_recursively = function(id, collision)
 		return false, 1, collision
 	end
 
-	local ents, err = fs.trash.list(fs.trash.entry(ent))
+	local ents, err = trash.list(trash.entry(ent))
 	if not ents then
 		return false, 1, err
 	end
@@ -103,7 +107,7 @@ restore_recursively = function(id, collision)
 		return changed, failed, first
 	end
 
-	local ok, err = fs.trash.remove("dir", ent)
+	local ok, err = trash.remove("dir", ent)
 	return changed or ok, ok and 0 or 1, err
 end
 
@@ -155,6 +159,9 @@ function M:setup()
 			end
 
 			local ok, err = fs.trash.empty()
+			if ok then
+				ok, err = fs.remote_trash.empty()
+			end
 			if ok then
 				ya.emit("refresh", {})
 			else
@@ -221,7 +228,7 @@ function M:spot(job)
 	local rows = {
 		ui.Row({ "Trash" }):style(ui.Style():fg("green")),
 		ui.Row { "  Original:", ui.Text(ent.original and tostring(ent.original) or "-"):wrap(ui.Wrap.YES) },
-		ui.Row { "  Backing:", ui.Text(tostring(ent.backing)):wrap(ui.Wrap.YES) },
+		ui.Row { "  Backing:", ui.Text(ent.backing and tostring(ent.backing) or "-"):wrap(ui.Wrap.YES) },
 		ui.Row {},
 	}
 
@@ -286,7 +293,7 @@ function M:provide(job)
 	elseif op == "Metadata" or op == "SymlinkMetadata" then
 		local ent, err = entry(job.url)
 		if ent then
-			return fs.trash.metadata(ent, op == "Metadata")
+			return backend(ent.top).metadata(ent, op == "Metadata")
 		elseif err then
 			return nil, err
 		else
@@ -297,7 +304,16 @@ function M:provide(job)
 		if err then
 			return nil, err
 		end
-		local ents, err = fs.trash.list(ent)
+		local ents, err
+		if ent then
+			ents, err = backend(ent.top).list(ent)
+		else
+			ents, err = fs.trash.list()
+			local remote = fs.remote_trash.list()
+			if ents or #remote ~= 0 then
+				ents = ya.list_merge(ents or {}, remote)
+			end
+		end
 		if ents then
 			return files(job.url, ents)
 		else
@@ -307,8 +323,17 @@ function M:provide(job)
 		local ent, err = entry(job.file.url)
 		if err then
 			return nil, err
+		elseif ent then
+			return backend(ent.top).revalidate(ent, job.file)
+		end
+
+		local file, err = fs.trash.revalidate(nil, job.file)
+		if file then
+			return file
+		elseif fs.remote_trash.changed() then
+			return job.file -- Items were added to or removed from a remote trash
 		else
-			return fs.trash.revalidate(ent, job.file)
+			return nil, err
 		end
 	elseif op == "File" then
 		local ent, err = entry(job.url)
@@ -320,7 +345,7 @@ function M:provide(job)
 	elseif op == "RemoveFile" or op == "RemoveDir" then
 		local ent, err = entry(job.url)
 		if ent then
-			return fs.trash.remove(op == "RemoveDir" and "dir" or "file", ent)
+			return backend(ent.top).remove(op == "RemoveDir" and "dir" or "file", ent)
 		else
 			return false, err
 		end
@@ -25,6 +25,7 @@ pub fn compose() -> Composer<ComposerGet, ComposerSet> {
 			b"op" => op(lua)?,
 			b"partitions" => partitions(lua)?,
 			b"read_dir" => read_dir(lua)?,
+			b"remote_trash" => return yazi_vfs::trash::VfsTrash.into_lua(lua),
 			b"remove" => remove(lua)?,
 			b"rename" => rename(lua)?,
 			b"safename" => safename(lua)?,
@@ -33,6 +33,8 @@ pub fn compose() -> Composer<ComposerGet, ComposerSet> {
 	Composer::new(get, set)
 }
 
+pub fn reset() -> mlua::Result<()> { crate::LUA.globals().raw_set("rt", compose()) }
+
 fn path() -> Composer<ComposerGet, ComposerSet> {
 	fn get(lua: &Lua, key: &[u8]) -> mlua::Result<Value> {
 		match key {
@@ -69,6 +71,7 @@ fn mgr() -> Composer<ComposerGet, ComposerSet> {
 	fn get(lua: &Lua, key: &[u8]) -> mlua::Result<Value> {
 		let m = &YAZI.mgr;
 		match key {
+			b"layout" => lua.to_value_with(&m.layout, SER_OPT)?,
 			b"ratio" => m.ratio.get().into_lua(lua)?,
 
 			b"sort_by" => lua.to_value_with(&m.sort_by, SER_OPT)?,
@@ -91,6 +94,10 @@ fn mgr() -> Composer<ComposerGet, ComposerSet> {
 	fn set(lua: &Lua, key: &[u8], value: Value) -> mlua::Result<Value> {
 		let m = &YAZI.mgr;
 		Ok(match key {
+			b"layout" => {
+				m.layout.set(lua.from_value(value)?);
+				Value::Nil
+			}
 			b"ratio" => {
 				m.ratio.set(MgrRatio::from_lua(value, lua)?);
 				Value::Nil
@@ -119,18 +126,18 @@ fn preview() -> Composer<ComposerGet, ComposerSet> {
 	fn get(lua: &Lua, key: &[u8]) -> mlua::Result<Value> {
 		let p = &YAZI.preview;
 		match key {
-			b"wrap" => Wrap::from(p.wrap).into_lua(lua)?,
-			b"tab_size" => p.tab_size.into_lua(lua)?,
-			b"max_width" => p.max_width.into_lua(lua)?,
-			b"max_height" => p.max_height.into_lua(lua)?,
+			b"wrap" => Wrap::from(p.wrap.get()).into_lua(lua)?,
+			b"tab_size" => p.tab_size.get().into_lua(lua)?,
+			b"max_width" => p.max_width.get().into_lua(lua)?,
+			b"max_height" => p.max_height.get().into_lua(lua)?,
 
-			b"cache_dir" => lua.to_value_with(&p.cache_dir, SER_OPT)?,
+			b"cache_dir" => lua.to_value_with(&**p.cache_dir.load(), SER_OPT)?,
 
-			b"image_delay" => p.image_delay.into_lua(lua)?,
-			b"image_filter" => lua.create_string(&p.image_filter)?.into_lua(lua)?,
-			b"image_quality" => p.image_quality.into_lua(lua)?,
+			b"image_delay" => p.image_delay.get().into_lua(lua)?,
+			b"image_filter" => lua.create_string(&**p.image_filter.load())?.into_lua(lua)?,
+			b"image_quality" => p.image_quality.get().into_lua(lua)?,
 
-			b"ueberzug_scale" => p.ueberzug_scale.into_lua(lua)?,
+			b"ueberzug_scale" => p.ueberzug_scale.get().into_lua(lua)?,
 			b"ueberzug_offset" => lua.to_value_with(&p.ueberzug_offset, SER_OPT)?,
 			_ => return Ok(Value::Nil),
 		}
@@ -151,12 +158,12 @@ fn tasks() -> Composer<ComposerGet, ComposerSet> {
 			b"fetch_workers" => t.fetch_workers.get().into_lua(lua)?,
 			b"preload_workers" => t.preload_workers.get().into_lua(lua)?,
 			b"process_workers" => t.process_workers.get().into_lua(lua)?,
-			b"bizarre_retry" => t.bizarre_retry.get().into_lua(lua)?,
+			b"bizarre_retry" => t.bizarre_retry.get().get().into_lua(lua)?,
 
-			b"image_alloc" => t.image_alloc.into_lua(lua)?,
+			b"image_alloc" => t.image_alloc.get().into_lua(lua)?,
 			b"image_bound" => lua.to_value_with(&t.image_bound, SER_OPT)?,
 
-			b"suppress_preload" => t.suppress_preload.into_lua(lua)?,
+			b"suppress_preload" => t.suppress_preload.get().into_lua(lua)?,
 			_ => return Ok(Value::Nil),
 		}
 		.into_lua(lua)
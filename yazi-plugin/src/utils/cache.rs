@@ -24,12 +24,12 @@ impl Utils {
 		lua.create_function(|_, t: Table| {
 			let file: FileRef = t.raw_get("file")?;
 			file.borrow(|f| {
-				if f.url.parent() == Some(Url::regular(&YAZI.preview.cache_dir)) {
+				if f.url.parent() == Some(Url::regular(&**YAZI.preview.cache_dir.load())) {
 					return Ok(None);
 				}
 
 				let sig = Sig(FileSig(f), t.raw_get("skip").unwrap_or_default());
-				Ok(Some(UrlBuf::from(YAZI.preview.cache_dir.join(sig.hash_u128_str(&mut [0; 26])))))
+				Ok(Some(UrlBuf::from(YAZI.preview.cache_dir.load().join(sig.hash_u128_str(&mut [0; 26])))))
 			})
 		})
 	}
@@ -16,4 +16,8 @@ impl AppProxy {
 	pub fn quit(opt: QuitOpt) {
 		emit!(Call(relay!(app:quit).with_any("opt", opt)));
 	}
+
+	pub fn resize() {
+		emit!(Call(relay!(app:resize)));
+	}
 }
@@ -1,3 +1,3 @@
 mod macros;
 
-yazi_macro::mod_flat!(app cmp confirm input mgr notify pick tasks which);
+yazi_macro::mod_flat!(app cmp confirm input mgr notify pane pick tasks which);
@@ -0,0 +1,10 @@
+use yazi_macro::{emit, relay};
+use yazi_scheduler::process::ShellOpt;
+
+pub struct PaneProxy;
+
+impl PaneProxy {
+	pub fn run(opt: ShellOpt) {
+		emit!(Call(relay!(pane:run).with_any("opt", opt)));
+	}
+}
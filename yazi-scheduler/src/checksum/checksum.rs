@@ -0,0 +1,174 @@
+use std::io;
+
+use anyhow::{Context, anyhow};
+use hashbrown::HashMap;
+use parking_lot::RwLock;
+use tokio::{io::AsyncReadExt, sync::mpsc};
+use yazi_fs::{Digest, DigestLine, FilesOp, cha::{Cha, ChaType}, file::File};
+use yazi_shared::{id::Id, url::{UrlBuf, UrlLike}};
+use yazi_vfs::engine;
+
+use crate::{TaskOp, TaskOps, checksum::{ChecksumIn, ChecksumInCompute, ChecksumInVerify, ChecksumOut}};
+
+pub struct Checksum {
+	ops: TaskOps,
+	tx:  async_priority_channel::Sender<ChecksumIn, u8>,
+
+	pub digests: RwLock<HashMap<UrlBuf, (Digest, String)>>,
+}
+
+impl Checksum {
+	pub(crate) fn new(
+		ops: &mpsc::UnboundedSender<TaskOp>,
+		tx: async_priority_channel::Sender<ChecksumIn, u8>,
+	) -> Self {
+		Self { ops: ops.into(), tx, digests: Default::default() }
+	}
+
+	pub(crate) async fn compute(&self, task: ChecksumInCompute) -> Result<(), ChecksumOut> {
+		// Only the digests of the latest run are kept around for copying
+		self.digests.write().clear();
+
+		let mut files = Vec::with_capacity(task.targets.len());
+		for target in task.targets {
+			match engine::metadata(&target).await {
+				Ok(cha) if cha.is_file() => {
+					self.ops.out(task.id, ChecksumOut::New(cha.len));
+					files.push(target);
+				}
+				Ok(_) => {
+					self.ops.out(task.id, ChecksumOut::Deform(format!("Not a regular file: {target:?}")))
+				}
+				Err(e) => {
+					self.ops.out(task.id, ChecksumOut::Deform(format!("Cannot access {target:?}: {e:?}")))
+				}
+			}
+		}
+
+		self.ops.out(task.id, ChecksumOut::Succ);
+		for url in files {
+			let hex = match self.hash(task.id, &url, task.digest).await {
+				Ok(hex) => hex,
+				Err(e) => {
+					self.ops.out(task.id, ChecksumOut::Reject(format!("Cannot read {url:?}: {e:?}")));
+					continue;
+				}
+			};
+
+			let name = url.name().unwrap_or_default().to_string_lossy().into_owned();
+			self.ops.out(task.id, ChecksumOut::Log(DigestLine::format(&hex, &name)));
+			self.ops.out(task.id, ChecksumOut::Pass);
+			self.digests.write().insert(url, (task.digest, hex));
+		}
+
+		Ok(())
+	}
+
+	pub(crate) async fn verify(&self, task: ChecksumInVerify) -> Result<(), ChecksumOut> {
+		let result = self.verify_do(&task).await;
+		if let Some((cwd, ticket)) = task.listing {
+			FilesOp::Done(File::from_dummy(cwd, Some(ChaType::Dir)), ticket).emit();
+		}
+		result
+	}
+
+	async fn verify_do(&self, task: &ChecksumInVerify) -> Result<(), ChecksumOut> {
+		let mut buf = vec![];
+		engine::open(&task.manifest)
+			.await
+			.context("Cannot open the manifest")?
+			.read_to_end(&mut buf)
+			.await
+			.context("Cannot read the manifest")?;
+
+		let dir = task.manifest.parent().ok_or_else(|| anyhow!("Manifest has no parent directory"))?;
+		let text = String::from_utf8_lossy(&buf);
+		let lines: Vec<_> = text.lines().filter_map(DigestLine::parse).collect();
+
+		let digest = task
+			.digest
+			.or_else(|| Digest::from_manifest(&task.manifest.name()?.to_string_lossy()))
+			.or_else(|| Digest::from_hex_len(lines.first()?.digest.len()))
+			.ok_or_else(|| anyhow!("Cannot determine the checksum algorithm"))?;
+
+		let mut files = Vec::with_capacity(lines.len());
+		for line in lines {
+			if !line.is_confined() {
+				let s = format!("Not within the manifest directory: {}", line.name);
+				self.ops.out(task.id, ChecksumOut::Deform(s));
+				continue;
+			}
+			let Ok(url) = dir.try_join(&*line.name) else {
+				self.ops.out(task.id, ChecksumOut::Deform(format!("Invalid file name: {}", line.name)));
+				continue;
+			};
+			match engine::metadata(&url).await {
+				Ok(cha) if cha.is_file() => {
+					self.ops.out(task.id, ChecksumOut::New(cha.len));
+					files.push((url, line));
+				}
+				_ => {
+					self.ops.out(task.id, ChecksumOut::Deform(format!("Missing: {url:?}")));
+					self.flag(task, &line.name, None);
+				}
+			}
+		}
+
+		self.ops.out(task.id, ChecksumOut::Succ);
+		for (url, line) in files {
+			match self.hash(task.id, &url, digest).await {
+				Ok(hex) if hex.eq_ignore_ascii_case(line.digest) => {
+					self.ops.out(task.id, ChecksumOut::Pass);
+				}
+				Ok(hex) => {
+					self.ops.out(
+						task.id,
+						ChecksumOut::Reject(format!("Mismatch: {url:?}, expected {}, got {hex}", line.digest)),
+					);
+					self.flag(task, &line.name, engine::metadata(&url).await.ok());
+				}
+				Err(e) => {
+					self.ops.out(task.id, ChecksumOut::Reject(format!("Cannot read {url:?}: {e:?}")));
+					self.flag(task, &line.name, None);
+				}
+			}
+		}
+
+		Ok(())
+	}
+
+	async fn hash(&self, id: Id, url: &UrlBuf, digest: Digest) -> io::Result<String> {
+		let mut it = engine::open(url).await?;
+		let mut h = digest.hasher();
+		let mut buf = vec![0; 64 * 1024];
+
+		loop {
+			let n = it.read(&mut buf).await?;
+			if n == 0 {
+				break;
+			}
+
+			h.update(&buf[..n]);
+			self.ops.out(id, ChecksumOut::Adv(n as u64));
+		}
+		Ok(h.finalize())
+	}
+
+	fn flag(&self, task: &ChecksumInVerify, name: &str, cha: Option<Cha>) {
+		let Some((cwd, ticket)) = &task.listing else { return };
+		let Ok(url) = cwd.try_join(name) else { return };
+
+		let file = match cha {
+			Some(cha) => File { url, cha, extra: Default::default() },
+			None => File::from_dummy(url, Some(ChaType::File)),
+		};
+		FilesOp::Part(cwd.clone(), vec![file], *ticket).emit();
+	}
+}
+
+impl Checksum {
+	#[inline]
+	pub(crate) fn submit(&self, r#in: impl Into<ChecksumIn>, priority: u8) {
+		_ = self.tx.try_send(r#in.into(), priority);
+	}
+}
@@ -0,0 +1,91 @@
+use std::borrow::Cow;
+
+use yazi_fs::Digest;
+use yazi_shared::{id::Id, url::{UrlBuf, UrlLike}};
+
+use crate::{TaskIn, checksum::ChecksumProg};
+
+#[derive(Debug)]
+pub(crate) enum ChecksumIn {
+	Compute(ChecksumInCompute),
+	Verify(ChecksumInVerify),
+}
+
+impl TaskIn for ChecksumIn {
+	type Prog = ();
+
+	fn id(&self) -> Id {
+		match self {
+			Self::Compute(r#in) => r#in.id(),
+			Self::Verify(r#in) => r#in.id(),
+		}
+	}
+
+	fn set_id(&mut self, id: Id) -> &mut Self {
+		match self {
+			Self::Compute(r#in) => _ = r#in.set_id(id),
+			Self::Verify(r#in) => _ = r#in.set_id(id),
+		}
+		self
+	}
+
+	fn title(&self) -> Cow<'_, str> {
+		match self {
+			Self::Compute(r#in) => r#in.title(),
+			Self::Verify(r#in) => r#in.title(),
+		}
+	}
+}
+
+impl_from_in!(Compute(ChecksumInCompute), Verify(ChecksumInVerify));
+
+// --- Compute
+#[derive(Debug)]
+pub(crate) struct ChecksumInCompute {
+	pub(crate) id:      Id,
+	pub(crate) targets: Vec<UrlBuf>,
+	pub(crate) digest:  Digest,
+}
+
+impl TaskIn for ChecksumInCompute {
+	type Prog = ChecksumProg;
+
+	fn id(&self) -> Id { self.id }
+
+	fn set_id(&mut self, id: Id) -> &mut Self {
+		self.id = id;
+		self
+	}
+
+	fn title(&self) -> Cow<'_, str> {
+		let digest: &str = self.digest.into();
+		match &self.targets[..] {
+			[target] => format!("Checksum ({digest}) '{}'", target.display()).into(),
+			targets => format!("Checksum ({digest}) {} files", targets.len()).into(),
+		}
+	}
+}
+
+// --- Verify
+#[derive(Debug)]
+pub(crate) struct ChecksumInVerify {
+	pub(crate) id:       Id,
+	pub(crate) manifest: UrlBuf,
+	pub(crate) digest:   Option<Digest>,
+	pub(crate) listing:  Option<(UrlBuf, Id)>,
+}
+
+impl TaskIn for ChecksumInVerify {
+	type Prog = ChecksumProg;
+
+	fn id(&self) -> Id { self.id }
+
+	fn set_id(&mut self, id: Id) -> &mut Self {
+		self.id = id;
+		self
+	}
+
+	fn title(&self) -> Cow<'_, str> {
+		format!("Verify checksums in '{}'", self.manifest.display()).into()
+	}
+}
@@ -0,0 +1,9 @@
+macro_rules! impl_from_in {
+	($($variant:ident($type:ty)),* $(,)?) => {
+		$(
+			impl From<$type> for $crate::checksum::ChecksumIn {
+				fn from(value: $type) -> Self { Self::$variant(value) }
+			}
+		)*
+	};
+}
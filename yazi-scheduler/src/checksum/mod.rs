@@ -0,0 +1,4 @@
+#[macro_use]
+mod macros;
+
+yazi_macro::mod_flat!(checksum out progress r#in);
@@ -0,0 +1,58 @@
+use crate::{Task, TaskProg};
+
+#[derive(Debug)]
+pub(crate) enum ChecksumOut {
+	New(u64),
+	Deform(String),
+	Adv(u64),
+	Log(String),
+	Pass,
+	Reject(String),
+	Succ,
+	Fail(String),
+}
+
+impl From<anyhow::Error> for ChecksumOut {
+	fn from(value: anyhow::Error) -> Self { Self::Fail(format!("{value:?}")) }
+}
+
+impl From<std::io::Error> for ChecksumOut {
+	fn from(value: std::io::Error) -> Self { Self::Fail(format!("{value:?}")) }
+}
+
+impl ChecksumOut {
+	pub(crate) fn reduce(self, task: &mut Task) {
+		let TaskProg::Checksum(prog) = &mut task.prog else { return };
+		match self {
+			Self::New(bytes) => {
+				prog.total_files += 1;
+				prog.total_bytes += bytes;
+			}
+			Self::Deform(reason) => {
+				prog.total_files += 1;
+				prog.failed_files += 1;
+				task.log(reason);
+			}
+			Self::Adv(bytes) => {
+				prog.processed_bytes += bytes;
+			}
+			Self::Log(line) => {
+				task.log(line);
+			}
+			Self::Pass => {
+				prog.success_files += 1;
+			}
+			Self::Reject(reason) => {
+				prog.failed_files += 1;
+				task.log(reason);
+			}
+			Self::Succ => {
+				prog.collected = Some(true);
+			}
+			Self::Fail(reason) => {
+				prog.collected = Some(false);
+				task.log(reason);
+			}
+		}
+	}
+}
@@ -0,0 +1,38 @@
+use serde::Serialize;
+
+use crate::{Progress, TaskSummary};
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize)]
+pub struct ChecksumProg {
+	pub total_files:     u32,
+	pub success_files:   u32,
+	pub failed_files:    u32,
+	pub total_bytes:     u64,
+	pub processed_bytes: u64,
+	pub collected:       Option<bool>,
+}
+
+impl From<ChecksumProg> for TaskSummary {
+	fn from(value: ChecksumProg) -> Self {
+		Self {
+			total:   value.total_files,
+			success: value.success_files,
+			failed:  value.failed_files,
+			percent: value.percent().map(Into::into),
+		}
+	}
+}
+
+impl Progress for ChecksumProg {
+	fn running(self) -> bool {
+		self.collected.is_none() || self.success_files + self.failed_files != self.total_files
+	}
+
+	fn cooked(self) -> bool { self.collected == Some(true) && self.success_files == self.total_files }
+
+	fn failed(self) -> bool { self.collected == Some(false) }
+
+	fn percent(self) -> Option<f32> {
+		Some(self.work_percent(self.processed_bytes, self.total_bytes, self.total_files))
+	}
+}
@@ -0,0 +1,225 @@
+use std::{collections::VecDeque, hash::Hasher, io};
+
+use hashbrown::HashMap;
+use parking_lot::RwLock;
+use tokio::{io::{AsyncRead, AsyncReadExt}, sync::mpsc};
+use yazi_fs::{FilesOp, cha::ChaType, engine::{DirReader, FileHolder}, file::File};
+use yazi_shared::{id::Id, url::UrlBuf};
+use yazi_shim::Twox128;
+use yazi_vfs::engine;
+
+use crate::{TaskOp, TaskOps, dedupe::{DedupeIn, DedupeOut}};
+
+const PARTIAL: u64 = 64 * 1024;
+
+pub struct Dedupe {
+	ops: TaskOps,
+	tx:  async_priority_channel::Sender<DedupeIn, u8>,
+
+	pub groups: RwLock<HashMap<UrlBuf, Vec<Vec<UrlBuf>>>>,
+}
+
+impl Dedupe {
+	pub(crate) fn new(
+		ops: &mpsc::UnboundedSender<TaskOp>,
+		tx: async_priority_channel::Sender<DedupeIn, u8>,
+	) -> Self {
+		Self { ops: ops.into(), tx, groups: Default::default() }
+	}
+
+	pub(crate) async fn dedupe(&self, task: DedupeIn) -> Result<(), DedupeOut> {
+		// Only the latest run is kept around for selecting and linking
+		*self.groups.write() = HashMap::from_iter([(task.target.clone(), vec![])]);
+
+		let mut candidates: Vec<_> =
+			self.collect(&task).await.into_values().filter(|v| v.len() > 1).collect();
+		candidates.sort_unstable_by(|a, b| b[0].len.cmp(&a[0].len));
+
+		let bytes = candidates.iter().flatten().map(|f| f.len).sum();
+		self.ops.out(task.id, DedupeOut::Queue(bytes));
+
+		for files in candidates {
+			for partial in self.split(task.id, files, Some(PARTIAL)).await {
+				if partial[0].len <= PARTIAL {
+					self.confirm(&task, partial).await;
+					continue;
+				}
+				for full in self.split(task.id, partial, None).await {
+					self.confirm(&task, full).await;
+				}
+			}
+		}
+
+		FilesOp::Done(File::from_dummy(task.target, Some(ChaType::Dir)), task.ticket).emit();
+		Ok(self.ops.out(task.id, DedupeOut::Succ))
+	}
+
+	async fn collect(&self, task: &DedupeIn) -> HashMap<u64, Vec<File>> {
+		let mut sizes: HashMap<u64, Vec<File>> = HashMap::new();
+		let mut dirs = VecDeque::from([task.target.clone()]);
+
+		while let Some(dir) = dirs.pop_front() {
+			let mut it = match engine::read_dir(&dir).await {
+				Ok(it) => it,
+				Err(e) => {
+					self.ops.out(task.id, DedupeOut::Log(format!("Cannot read directory {dir:?}: {e:?}")));
+					continue;
+				}
+			};
+
+			let mut found = 0;
+			while let Ok(Some(dent)) = it.next().await {
+				let Ok(cha) = dent.metadata().await else { continue };
+				if cha.is_indirect() || (!task.hidden && cha.is_hidden()) {
+					continue;
+				} else if cha.is_dir() {
+					dirs.push_back(dent.url());
+				} else if cha.is_file() && cha.len > 0 {
+					found += 1;
+					sizes.entry(cha.len).or_default().push(File {
+						url: dent.url(),
+						cha,
+						extra: Default::default(),
+					});
+				}
+			}
+			self.ops.out(task.id, DedupeOut::New(found));
+		}
+
+		sizes
+	}
+
+	async fn split(&self, id: Id, files: Vec<File>, limit: Option<u64>) -> Vec<Vec<File>> {
+		let mut hashes: HashMap<u128, Vec<File>> = HashMap::new();
+		for file in files {
+			match self.hash(id, &file, limit).await {
+				Ok(h) => hashes.entry(h).or_default().push(file),
+				Err(e) => {
+					self.ops.out(id, DedupeOut::Log(format!("Cannot read file {:?}: {e:?}", file.url)))
+				}
+			}
+		}
+
+		let mut groups = vec![];
+		for (_, files) in hashes {
+			if files.len() > 1 {
+				groups.push(files);
+			} else if limit.is_some_and(|l| files[0].len > l) {
+				self.ops.out(id, DedupeOut::Adv(files[0].len));
+			}
+		}
+		groups
+	}
+
+	async fn hash(&self, id: Id, file: &File, limit: Option<u64>) -> io::Result<u128> {
+		let mut it = engine::open(&file.url).await?;
+		let mut h = Twox128::default();
+		let mut buf = vec![0; PARTIAL as usize];
+		let mut left = limit.unwrap_or(u64::MAX);
+
+		while left > 0 {
+			let max = buf.len().min(usize::try_from(left).unwrap_or(usize::MAX));
+			let n = it.read(&mut buf[..max]).await?;
+			if n == 0 {
+				break;
+			}
+
+			h.write(&buf[..n]);
+			left -= n as u64;
+			if limit.is_none() || file.len <= PARTIAL {
+				self.ops.out(id, DedupeOut::Adv(n as u64));
+			}
+		}
+		Ok(h.finish_128())
+	}
+
+	/// Compare the files sharing a hash byte by byte, emitting each set of them
+	/// that's truly identical.
+	async fn confirm(&self, task: &DedupeIn, files: Vec<File>) {
+		let mut sets: Vec<Vec<File>> = vec![];
+		'files: for file in files {
+			for set in &mut sets {
+				match Self::same(&set[0].url, &file.url).await {
+					Ok(true) => {
+						set.push(file);
+						continue 'files;
+					}
+					Ok(false) => {}
+					Err(e) => {
+						let s = format!("Cannot compare {:?} with {:?}: {e:?}", file.url, set[0].url);
+						self.ops.out(task.id, DedupeOut::Log(s));
+						continue 'files;
+					}
+				}
+			}
+			sets.push(vec![file]);
+		}
+
+		for set in sets.into_iter().filter(|s| s.len() > 1) {
+			self.emit(task, set);
+		}
+	}
+
+	pub async fn same(a: &UrlBuf, b: &UrlBuf) -> io::Result<bool> {
+		Self::compare(engine::open(a).await?, engine::open(b).await?).await
+	}
+
+	async fn compare<A, B>(mut a: A, mut b: B) -> io::Result<bool>
+	where
+		A: AsyncRead + Unpin,
+		B: AsyncRead + Unpin,
+	{
+		let (mut x, mut y) = (vec![0; PARTIAL as usize], vec![0; PARTIAL as usize]);
+		loop {
+			let n = Self::fill(&mut a, &mut x).await?;
+			if n != Self::fill(&mut b, &mut y).await? || x[..n] != y[..n] {
+				return Ok(false);
+			} else if n == 0 {
+				return Ok(true);
+			}
+		}
+	}
+
+	async fn fill(r: &mut (impl AsyncRead + Unpin), buf: &mut [u8]) -> io::Result<usize> {
+		let mut n = 0;
+		while n < buf.len() {
+			match r.read(&mut buf[n..]).await? {
+				0 => break,
+				m => n += m,
+			}
+		}
+		Ok(n)
+	}
+
+	fn emit(&self, task: &DedupeIn, files: Vec<File>) {
+		self.ops.out(task.id, DedupeOut::Group(files.len() as u32));
+		if let Some(groups) = self.groups.write().get_mut(&task.target) {
+			groups.push(files.iter().map(|f| f.url.clone()).collect());
+		}
+		FilesOp::Part(task.target.clone(), files, task.ticket).emit();
+	}
+}
+
+impl Dedupe {
+	#[inline]
+	pub(crate) fn submit(&self, r#in: impl Into<DedupeIn>, priority: u8) {
+		_ = self.tx.try_send(r#in.into(), priority);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[tokio::test]
+	async fn test_compare() {
+		let long = vec![7u8; PARTIAL as usize * 2 + 3];
+		let mut other = long.clone();
+		*other.last_mut().unwrap() = 8;
+
+		assert!(Dedupe::compare(&long[..], &long[..]).await.unwrap());
+		assert!(!Dedupe::compare(&long[..], &other[..]).await.unwrap());
+		assert!(!Dedupe::compare(&long[..], &long[1..]).await.unwrap());
+		assert!(Dedupe::compare(&b""[..], &b""[..]).await.unwrap());
+	}
+}
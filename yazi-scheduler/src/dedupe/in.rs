@@ -0,0 +1,28 @@
+use std::borrow::Cow;
+
+use yazi_shared::{id::Id, url::{UrlBuf, UrlLike}};
+
+use crate::{TaskIn, dedupe::DedupeProg};
+
+#[derive(Debug)]
+pub(crate) struct DedupeIn {
+	pub(crate) id:     Id,
+	pub(crate) target: UrlBuf,
+	pub(crate) hidden: bool,
+	pub(crate) ticket: Id,
+}
+
+impl TaskIn for DedupeIn {
+	type Prog = DedupeProg;
+
+	fn id(&self) -> Id { self.id }
+
+	fn set_id(&mut self, id: Id) -> &mut Self {
+		self.id = id;
+		self
+	}
+
+	fn title(&self) -> Cow<'_, str> {
+		format!("Find duplicates in '{}'", self.target.display()).into()
+	}
+}
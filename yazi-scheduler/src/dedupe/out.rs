@@ -0,0 +1,47 @@
+use crate::{Task, TaskProg};
+
+#[derive(Debug)]
+pub(crate) enum DedupeOut {
+	New(u32),
+	Queue(u64),
+	Adv(u64),
+	Group(u32),
+	Log(String),
+	Succ,
+	Fail(String),
+}
+
+impl From<anyhow::Error> for DedupeOut {
+	fn from(value: anyhow::Error) -> Self { Self::Fail(format!("{value:?}")) }
+}
+
+impl DedupeOut {
+	pub(crate) fn reduce(self, task: &mut Task) {
+		let TaskProg::Dedupe(prog) = &mut task.prog else { return };
+		match self {
+			Self::New(files) => {
+				prog.total_files += files;
+			}
+			Self::Queue(bytes) => {
+				prog.total_bytes += bytes;
+			}
+			Self::Adv(bytes) => {
+				prog.processed_bytes += bytes;
+			}
+			Self::Group(files) => {
+				prog.groups += 1;
+				prog.duplicates += files;
+			}
+			Self::Log(line) => {
+				task.log(line);
+			}
+			Self::Succ => {
+				prog.state = Some(true);
+			}
+			Self::Fail(reason) => {
+				prog.state = Some(false);
+				task.log(reason);
+			}
+		}
+	}
+}
@@ -0,0 +1,36 @@
+use serde::Serialize;
+
+use crate::{Progress, TaskSummary};
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize)]
+pub struct DedupeProg {
+	pub total_files:     u32,
+	pub total_bytes:     u64,
+	pub processed_bytes: u64,
+	pub groups:          u32,
+	pub duplicates:      u32,
+	pub state:           Option<bool>,
+}
+
+impl From<DedupeProg> for TaskSummary {
+	fn from(value: DedupeProg) -> Self {
+		Self {
+			total:   1,
+			success: value.success() as u32,
+			failed:  value.failed() as u32,
+			percent: value.percent().map(Into::into),
+		}
+	}
+}
+
+impl Progress for DedupeProg {
+	fn running(self) -> bool { self.state.is_none() }
+
+	fn cooked(self) -> bool { self.state == Some(true) }
+
+	fn failed(self) -> bool { self.state == Some(false) }
+
+	fn percent(self) -> Option<f32> {
+		Some(self.work_percent(self.processed_bytes, self.total_bytes, self.total_files))
+	}
+}
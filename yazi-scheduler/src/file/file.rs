@@ -1,15 +1,15 @@
-use std::mem;
+use std::{mem, time::Duration};
 
 use anyhow::{Context, Result, anyhow};
 use tokio::{io::{self, ErrorKind::NotFound}, sync::mpsc};
 use yazi_config::YAZI;
-use yazi_fs::{Cwd, FsHash128, FsUrl, cha::Cha, engine::{Attrs, Engine, FileHolder, local::Local}, ok_or_not_found, path::path_relative_to};
+use yazi_fs::{Cwd, Digest, FsHash128, FsUrl, cha::Cha, engine::{Attrs, Engine, FileHolder, Limiter, Preserve, local::Local}, ok_or_not_found, path::path_relative_to};
 use yazi_macro::warn;
 use yazi_shared::{path::{PathCow, PathLike}, url::{AsUrl, UrlCow, UrlLike}};
 use yazi_vfs::{Stamp, VfsCha, engine::{self, DirEntry}, maybe_exists, unique_file};
 
 use super::{FileInCopy, FileInDelete, FileInHardlink, FileInLink, FileInTrash};
-use crate::{LOW, NORMAL, TaskOp, TaskOps, TasksProxy, ctx, file::{FileIn, FileInDownload, FileInMove, FileInUpload, FileOutCopy, FileOutCopyDo, FileOutDelete, FileOutDeleteDo, FileOutDownload, FileOutDownloadDo, FileOutHardlink, FileOutHardlinkDo, FileOutLink, FileOutMove, FileOutMoveDo, FileOutTrash, FileOutUpload, FileOutUploadDo, Transaction, Traverse}, hook::{HookInOutCopy, HookInOutHardlink, HookInOutLink, HookInOutMove}, ok_or_not_found};
+use crate::{LOW, NORMAL, TaskIn, TaskOp, TaskOps, TaskOut, TasksProxy, ctx, file::{FileIn, FileInDownload, FileInMove, FileInUpload, FileOutCopy, FileOutCopyDo, FileOutDelete, FileOutDeleteDo, FileOutDownload, FileOutDownloadDo, FileOutHardlink, FileOutHardlinkDo, FileOutLink, FileOutMove, FileOutMoveDo, FileOutTrash, FileOutUpload, FileOutUploadDo, Transaction, Traverse}, hook::{HookInOutCopy, HookInOutHardlink, HookInOutLink, HookInOutMove}, ok_or_not_found};
 
 pub(crate) struct File {
 	ops: TaskOps,
@@ -33,6 +33,20 @@ impl File {
 				.context("Cannot determine unique destination name")?;
 		}
 
+		if !task.preserve.is_empty() {
+			let supported = Self::preservable(&task.from, &task.to, task.preserve).await;
+			if supported != task.preserve {
+				self.ops.out(
+					id,
+					FileOutCopy::Log(format!(
+						"Cannot preserve {} for this transfer",
+						task.preserve - supported
+					)),
+				);
+			}
+			task.preserve = supported;
+		}
+
 		self.ops.out(id, HookInOutCopy::new(&task.from, &task.to));
 		TasksProxy::update_succeed(id, [&task.to], true);
 
@@ -62,13 +76,34 @@ impl File {
 
 	pub(crate) async fn copy_do(&self, mut task: FileInCopy) -> Result<(), FileOutCopyDo> {
 		ok_or_not_found!(task, Transaction::unlink(&task.to).await);
-		let mut rx =
-			ctx!(task, engine::copy_progressive(&task.from, &task.to, task.cha.unwrap()).await)?;
+		let attrs = Attrs {
+			throttle: Limiter::task(task.id),
+			task: Some(task.id),
+			..Attrs::preserving(task.cha.unwrap(), task.preserve)
+		};
+		let mut rx = ctx!(task, engine::copy_progressive(&task.from, &task.to, attrs).await)?;
 
+		let mut done = 0;
 		loop {
 			match rx.recv().await.unwrap_or(Ok(0)) {
-				Ok(0) => break,
-				Ok(n) => self.ops.out(task.id, FileOutCopyDo::Adv(n)),
+				Ok(0) => {
+					if task.verify && !ctx!(task, Self::verify(&task.from, &task.to).await)? {
+						if task.retry < YAZI.tasks.bizarre_retry.get().get() {
+							task.retry += 1;
+							self.ops.out(task.id, FileOutCopyDo::Rewind(done));
+							self
+								.ops
+								.out(task.id, FileOutCopyDo::Log("Retrying due to checksum mismatch".to_owned()));
+							return Ok(self.requeue(task, LOW));
+						}
+						Err(anyhow!("Failed to work on {task:?}: checksum mismatch"))?;
+					}
+					break;
+				}
+				Ok(n) => {
+					done += n;
+					self.ops.out(task.id, FileOutCopyDo::Adv(n));
+				}
 				Err(e) if e.kind() == NotFound => {
 					warn!("Copy task partially done: {task:?}");
 					break;
@@ -76,7 +111,7 @@ impl File {
 				// Operation not permitted (os error 1)
 				// Attribute not found (os error 93)
 				Err(e)
-					if task.retry < YAZI.tasks.bizarre_retry.get()
+					if task.retry < YAZI.tasks.bizarre_retry.get().get()
 						&& matches!(e.raw_os_error(), Some(1) | Some(93)) =>
 				{
 					task.retry += 1;
@@ -86,6 +121,12 @@ impl File {
 				Err(e) => ctx!(task, Err(e))?,
 			}
 		}
+
+		for (what, e) in Preserve::lapses(task.id) {
+			self
+				.ops
+				.out(task.id, FileOutCopyDo::Log(format!("Cannot preserve {what} for some files: {e}")));
+		}
 		Ok(self.ops.out(task.id, FileOutCopyDo::Succ))
 	}
 
@@ -98,6 +139,20 @@ impl File {
 				.context("Cannot determine unique destination name")?;
 		}
 
+		if !task.preserve.is_empty() {
+			let supported = Self::preservable(&task.from, &task.to, task.preserve).await;
+			if supported != task.preserve {
+				self.ops.out(
+					id,
+					FileOutMove::Log(format!(
+						"Cannot preserve {} for this transfer",
+						task.preserve - supported
+					)),
+				);
+			}
+			task.preserve = supported;
+		}
+
 		self.ops.out(id, HookInOutMove::new(&task.from, &task.to));
 		TasksProxy::update_succeed(id, [&task.to], true);
 
@@ -154,16 +209,35 @@ impl File {
 
 	pub(crate) async fn move_do(&self, mut task: FileInMove) -> Result<(), FileOutMoveDo> {
 		ok_or_not_found!(task, Transaction::unlink(&task.to).await);
-		let mut rx =
-			ctx!(task, engine::copy_progressive(&task.from, &task.to, task.cha.unwrap()).await)?;
+		let attrs = Attrs {
+			throttle: Limiter::task(task.id),
+			task: Some(task.id),
+			..Attrs::preserving(task.cha.unwrap(), task.preserve)
+		};
+		let mut rx = ctx!(task, engine::copy_progressive(&task.from, &task.to, attrs).await)?;
 
+		let mut done = 0;
 		loop {
 			match rx.recv().await.unwrap_or(Ok(0)) {
 				Ok(0) => {
+					if task.verify && !ctx!(task, Self::verify(&task.from, &task.to).await)? {
+						if task.retry < YAZI.tasks.bizarre_retry.get().get() {
+							task.retry += 1;
+							self.ops.out(task.id, FileOutMoveDo::Rewind(done));
+							self
+								.ops
+								.out(task.id, FileOutMoveDo::Log("Retrying due to checksum mismatch".to_owned()));
+							return Ok(self.requeue(task, LOW));
+						}
+						Err(anyhow!("Failed to work on {task:?}: checksum mismatch"))?;
+					}
 					engine::remove_file(&task.from).await.ok();
 					break;
 				}
-				Ok(n) => self.ops.out(task.id, FileOutMoveDo::Adv(n)),
+				Ok(n) => {
+					done += n;
+					self.ops.out(task.id, FileOutMoveDo::Adv(n));
+				}
 				Err(e) if e.kind() == NotFound => {
 					warn!("Move task partially done: {task:?}");
 					break;
@@ -171,7 +245,7 @@ impl File {
 				// Operation not permitted (os error 1)
 				// Attribute not found (os error 93)
 				Err(e)
-					if task.retry < YAZI.tasks.bizarre_retry.get()
+					if task.retry < YAZI.tasks.bizarre_retry.get().get()
 						&& matches!(e.raw_os_error(), Some(1) | Some(93)) =>
 				{
 					task.retry += 1;
@@ -181,6 +255,12 @@ impl File {
 				Err(e) => ctx!(task, Err(e))?,
 			}
 		}
+
+		for (what, e) in Preserve::lapses(task.id) {
+			self
+				.ops
+				.out(task.id, FileOutMoveDo::Log(format!("Cannot preserve {what} for some files: {e}")));
+		}
 		Ok(self.ops.out(task.id, FileOutMoveDo::Succ))
 	}
 
@@ -363,18 +443,55 @@ impl File {
 		let cha = task.cha.unwrap();
 
 		let cache = ctx!(task, task.target.cache_entry(), "Cannot determine cache path")?;
-		let cache_tmp = ctx!(task, Transaction::tmp(&cache).await, "Cannot determine download cache")?;
+		let cache_tmp = ctx!(task, Transaction::partial(&cache), "Cannot determine download cache")?;
+
+		let (offset, mut rx) = match engine::copy_resumable(&task.target, &cache_tmp, Attrs {
+			throttle: Limiter::task(task.id),
+			..cha.into()
+		})
+		.await
+		{
+			Ok(r) => r,
+			Err(e) => {
+				let (target, log) = (task.target.clone(), FileOutDownloadDo::Log);
+				return Ok(self.retry_transient(task, e, target, &cache_tmp, log).await?);
+			}
+		};
+		// The bytes before the offset are already counted, now or by an earlier try
+		let mut done = offset;
+		if offset > 0 {
+			self.ops.out(task.id, FileOutDownloadDo::Log(format!("Resuming from byte {offset}")));
+			if task.retry == 0 {
+				self.ops.out(task.id, FileOutDownloadDo::Adv(offset));
+			}
+		}
 
-		let mut rx = ctx!(task, engine::copy_progressive(&task.target, &cache_tmp, cha).await)?;
 		loop {
 			match rx.recv().await.unwrap_or(Ok(0)) {
 				Ok(0) => {
+					if task.verify && !ctx!(task, Self::verify(&task.target, &cache_tmp).await)? {
+						engine::remove_file(&cache_tmp).await.ok();
+						if task.retry < YAZI.tasks.bizarre_retry.get().get() {
+							task.retry += 1;
+							self.ops.out(task.id, FileOutDownloadDo::Rewind(done));
+							self.ops.out(
+								task.id,
+								FileOutDownloadDo::Log("Retrying due to checksum mismatch".to_owned()),
+							);
+							return Ok(self.requeue(task, LOW));
+						}
+						Err(anyhow!("Failed to work on {task:?}: checksum mismatch"))?;
+					}
+
 					Local::regular(&cache).remove_dir_all().await.ok();
 					ctx!(task, Stamp::write(cha, task.target.as_url()).await)?;
 					ctx!(task, engine::rename(cache_tmp, cache).await, "Cannot persist downloaded file")?;
 					break;
 				}
-				Ok(n) => self.ops.out(task.id, FileOutDownloadDo::Adv(n)),
+				Ok(n) => {
+					done += n;
+					self.ops.out(task.id, FileOutDownloadDo::Adv(n));
+				}
 				Err(e) if e.kind() == NotFound => {
 					warn!("Download task partially done: {task:?}");
 					break;
@@ -382,14 +499,17 @@ impl File {
 				// Operation not permitted (os error 1)
 				// Attribute not found (os error 93)
 				Err(e)
-					if task.retry < YAZI.tasks.bizarre_retry.get()
+					if task.retry < YAZI.tasks.bizarre_retry.get().get()
 						&& matches!(e.raw_os_error(), Some(1) | Some(93)) =>
 				{
 					task.retry += 1;
 					self.ops.out(task.id, FileOutDownloadDo::Log(format!("Retrying due to error: {e}")));
 					return Ok(self.requeue(task, LOW));
 				}
-				Err(e) => ctx!(task, Err(e))?,
+				Err(e) => {
+					let (target, log) = (task.target.clone(), FileOutDownloadDo::Log);
+					return Ok(self.retry_transient(task, e, target, &cache_tmp, log).await?);
+				}
 			}
 		}
 		Ok(self.ops.out(task.id, FileOutDownloadDo::Succ))
@@ -431,24 +551,38 @@ impl File {
 		ctx!(task, stamp.validate(cha, task.target.as_url()))?;
 
 		let tmp =
-			ctx!(task, Transaction::tmp(&task.target).await, "Cannot determine temporary upload path")?;
-		let mut rx = ctx!(
-			task,
-			engine::copy_progressive(cache, &tmp, Attrs {
-				mode:  Some(cha.mode),
-				atime: None,
-				btime: None,
-				mtime: None,
-			})
-			.await
-		)?;
+			ctx!(task, Transaction::partial(&task.target), "Cannot determine temporary upload path")?;
+		let (offset, mut rx) = match engine::copy_resumable(cache, &tmp, Attrs {
+			throttle: Limiter::task(task.id),
+			..Attrs::mode(cha.mode)
+		})
+		.await
+		{
+			Ok(r) => r,
+			Err(e) => {
+				let cache = cache.clone();
+				return Ok(self.retry_transient(task, e, cache, &tmp, FileOutUploadDo::Log).await?);
+			}
+		};
+		if offset > 0 {
+			self.ops.out(task.id, FileOutUploadDo::Log(format!("Resuming from byte {offset}")));
+			if task.retry == 0 {
+				self.ops.out(task.id, FileOutUploadDo::Adv(offset));
+			}
+		}
 
 		loop {
 			match rx.recv().await.unwrap_or(Ok(0)) {
 				Ok(0) => {
+					if task.verify && !ctx!(task, Self::verify(cache, &tmp).await)? {
+						engine::remove_file(&tmp).await.ok();
+						Err(anyhow!("Failed to work on {task:?}: checksum mismatch"))?;
+					}
+
 					let cha =
 						ctx!(task, Self::cha(&task.target, true, None).await, "Cannot stat original file")?;
 					if stamp.sig() != cha.hash_u128_str(&mut [0; 26]) {
+						engine::discard_resumable(cache, &tmp).await.ok();
 						Err(anyhow!("Failed to work on: {task:?}: remote file has changed during upload"))?;
 					}
 
@@ -461,12 +595,38 @@ impl File {
 					break;
 				}
 				Ok(n) => self.ops.out(task.id, FileOutUploadDo::Adv(n)),
-				Err(e) => ctx!(task, Err(e))?,
+				Err(e) => {
+					let cache = cache.clone();
+					return Ok(self.retry_transient(task, e, cache, &tmp, FileOutUploadDo::Log).await?);
+				}
 			}
 		}
 		Ok(self.ops.out(task.id, FileOutUploadDo::Succ))
 	}
 
+	async fn preservable<U, V>(from: U, to: V, preserve: Preserve) -> Preserve
+	where
+		U: AsUrl,
+		V: AsUrl,
+	{
+		match tokio::join!(engine::capabilities(from.as_url()), engine::capabilities(to.as_url())) {
+			(Ok(a), Ok(b)) => a.preservable(b.preservable(preserve)),
+			_ => Preserve::empty(),
+		}
+	}
+
+	async fn verify<U, V>(a: U, b: V) -> io::Result<bool>
+	where
+		U: AsUrl,
+		V: AsUrl,
+	{
+		let (a, b) = tokio::try_join!(
+			engine::checksum(a.as_url(), Digest::Sha256),
+			engine::checksum(b.as_url(), Digest::Sha256)
+		)?;
+		Ok(a == b)
+	}
+
 	pub(super) async fn cha<U>(url: U, follow: bool, dent: Option<DirEntry>) -> io::Result<Cha>
 	where
 		U: AsUrl,
@@ -490,4 +650,90 @@ impl File {
 	fn requeue(&self, r#in: impl Into<FileIn>, priority: u8) {
 		_ = self.tx.try_send(r#in.into().into_doable(), priority);
 	}
+
+	/// Requeue after `delay`, e.g. to give the connection pool time to reconnect
+	/// after a dropped connection.
+	fn requeue_after(&self, r#in: impl Into<FileIn>, priority: u8, delay: Duration) {
+		let (tx, r#in) = (self.tx.clone(), r#in.into().into_doable());
+		tokio::spawn(async move {
+			tokio::time::sleep(delay).await;
+			_ = tx.try_send(r#in, priority);
+		});
+	}
+
+	/// Requeue a transfer that failed with `e` after a back-off, if the error is
+	/// likely to go away once the connection is re-established and there are
+	/// retries left. Otherwise, the partial data it left at `tmp` can't be
+	/// resumed anymore, so it's discarded and the error is returned.
+	async fn retry_transient<T, O>(
+		&self,
+		task: T,
+		e: io::Error,
+		from: impl AsUrl,
+		tmp: impl AsUrl,
+		log: fn(String) -> O,
+	) -> Result<()>
+	where
+		T: Into<FileIn> + std::fmt::Debug,
+		O: Into<TaskOut>,
+	{
+		let failed = format!("Failed to work on {task:?}");
+		let mut r#in = task.into();
+
+		let max = YAZI.tasks.bizarre_retry.get().get();
+		if let Some(delay) = r#in.retry_mut().and_then(|retry| Self::backoff(retry, max, &e)) {
+			self.ops.out(r#in.id(), log(format!("Retrying in {}s due to error: {e}", delay.as_secs())));
+			return Ok(self.requeue_after(r#in, LOW, delay));
+		}
+
+		engine::discard_resumable(from, tmp).await.ok();
+		Err(anyhow::Error::new(e).context(failed))
+	}
+
+	/// How long to wait before retrying after `e`, if it's transient and `retry`
+	/// hasn't reached `max` yet, in which case the retry is counted. The delay
+	/// doubles with each retry, up to about a minute.
+	fn backoff(retry: &mut u8, max: u8, e: &io::Error) -> Option<Duration> {
+		if *retry >= max || !Self::transient(e) {
+			return None;
+		}
+
+		*retry += 1;
+		Some(Duration::from_secs(1 << (*retry).min(6)))
+	}
+
+	fn transient(e: &io::Error) -> bool {
+		use io::ErrorKind::*;
+		matches!(
+			e.kind(),
+			BrokenPipe
+				| ConnectionAborted
+				| ConnectionRefused
+				| ConnectionReset
+				| NotConnected
+				| TimedOut
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_backoff() {
+		let mut retry = 0;
+		let delays: Vec<_> =
+			std::iter::from_fn(|| File::backoff(&mut retry, 8, &io::ErrorKind::ConnectionReset.into()))
+				.map(|d| d.as_secs())
+				.collect();
+
+		assert_eq!(delays, [2, 4, 8, 16, 32, 64, 64, 64]);
+		assert_eq!(retry, 8);
+
+		// Errors that won't go away by themselves aren't retried
+		let mut retry = 0;
+		assert_eq!(File::backoff(&mut retry, 8, &io::ErrorKind::PermissionDenied.into()), None);
+		assert_eq!(retry, 0);
+	}
 }
@@ -2,7 +2,8 @@ use std::{borrow::Cow, mem, path::PathBuf};
 
 use mlua::{FromLua, Lua, Table, Value};
 use tokio::sync::mpsc;
-use yazi_fs::cha::Cha;
+use yazi_config::YAZI;
+use yazi_fs::{cha::Cha, engine::Preserve};
 use yazi_shared::{id::Id, url::{UrlBuf, UrlLike}};
 
 use crate::{TaskIn, custom::CustomIn, file::{FileProgCopy, FileProgDelete, FileProgDownload, FileProgHardlink, FileProgLink, FileProgMove, FileProgTrash, FileProgUpload}};
@@ -133,6 +134,17 @@ impl FileIn {
 			Self::Custom(_) => self,
 		}
 	}
+
+	/// The number of times a transfer has been retried, for those that retry.
+	pub(crate) fn retry_mut(&mut self) -> Option<&mut u8> {
+		match self {
+			Self::Copy(r#in) | Self::CopyDo(r#in) => Some(&mut r#in.retry),
+			Self::Move(r#in) | Self::MoveDo(r#in) => Some(&mut r#in.retry),
+			Self::Download(r#in) | Self::DownloadDo(r#in) => Some(&mut r#in.retry),
+			Self::Upload(r#in) | Self::UploadDo(r#in) => Some(&mut r#in.retry),
+			_ => None,
+		}
+	}
 }
 
 // --- Copy
@@ -145,6 +157,9 @@ pub struct FileInCopy {
 	pub(crate) cha:    Option<Cha>,
 	pub(crate) follow: bool,
 	pub(crate) retry:  u8,
+	pub(crate) verify: bool,
+
+	pub(crate) preserve: Preserve,
 }
 
 impl TaskIn for FileInCopy {
@@ -172,9 +187,21 @@ impl FileInCopy {
 			force,
 			cha: None,
 			retry: 0,
+			verify: YAZI.tasks.verify.get(),
+			preserve: Preserve::empty(),
 		}
 	}
 
+	pub fn with_verify(mut self, verify: bool) -> Self {
+		self.verify |= verify;
+		self
+	}
+
+	pub fn with_preserve(mut self, preserve: Preserve) -> Self {
+		self.preserve = preserve;
+		self
+	}
+
 	pub(super) fn into_link(self) -> FileInLink {
 		FileInLink {
 			id:       self.id,
@@ -205,7 +232,10 @@ pub struct FileInMove {
 	pub(crate) cha:    Option<Cha>,
 	pub(crate) follow: bool,
 	pub(crate) retry:  u8,
+	pub(crate) verify: bool,
 	pub(crate) drop:   Option<mpsc::Sender<()>>,
+
+	pub(crate) preserve: Preserve,
 }
 
 impl TaskIn for FileInMove {
@@ -237,10 +267,22 @@ impl FileInMove {
 			force,
 			cha: None,
 			retry: 0,
+			verify: YAZI.tasks.verify.get(),
 			drop: None,
+			preserve: Preserve::empty(),
 		}
 	}
 
+	pub fn with_verify(mut self, verify: bool) -> Self {
+		self.verify |= verify;
+		self
+	}
+
+	pub fn with_preserve(mut self, preserve: Preserve) -> Self {
+		self.preserve = preserve;
+		self
+	}
+
 	pub(super) fn into_link(mut self) -> FileInLink {
 		FileInLink {
 			id:       self.id,
@@ -375,6 +417,7 @@ pub(crate) struct FileInDownload {
 	pub(crate) target: UrlBuf,
 	pub(crate) cha:    Option<Cha>,
 	pub(crate) retry:  u8,
+	pub(crate) verify: bool,
 }
 
 impl TaskIn for FileInDownload {
@@ -397,6 +440,8 @@ pub(crate) struct FileInUpload {
 	pub(crate) target: UrlBuf,
 	pub(crate) cha:    Option<Cha>,
 	pub(crate) cache:  Option<PathBuf>,
+	pub(crate) retry:  u8,
+	pub(crate) verify: bool,
 }
 
 impl TaskIn for FileInUpload {
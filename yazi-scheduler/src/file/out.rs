@@ -7,6 +7,7 @@ use crate::{CleanupState, Task, TaskProg};
 pub(crate) enum FileOutCopy {
 	New(u64),
 	Deform(String),
+	Log(String),
 	Succ,
 	Fail(String),
 	Clean,
@@ -33,6 +34,9 @@ impl FileOutCopy {
 				prog.failed_files += 1;
 				task.log(reason);
 			}
+			Self::Log(line) => {
+				task.log(line);
+			}
 			Self::Succ => {
 				prog.collected = Some(true);
 			}
@@ -51,6 +55,7 @@ impl FileOutCopy {
 #[derive(Debug)]
 pub(crate) enum FileOutCopyDo {
 	Adv(u64),
+	Rewind(u64),
 	Log(String),
 	Succ,
 	Fail(String),
@@ -67,6 +72,9 @@ impl FileOutCopyDo {
 			Self::Adv(size) => {
 				prog.processed_bytes += size;
 			}
+			Self::Rewind(size) => {
+				prog.processed_bytes = prog.processed_bytes.saturating_sub(size);
+			}
 			Self::Log(line) => {
 				task.log(line);
 			}
@@ -86,6 +94,7 @@ impl FileOutCopyDo {
 pub(crate) enum FileOutMove {
 	New(u64),
 	Deform(String),
+	Log(String),
 	Succ,
 	Fail(String),
 	Clean(io::Result<()>),
@@ -112,6 +121,9 @@ impl FileOutMove {
 				prog.failed_files += 1;
 				task.log(reason);
 			}
+			Self::Log(line) => {
+				task.log(line);
+			}
 			Self::Succ => {
 				prog.collected = Some(true);
 			}
@@ -134,6 +146,7 @@ impl FileOutMove {
 #[derive(Debug)]
 pub(crate) enum FileOutMoveDo {
 	Adv(u64),
+	Rewind(u64),
 	Log(String),
 	Succ,
 	Fail(String),
@@ -150,6 +163,9 @@ impl FileOutMoveDo {
 			Self::Adv(size) => {
 				prog.processed_bytes += size;
 			}
+			Self::Rewind(size) => {
+				prog.processed_bytes = prog.processed_bytes.saturating_sub(size);
+			}
 			Self::Log(line) => {
 				task.log(line);
 			}
@@ -428,6 +444,7 @@ impl FileOutDownload {
 #[derive(Debug)]
 pub(crate) enum FileOutDownloadDo {
 	Adv(u64),
+	Rewind(u64),
 	Log(String),
 	Succ,
 	Fail(String),
@@ -444,6 +461,9 @@ impl FileOutDownloadDo {
 			Self::Adv(size) => {
 				prog.processed_bytes += size;
 			}
+			Self::Rewind(size) => {
+				prog.processed_bytes = prog.processed_bytes.saturating_sub(size);
+			}
 			Self::Log(line) => {
 				task.log(line);
 			}
@@ -503,6 +523,7 @@ impl FileOutUpload {
 #[derive(Debug)]
 pub(crate) enum FileOutUploadDo {
 	Adv(u64),
+	Log(String),
 	Succ,
 	Fail(String),
 }
@@ -518,6 +539,9 @@ impl FileOutUploadDo {
 			Self::Adv(size) => {
 				prog.processed_bytes += size;
 			}
+			Self::Log(line) => {
+				task.log(line);
+			}
 			Self::Succ => {
 				prog.success_files += 1;
 			}
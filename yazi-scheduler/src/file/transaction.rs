@@ -1,6 +1,6 @@
 use std::{hash::{BuildHasher, Hash, Hasher}, io};
 
-use yazi_fs::{cha::ChaMode, engine::Attrs};
+use yazi_fs::{FsHash128, cha::ChaMode, engine::Attrs};
 use yazi_macro::ok_or_not_found;
 use yazi_shared::{timestamp_us, url::{AsUrl, Url, UrlBuf}};
 use yazi_vfs::{engine, unique_file};
@@ -27,6 +27,20 @@ impl Transaction {
 		unique_file(parent.try_join(format!(".{:x}.%tmp", h.finish()))?, false).await
 	}
 
+	/// A temporary path of `url` that stays the same across attempts, for
+	/// transfers that can resume from its partial data.
+	pub(super) fn partial<U>(url: U) -> io::Result<UrlBuf>
+	where
+		U: AsUrl,
+	{
+		let url = url.as_url();
+		let Some(parent) = url.parent() else {
+			Err(io::Error::new(io::ErrorKind::InvalidInput, "Url has no parent"))?
+		};
+
+		Ok(parent.try_join(format!(".{}.%part", url.hash_u128_str(&mut [0; 26])))?)
+	}
+
 	pub(super) async fn unlink<U>(url: U) -> io::Result<()>
 	where
 		U: AsUrl,
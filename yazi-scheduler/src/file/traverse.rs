@@ -41,6 +41,8 @@ impl Traverse for FileInCopy {
 			cha: Some(cha),
 			follow: self.follow,
 			retry: self.retry,
+			verify: self.verify,
+			preserve: self.preserve,
 		}
 	}
 
@@ -63,7 +65,9 @@ impl Traverse for FileInMove {
 			cha: Some(cha),
 			follow: self.follow,
 			retry: self.retry,
+			verify: self.verify,
 			drop: self.drop.clone(),
+			preserve: self.preserve,
 		}
 	}
 
@@ -113,7 +117,13 @@ impl Traverse for FileInDownload {
 	fn from(&self) -> Url<'_> { self.target.as_url() }
 
 	fn spawn(&self, from: UrlBuf, _to: Option<UrlBuf>, cha: Cha) -> Self {
-		Self { id: self.id, target: from, cha: Some(cha), retry: self.retry }
+		Self {
+			id:     self.id,
+			target: from,
+			cha:    Some(cha),
+			retry:  self.retry,
+			verify: self.verify,
+		}
 	}
 
 	fn to(&self) -> Option<Url<'_>> { None }
@@ -137,7 +147,14 @@ impl Traverse for FileInUpload {
 	}
 
 	fn spawn(&self, from: UrlBuf, _to: Option<UrlBuf>, cha: Cha) -> Self {
-		Self { id: self.id, cha: Some(cha), cache: from.cache_entry(), target: from }
+		Self {
+			id:     self.id,
+			cha:    Some(cha),
+			cache:  from.cache_entry(),
+			target: from,
+			retry:  self.retry,
+			verify: self.verify,
+		}
 	}
 
 	fn to(&self) -> Option<Url<'_>> { None }
@@ -1,6 +1,6 @@
 mod macros;
 
-yazi_macro::mod_pub!(custom fetch file hook plugin preload process size);
+yazi_macro::mod_pub!(checksum custom dedupe fetch file hook plugin preload process size);
 
 yazi_macro::mod_flat!(behavior cleanup handle loaded ongoing op out progress proxy r#in scheduler snap status summary task worker);
 
@@ -65,6 +65,10 @@ macro_rules! dispatch_progress {
 			$crate::TaskProg::Fetch(p) => $crate::Progress::$method(p),
 			$crate::TaskProg::Preload(p) => $crate::Progress::$method(p),
 			$crate::TaskProg::Size(p) => $crate::Progress::$method(p),
+			// Dedupe
+			$crate::TaskProg::Dedupe(p) => $crate::Progress::$method(p),
+			// Checksum
+			$crate::TaskProg::Checksum(p) => $crate::Progress::$method(p),
 			// Process
 			$crate::TaskProg::ProcessBlock(p) => $crate::Progress::$method(p),
 			$crate::TaskProg::ProcessOrphan(p) => $crate::Progress::$method(p),
@@ -1,5 +1,6 @@
 use hashbrown::{HashMap, hash_map::Entry};
 use yazi_config::YAZI;
+use yazi_fs::engine::{Limiter, Preserve};
 use yazi_shared::id::{Id, Ids};
 
 use super::Task;
@@ -35,6 +36,8 @@ impl Ongoing {
 					return Some(hook);
 				}
 
+				Limiter::forget(id);
+				Preserve::forget(id);
 				oe.remove();
 			}
 			Entry::Vacant(_) => {}
@@ -45,6 +48,8 @@ impl Ongoing {
 	pub(super) fn fulfill(&mut self, id: Id) -> Option<Task> {
 		let task = self.inner.remove(&id)?;
 		task.succeed();
+		Limiter::forget(id);
+		Preserve::forget(id);
 		Some(task)
 	}
 
@@ -59,7 +64,7 @@ impl Ongoing {
 	}
 
 	pub fn len(&self) -> usize {
-		if YAZI.tasks.suppress_preload {
+		if YAZI.tasks.suppress_preload.get() {
 			self.inner.values().filter(|&t| t.prog.is_user()).count()
 		} else {
 			self.inner.len()
@@ -73,7 +78,7 @@ impl Ongoing {
 	pub fn intact(&self, id: Id) -> bool { self.inner.get(&id).is_some_and(|t| !t.is_canceled()) }
 
 	pub fn values(&self) -> Box<dyn Iterator<Item = &Task> + '_> {
-		if YAZI.tasks.suppress_preload {
+		if YAZI.tasks.suppress_preload.get() {
 			Box::new(self.inner.values().filter(|&t| t.prog.is_user()))
 		} else {
 			Box::new(self.inner.values())
@@ -1,4 +1,4 @@
-use crate::{Task, custom::CustomOut, fetch::FetchOutFetch, file::{FileOutCopy, FileOutCopyDo, FileOutDelete, FileOutDeleteDo, FileOutDownload, FileOutDownloadDo, FileOutHardlink, FileOutHardlinkDo, FileOutLink, FileOutMove, FileOutMoveDo, FileOutTrash, FileOutUpload, FileOutUploadDo}, hook::{HookInOutCopy, HookInOutHardlink, HookInOutLink, HookInOutMove}, impl_from_out, plugin::PluginOutEntry, preload::PreloadOut, process::{ProcessOutBg, ProcessOutBlock, ProcessOutOrphan}, size::SizeOut};
+use crate::{Task, checksum::ChecksumOut, custom::CustomOut, dedupe::DedupeOut, fetch::FetchOutFetch, file::{FileOutCopy, FileOutCopyDo, FileOutDelete, FileOutDeleteDo, FileOutDownload, FileOutDownloadDo, FileOutHardlink, FileOutHardlinkDo, FileOutLink, FileOutMove, FileOutMoveDo, FileOutTrash, FileOutUpload, FileOutUploadDo}, hook::{HookInOutCopy, HookInOutHardlink, HookInOutLink, HookInOutMove}, impl_from_out, plugin::PluginOutEntry, preload::PreloadOut, process::{ProcessOutBg, ProcessOutBlock, ProcessOutOrphan}, size::SizeOut};
 
 #[derive(Debug)]
 pub(super) enum TaskOut {
@@ -25,6 +25,10 @@ pub(super) enum TaskOut {
 	Preload(PreloadOut),
 	// Size
 	Size(SizeOut),
+	// Dedupe
+	Dedupe(DedupeOut),
+	// Checksum
+	Checksum(ChecksumOut),
 	// Process
 	ProcessBlock(ProcessOutBlock),
 	ProcessOrphan(ProcessOutOrphan),
@@ -49,6 +53,10 @@ impl_from_out! {
 	Preload(PreloadOut),
 	// Size
 	Size(SizeOut),
+	// Dedupe
+	Dedupe(DedupeOut),
+	// Checksum
+	Checksum(ChecksumOut),
 	// Process
 	ProcessBlock(ProcessOutBlock), ProcessOrphan(ProcessOutOrphan), ProcessBg(ProcessOutBg),
 	// Custom
@@ -81,6 +89,10 @@ impl TaskOut {
 			Self::Fetch(out) => out.reduce(task),
 			Self::Preload(out) => out.reduce(task),
 			Self::Size(out) => out.reduce(task),
+			// Dedupe
+			Self::Dedupe(out) => out.reduce(task),
+			// Checksum
+			Self::Checksum(out) => out.reduce(task),
 			// Process
 			Self::ProcessBlock(out) => out.reduce(task),
 			Self::ProcessOrphan(out) => out.reduce(task),
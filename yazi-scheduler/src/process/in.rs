@@ -50,9 +50,10 @@ impl TaskIn for ProcessIn {
 // --- Block
 #[derive(Debug)]
 pub(crate) struct ProcessInBlock {
-	pub(crate) id:  Id,
-	pub(crate) cwd: UrlBuf,
-	pub(crate) cmd: OsString,
+	pub(crate) id:     Id,
+	pub(crate) cwd:    UrlBuf,
+	pub(crate) cmd:    OsString,
+	pub(crate) remote: bool,
 }
 
 impl TaskIn for ProcessInBlock {
@@ -70,7 +71,7 @@ impl TaskIn for ProcessInBlock {
 
 impl From<ProcessInBlock> for ShellOpt {
 	fn from(r#in: ProcessInBlock) -> Self {
-		Self { cwd: r#in.cwd, cmd: r#in.cmd, block: true, orphan: false }
+		Self { cwd: r#in.cwd, cmd: r#in.cmd, block: true, orphan: false, remote: r#in.remote }
 	}
 }
 
@@ -97,16 +98,17 @@ impl TaskIn for ProcessInOrphan {
 
 impl From<ProcessInOrphan> for ShellOpt {
 	fn from(r#in: ProcessInOrphan) -> Self {
-		Self { cwd: r#in.cwd, cmd: r#in.cmd, block: false, orphan: true }
+		Self { cwd: r#in.cwd, cmd: r#in.cmd, block: false, orphan: true, remote: false }
 	}
 }
 
 // --- Bg
 #[derive(Debug)]
 pub(crate) struct ProcessInBg {
-	pub(crate) id:  Id,
-	pub(crate) cwd: UrlBuf,
-	pub(crate) cmd: OsString,
+	pub(crate) id:     Id,
+	pub(crate) cwd:    UrlBuf,
+	pub(crate) cmd:    OsString,
+	pub(crate) remote: bool,
 }
 
 impl TaskIn for ProcessInBg {
@@ -124,6 +126,6 @@ impl TaskIn for ProcessInBg {
 
 impl From<ProcessInBg> for ShellOpt {
 	fn from(r#in: ProcessInBg) -> Self {
-		Self { cwd: r#in.cwd, cmd: r#in.cmd, block: false, orphan: false }
+		Self { cwd: r#in.cwd, cmd: r#in.cmd, block: false, orphan: false, remote: r#in.remote }
 	}
 }
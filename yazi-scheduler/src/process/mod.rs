@@ -1,4 +1,4 @@
 #[macro_use]
 mod macros;
 
-yazi_macro::mod_flat!(out process progress r#in shell);
+yazi_macro::mod_flat!(out process progress r#in remote shell);
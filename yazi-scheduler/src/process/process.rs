@@ -24,24 +24,24 @@ impl Process {
 		AppProxy::stop().await;
 
 		let (id, cmd) = (task.id, task.cmd.clone());
-		let result = super::shell(task.into()).await;
-		if let Err(e) = result {
-			NotifyProxy::push_warn(
-				cmd.to_string_lossy().into_owned(),
-				format!("Failed to start process: {e}"),
-			);
-			return Ok(self.ops.out(id, ProcessOutBlock::Succ));
-		}
+		let code = if task.remote {
+			super::remote_block(task.into()).await.map(|c| c.map(|c| c as i32)).map_err(Into::into)
+		} else {
+			match super::shell(task.into()).await {
+				Ok(mut child) => Ok(child.wait().await?.code()),
+				Err(e) => Err(e),
+			}
+		};
 
-		let status = result.unwrap().wait().await?;
-		if !status.success() {
-			let content = match status.code() {
-				Some(130) => return Ok(self.ops.out(id, ProcessOutBlock::Succ)), // Ctrl-C pressed by user
-				Some(code) => format!("Process exited with status code: {code}"),
-				None => "Process terminated by signal".to_string(),
-			};
-			NotifyProxy::push_warn(cmd.to_string_lossy().into_owned(), content);
-		}
+		let content = match code {
+			Err(e) => format!("Failed to start process: {e}"),
+			Ok(Some(0)) => return Ok(self.ops.out(id, ProcessOutBlock::Succ)),
+			// Ctrl-C pressed by user
+			Ok(Some(130)) => return Ok(self.ops.out(id, ProcessOutBlock::Succ)),
+			Ok(Some(code)) => format!("Process exited with status code: {code}"),
+			Ok(None) => "Process terminated by signal".to_string(),
+		};
+		NotifyProxy::push_task(id, "warn", cmd.to_string_lossy().into_owned(), content);
 
 		Ok(self.ops.out(id, ProcessOutBlock::Succ))
 	}
@@ -54,8 +54,18 @@ impl Process {
 	}
 
 	pub(crate) async fn bg(&self, task: ProcessInBg) -> Result<(), ProcessOutBg> {
-		let mut child =
-			super::shell(ShellOpt { cwd: task.cwd, cmd: task.cmd, block: false, orphan: false }).await?;
+		if task.remote {
+			return self.bg_remote(task).await;
+		}
+
+		let mut child = super::shell(ShellOpt {
+			cwd:    task.cwd,
+			cmd:    task.cmd,
+			block:  false,
+			orphan: false,
+			remote: false,
+		})
+		.await?;
 
 		let mut stdout = BufReader::new(child.stdout.take().unwrap()).lines();
 		let mut stderr = BufReader::new(child.stderr.take().unwrap()).lines();
@@ -83,6 +93,26 @@ impl Process {
 
 		Ok(self.ops.out(task.id, ProcessOutBg::Succ))
 	}
+
+	async fn bg_remote(&self, task: ProcessInBg) -> Result<(), ProcessOutBg> {
+		let id = task.id;
+		let code = super::remote_bg(task.into(), |line| self.ops.out(id, ProcessOutBg::Log(line)))
+			.await
+			.map_err(anyhow::Error::from)?;
+
+		self.ops.out(
+			id,
+			ProcessOutBg::Log(match code {
+				Some(code) => format!("Exited with status code: {code}"),
+				None => "Process terminated by signal".to_string(),
+			}),
+		);
+		if code != Some(0) {
+			Err(anyhow!("Process failed"))?;
+		}
+
+		Ok(self.ops.out(id, ProcessOutBg::Succ))
+	}
 }
 
 impl Process {
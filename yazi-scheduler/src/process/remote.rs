@@ -0,0 +1,126 @@
+use std::{io::{self, Write}, time::Duration};
+
+use scopeguard::defer;
+use tokio::{select, sync::mpsc};
+use yazi_shared::url::AsUrl;
+use yazi_term::TERM;
+use yazi_tty::TTY;
+use yazi_vfs::engine::sftp::{RemoteOutput, RemoteProcess};
+
+use super::ShellOpt;
+
+/// Run a blocking command on the remote host, with the local terminal attached
+/// to a remote pseudo-terminal, returning its exit code.
+pub(crate) async fn remote_block(opt: ShellOpt) -> io::Result<Option<u32>> {
+	let mut proc = RemoteProcess::spawn(
+		opt.cwd.as_url(),
+		opt.cmd.as_encoded_bytes(),
+		Some(TERM.dimension().area()),
+	)
+	.await?;
+
+	defer! { TERM.enter_cooked_mode().ok(); }
+	TERM.enter_raw_mode()?;
+
+	let (tx, mut rx) = mpsc::channel(64);
+	let reader = tokio::task::spawn_blocking(move || forward_stdin(tx));
+
+	let mut resized = Resized::new()?;
+	let mut code = None;
+	loop {
+		select! {
+			out = proc.next() => match out? {
+				Some(RemoteOutput::Stdout(b) | RemoteOutput::Stderr(b)) => {
+					let mut w = TTY.lockout();
+					w.write_all(&b)?;
+					w.flush()?;
+				}
+				Some(RemoteOutput::Exit(c)) => code = c,
+				None => break,
+			},
+			Some(b) = rx.recv() => proc.write(&b).await?,
+			_ = resized.recv() => {
+				let (cols, rows) = TERM.dimension().area();
+				proc.resize(cols, rows).await.ok();
+			}
+		}
+	}
+
+	drop(rx);
+	reader.await.ok();
+	Ok(code)
+}
+
+/// Run a background command on the remote host, sending each line of its
+/// output to `log`, returning its exit code.
+pub(crate) async fn remote_bg(
+	opt: ShellOpt,
+	mut log: impl FnMut(String),
+) -> io::Result<Option<u32>> {
+	let mut proc = RemoteProcess::spawn(opt.cwd.as_url(), opt.cmd.as_encoded_bytes(), None).await?;
+
+	let (mut stdout, mut stderr, mut code) = (vec![], vec![], None);
+	while let Some(out) = proc.next().await? {
+		let buf = match out {
+			RemoteOutput::Stdout(b) => {
+				stdout.extend_from_slice(&b);
+				&mut stdout
+			}
+			RemoteOutput::Stderr(b) => {
+				stderr.extend_from_slice(&b);
+				&mut stderr
+			}
+			RemoteOutput::Exit(c) => {
+				code = c;
+				continue;
+			}
+		};
+		while let Some(n) = buf.iter().position(|&b| b == b'\n') {
+			let line: Vec<_> = buf.drain(..=n).collect();
+			log(String::from_utf8_lossy(&line[..n]).into_owned());
+		}
+	}
+
+	for rest in [stdout, stderr] {
+		if !rest.is_empty() {
+			log(String::from_utf8_lossy(&rest).into_owned());
+		}
+	}
+	Ok(code)
+}
+
+// Read the TTY in short polls rather than with a blocking read, so no keystroke
+// meant for the app is swallowed after the command exits.
+fn forward_stdin(tx: mpsc::Sender<Vec<u8>>) {
+	while !tx.is_closed() {
+		let (buf, result) = TTY.read_until(Duration::from_millis(30), |_, _| true);
+		if !buf.is_empty() && tx.blocking_send(buf).is_err() {
+			break;
+		} else if result.as_ref().is_err_and(|e| e.kind() != io::ErrorKind::TimedOut) {
+			break;
+		}
+	}
+}
+
+struct Resized {
+	#[cfg(unix)]
+	signal: tokio::signal::unix::Signal,
+}
+
+impl Resized {
+	fn new() -> io::Result<Self> {
+		Ok(Self {
+			#[cfg(unix)]
+			signal:              tokio::signal::unix::signal(
+				tokio::signal::unix::SignalKind::window_change(),
+			)?,
+		})
+	}
+
+	async fn recv(&mut self) {
+		#[cfg(unix)]
+		self.signal.recv().await;
+		#[cfg(windows)]
+		std::future::pending::<()>().await;
+	}
+}
@@ -12,6 +12,8 @@ pub struct ShellOpt {
 	pub cmd:    OsString,
 	pub block:  bool,
 	pub orphan: bool,
+	/// Run the command on the remote host of the SFTP `cwd`, rather than locally.
+	pub remote: bool,
 }
 
 impl_data_any!(ShellOpt);
@@ -1,6 +1,6 @@
 use serde::Serialize;
 
-use crate::{CleanupState, TaskSummary, custom::CustomProg, dispatch_progress, fetch::FetchProg, file::{FileProgCopy, FileProgDelete, FileProgDownload, FileProgHardlink, FileProgLink, FileProgMove, FileProgTrash, FileProgUpload}, impl_from_prog, plugin::PluginProgEntry, preload::PreloadProg, process::{ProcessProgBg, ProcessProgBlock, ProcessProgOrphan}, size::SizeProg};
+use crate::{CleanupState, TaskSummary, checksum::ChecksumProg, custom::CustomProg, dedupe::DedupeProg, dispatch_progress, fetch::FetchProg, file::{FileProgCopy, FileProgDelete, FileProgDownload, FileProgHardlink, FileProgLink, FileProgMove, FileProgTrash, FileProgUpload}, impl_from_prog, plugin::PluginProgEntry, preload::PreloadProg, process::{ProcessProgBg, ProcessProgBlock, ProcessProgOrphan}, size::SizeProg};
 
 pub trait Progress: Copy {
 	// Whether the task is still cooking or cleaning.
@@ -76,6 +76,10 @@ pub enum TaskProg {
 	Preload(PreloadProg),
 	// Size
 	Size(SizeProg),
+	// Dedupe
+	Dedupe(DedupeProg),
+	// Checksum
+	Checksum(ChecksumProg),
 	// Process
 	ProcessBlock(ProcessProgBlock),
 	ProcessOrphan(ProcessProgOrphan),
@@ -95,6 +99,10 @@ impl_from_prog! {
 	Preload(PreloadProg),
 	// Size
 	Size(SizeProg),
+	// Dedupe
+	Dedupe(DedupeProg),
+	// Checksum
+	Checksum(ChecksumProg),
 	// Process
 	ProcessBlock(ProcessProgBlock), ProcessOrphan(ProcessProgOrphan), ProcessBg(ProcessProgBg),
 	// Custom
@@ -119,6 +127,10 @@ impl From<TaskProg> for TaskSummary {
 			TaskProg::Fetch(p) => p.into(),
 			TaskProg::Preload(p) => p.into(),
 			TaskProg::Size(p) => p.into(),
+			// Dedupe
+			TaskProg::Dedupe(p) => p.into(),
+			// Checksum
+			TaskProg::Checksum(p) => p.into(),
 			// Process
 			TaskProg::ProcessBlock(p) => p.into(),
 			TaskProg::ProcessOrphan(p) => p.into(),
@@ -161,6 +173,10 @@ impl TaskProg {
 			Self::Fetch(_) => false,
 			Self::Preload(_) => false,
 			Self::Size(_) => false,
+			// Dedupe
+			Self::Dedupe(_) => true,
+			// Checksum
+			Self::Checksum(_) => true,
 			// Process
 			Self::ProcessBlock(_) => true,
 			Self::ProcessOrphan(_) => true,
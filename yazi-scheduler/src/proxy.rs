@@ -63,4 +63,19 @@ impl NotifyProxy {
 				.with("timeout", 10f64)
 		));
 	}
+
+	/// Push a message about a task, which the notification log can jump to.
+	pub fn push_task(
+		task: Id,
+		level: &'static str,
+		title: impl Into<SStr>,
+		content: impl Into<SStr>,
+	) {
+		emit!(Call(
+			relay!(notify:push, [content.into(), title.into()])
+				.with("level", SStr::Borrowed(level))
+				.with("timeout", if level == "error" { 10f64 } else { 5f64 })
+				.with("task", task)
+		));
+	}
 }
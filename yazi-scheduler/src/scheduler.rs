@@ -2,10 +2,10 @@ use std::{ops::Deref, sync::Arc, time::Duration};
 
 use tokio::{select, task::JoinHandle};
 use yazi_config::{YAZI, plugin::{FetcherArc, PreloaderArc}};
-use yazi_fs::{FsHash64, file::{File, FileSig}};
+use yazi_fs::{Digest, FsHash64, engine::{BANDWIDTH, REFLINK}, file::{File, FileSig}};
 use yazi_shared::{Throttle, id::Id, pool::Symbol, url::{UrlBuf, UrlLike}};
 
-use crate::{Behavior, HIGH, LOW, NORMAL, Task, TaskHandle, TaskIn, TaskProg, Worker, custom::{CustomIn, CustomOut, CustomPool}, fetch::FetchInFetch, file::{FileInCopy, FileInDelete, FileInDownload, FileInHardlink, FileInLink, FileInMove, FileInTrash, FileInUpload, FileOutCopy, FileOutDownload, FileOutHardlink, FileOutMove, FileOutUpload}, hook::{HookIn, HookInDelete, HookInDownload, HookInPreload, HookInTrash, HookInUpload}, plugin::PluginInEntry, preload::PreloadInPreload, process::{ProcessIn, ProcessInBg, ProcessInBlock, ProcessInOrphan, ShellOpt}, size::SizeIn};
+use crate::{Behavior, HIGH, LOW, NORMAL, Task, TaskHandle, TaskIn, TaskProg, Worker, checksum::{ChecksumInCompute, ChecksumInVerify}, custom::{CustomIn, CustomOut, CustomPool}, dedupe::DedupeIn, fetch::FetchInFetch, file::{FileInCopy, FileInDelete, FileInDownload, FileInHardlink, FileInLink, FileInMove, FileInTrash, FileInUpload, FileOutCopy, FileOutDownload, FileOutHardlink, FileOutMove, FileOutUpload}, hook::{HookIn, HookInDelete, HookInDownload, HookInPreload, HookInTrash, HookInUpload}, plugin::PluginInEntry, preload::PreloadInPreload, process::{ProcessIn, ProcessInBg, ProcessInBlock, ProcessInOrphan, ShellOpt}, size::SizeIn};
 
 pub struct Scheduler {
 	pub worker:   Worker,
@@ -21,10 +21,19 @@ impl Deref for Scheduler {
 
 impl Scheduler {
 	pub fn serve() -> Self {
+		Self::configure();
+
 		let (worker, handles) = Worker::make();
 		Self { worker, behavior: Behavior::new(), handles }
 	}
 
+	/// Apply the `[tasks]` options shared by the file engines, at startup and
+	/// again after the config is reloaded.
+	pub fn configure() {
+		REFLINK.set(YAZI.tasks.reflink.get());
+		BANDWIDTH.set_rate(YAZI.tasks.throttle.get());
+	}
+
 	fn add<T, R>(&self, r#in: &mut T, map: impl FnOnce(&mut Task) -> R) -> R
 	where
 		T: TaskIn,
@@ -130,7 +139,8 @@ impl Scheduler {
 	}
 
 	pub fn file_download(&self, target: UrlBuf) -> TaskHandle {
-		let mut r#in = FileInDownload { id: Id::ZERO, target, cha: None, retry: 0 };
+		let mut r#in =
+			FileInDownload { id: Id::ZERO, target, cha: None, retry: 0, verify: YAZI.tasks.verify.get() };
 		let hook = HookInDownload::new(&r#in.target);
 		let handle = self.add_hooked(&mut r#in, hook, |t| t.handle.clone());
 
@@ -143,7 +153,14 @@ impl Scheduler {
 	}
 
 	pub fn file_upload(&self, target: UrlBuf) {
-		let mut r#in = FileInUpload { id: Id::ZERO, target, cha: None, cache: None };
+		let mut r#in = FileInUpload {
+			id: Id::ZERO,
+			target,
+			cha: None,
+			cache: None,
+			retry: 0,
+			verify: YAZI.tasks.verify.get(),
+		};
 		let hook = HookInUpload::new(&r#in.target);
 		self.add_hooked(&mut r#in, hook, |_| ());
 
@@ -212,13 +229,42 @@ impl Scheduler {
 		}
 	}
 
+	pub fn dedupe(&self, target: UrlBuf, hidden: bool, ticket: Id) -> TaskHandle {
+		let mut r#in = DedupeIn { id: Id::ZERO, target, hidden, ticket };
+		let handle = self.add(&mut r#in, |t| t.handle.clone());
+
+		self.dedupe.submit(r#in, NORMAL);
+		handle
+	}
+
+	pub fn checksum_compute(&self, targets: Vec<UrlBuf>, digest: Digest) -> TaskHandle {
+		let mut r#in = ChecksumInCompute { id: Id::ZERO, targets, digest };
+		let handle = self.add(&mut r#in, |t| t.handle.clone());
+
+		self.checksum.submit(r#in, NORMAL);
+		handle
+	}
+
+	pub fn checksum_verify(
+		&self,
+		manifest: UrlBuf,
+		digest: Option<Digest>,
+		listing: Option<(UrlBuf, Id)>,
+	) -> TaskHandle {
+		let mut r#in = ChecksumInVerify { id: Id::ZERO, manifest, digest, listing };
+		let handle = self.add(&mut r#in, |t| t.handle.clone());
+
+		self.checksum.submit(r#in, NORMAL);
+		handle
+	}
+
 	pub fn process_open(&self, opt: ShellOpt) -> TaskHandle {
 		let mut r#in: ProcessIn = if opt.block {
-			ProcessInBlock { id: Id::ZERO, cwd: opt.cwd, cmd: opt.cmd }.into()
+			ProcessInBlock { id: Id::ZERO, cwd: opt.cwd, cmd: opt.cmd, remote: opt.remote }.into()
 		} else if opt.orphan {
 			ProcessInOrphan { id: Id::ZERO, cwd: opt.cwd, cmd: opt.cmd }.into()
 		} else {
-			ProcessInBg { id: Id::ZERO, cwd: opt.cwd, cmd: opt.cmd }.into()
+			ProcessInBg { id: Id::ZERO, cwd: opt.cwd, cmd: opt.cmd, remote: opt.remote }.into()
 		};
 
 		let handle = match &mut r#in {
@@ -4,7 +4,7 @@ use parking_lot::Mutex;
 use tokio::{select, sync::mpsc, task::JoinHandle};
 use yazi_config::YAZI;
 
-use crate::{LOW, Ongoing, Progress, TaskIn, TaskOp, TaskOps, TaskOut, custom::Custom, fetch::{Fetch, FetchIn}, file::{File, FileIn}, hook::{Hook, HookIn}, plugin::{Plugin, PluginIn}, preload::{Preload, PreloadIn}, process::{Process, ProcessIn}, size::{Size, SizeIn}};
+use crate::{LOW, Ongoing, Progress, TaskIn, TaskOp, TaskOps, TaskOut, checksum::{Checksum, ChecksumIn}, custom::Custom, dedupe::{Dedupe, DedupeIn}, fetch::{Fetch, FetchIn}, file::{File, FileIn}, hook::{Hook, HookIn}, plugin::{Plugin, PluginIn}, preload::{Preload, PreloadIn}, process::{Process, ProcessIn}, size::{Size, SizeIn}};
 
 #[derive(Clone)]
 pub struct Worker {
@@ -13,6 +13,8 @@ pub struct Worker {
 	pub fetch:          Arc<Fetch>,
 	pub preload:        Arc<Preload>,
 	pub size:           Arc<Size>,
+	pub dedupe:         Arc<Dedupe>,
+	pub checksum:       Arc<Checksum>,
 	pub(super) process: Arc<Process>,
 	pub(super) custom:  Custom,
 	pub(super) hook:    Arc<Hook>,
@@ -28,6 +30,8 @@ impl Worker {
 		let (fetch_tx, fetch_rx) = async_priority_channel::unbounded();
 		let (preload_tx, preload_rx) = async_priority_channel::unbounded();
 		let (size_tx, size_rx) = async_priority_channel::unbounded();
+		let (dedupe_tx, dedupe_rx) = async_priority_channel::unbounded();
+		let (checksum_tx, checksum_rx) = async_priority_channel::unbounded();
 		let (process_tx, process_rx) = async_priority_channel::unbounded();
 		let (hook_tx, hook_rx) = async_priority_channel::unbounded();
 		let (op_tx, op_rx) = mpsc::unbounded_channel();
@@ -38,12 +42,27 @@ impl Worker {
 		let fetch = Arc::new(Fetch::new(&op_tx, fetch_tx));
 		let preload = Arc::new(Preload::new(&op_tx, preload_tx));
 		let size = Arc::new(Size::new(&op_tx, size_tx));
+		let dedupe = Arc::new(Dedupe::new(&op_tx, dedupe_tx));
+		let checksum = Arc::new(Checksum::new(&op_tx, checksum_tx));
 		let process = Arc::new(Process::new(&op_tx, process_tx));
 		let custom = Custom::new();
 		let hook = Arc::new(Hook::new(&op_tx, &ongoing, &preload, hook_tx));
 
 		let ops = TaskOps(op_tx);
-		let me = Self { file, plugin, fetch, preload, size, process, custom, hook, ops, ongoing };
+		let me = Self {
+			file,
+			plugin,
+			fetch,
+			preload,
+			size,
+			dedupe,
+			checksum,
+			process,
+			custom,
+			hook,
+			ops,
+			ongoing,
+		};
 
 		let handles = []
 			.into_iter()
@@ -52,6 +71,8 @@ impl Worker {
 			.chain((0..YAZI.tasks.fetch_workers.get()).map(|_| me.fetch(fetch_rx.clone())))
 			.chain((0..YAZI.tasks.preload_workers.get()).map(|_| me.preload(preload_rx.clone())))
 			.chain((0..3).map(|_| me.size(size_rx.clone())))
+			.chain([me.dedupe(dedupe_rx)])
+			.chain((0..2).map(|_| me.checksum(checksum_rx.clone())))
 			.chain((0..YAZI.tasks.process_workers.get()).map(|_| me.process(process_rx.clone())))
 			.chain((0..3).map(|_| me.hook(hook_rx.clone())))
 			.chain([me.op(op_rx)])
@@ -227,6 +248,65 @@ impl Worker {
 		self.size.size(r#in).await.map_err(Into::into)
 	}
 
+	fn dedupe(&self, rx: async_priority_channel::Receiver<DedupeIn, u8>) -> JoinHandle<()> {
+		let me = self.clone();
+		tokio::spawn(async move {
+			loop {
+				if let Ok((r#in, _)) = rx.recv().await {
+					let id = r#in.id();
+					let Some(handle) = me.ongoing.lock().get_handle(id) else {
+						continue;
+					};
+
+					handle.start();
+					let result = select! {
+						r = me.dedupe_do(r#in) => r,
+						_ = handle.finished() => Ok(())
+					};
+
+					if let Err(out) = result {
+						me.ops.out(id, out);
+					}
+				}
+			}
+		})
+	}
+
+	async fn dedupe_do(&self, r#in: DedupeIn) -> Result<(), TaskOut> {
+		self.dedupe.dedupe(r#in).await.map_err(Into::into)
+	}
+
+	fn checksum(&self, rx: async_priority_channel::Receiver<ChecksumIn, u8>) -> JoinHandle<()> {
+		let me = self.clone();
+		tokio::spawn(async move {
+			loop {
+				if let Ok((r#in, _)) = rx.recv().await {
+					let id = r#in.id();
+					let Some(handle) = me.ongoing.lock().get_handle(id) else {
+						continue;
+					};
+
+					handle.start();
+					let result = select! {
+						r = me.checksum_do(r#in) => r,
+						_ = handle.finished() => Ok(())
+					};
+
+					if let Err(out) = result {
+						me.ops.out(id, out);
+					}
+				}
+			}
+		})
+	}
+
+	async fn checksum_do(&self, r#in: ChecksumIn) -> Result<(), TaskOut> {
+		match r#in {
+			ChecksumIn::Compute(r#in) => self.checksum.compute(r#in).await.map_err(Into::into),
+			ChecksumIn::Verify(r#in) => self.checksum.verify(r#in).await.map_err(Into::into),
+		}
+	}
+
 	fn process(&self, rx: async_priority_channel::Receiver<ProcessIn, u8>) -> JoinHandle<()> {
 		let me = self.clone();
 		tokio::spawn(async move {
@@ -2,6 +2,7 @@ use std::{io::{self, SeekFrom}, pin::Pin, sync::Arc, task::{Context, Poll, ready
 
 use tokio::{io::{AsyncRead, AsyncSeek, AsyncWrite, ReadBuf}, time::{Timeout, timeout}};
 
+use super::window::{CHUNK, Window};
 use crate::{Error, Operator, Packet, Receiver, Session, fs::Attrs, requests};
 
 pub struct File {
@@ -11,10 +12,14 @@ pub struct File {
 	cursor: u64,
 	closed: bool,
 
+	reads:    Window,
+	read_end: Option<u64>,
+	read_buf: Vec<u8>,
+	read_pos: usize,
+	writes:   Window,
+
 	close_rx: Option<Timeout<Receiver>>,
-	read_rx:  Option<Receiver>,
 	seek_rx:  Option<SeekState>,
-	write_rx: Option<(Receiver, usize)>,
 	flush_rx: Option<Timeout<Receiver>>,
 }
 
@@ -42,10 +47,14 @@ impl File {
 			closed: false,
 			cursor: 0,
 
+			reads:    Window::default(),
+			read_end: None,
+			read_buf: Vec::new(),
+			read_pos: 0,
+			writes:   Window::default(),
+
 			close_rx: None,
-			read_rx:  None,
 			seek_rx:  None,
-			write_rx: None,
 			flush_rx: None,
 		}
 	}
@@ -57,6 +66,36 @@ impl File {
 	pub async fn fsetstat(&self, attrs: &Attrs) -> Result<(), Error> {
 		Operator::from(&self.session).fsetstat(&self.handle, attrs).await
 	}
+
+	/// Copy the whole content of this file to `to` on the server side, without
+	/// transferring it through the client.
+	pub async fn copy_to(&self, to: &Self) -> Result<(), Error> {
+		Operator::from(&self.session).copy_data(&self.handle, &to.handle).await
+	}
+
+	/// Stop reading ahead past `end`, reads beyond it will hit EOF.
+	///
+	/// Useful when only a range of the file is needed, to avoid requesting data
+	/// that will be thrown away.
+	pub fn set_read_end(&mut self, end: Option<u64>) {
+		if end != self.read_end {
+			self.read_end = end;
+			self.discard_reads();
+		}
+	}
+
+	fn discard_reads(&mut self) {
+		self.reads.clear();
+		(self.read_buf, self.read_pos) = (Vec::new(), 0);
+	}
+
+	/// Wait for all outstanding WRITEs to be acknowledged.
+	fn poll_drain(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+		while !self.writes.is_empty() {
+			ready!(self.writes.poll_ack(cx))?;
+		}
+		Poll::Ready(Ok(()))
+	}
 }
 
 impl AsyncRead for File {
@@ -66,27 +105,31 @@ impl AsyncRead for File {
 		buf: &mut ReadBuf<'_>,
 	) -> Poll<io::Result<()>> {
 		let me = unsafe { self.get_unchecked_mut() };
+		ready!(me.poll_drain(cx))?;
 
-		if me.read_rx.is_none() {
-			let max = buf.remaining().min(261120) as u32;
-			me.read_rx = Some(Operator::from(&me.session).read(&me.handle, me.cursor, max)?);
-		}
+		loop {
+			if me.read_pos < me.read_buf.len() || buf.remaining() == 0 {
+				let len = buf.remaining().min(me.read_buf.len() - me.read_pos);
+				buf.put_slice(&me.read_buf[me.read_pos..me.read_pos + len]);
+				me.read_pos += len;
+				me.cursor += len as u64;
+				return Poll::Ready(Ok(()));
+			}
 
-		let result = ready!(Pin::new(me.read_rx.as_mut().unwrap()).poll(cx));
-		me.read_rx = None;
+			let mut offset = me.reads.pending.back().map_or(me.cursor, |r| r.offset + r.len as u64);
+			let end = me.read_end.unwrap_or(u64::MAX);
+			while !me.reads.is_full() && offset < end {
+				let len = (end - offset).min(CHUNK as u64) as usize;
+				let rx = Operator::from(&me.session).read(&me.handle, offset, len as u32)?;
+				me.reads.push(rx, offset, len);
+				offset += len as u64;
+			}
 
-		Poll::Ready(match result {
-			Ok(Packet::Data(data)) => {
-				let len = buf.remaining().min(data.data.len());
-				me.cursor += len as u64;
-				buf.put_slice(&data.data[..len]);
-				Ok(())
+			match ready!(me.reads.poll_data(cx))? {
+				Some(data) => (me.read_buf, me.read_pos) = (data, 0),
+				None => return Poll::Ready(Ok(())),
 			}
-			Ok(Packet::Status(status)) if status.is_eof() => Ok(()),
-			Ok(Packet::Status(status)) => Err(Error::Status(status).into()),
-			Ok(_) => Err(Error::Packet("not a Data or Status").into()),
-			Err(e) => Err(Error::from(e).into()),
-		})
+		}
 	}
 }
 
@@ -120,9 +163,12 @@ impl AsyncSeek for File {
 	fn poll_complete(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<u64>> {
 		let me = unsafe { self.get_unchecked_mut() };
 
-		let Some(state) = &mut me.seek_rx else {
+		if me.seek_rx.is_none() {
 			return Poll::Ready(Ok(me.cursor));
-		};
+		} else if let Err(e) = ready!(me.poll_drain(cx)) {
+			me.seek_rx = None;
+			return Poll::Ready(Err(e));
+		}
 
 		fn imp(cx: &mut Context<'_>, state: &mut SeekState) -> Poll<io::Result<u64>> {
 			use Poll::Ready;
@@ -152,9 +198,12 @@ impl AsyncSeek for File {
 			)
 		}
 
-		let result = ready!(imp(cx, state));
-		if let Ok(n) = result {
+		let result = ready!(imp(cx, me.seek_rx.as_mut().unwrap()));
+		if let Ok(n) = result
+			&& n != me.cursor
+		{
 			me.cursor = n;
+			me.discard_reads();
 		}
 
 		me.seek_rx = None;
@@ -169,32 +218,27 @@ impl AsyncWrite for File {
 		buf: &[u8],
 	) -> Poll<Result<usize, io::Error>> {
 		let me = unsafe { self.get_unchecked_mut() };
+		if buf.is_empty() {
+			return Poll::Ready(Ok(0));
+		}
 
-		let (rx, len) = match &mut me.write_rx {
-			Some((rx, len)) => (rx, *len),
-			None => {
-				let max = buf.len().min(261120);
-				let rx = Operator::from(&me.session).write(&me.handle, me.cursor, &buf[..max])?;
-				(&mut me.write_rx.get_or_insert((rx, max)).0, max)
-			}
-		};
+		me.discard_reads();
+		me.writes.poll_acked(cx)?;
+		while me.writes.is_full() {
+			ready!(me.writes.poll_ack(cx))?;
+		}
 
-		let result = ready!(Pin::new(rx).poll(cx));
-		me.write_rx = None;
+		let len = buf.len().min(CHUNK);
+		let rx = Operator::from(&me.session).write(&me.handle, me.cursor, &buf[..len])?;
+		me.writes.push(rx, me.cursor, len);
+		me.cursor += len as u64;
 
-		Poll::Ready(match result {
-			Ok(Packet::Status(status)) if status.is_ok() => {
-				me.cursor += len as u64;
-				Ok(len)
-			}
-			Ok(Packet::Status(status)) => Err(Error::Status(status).into()),
-			Ok(_) => Err(Error::Packet("not a Status").into()),
-			Err(e) => Err(Error::from(e).into()),
-		})
+		Poll::Ready(Ok(len))
 	}
 
 	fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), io::Error>> {
 		let me = unsafe { self.get_unchecked_mut() };
+		ready!(me.poll_drain(cx))?;
 
 		if me.flush_rx.is_none() {
 			match Operator::from(&me.session).fsync(&me.handle) {
@@ -222,6 +266,7 @@ impl AsyncWrite for File {
 
 	fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), io::Error>> {
 		let me = unsafe { self.get_unchecked_mut() };
+		ready!(me.poll_drain(cx))?;
 
 		if me.close_rx.is_none() {
 			me.close_rx =
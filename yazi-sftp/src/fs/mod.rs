@@ -3,6 +3,7 @@ mod dir_entry;
 mod file;
 mod flags;
 mod read_dir;
+mod window;
 
 pub use attrs::*;
 pub use dir_entry::*;
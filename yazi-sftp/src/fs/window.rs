@@ -0,0 +1,269 @@
+use std::{collections::VecDeque, io, pin::Pin, task::{Context, Poll, ready}};
+
+use tokio::sync::oneshot::error::RecvError;
+
+use crate::{Error, Packet, Receiver};
+
+pub(super) const CHUNK: usize = 261120;
+
+const MIN: usize = 1;
+const INIT: usize = 4;
+const MAX: usize = 16;
+
+/// Outstanding READ or WRITE requests of a file, in the order they were sent.
+///
+/// Responses may arrive in any order, but they're always taken in the order of
+/// the requests, so the data comes out in file order.
+///
+/// The window grows by one whenever the caller has to wait for the oldest
+/// request, i.e. the link is latency-bound, and halves on short reads or
+/// errors.
+pub(super) struct Window<R = Receiver> {
+	pub(super) size:    usize,
+	pub(super) pending: VecDeque<Inflight<R>>,
+}
+
+pub(super) struct Inflight<R> {
+	pub(super) rx:     R,
+	pub(super) offset: u64,
+	pub(super) len:    usize,
+	pub(super) waited: bool,
+}
+
+impl<R> Default for Window<R> {
+	fn default() -> Self { Self { size: INIT, pending: VecDeque::with_capacity(MAX) } }
+}
+
+impl<R> Window<R>
+where
+	R: Future<Output = Result<Packet<'static>, RecvError>> + Unpin,
+{
+	#[inline]
+	pub(super) fn is_full(&self) -> bool { self.pending.len() >= self.size }
+
+	#[inline]
+	pub(super) fn is_empty(&self) -> bool { self.pending.is_empty() }
+
+	pub(super) fn push(&mut self, rx: R, offset: u64, len: usize) {
+		self.pending.push_back(Inflight { rx, offset, len, waited: false });
+	}
+
+	pub(super) fn grow(&mut self) { self.size = (self.size + 1).min(MAX); }
+
+	pub(super) fn shrink(&mut self) { self.size = (self.size / 2).max(MIN); }
+
+	/// Drop all outstanding requests, their responses will be discarded.
+	pub(super) fn clear(&mut self) { self.pending.clear(); }
+
+	/// Wait for the oldest outstanding WRITE to be acknowledged.
+	pub(super) fn poll_ack(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+		let Some((result, _, waited)) = ready!(self.poll_front(cx, true)) else {
+			return Poll::Ready(Ok(()));
+		};
+
+		Poll::Ready(match result {
+			Ok(Packet::Status(status)) if status.is_ok() => {
+				if waited {
+					self.grow();
+				}
+				Ok(())
+			}
+			result => Err(self.fail(result, "not a Status")),
+		})
+	}
+
+	/// Take the WRITE acknowledgements that have already arrived, without waiting
+	/// for the rest, so a failed WRITE is reported by the next write instead of
+	/// only at flush or close.
+	pub(super) fn poll_acked(&mut self, cx: &mut Context<'_>) -> io::Result<()> {
+		while let Poll::Ready(Some((result, ..))) = self.poll_front(cx, false) {
+			match result {
+				Ok(Packet::Status(status)) if status.is_ok() => {}
+				result => return Err(self.fail(result, "not a Status")),
+			}
+		}
+		Ok(())
+	}
+
+	/// Wait for the oldest outstanding READ, returning its data, or `None` at
+	/// EOF, after which the rest of the window is dropped.
+	pub(super) fn poll_data(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<Option<Vec<u8>>>> {
+		let Some((result, len, waited)) = ready!(self.poll_front(cx, true)) else {
+			return Poll::Ready(Ok(None));
+		};
+
+		Poll::Ready(match result {
+			Ok(Packet::Data(data)) if data.data.is_empty() => {
+				self.clear();
+				Ok(None)
+			}
+			Ok(Packet::Data(data)) => {
+				if data.data.len() < len {
+					// The rest of the window no longer lines up with the data
+					self.clear();
+					self.shrink();
+				} else if waited {
+					self.grow();
+				}
+				Ok(Some(data.data))
+			}
+			Ok(Packet::Status(status)) if status.is_eof() => {
+				self.clear();
+				Ok(None)
+			}
+			result => Err(self.fail(result, "not a Data or Status")),
+		})
+	}
+
+	/// Poll the oldest request, taking it off the window once it's answered,
+	/// along with the length it asked for and whether it was waited for. None
+	/// is returned if there's no request left.
+	///
+	/// A pending request is marked as waited for if `wait` is set, which is what
+	/// grows the window.
+	fn poll_front(
+		&mut self,
+		cx: &mut Context<'_>,
+		wait: bool,
+	) -> Poll<Option<(io::Result<Packet<'static>>, usize, bool)>> {
+		let Some(front) = self.pending.front_mut() else {
+			return Poll::Ready(None);
+		};
+		let Poll::Ready(result) = Pin::new(&mut front.rx).poll(cx) else {
+			front.waited |= wait;
+			return Poll::Pending;
+		};
+
+		let Inflight { len, waited, .. } = self.pending.pop_front().unwrap();
+		Poll::Ready(Some((result.map_err(|e| Error::from(e).into()), len, waited)))
+	}
+
+	/// Drop the rest of the window after a failed request, turning its response
+	/// into an error.
+	fn fail(&mut self, result: io::Result<Packet<'static>>, expected: &'static str) -> io::Error {
+		self.clear();
+		self.shrink();
+		match result {
+			Ok(Packet::Status(status)) => Error::Status(status).into(),
+			Ok(_) => Error::Packet(expected).into(),
+			Err(e) => e,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::task::Waker;
+
+	use tokio::sync::oneshot;
+
+	use super::*;
+	use crate::responses::{Data, Status, StatusCode};
+
+	type Tx = oneshot::Sender<Packet<'static>>;
+
+	/// A window of `N` requests, and the senders of their responses.
+	fn inflight<const N: usize>() -> (Window<oneshot::Receiver<Packet<'static>>>, [Tx; N]) {
+		let mut window = Window::default();
+		let txs = std::array::from_fn(|i| {
+			let (tx, rx) = oneshot::channel();
+			window.push(rx, (i * CHUNK) as u64, CHUNK);
+			tx
+		});
+		(window, txs)
+	}
+
+	fn status(code: StatusCode) -> Packet<'static> {
+		Packet::Status(Status { id: 0, code, message: String::new(), language: String::new() })
+	}
+
+	fn data(byte: u8, len: usize) -> Packet<'static> {
+		Packet::Data(Data { id: 0, data: vec![byte; len] })
+	}
+
+	#[test]
+	fn test_acks_out_of_order() {
+		let mut cx = Context::from_waker(Waker::noop());
+		let (mut window, [a, b, c]) = inflight();
+
+		// Later acks are held until the oldest one arrives
+		c.send(status(StatusCode::Ok)).unwrap();
+		b.send(status(StatusCode::Ok)).unwrap();
+		assert!(window.poll_acked(&mut cx).is_ok());
+		assert!(window.poll_ack(&mut cx).is_pending());
+		assert_eq!(window.pending.len(), 3);
+
+		a.send(status(StatusCode::Ok)).unwrap();
+		assert!(matches!(window.poll_ack(&mut cx), Poll::Ready(Ok(()))));
+		assert_eq!(window.size, INIT + 1);
+
+		// The rest have already arrived, so they don't grow the window
+		assert!(window.poll_acked(&mut cx).is_ok());
+		assert!(window.is_empty());
+		assert_eq!(window.size, INIT + 1);
+	}
+
+	#[test]
+	fn test_ack_error() {
+		let mut cx = Context::from_waker(Waker::noop());
+		let (mut window, [a, b, _c]) = inflight();
+
+		a.send(status(StatusCode::Ok)).unwrap();
+		b.send(status(StatusCode::PermissionDenied)).unwrap();
+
+		// Reported without waiting for the last ack
+		let e = window.poll_acked(&mut cx).unwrap_err();
+		assert_eq!(e.kind(), io::ErrorKind::PermissionDenied);
+		assert!(window.is_empty());
+		assert_eq!(window.size, INIT / 2);
+
+		// So is a request dropped by a lost connection
+		let (mut window, [a]) = inflight();
+		drop(a);
+		assert!(matches!(window.poll_ack(&mut cx), Poll::Ready(Err(_))));
+	}
+
+	#[test]
+	fn test_data_in_order() {
+		let mut cx = Context::from_waker(Waker::noop());
+		let (mut window, [a, b, c]) = inflight();
+
+		c.send(data(2, CHUNK)).unwrap();
+		b.send(data(1, CHUNK)).unwrap();
+		assert!(window.poll_data(&mut cx).is_pending());
+
+		a.send(data(0, CHUNK)).unwrap();
+		let firsts: Vec<_> = (0..3)
+			.map(|_| match window.poll_data(&mut cx) {
+				Poll::Ready(Ok(Some(data))) => data[0],
+				_ => unreachable!(),
+			})
+			.collect();
+		assert_eq!(firsts, [0, 1, 2]);
+		assert!(matches!(window.poll_data(&mut cx), Poll::Ready(Ok(None))));
+	}
+
+	#[test]
+	fn test_data_short() {
+		let mut cx = Context::from_waker(Waker::noop());
+
+		// The rest of the window is dropped, as it no longer lines up
+		let (mut window, [a, _, _]) = inflight();
+		a.send(data(0, 100)).unwrap();
+		assert!(matches!(window.poll_data(&mut cx), Poll::Ready(Ok(Some(d))) if d.len() == 100));
+		assert!(window.is_empty());
+		assert_eq!(window.size, INIT / 2);
+
+		let (mut window, [a, _]) = inflight();
+		a.send(status(StatusCode::Eof)).unwrap();
+		assert!(matches!(window.poll_data(&mut cx), Poll::Ready(Ok(None))));
+		assert!(window.is_empty());
+
+		let (mut window, [a, _]) = inflight();
+		a.send(status(StatusCode::NoSuchFile)).unwrap();
+		assert!(matches!(
+			window.poll_data(&mut cx),
+			Poll::Ready(Err(e)) if e.kind() == io::ErrorKind::NotFound
+		));
+	}
+}
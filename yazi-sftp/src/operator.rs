@@ -1,4 +1,4 @@
-use std::{ops::Deref, sync::Arc};
+use std::{ops::Deref, sync::Arc, time::Duration};
 
 use russh::{ChannelStream, client::Msg};
 use typed_path::UnixPathBuf;
@@ -26,6 +26,9 @@ impl Operator {
 		Ok(())
 	}
 
+	/// Whether the server advertised the given extension.
+	pub fn supports(&self, ext: &str) -> bool { self.extensions.lock().contains_key(ext) }
+
 	pub async fn open<'a, P>(&self, path: P, flags: Flags, attrs: &'a Attrs) -> Result<File, Error>
 	where
 		P: AsSftpPath<'a>,
@@ -205,4 +208,34 @@ impl Operator {
 			self.send(requests::Extended::new("limits@openssh.com", requests::ExtendedLimits)).await?;
 		extended.try_into()
 	}
+
+	pub async fn check_file<'a, P>(&self, path: P, algos: &'a str) -> Result<(String, Vec<u8>), Error>
+	where
+		P: AsSftpPath<'a>,
+	{
+		if !self.extensions.lock().contains_key("check-file") {
+			return Err(Error::Unsupported);
+		}
+
+		let data = requests::ExtendedCheckFile::new(path, algos);
+		let extended: responses::Extended =
+			self.send(requests::Extended::new("check-file-name", data)).await?;
+
+		let reply: responses::ExtendedCheckFile = extended.try_into()?;
+		Ok((reply.algo, reply.hash.to_vec()))
+	}
+
+	/// Copy the whole content of `from` to `to` on the server side, both handles
+	/// must be opened in this session.
+	pub async fn copy_data(&self, from: &str, to: &str) -> Result<(), Error> {
+		if !self.extensions.lock().contains_key("copy-data") {
+			return Err(Error::Unsupported);
+		}
+
+		let data = requests::ExtendedCopyData::new(from, to);
+		// The copy can take arbitrarily long, depending on the file size
+		let status: responses::Status =
+			self.send_with_timeout(requests::Extended::new("copy-data", data), Duration::MAX).await?;
+		status.into()
+	}
 }
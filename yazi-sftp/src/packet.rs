@@ -29,6 +29,8 @@ pub enum Packet<'a> {
 	ExtendedFsync(requests::Extended<'a, requests::ExtendedFsync<'a>>),
 	ExtendedHardlink(requests::Extended<'a, requests::ExtendedHardlink<'a>>),
 	ExtendedLimits(requests::Extended<'a, requests::ExtendedLimits>),
+	ExtendedCheckFile(requests::Extended<'a, requests::ExtendedCheckFile<'a>>),
+	ExtendedCopyData(requests::Extended<'a, requests::ExtendedCopyData<'a>>),
 
 	// Responses
 	Version(responses::Version),
@@ -64,6 +66,8 @@ impl_from_packet! {
 	ExtendedFsync(requests::Extended<'a, requests::ExtendedFsync<'a>>),
 	ExtendedHardlink(requests::Extended<'a, requests::ExtendedHardlink<'a>>),
 	ExtendedLimits(requests::Extended<'a, requests::ExtendedLimits>),
+	ExtendedCheckFile(requests::Extended<'a, requests::ExtendedCheckFile<'a>>),
+	ExtendedCopyData(requests::Extended<'a, requests::ExtendedCopyData<'a>>),
 
 	// Responses
 	Version(responses::Version),
@@ -111,6 +115,8 @@ impl Packet<'_> {
 			Self::ExtendedFsync(_) => 200,
 			Self::ExtendedHardlink(_) => 200,
 			Self::ExtendedLimits(_) => 200,
+			Self::ExtendedCheckFile(_) => 200,
+			Self::ExtendedCopyData(_) => 200,
 
 			// Responses
 			Self::Version(_) => 2,
@@ -148,6 +154,8 @@ impl Packet<'_> {
 			Self::ExtendedFsync(v) => v.id,
 			Self::ExtendedHardlink(v) => v.id,
 			Self::ExtendedLimits(v) => v.id,
+			Self::ExtendedCheckFile(v) => v.id,
+			Self::ExtendedCopyData(v) => v.id,
 
 			// Responses
 			Self::Version(_) => 0,
@@ -185,6 +193,8 @@ impl Packet<'_> {
 			Self::ExtendedFsync(v) => v.id = id,
 			Self::ExtendedHardlink(v) => v.id = id,
 			Self::ExtendedLimits(v) => v.id = id,
+			Self::ExtendedCheckFile(v) => v.id = id,
+			Self::ExtendedCopyData(v) => v.id = id,
 
 			// Responses
 			Self::Version(_) => {}
@@ -224,6 +234,8 @@ impl Packet<'_> {
 			Self::ExtendedFsync(v) => type_len + v.len(),
 			Self::ExtendedHardlink(v) => type_len + v.len(),
 			Self::ExtendedLimits(v) => type_len + v.len(),
+			Self::ExtendedCheckFile(v) => type_len + v.len(),
+			Self::ExtendedCopyData(v) => type_len + v.len(),
 
 			// Responses
 			Self::Version(v) => type_len + v.len(),
@@ -90,3 +90,52 @@ pub struct ExtendedLimits;
 impl ExtendedData for ExtendedLimits {
 	fn len(&self) -> usize { 0 }
 }
+
+// --- Check file
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ExtendedCheckFile<'a> {
+	pub path:   SftpPath<'a>,
+	pub algos:  Cow<'a, str>,
+	pub offset: u64,
+	pub length: u64,
+	pub block:  u32,
+}
+
+impl<'a> ExtendedCheckFile<'a> {
+	pub fn new<P>(path: P, algos: impl Into<Cow<'a, str>>) -> Self
+	where
+		P: AsSftpPath<'a>,
+	{
+		Self { path: path.as_sftp_path(), algos: algos.into(), offset: 0, length: 0, block: 0 }
+	}
+}
+
+impl ExtendedData for ExtendedCheckFile<'_> {
+	fn len(&self) -> usize { 4 + self.path.len() + 4 + self.algos.len() + 8 + 8 + 4 }
+}
+
+// --- Copy data
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ExtendedCopyData<'a> {
+	pub read_from:    Cow<'a, str>,
+	pub read_offset:  u64,
+	pub read_length:  u64,
+	pub write_to:     Cow<'a, str>,
+	pub write_offset: u64,
+}
+
+impl<'a> ExtendedCopyData<'a> {
+	pub fn new(read_from: impl Into<Cow<'a, str>>, write_to: impl Into<Cow<'a, str>>) -> Self {
+		Self {
+			read_from:    read_from.into(),
+			read_offset:  0,
+			read_length:  0,
+			write_to:     write_to.into(),
+			write_offset: 0,
+		}
+	}
+}
+
+impl ExtendedData for ExtendedCopyData<'_> {
+	fn len(&self) -> usize { 4 + self.read_from.len() + 8 + 8 + 4 + self.write_to.len() + 8 }
+}
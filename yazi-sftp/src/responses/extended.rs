@@ -83,3 +83,19 @@ impl TryFrom<Extended<'_>> for ExtendedLimits {
 		crate::Deserializer::once(&value.data)
 	}
 }
+
+// --- Check file
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ExtendedCheckFile {
+	pub name: String,
+	pub algo: String,
+	pub hash: ExtendedData<'static>,
+}
+
+impl TryFrom<Extended<'_>> for ExtendedCheckFile {
+	type Error = Error;
+
+	fn try_from(value: Extended<'_>) -> Result<Self, Self::Error> {
+		crate::Deserializer::once(&value.data)
+	}
+}
@@ -14,6 +14,9 @@ pub enum AuthKind {
 	Hub,
 	Scope,
 	Sftp,
+	Webdav,
+	S3,
+	Ftp,
 }
 
 impl AuthKind {
@@ -21,7 +24,9 @@ impl AuthKind {
 	pub fn is_local(self) -> bool {
 		match self {
 			Self::Regular | Self::Search => true,
-			Self::Mount | Self::Hub | Self::Scope | Self::Sftp => false,
+			Self::Mount | Self::Hub | Self::Scope | Self::Sftp | Self::Webdav | Self::S3 | Self::Ftp => {
+				false
+			}
 		}
 	}
 
@@ -29,7 +34,7 @@ impl AuthKind {
 	pub fn is_remote(self) -> bool {
 		match self {
 			Self::Regular | Self::Search | Self::Mount | Self::Hub | Self::Scope => false,
-			Self::Sftp => true,
+			Self::Sftp | Self::Webdav | Self::S3 | Self::Ftp => true,
 		}
 	}
 
@@ -37,7 +42,9 @@ impl AuthKind {
 	pub fn is_virtual(self) -> bool {
 		match self {
 			Self::Regular | Self::Search => false,
-			Self::Mount | Self::Hub | Self::Scope | Self::Sftp => true,
+			Self::Mount | Self::Hub | Self::Scope | Self::Sftp | Self::Webdav | Self::S3 | Self::Ftp => {
+				true
+			}
 		}
 	}
 }
@@ -10,6 +10,9 @@ pub enum Scheme {
 	Regular,
 	Search,
 	Sftp,
+	Webdav,
+	S3,
+	Ftp,
 	Custom(KebabCasedKey),
 }
 
@@ -49,6 +52,9 @@ impl FromStr for Scheme {
 			"regular" => Self::Regular,
 			"search" => Self::Search,
 			"sftp" => Self::Sftp,
+			"webdav" => Self::Webdav,
+			"s3" => Self::S3,
+			"ftp" => Self::Ftp,
 			_ if let Some(s) = KebabCasedKey::new(s) => Self::Custom(s),
 			_ => bail!("scheme must be 1-20 characters in kebab-case, got: {s}"),
 		})
@@ -61,6 +67,9 @@ impl Scheme {
 			Self::Regular => "regular",
 			Self::Search => "search",
 			Self::Sftp => "sftp",
+			Self::Webdav => "webdav",
+			Self::S3 => "s3",
+			Self::Ftp => "ftp",
 			Self::Custom(s) => s,
 		}
 	}
@@ -34,6 +34,8 @@ pub enum Layer {
 	Cmp,
 	Which,
 	Notify,
+	Palette,
+	Pane,
 }
 
 impl Layer {
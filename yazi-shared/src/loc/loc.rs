@@ -157,6 +157,9 @@ where
 			AuthKind::Hub => Self::bare(path),
 			AuthKind::Scope => Self::bare(path),
 			AuthKind::Sftp => Self::bare(path),
+			AuthKind::Webdav => Self::bare(path),
+			AuthKind::S3 => Self::bare(path),
+			AuthKind::Ftp => Self::bare(path),
 		}
 	}
 
@@ -18,6 +18,9 @@ impl From<AuthKind> for PathKind {
 			AuthKind::Hub => Self::Os,
 			AuthKind::Scope => Self::Unix,
 			AuthKind::Sftp => Self::Unix,
+			AuthKind::Webdav => Self::Unix,
+			AuthKind::S3 => Self::Unix,
+			AuthKind::Ftp => Self::Unix,
 		}
 	}
 }
@@ -11,7 +11,7 @@ use std::{borrow::Cow, ffi::OsStr};
 
 yazi_macro::mod_pub!(unix, windows);
 
-yazi_macro::mod_flat!(error);
+yazi_macro::mod_flat!(error word);
 
 #[inline]
 pub fn escape_os_bytes(b: &[u8]) -> Cow<'_, [u8]> {
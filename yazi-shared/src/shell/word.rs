@@ -0,0 +1,107 @@
+use std::borrow::Cow;
+
+/// The last word of a partially typed command line, the one being completed.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct LastWord {
+	/// Byte offset of the word in the line.
+	pub start:   usize,
+	/// The word with its quotes and escapes removed.
+	pub value:   String,
+	/// The quote the word is still open with.
+	pub quote:   Option<char>,
+	/// Whether the word is a command name, rather than an argument.
+	pub command: bool,
+}
+
+impl LastWord {
+	pub fn parse(s: &str) -> Self {
+		let escapable = cfg!(unix);
+		let (mut word, mut words) = (Self::default(), 0);
+		let (mut in_word, mut escaped) = (false, false);
+
+		for (i, c) in s.char_indices() {
+			if escaped {
+				escaped = false;
+				word.value.push(c);
+				continue;
+			}
+
+			match (word.quote, c) {
+				(Some(q), _) if c == q => word.quote = None,
+				(Some('"'), '\\') if escapable => escaped = true,
+				(Some(_), _) => word.value.push(c),
+				(None, _) if c.is_whitespace() => {
+					words += in_word as usize;
+					in_word = false;
+				}
+				(None, '|' | ';' | '&' | '(') => (in_word, words) = (false, 0),
+				(None, _) => {
+					if !in_word {
+						(in_word, word.start) = (true, i);
+						word.value.clear();
+					}
+					match c {
+						'\'' | '"' => word.quote = Some(c),
+						'\\' if escapable => escaped = true,
+						_ => word.value.push(c),
+					}
+				}
+			}
+		}
+
+		if !in_word {
+			word.start = s.len();
+			word.value.clear();
+		}
+		word.command = words == 0;
+		word
+	}
+
+	/// Escape a completed name to be inserted into this word. Placeholders such
+	/// as `%s` are expanded before the shell sees them, so they are kept as is.
+	pub fn escape<'a>(&self, name: &'a str) -> Cow<'a, str> {
+		if self.quote.is_some() || self.value.starts_with('%') {
+			return name.into();
+		}
+
+		match super::escape_os_str(name.as_ref()) {
+			Cow::Borrowed(_) => name.into(),
+			Cow::Owned(s) => s.to_string_lossy().into_owned().into(),
+		}
+	}
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+	use super::LastWord;
+
+	fn parse(s: &str) -> (usize, String, Option<char>, bool) {
+		let w = LastWord::parse(s);
+		(w.start, w.value, w.quote, w.command)
+	}
+
+	#[test]
+	fn test_parse() {
+		assert_eq!(parse(""), (0, "".into(), None, true));
+		assert_eq!(parse("ls"), (0, "ls".into(), None, true));
+		assert_eq!(parse("ls "), (3, "".into(), None, false));
+		assert_eq!(parse("ls -l ~/D"), (6, "~/D".into(), None, false));
+
+		assert_eq!(parse("cat 'My Docs'/a"), (4, "My Docs/a".into(), None, false));
+		assert_eq!(parse(r"cat My\ Docs/a"), (4, "My Docs/a".into(), None, false));
+		assert_eq!(parse(r#"cat "My \"Docs"#), (4, r#"My "Docs"#.into(), Some('"'), false));
+
+		assert_eq!(parse("ls | gr"), (5, "gr".into(), None, true));
+		assert_eq!(parse("make&&ec"), (6, "ec".into(), None, true));
+		assert_eq!(parse("echo %"), (5, "%".into(), None, false));
+	}
+
+	#[test]
+	fn test_escape() {
+		let w = LastWord::parse("cat ");
+		assert_eq!(w.escape("a b"), "'a b'");
+		assert_eq!(w.escape("ab"), "ab");
+		assert_eq!(LastWord::parse("cat 'a").escape("a b"), "a b");
+		assert_eq!(LastWord::parse("cat %").escape("%s"), "%s");
+	}
+}
@@ -30,7 +30,12 @@ impl<'a> EncodeSpec<'a> {
 				match self.0.0.kind() {
 					AuthKind::Regular => Ok(()),
 					AuthKind::Search | AuthKind::Mount => w!(0, 0),
-					AuthKind::Hub | AuthKind::Scope | AuthKind::Sftp => {
+					AuthKind::Hub
+					| AuthKind::Scope
+					| AuthKind::Sftp
+					| AuthKind::Webdav
+					| AuthKind::S3
+					| AuthKind::Ftp => {
 						w!(self.0.0.loc().name().is_some() as usize, self.0.0.loc().name().is_some() as usize)
 					}
 				}
@@ -49,7 +54,10 @@ impl Display for EncodeSpec<'_> {
 			| Url::Mount { auth, .. }
 			| Url::Hub { auth, .. }
 			| Url::Scope { auth, .. }
-			| Url::Sftp { auth, .. } => {
+			| Url::Sftp { auth, .. }
+			| Url::Webdav { auth, .. }
+			| Url::S3 { auth, .. }
+			| Url::Ftp { auth, .. } => {
 				write!(f, "{}{}{}", EncodeAuth(auth, false), self.ports(), EncodePrefix(auth))
 			}
 		}
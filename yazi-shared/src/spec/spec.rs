@@ -144,7 +144,12 @@ impl Spec {
 				(uri, urn)
 			}
 			AuthKind::Mount => (uri.unwrap_or(0), urn.unwrap_or(0)),
-			AuthKind::Hub | AuthKind::Scope | AuthKind::Sftp => {
+			AuthKind::Hub
+			| AuthKind::Scope
+			| AuthKind::Sftp
+			| AuthKind::Webdav
+			| AuthKind::S3
+			| AuthKind::Ftp => {
 				let uri = uri.unwrap_or(path.name().is_some() as usize);
 				let urn = urn.unwrap_or(path.name().is_some() as usize);
 				(uri, urn)
@@ -158,9 +163,11 @@ impl Spec {
 			Url::Search { loc, .. } | Url::Mount { loc, .. } | Url::Hub { loc, .. } => {
 				(loc.uri().components().count(), loc.urn().components().count())
 			}
-			Url::Scope { loc, .. } | Url::Sftp { loc, .. } => {
-				(loc.uri().components().count(), loc.urn().components().count())
-			}
+			Url::Scope { loc, .. }
+			| Url::Sftp { loc, .. }
+			| Url::Webdav { loc, .. }
+			| Url::S3 { loc, .. }
+			| Url::Ftp { loc, .. } => (loc.uri().components().count(), loc.urn().components().count()),
 		}
 	}
 }
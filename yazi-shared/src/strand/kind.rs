@@ -25,6 +25,9 @@ impl From<AuthKind> for StrandKind {
 			AuthKind::Hub => Self::Os,
 			AuthKind::Scope => Self::Bytes,
 			AuthKind::Sftp => Self::Bytes,
+			AuthKind::Webdav => Self::Bytes,
+			AuthKind::S3 => Self::Bytes,
+			AuthKind::Ftp => Self::Bytes,
 		}
 	}
 }
@@ -15,6 +15,9 @@ inventory::submit! {
 			("test-hub", _) => Some(Auth::new(AuthKind::Hub, scheme.clone(), domain.clone())),
 			("test-scope", b"aws") => Some(Auth::new(AuthKind::Scope, scheme.clone(), "aws")),
 			("sftp", b"vps") => Some(Auth::new(AuthKind::Sftp, scheme.clone(), "vps")),
+			("webdav", b"nas") => Some(Auth::new(AuthKind::Webdav, scheme.clone(), "nas")),
+			("s3", b"minio") => Some(Auth::new(AuthKind::S3, scheme.clone(), "minio")),
+			("ftp", b"router") => Some(Auth::new(AuthKind::Ftp, scheme.clone(), "router")),
 			_ => None,
 		},
 	}
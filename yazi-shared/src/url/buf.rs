@@ -15,6 +15,9 @@ pub enum UrlBuf {
 	Hub { loc: LocBuf, auth: Arc<Auth> },
 	Scope { loc: LocBuf<typed_path::UnixPathBuf>, auth: Arc<Auth> },
 	Sftp { loc: LocBuf<typed_path::UnixPathBuf>, auth: Arc<Auth> },
+	Webdav { loc: LocBuf<typed_path::UnixPathBuf>, auth: Arc<Auth> },
+	S3 { loc: LocBuf<typed_path::UnixPathBuf>, auth: Arc<Auth> },
+	Ftp { loc: LocBuf<typed_path::UnixPathBuf>, auth: Arc<Auth> },
 }
 
 impl_data_any!(UrlBuf);
@@ -37,6 +40,9 @@ impl From<Url<'_>> for UrlBuf {
 			Url::Hub { loc, auth } => Self::Hub { loc: loc.into(), auth: auth.clone() },
 			Url::Scope { loc, auth } => Self::Scope { loc: loc.into(), auth: auth.clone() },
 			Url::Sftp { loc, auth } => Self::Sftp { loc: loc.into(), auth: auth.clone() },
+			Url::Webdav { loc, auth } => Self::Webdav { loc: loc.into(), auth: auth.clone() },
+			Url::S3 { loc, auth } => Self::S3 { loc: loc.into(), auth: auth.clone() },
+			Url::Ftp { loc, auth } => Self::Ftp { loc: loc.into(), auth: auth.clone() },
 		}
 	}
 }
@@ -145,6 +151,9 @@ impl UrlBuf {
 			Self::Hub { loc, .. } => loc.into_inner().into(),
 			Self::Scope { loc, .. } => loc.into_inner().into(),
 			Self::Sftp { loc, .. } => loc.into_inner().into(),
+			Self::Webdav { loc, .. } => loc.into_inner().into(),
+			Self::S3 { loc, .. } => loc.into_inner().into(),
+			Self::Ftp { loc, .. } => loc.into_inner().into(),
 		}
 	}
 
@@ -162,6 +171,9 @@ impl UrlBuf {
 			Self::Hub { loc, .. } => loc.try_set_name(name.as_os()?)?,
 			Self::Scope { loc, .. } => loc.try_set_name(name.encoded_bytes())?,
 			Self::Sftp { loc, .. } => loc.try_set_name(name.encoded_bytes())?,
+			Self::Webdav { loc, .. } => loc.try_set_name(name.encoded_bytes())?,
+			Self::S3 { loc, .. } => loc.try_set_name(name.encoded_bytes())?,
+			Self::Ftp { loc, .. } => loc.try_set_name(name.encoded_bytes())?,
 		})
 	}
 
@@ -173,6 +185,9 @@ impl UrlBuf {
 			Self::Hub { .. } => todo!(),
 			Self::Scope { .. } => todo!(),
 			Self::Sftp { .. } => todo!(),
+			Self::Webdav { .. } => todo!(),
+			Self::S3 { .. } => todo!(),
+			Self::Ftp { .. } => todo!(),
 		}
 	}
 }
@@ -201,7 +216,10 @@ impl UrlBuf {
 			| Self::Mount { auth, .. }
 			| Self::Hub { auth, .. }
 			| Self::Scope { auth, .. }
-			| Self::Sftp { auth, .. } => Arc::make_mut(auth).domain = domain.into().into_owned(),
+			| Self::Sftp { auth, .. }
+			| Self::Webdav { auth, .. }
+			| Self::S3 { auth, .. }
+			| Self::Ftp { auth, .. } => Arc::make_mut(auth).domain = domain.into().into_owned(),
 		}
 		self
 	}
@@ -307,6 +325,12 @@ mod tests {
 			// SFTP
 			("sftp://vps//a", "b/c", "sftp://vps//a/b/c"),
 			("sftp://vps:1:1//a/b/c", "d/e", "sftp://vps//a/b/c/d/e"),
+			// WebDAV
+			("webdav://nas//a", "b/c", "webdav://nas//a/b/c"),
+			// S3
+			("s3://minio//a", "b/c", "s3://minio//a/b/c"),
+			// FTP
+			("ftp://router//a", "b/c", "ftp://router//a/b/c"),
 			// Relative
 			("search://kw", "b/c", "search://kw:2:2/b/c"),
 			("search://kw/", "b/c", "search://kw:2:2/b/c"),
@@ -344,6 +368,15 @@ mod tests {
 			("sftp://vps//a", Some("sftp://vps//")),
 			("sftp://vps:1//", None),
 			("sftp://vps//", None),
+			// WebDAV
+			("webdav://nas:1:1//a", Some("webdav://nas//")),
+			("webdav://nas//", None),
+			// S3
+			("s3://minio:1:1//a", Some("s3://minio//")),
+			("s3://minio//", None),
+			// FTP
+			("ftp://router:1:1//a", Some("ftp://router//")),
+			("ftp://router//", None),
 			// Relative
 			("search://kw:2:2/a/b", Some("search://kw:1:1/a")),
 			("search://kw:1:1/a", Some("search://kw/")),
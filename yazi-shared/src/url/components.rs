@@ -95,6 +95,15 @@ impl<'a> Components<'a> {
 			Url::Sftp { auth, .. } => {
 				Url::Sftp { loc: Loc::with(path.as_unix().unwrap(), uri, urn).unwrap(), auth }
 			}
+			Url::Webdav { auth, .. } => {
+				Url::Webdav { loc: Loc::with(path.as_unix().unwrap(), uri, urn).unwrap(), auth }
+			}
+			Url::S3 { auth, .. } => {
+				Url::S3 { loc: Loc::with(path.as_unix().unwrap(), uri, urn).unwrap(), auth }
+			}
+			Url::Ftp { auth, .. } => {
+				Url::Ftp { loc: Loc::with(path.as_unix().unwrap(), uri, urn).unwrap(), auth }
+			}
 		}
 	}
 }
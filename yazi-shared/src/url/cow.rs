@@ -14,6 +14,9 @@ pub enum UrlCow<'a> {
 	Hub { loc: LocCow<'a>, auth: Arc<Auth> },
 	Scope { loc: LocCow<'a, &'a UnixPath, UnixPathBuf>, auth: Arc<Auth> },
 	Sftp { loc: LocCow<'a, &'a UnixPath, UnixPathBuf>, auth: Arc<Auth> },
+	Webdav { loc: LocCow<'a, &'a UnixPath, UnixPathBuf>, auth: Arc<Auth> },
+	S3 { loc: LocCow<'a, &'a UnixPath, UnixPathBuf>, auth: Arc<Auth> },
+	Ftp { loc: LocCow<'a, &'a UnixPath, UnixPathBuf>, auth: Arc<Auth> },
 }
 
 impl<'a> From<Url<'a>> for UrlCow<'a> {
@@ -25,6 +28,9 @@ impl<'a> From<Url<'a>> for UrlCow<'a> {
 			Url::Hub { loc, auth } => Self::Hub { loc: loc.into(), auth: auth.clone() },
 			Url::Scope { loc, auth } => Self::Scope { loc: loc.into(), auth: auth.clone() },
 			Url::Sftp { loc, auth } => Self::Sftp { loc: loc.into(), auth: auth.clone() },
+			Url::Webdav { loc, auth } => Self::Webdav { loc: loc.into(), auth: auth.clone() },
+			Url::S3 { loc, auth } => Self::S3 { loc: loc.into(), auth: auth.clone() },
+			Url::Ftp { loc, auth } => Self::Ftp { loc: loc.into(), auth: auth.clone() },
 		}
 	}
 }
@@ -45,6 +51,9 @@ impl From<UrlBuf> for UrlCow<'_> {
 			UrlBuf::Hub { loc, auth } => Self::Hub { loc: loc.into(), auth },
 			UrlBuf::Scope { loc, auth } => Self::Scope { loc: loc.into(), auth },
 			UrlBuf::Sftp { loc, auth } => Self::Sftp { loc: loc.into(), auth },
+			UrlBuf::Webdav { loc, auth } => Self::Webdav { loc: loc.into(), auth },
+			UrlBuf::S3 { loc, auth } => Self::S3 { loc: loc.into(), auth },
+			UrlBuf::Ftp { loc, auth } => Self::Ftp { loc: loc.into(), auth },
 		}
 	}
 }
@@ -125,6 +134,9 @@ impl<'a> TryFrom<(Spec, PathDyn<'a>)> for UrlCow<'a> {
 			AuthKind::Hub => Self::Hub { loc: Loc::with(path.as_os()?, uri, urn)?.into(), auth },
 			AuthKind::Scope => Self::Scope { loc: Loc::with(path.as_unix()?, uri, urn)?.into(), auth },
 			AuthKind::Sftp => Self::Sftp { loc: Loc::with(path.as_unix()?, uri, urn)?.into(), auth },
+			AuthKind::Webdav => Self::Webdav { loc: Loc::with(path.as_unix()?, uri, urn)?.into(), auth },
+			AuthKind::S3 => Self::S3 { loc: Loc::with(path.as_unix()?, uri, urn)?.into(), auth },
+			AuthKind::Ftp => Self::Ftp { loc: Loc::with(path.as_unix()?, uri, urn)?.into(), auth },
 		})
 	}
 }
@@ -157,6 +169,15 @@ impl<'a> TryFrom<(Spec, PathBufDyn)> for UrlCow<'a> {
 			AuthKind::Sftp => {
 				Self::Sftp { loc: LocBuf::<UnixPathBuf>::with(path.try_into()?, uri, urn)?.into(), auth }
 			}
+			AuthKind::Webdav => {
+				Self::Webdav { loc: LocBuf::<UnixPathBuf>::with(path.try_into()?, uri, urn)?.into(), auth }
+			}
+			AuthKind::S3 => {
+				Self::S3 { loc: LocBuf::<UnixPathBuf>::with(path.try_into()?, uri, urn)?.into(), auth }
+			}
+			AuthKind::Ftp => {
+				Self::Ftp { loc: LocBuf::<UnixPathBuf>::with(path.try_into()?, uri, urn)?.into(), auth }
+			}
 		})
 	}
 }
@@ -186,6 +207,9 @@ impl<'a> UrlCow<'a> {
 			Self::Hub { loc, .. } => loc.is_owned(),
 			Self::Scope { loc, .. } => loc.is_owned(),
 			Self::Sftp { loc, .. } => loc.is_owned(),
+			Self::Webdav { loc, .. } => loc.is_owned(),
+			Self::S3 { loc, .. } => loc.is_owned(),
+			Self::Ftp { loc, .. } => loc.is_owned(),
 		}
 	}
 
@@ -197,6 +221,9 @@ impl<'a> UrlCow<'a> {
 			Self::Hub { loc, auth } => UrlBuf::Hub { loc: loc.into_owned(), auth },
 			Self::Scope { loc, auth } => UrlBuf::Scope { loc: loc.into_owned(), auth },
 			Self::Sftp { loc, auth } => UrlBuf::Sftp { loc: loc.into_owned(), auth },
+			Self::Webdav { loc, auth } => UrlBuf::Webdav { loc: loc.into_owned(), auth },
+			Self::S3 { loc, auth } => UrlBuf::S3 { loc: loc.into_owned(), auth },
+			Self::Ftp { loc, auth } => UrlBuf::Ftp { loc: loc.into_owned(), auth },
 		}
 	}
 
@@ -207,7 +234,11 @@ impl<'a> UrlCow<'a> {
 			Self::Search { loc, auth } | Self::Mount { loc, auth } | Self::Hub { loc, auth } => {
 				(auth, loc.into_path())
 			}
-			Self::Scope { loc, auth } | Self::Sftp { loc, auth } => (auth, loc.into_path()),
+			Self::Scope { loc, auth }
+			| Self::Sftp { loc, auth }
+			| Self::Webdav { loc, auth }
+			| Self::S3 { loc, auth }
+			| Self::Ftp { loc, auth } => (auth, loc.into_path()),
 		};
 		(Spec { auth, uri, urn }, path)
 	}
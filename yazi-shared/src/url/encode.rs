@@ -23,7 +23,10 @@ impl Display for Encode<'_> {
 			| Url::Mount { auth, .. }
 			| Url::Hub { auth, .. }
 			| Url::Scope { auth, .. }
-			| Url::Sftp { auth, .. } => {
+			| Url::Sftp { auth, .. }
+			| Url::Webdav { auth, .. }
+			| Url::S3 { auth, .. }
+			| Url::Ftp { auth, .. } => {
 				write!(
 					f,
 					"{}{}{}{loc}",
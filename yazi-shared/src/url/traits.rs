@@ -45,6 +45,9 @@ impl AsUrl for UrlBuf {
 			Self::Hub { loc, auth } => Url::Hub { loc: loc.as_loc(), auth },
 			Self::Scope { loc, auth } => Url::Scope { loc: loc.as_loc(), auth },
 			Self::Sftp { loc, auth } => Url::Sftp { loc: loc.as_loc(), auth },
+			Self::Webdav { loc, auth } => Url::Webdav { loc: loc.as_loc(), auth },
+			Self::S3 { loc, auth } => Url::S3 { loc: loc.as_loc(), auth },
+			Self::Ftp { loc, auth } => Url::Ftp { loc: loc.as_loc(), auth },
 		}
 	}
 }
@@ -68,6 +71,9 @@ impl AsUrl for UrlCow<'_> {
 			Self::Hub { loc, auth } => Url::Hub { loc: loc.as_loc(), auth },
 			Self::Scope { loc, auth } => Url::Scope { loc: loc.as_loc(), auth },
 			Self::Sftp { loc, auth } => Url::Sftp { loc: loc.as_loc(), auth },
+			Self::Webdav { loc, auth } => Url::Webdav { loc: loc.as_loc(), auth },
+			Self::S3 { loc, auth } => Url::S3 { loc: loc.as_loc(), auth },
+			Self::Ftp { loc, auth } => Url::Ftp { loc: loc.as_loc(), auth },
 		}
 	}
 }
@@ -15,6 +15,9 @@ pub enum Url<'a> {
 	Hub { loc: Loc<'a>, auth: &'a Arc<Auth> },
 	Scope { loc: Loc<'a, &'a typed_path::UnixPath>, auth: &'a Arc<Auth> },
 	Sftp { loc: Loc<'a, &'a typed_path::UnixPath>, auth: &'a Arc<Auth> },
+	Webdav { loc: Loc<'a, &'a typed_path::UnixPath>, auth: &'a Arc<Auth> },
+	S3 { loc: Loc<'a, &'a typed_path::UnixPath>, auth: &'a Arc<Auth> },
+	Ftp { loc: Loc<'a, &'a typed_path::UnixPath>, auth: &'a Arc<Auth> },
 }
 
 // --- Eq
@@ -63,7 +66,10 @@ impl<'a> Url<'a> {
 			| Self::Mount { auth, .. }
 			| Self::Hub { auth, .. }
 			| Self::Scope { auth, .. }
-			| Self::Sftp { auth, .. } => auth,
+			| Self::Sftp { auth, .. }
+			| Self::Webdav { auth, .. }
+			| Self::S3 { auth, .. }
+			| Self::Ftp { auth, .. } => auth,
 		}
 	}
 
@@ -93,6 +99,9 @@ impl<'a> Url<'a> {
 			},
 			Self::Scope { loc, auth } => Self::Scope { loc: Loc::bare(loc.base()), auth },
 			Self::Sftp { loc, auth } => Self::Sftp { loc: Loc::bare(loc.base()), auth },
+			Self::Webdav { loc, auth } => Self::Webdav { loc: Loc::bare(loc.base()), auth },
+			Self::S3 { loc, auth } => Self::S3 { loc: Loc::bare(loc.base()), auth },
+			Self::Ftp { loc, auth } => Self::Ftp { loc: Loc::bare(loc.base()), auth },
 		}
 	}
 
@@ -114,6 +123,9 @@ impl<'a> Url<'a> {
 			Self::Hub { loc, .. } => loc.extension()?.as_strand(),
 			Self::Scope { loc, .. } => loc.extension()?.as_strand(),
 			Self::Sftp { loc, .. } => loc.extension()?.as_strand(),
+			Self::Webdav { loc, .. } => loc.extension()?.as_strand(),
+			Self::S3 { loc, .. } => loc.extension()?.as_strand(),
+			Self::Ftp { loc, .. } => loc.extension()?.as_strand(),
 		})
 	}
 
@@ -126,6 +138,9 @@ impl<'a> Url<'a> {
 			Self::Hub { loc, .. } => loc.has_base(),
 			Self::Scope { loc, .. } => loc.has_base(),
 			Self::Sftp { loc, .. } => loc.has_base(),
+			Self::Webdav { loc, .. } => loc.has_base(),
+			Self::S3 { loc, .. } => loc.has_base(),
+			Self::Ftp { loc, .. } => loc.has_base(),
 		}
 	}
 
@@ -141,6 +156,9 @@ impl<'a> Url<'a> {
 			Self::Hub { loc, .. } => loc.has_trail(),
 			Self::Scope { loc, .. } => loc.has_trail(),
 			Self::Sftp { loc, .. } => loc.has_trail(),
+			Self::Webdav { loc, .. } => loc.has_trail(),
+			Self::S3 { loc, .. } => loc.has_trail(),
+			Self::Ftp { loc, .. } => loc.has_trail(),
 		}
 	}
 
@@ -173,6 +191,9 @@ impl<'a> Url<'a> {
 			Self::Hub { loc, .. } => loc.dyn_path(),
 			Self::Scope { loc, .. } => loc.dyn_path(),
 			Self::Sftp { loc, .. } => loc.dyn_path(),
+			Self::Webdav { loc, .. } => loc.dyn_path(),
+			Self::S3 { loc, .. } => loc.dyn_path(),
+			Self::Ftp { loc, .. } => loc.dyn_path(),
 		}
 	}
 
@@ -185,6 +206,9 @@ impl<'a> Url<'a> {
 			Self::Hub { loc, .. } => loc.file_name()?.as_strand(),
 			Self::Scope { loc, .. } => loc.file_name()?.as_strand(),
 			Self::Sftp { loc, .. } => loc.file_name()?.as_strand(),
+			Self::Webdav { loc, .. } => loc.file_name()?.as_strand(),
+			Self::S3 { loc, .. } => loc.file_name()?.as_strand(),
+			Self::Ftp { loc, .. } => loc.file_name()?.as_strand(),
 		})
 	}
 
@@ -229,6 +253,9 @@ impl<'a> Url<'a> {
 
 			// SFTP
 			Self::Sftp { loc, auth } => Self::Sftp { loc: Loc::bare(loc.parent()?), auth },
+			Self::Webdav { loc, auth } => Self::Webdav { loc: Loc::bare(loc.parent()?), auth },
+			Self::S3 { loc, auth } => Self::S3 { loc: Loc::bare(loc.parent()?), auth },
+			Self::Ftp { loc, auth } => Self::Ftp { loc: Loc::bare(loc.parent()?), auth },
 		})
 	}
 
@@ -244,7 +271,10 @@ impl<'a> Url<'a> {
 			| Self::Mount { auth, .. }
 			| Self::Hub { auth, .. }
 			| Self::Scope { auth, .. }
-			| Self::Sftp { auth, .. } => auth.clone(),
+			| Self::Sftp { auth, .. }
+			| Self::Webdav { auth, .. }
+			| Self::S3 { auth, .. }
+			| Self::Ftp { auth, .. } => auth.clone(),
 		};
 
 		let (uri, urn) = Spec::retrieve_ports(self);
@@ -260,6 +290,9 @@ impl<'a> Url<'a> {
 			Self::Hub { loc, .. } => loc.file_stem()?.as_strand(),
 			Self::Scope { loc, .. } => loc.file_stem()?.as_strand(),
 			Self::Sftp { loc, .. } => loc.file_stem()?.as_strand(),
+			Self::Webdav { loc, .. } => loc.file_stem()?.as_strand(),
+			Self::S3 { loc, .. } => loc.file_stem()?.as_strand(),
+			Self::Ftp { loc, .. } => loc.file_stem()?.as_strand(),
 		})
 	}
 
@@ -300,6 +333,9 @@ impl<'a> Url<'a> {
 			Self::Scope { loc, auth } => Self::Scope { loc: Loc::bare(loc.trail()), auth },
 
 			Self::Sftp { loc, auth } => Self::Sftp { loc: Loc::bare(loc.trail()), auth },
+			Self::Webdav { loc, auth } => Self::Webdav { loc: Loc::bare(loc.trail()), auth },
+			Self::S3 { loc, auth } => Self::S3 { loc: Loc::bare(loc.trail()), auth },
+			Self::Ftp { loc, auth } => Self::Ftp { loc: Loc::bare(loc.trail()), auth },
 		}
 	}
 
@@ -312,7 +348,11 @@ impl<'a> Url<'a> {
 				let (base, rest, urn) = loc.triple();
 				(base.dyn_path(), rest.dyn_path(), urn.dyn_path())
 			}
-			Self::Scope { loc, .. } | Self::Sftp { loc, .. } => {
+			Self::Scope { loc, .. }
+			| Self::Sftp { loc, .. }
+			| Self::Webdav { loc, .. }
+			| Self::S3 { loc, .. }
+			| Self::Ftp { loc, .. } => {
 				let (base, rest, urn) = loc.triple();
 				(base.dyn_path(), rest.dyn_path(), urn.dyn_path())
 			}
@@ -360,6 +400,13 @@ impl<'a> Url<'a> {
 			Self::Sftp { auth, .. } => {
 				UrlBuf::Sftp { loc: joined.into_unix()?.into(), auth: auth.clone() }
 			}
+			Self::Webdav { auth, .. } => {
+				UrlBuf::Webdav { loc: joined.into_unix()?.into(), auth: auth.clone() }
+			}
+			Self::S3 { auth, .. } => UrlBuf::S3 { loc: joined.into_unix()?.into(), auth: auth.clone() },
+			Self::Ftp { auth, .. } => {
+				UrlBuf::Ftp { loc: joined.into_unix()?.into(), auth: auth.clone() }
+			}
 		})
 	}
 
@@ -402,6 +449,18 @@ impl<'a> Url<'a> {
 				loc:  LocBuf::<typed_path::UnixPathBuf>::new(path.into_unix()?, loc.base(), loc.trail()),
 				auth: auth.clone(),
 			},
+			Self::Webdav { loc, auth } if path.try_starts_with(loc.trail())? => UrlBuf::Webdav {
+				loc:  LocBuf::<typed_path::UnixPathBuf>::new(path.into_unix()?, loc.base(), loc.trail()),
+				auth: auth.clone(),
+			},
+			Self::S3 { loc, auth } if path.try_starts_with(loc.trail())? => UrlBuf::S3 {
+				loc:  LocBuf::<typed_path::UnixPathBuf>::new(path.into_unix()?, loc.base(), loc.trail()),
+				auth: auth.clone(),
+			},
+			Self::Ftp { loc, auth } if path.try_starts_with(loc.trail())? => UrlBuf::Ftp {
+				loc:  LocBuf::<typed_path::UnixPathBuf>::new(path.into_unix()?, loc.base(), loc.trail()),
+				auth: auth.clone(),
+			},
 
 			Self::Search { auth, .. } => UrlBuf::Search {
 				loc:  LocBuf::<std::path::PathBuf>::saturated(path.into_os()?, self.kind()),
@@ -423,6 +482,18 @@ impl<'a> Url<'a> {
 				loc:  LocBuf::<typed_path::UnixPathBuf>::saturated(path.into_unix()?, self.kind()),
 				auth: auth.clone(),
 			},
+			Self::Webdav { auth, .. } => UrlBuf::Webdav {
+				loc:  LocBuf::<typed_path::UnixPathBuf>::saturated(path.into_unix()?, self.kind()),
+				auth: auth.clone(),
+			},
+			Self::S3 { auth, .. } => UrlBuf::S3 {
+				loc:  LocBuf::<typed_path::UnixPathBuf>::saturated(path.into_unix()?, self.kind()),
+				auth: auth.clone(),
+			},
+			Self::Ftp { auth, .. } => UrlBuf::Ftp {
+				loc:  LocBuf::<typed_path::UnixPathBuf>::saturated(path.into_unix()?, self.kind()),
+				auth: auth.clone(),
+			},
 		};
 
 		Ok(url.into())
@@ -489,6 +560,9 @@ impl<'a> Url<'a> {
 			Self::Hub { loc, .. } => loc.uri().dyn_path(),
 			Self::Scope { loc, .. } => loc.uri().dyn_path(),
 			Self::Sftp { loc, .. } => loc.uri().dyn_path(),
+			Self::Webdav { loc, .. } => loc.uri().dyn_path(),
+			Self::S3 { loc, .. } => loc.uri().dyn_path(),
+			Self::Ftp { loc, .. } => loc.uri().dyn_path(),
 		}
 	}
 
@@ -501,6 +575,9 @@ impl<'a> Url<'a> {
 			Self::Hub { loc, .. } => loc.urn().dyn_path(),
 			Self::Scope { loc, .. } => loc.urn().dyn_path(),
 			Self::Sftp { loc, .. } => loc.urn().dyn_path(),
+			Self::Webdav { loc, .. } => loc.urn().dyn_path(),
+			Self::S3 { loc, .. } => loc.urn().dyn_path(),
+			Self::Ftp { loc, .. } => loc.urn().dyn_path(),
 		}
 	}
 }
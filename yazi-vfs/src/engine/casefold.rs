@@ -0,0 +1,32 @@
+use std::io;
+
+use yazi_fs::engine::{DirReader, FileHolder};
+use yazi_shared::url::{Url, UrlBuf};
+
+/// Look up the real case of the name of `url` in its parent directory, for
+/// services that can't be asked for it directly.
+///
+/// Fails with `NotFound` if there's no exact match but more than one entry
+/// differing only in case, since there's no telling which one is meant.
+pub(crate) async fn casefold_impl(url: Url<'_>) -> io::Result<UrlBuf> {
+	let Some((parent, name)) = url.parent().zip(url.name()) else {
+		return Ok(url.to_owned());
+	};
+
+	let mut it = super::read_dir(parent).await?;
+	let mut similar = None;
+	while let Some(dent) = it.next().await? {
+		let s = dent.name();
+		if !name.eq_ignore_ascii_case(&s) {
+			continue;
+		} else if s == name {
+			return Ok(dent.url());
+		} else if similar.is_none() {
+			similar = Some(s.into_owned());
+		} else {
+			return Err(io::ErrorKind::NotFound.into());
+		}
+	}
+
+	similar.map(|n| parent.try_join(n)).transpose()?.ok_or(io::ErrorKind::NotFound.into())
+}
@@ -1,14 +1,15 @@
 use std::{io::{self, SeekFrom}, sync::{Arc, atomic::{AtomicU64, Ordering}}};
 
 use futures::{StreamExt, TryStreamExt};
+use parking_lot::Mutex;
 use tokio::{io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt, BufReader, BufWriter}, select, sync::{mpsc, oneshot}};
 use yazi_fs::{cha::Cha, engine::{Attrs, FileBuilder}};
-use yazi_shared::url::{Url, UrlBuf};
+use yazi_shared::{auth::AuthKind, url::{AsUrl, Url, UrlBuf}};
 
-use crate::engine::{self, Demand, RwFile};
+use crate::{Resume, engine::{self, Demand, RwFile, bucket}};
 
 const BUF_SIZE: usize = 512 * 1024;
-const PER_CHUNK: u64 = 8 * 1024 * 1024;
+pub(super) const PER_CHUNK: u64 = 8 * 1024 * 1024;
 
 pub(super) async fn copy_impl(from: Url<'_>, to: Url<'_>, attrs: Attrs) -> io::Result<u64> {
 	let src = engine::open(from).await?;
@@ -20,7 +21,7 @@ pub(super) async fn copy_impl(from: Url<'_>, to: Url<'_>, attrs: Attrs) -> io::R
 
 	writer.flush().await?;
 	writer.get_ref().set_attrs(attrs).await.ok();
-	writer.shutdown().await.ok();
+	writer.shutdown().await?;
 	Ok(written)
 }
 
@@ -29,7 +30,40 @@ pub(super) fn copy_progressive_impl(
 	to: UrlBuf,
 	attrs: Attrs,
 ) -> mpsc::Receiver<io::Result<u64>> {
-	let (copier, rx) = ProgressiveCopier::new(from, to, attrs);
+	let (copier, rx) = ProgressiveCopier::new(from, to, attrs, 0, None);
+	copier.spawn();
+	rx
+}
+
+/// Report a copy that's done in one go, e.g. on the server, as a progressive
+/// one: the bytes written once it's over, followed by the final `0`.
+pub(super) fn copy_progressive_once<F>(copy: F) -> mpsc::Receiver<io::Result<u64>>
+where
+	F: Future<Output = io::Result<u64>> + Send + 'static,
+{
+	let (tx, rx) = mpsc::channel(1);
+	tokio::spawn(async move {
+		match copy.await {
+			Ok(written) => {
+				if written > 0 {
+					tx.send(Ok(written)).await.ok();
+				}
+				tx.send(Ok(0)).await.ok();
+			}
+			Err(e) => _ = tx.send(Err(e)).await,
+		}
+	});
+	rx
+}
+
+pub(super) fn copy_resumable_impl(
+	from: UrlBuf,
+	to: UrlBuf,
+	attrs: Attrs,
+	offset: u64,
+	resume: Resume,
+) -> mpsc::Receiver<io::Result<u64>> {
+	let (copier, rx) = ProgressiveCopier::new(from, to, attrs, offset, Some(resume));
 	copier.spawn();
 	rx
 }
@@ -40,16 +74,31 @@ struct ProgressiveCopier {
 	to:    UrlBuf,
 	attrs: Attrs,
 
+	// Where to start copying, which is always at a chunk boundary
+	offset:    u64,
+	per_chunk: u64,
+	resume:    Option<Resume>,
+	done:      Mutex<Vec<bool>>,
+
 	acc:     AtomicU64,
 	prog_tx: mpsc::Sender<io::Result<u64>>,
 }
 
 impl ProgressiveCopier {
-	fn new(from: UrlBuf, to: UrlBuf, attrs: Attrs) -> (Arc<Self>, mpsc::Receiver<io::Result<u64>>) {
+	fn new(
+		from: UrlBuf,
+		to: UrlBuf,
+		attrs: Attrs,
+		offset: u64,
+		resume: Option<Resume>,
+	) -> (Arc<Self>, mpsc::Receiver<io::Result<u64>>) {
 		let acc = AtomicU64::new(0);
 		let (prog_tx, prog_rx) = mpsc::channel(20);
 
-		(Arc::new(Self { from, to, attrs, acc, prog_tx }), prog_rx)
+		let per_chunk = if sequential(to.as_url()) { u64::MAX } else { PER_CHUNK };
+
+		let done = Mutex::default();
+		(Arc::new(Self { from, to, attrs, offset, per_chunk, resume, done, acc, prog_tx }), prog_rx)
 	}
 
 	fn spawn(self: Arc<Self>) {
@@ -58,6 +107,7 @@ impl ProgressiveCopier {
 		tokio::spawn(self.clone().watch(done_rx));
 		tokio::spawn(async move {
 			if let Err(e) = self.work().await {
+				self.save().await;
 				self.prog_tx.send(Err(e)).await.ok();
 			}
 			done_tx.send(()).ok();
@@ -68,7 +118,11 @@ impl ProgressiveCopier {
 		let src = engine::open(&self.from).await?;
 		let cha = src.metadata().await?;
 
-		let dist = engine::create(&self.to).await?;
+		let dist = if self.offset > 0 {
+			Demand::default().write(true).open(&self.to).await?
+		} else {
+			engine::create(&self.to).await?
+		};
 		dist.set_len(cha.len).await?;
 		Ok((cha, src, dist))
 	}
@@ -77,10 +131,12 @@ impl ProgressiveCopier {
 		let (cha, src, dist) = self.init().await?;
 		let (mut src, mut dist) = (Some(src), Some(dist));
 
-		let chunks = cha.len.div_ceil(PER_CHUNK);
-		let it = futures::stream::iter(0..chunks)
+		let chunks = cha.len.div_ceil(self.per_chunk);
+		*self.done.lock() = (0..chunks).map(|i| i * self.per_chunk < self.offset).collect();
+
+		let it = futures::stream::iter(self.offset / self.per_chunk..chunks)
 			.map(|i| self.map(i, cha, chunks, src.take(), dist.take()))
-			.buffer_unordered(4)
+			.buffer_unordered(lanes(self.to.as_url()))
 			.try_fold(None, |first, file| async { Ok(first.or(file)) });
 
 		let mut result = select! {
@@ -96,14 +152,18 @@ impl ProgressiveCopier {
 		if let Ok(None) = &mut result {
 			result = Ok(dist.take());
 		}
-		if let Ok(Some(file)) = &mut result {
+		if let Ok(Some(mut file)) = result {
 			file.set_attrs(self.attrs).await.ok();
-			file.shutdown().await.ok();
+			result = file.shutdown().await.map(|()| None);
 		}
 
 		if let Err(e) = result {
+			self.save().await;
 			self.prog_tx.send(Err(e)).await.ok();
 		} else {
+			if let Some(resume) = &self.resume {
+				resume.clear().await;
+			}
 			self.prog_tx.send(Ok(0)).await.ok();
 		}
 		Ok(())
@@ -117,8 +177,8 @@ impl ProgressiveCopier {
 		src: Option<RwFile>,
 		dist: Option<RwFile>,
 	) -> io::Result<Option<RwFile>> {
-		let offset = i * PER_CHUNK;
-		let take = cha.len.saturating_sub(offset).min(PER_CHUNK);
+		let offset = i * self.per_chunk;
+		let take = cha.len.saturating_sub(offset).min(self.per_chunk);
 
 		let mut src = BufReader::with_capacity(BUF_SIZE, match src {
 			Some(f) => f,
@@ -129,11 +189,13 @@ impl ProgressiveCopier {
 			None => Demand::default().write(true).open(&self.to).await?,
 		});
 
+		src.get_mut().set_read_end(Some(offset + take));
 		src.seek(SeekFrom::Start(offset)).await?;
 		dist.seek(SeekFrom::Start(offset)).await?;
 
 		let mut src = src.take(take);
 		let mut buf = vec![0u8; 65536];
+		let buckets = [bucket(self.from.as_url()), bucket(self.to.as_url())];
 		let mut copied = 0u64;
 		loop {
 			let n = src.read(&mut buf).await?;
@@ -144,14 +206,18 @@ impl ProgressiveCopier {
 			dist.write_all(&buf[..n]).await?;
 			copied += n as u64;
 			self.acc.fetch_add(n as u64, Ordering::SeqCst);
+			self.attrs.throttle.wait(n as u64, buckets.iter().flatten().map(|b| &**b)).await;
 		}
 		dist.flush().await?;
 
 		if copied != take {
-			Err(io::Error::other(format!(
+			return Err(io::Error::other(format!(
 				"short copy for chunk {i}: copied {copied} bytes, expected {take}"
-			)))
-		} else if i == chunks - 1 {
+			)));
+		}
+
+		self.done.lock()[i as usize] = true;
+		if i == chunks - 1 {
 			Ok(Some(dist.into_inner()))
 		} else {
 			dist.shutdown().await.ok();
@@ -170,7 +236,32 @@ impl ProgressiveCopier {
 			let n = self.acc.swap(0, Ordering::SeqCst);
 			if n > 0 {
 				self.prog_tx.send(Ok(n)).await.ok();
+				self.save().await;
 			}
 		}
 	}
+
+	/// Record the end of the contiguous run of finished chunks from the start, so
+	/// an interrupted copy can pick up from there.
+	async fn save(&self) {
+		let Some(resume) = &self.resume else { return };
+
+		let offset = {
+			let done = self.done.lock();
+			done.iter().position(|&b| !b).unwrap_or(done.len()) as u64 * self.per_chunk
+		};
+		if offset > 0
+			&& let Err(e) = resume.save(offset).await
+		{
+			yazi_macro::debug!("{e}");
+		}
+	}
 }
+
+/// Whether files at `url` can only be written from start to end in one go,
+/// i.e. not in parallel chunks nor at an offset, like WebDAV and S3 uploads.
+pub(super) fn sequential(url: Url) -> bool { matches!(url.kind(), AuthKind::Webdav | AuthKind::S3) }
+
+/// How many chunks can be written to `url` at once. FTP servers only take
+/// writes at an offset within what's already there, so chunks go in order.
+fn lanes(url: Url) -> usize { if url.kind() == AuthKind::Ftp { 1 } else { 4 } }
@@ -46,6 +46,13 @@ impl FileBuilder for Demand {
 			AuthKind::Sftp => {
 				(self.0.build::<super::sftp::Demand>().open(url).await?, url.to_owned()).into()
 			}
+			AuthKind::Webdav => {
+				(self.0.build::<super::webdav::Demand>().open(url).await?, url.to_owned()).into()
+			}
+			AuthKind::S3 => (self.0.build::<super::s3::Demand>().open(url).await?, url.to_owned()).into(),
+			AuthKind::Ftp => {
+				(self.0.build::<super::ftp::Demand>().open(url).await?, url.to_owned()).into()
+			}
 		})
 	}
 
@@ -65,6 +72,36 @@ impl FileBuilder for Demand {
 	}
 }
 
+/// Whether opening a file with `demand` creates or empties it, for services
+/// where that takes an upload of empty content. `stat` is only awaited if the
+/// answer depends on whether the file exists.
+pub(super) async fn fresh_on_open<T>(
+	demand: yazi_fs::engine::Demand,
+	stat: impl Future<Output = io::Result<T>>,
+) -> io::Result<bool> {
+	let exists = async move || match stat.await {
+		Ok(_) => Ok(true),
+		Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(false),
+		Err(e) => Err(e),
+	};
+
+	Ok(if demand.create_new {
+		if exists().await? {
+			return Err(io::ErrorKind::AlreadyExists.into());
+		}
+		true
+	} else if demand.create {
+		demand.truncate || !exists().await?
+	} else if demand.write || demand.append || demand.truncate {
+		if !exists().await? {
+			return Err(io::ErrorKind::NotFound.into());
+		}
+		demand.truncate
+	} else {
+		false
+	})
+}
+
 impl UserData for Demand {
 	fn add_methods<M: UserDataMethods<Self>>(methods: &mut M) {
 		methods.add_function("append", |_, (ud, append): (AnyUserData, bool)| {
@@ -99,3 +136,37 @@ impl UserData for Demand {
 		});
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use std::io::ErrorKind::{AlreadyExists, NotFound, PermissionDenied};
+
+	use super::*;
+
+	async fn fresh(flags: &str, exists: Result<(), io::ErrorKind>) -> Result<bool, io::ErrorKind> {
+		let demand = yazi_fs::engine::Demand {
+			append: flags.contains('a'),
+			create: flags.contains('c'),
+			create_new: flags.contains('n'),
+			read: flags.contains('r'),
+			truncate: flags.contains('t'),
+			write: flags.contains('w'),
+			..Default::default()
+		};
+		fresh_on_open(demand, async { exists.map_err(io::Error::from) }).await.map_err(|e| e.kind())
+	}
+
+	#[tokio::test]
+	async fn test_fresh_on_open() {
+		assert_eq!(fresh("r", Err(NotFound)).await, Ok(false));
+		assert_eq!(fresh("w", Ok(())).await, Ok(false));
+		assert_eq!(fresh("w", Err(NotFound)).await, Err(NotFound));
+		assert_eq!(fresh("wt", Ok(())).await, Ok(true));
+		assert_eq!(fresh("wc", Ok(())).await, Ok(false));
+		assert_eq!(fresh("wc", Err(NotFound)).await, Ok(true));
+		assert_eq!(fresh("wct", Err(PermissionDenied)).await, Ok(true));
+		assert_eq!(fresh("wn", Ok(())).await, Err(AlreadyExists));
+		assert_eq!(fresh("wn", Err(NotFound)).await, Ok(true));
+		assert_eq!(fresh("wc", Err(PermissionDenied)).await, Err(PermissionDenied));
+	}
+}
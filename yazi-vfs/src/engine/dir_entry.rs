@@ -7,6 +7,9 @@ pub enum DirEntry {
 	Local(yazi_fs::engine::local::DirEntry),
 	Lua(super::lua::DirEntry),
 	Sftp(super::sftp::DirEntry),
+	Webdav(super::webdav::DirEntry),
+	S3(super::s3::DirEntry),
+	Ftp(super::ftp::DirEntry),
 }
 
 impl FileHolder for DirEntry {
@@ -15,6 +18,9 @@ impl FileHolder for DirEntry {
 			Self::Local(dent) => dent.file().await,
 			Self::Lua(dent) => dent.file().await,
 			Self::Sftp(dent) => dent.file().await,
+			Self::Webdav(dent) => dent.file().await,
+			Self::S3(dent) => dent.file().await,
+			Self::Ftp(dent) => dent.file().await,
 		}
 	}
 
@@ -23,6 +29,9 @@ impl FileHolder for DirEntry {
 			Self::Local(dent) => dent.file_type().await,
 			Self::Lua(dent) => dent.file_type().await,
 			Self::Sftp(dent) => dent.file_type().await,
+			Self::Webdav(dent) => dent.file_type().await,
+			Self::S3(dent) => dent.file_type().await,
+			Self::Ftp(dent) => dent.file_type().await,
 		}
 	}
 
@@ -31,6 +40,9 @@ impl FileHolder for DirEntry {
 			Self::Local(dent) => dent.metadata().await,
 			Self::Lua(dent) => dent.metadata().await,
 			Self::Sftp(dent) => dent.metadata().await,
+			Self::Webdav(dent) => dent.metadata().await,
+			Self::S3(dent) => dent.metadata().await,
+			Self::Ftp(dent) => dent.metadata().await,
 		}
 	}
 
@@ -39,6 +51,9 @@ impl FileHolder for DirEntry {
 			Self::Local(dent) => dent.name(),
 			Self::Lua(dent) => dent.name(),
 			Self::Sftp(dent) => dent.name(),
+			Self::Webdav(dent) => dent.name(),
+			Self::S3(dent) => dent.name(),
+			Self::Ftp(dent) => dent.name(),
 		}
 	}
 
@@ -47,6 +62,9 @@ impl FileHolder for DirEntry {
 			Self::Local(dent) => dent.path(),
 			Self::Lua(dent) => dent.path(),
 			Self::Sftp(dent) => dent.path(),
+			Self::Webdav(dent) => dent.path(),
+			Self::S3(dent) => dent.path(),
+			Self::Ftp(dent) => dent.path(),
 		}
 	}
 
@@ -55,6 +73,9 @@ impl FileHolder for DirEntry {
 			Self::Local(dent) => dent.url(),
 			Self::Lua(dent) => dent.url(),
 			Self::Sftp(dent) => dent.url(),
+			Self::Webdav(dent) => dent.url(),
+			Self::S3(dent) => dent.url(),
+			Self::Ftp(dent) => dent.url(),
 		}
 	}
 }
@@ -1,10 +1,11 @@
 use std::io;
 
 use tokio::sync::mpsc;
-use yazi_fs::{cha::Cha, engine::{Attrs, Capabilities, Engine, local::Local}, file::File};
+use yazi_fs::{Digest, cha::Cha, engine::{Attrs, Capabilities, Engine, local::Local}, file::File};
 use yazi_shared::{path::PathBufDyn, strand::AsStrand, url::{AsUrl, Url, UrlBuf, UrlCow}};
 
 use super::{Engines, ReadDir, RwFile};
+use crate::Resume;
 
 pub async fn absolute<'a, U>(url: &'a U) -> io::Result<UrlCow<'a>>
 where
@@ -46,6 +47,13 @@ where
 	Engines::new(url.as_url()).await?.casefold().await
 }
 
+pub async fn checksum<U>(url: U, digest: Digest) -> io::Result<String>
+where
+	U: AsUrl,
+{
+	Engines::new(url.as_url()).await?.checksum(digest).await
+}
+
 pub async fn copy<U, V>(from: U, to: V, attrs: Attrs) -> io::Result<u64>
 where
 	U: AsUrl,
@@ -85,6 +93,45 @@ where
 	Ok(super::copy_progressive_impl(from.to_owned(), to.to_owned(), attrs))
 }
 
+/// Like [`copy_progressive`], but the partial data of `to` is kept if the copy
+/// is interrupted, and the next copy between the same URLs continues from
+/// there if `from` hasn't changed, returning the offset it starts at.
+pub async fn copy_resumable<U, V, A>(
+	from: U,
+	to: V,
+	attrs: A,
+) -> io::Result<(u64, mpsc::Receiver<Result<u64, io::Error>>)>
+where
+	U: AsUrl,
+	V: AsUrl,
+	A: Into<Attrs>,
+{
+	let (from, to) = (from.as_url().to_owned(), to.as_url().to_owned());
+	let attrs = attrs.into();
+
+	// Uploads that can't be written at an offset always start over
+	let cha = metadata(&from).await?;
+	let resume = Some(()).filter(|()| !super::sequential(to.as_url()));
+	let Some(resume) = resume.and_then(|()| Resume::new(from.as_url(), to.as_url(), cha)) else {
+		return Ok((0, super::copy_progressive_impl(from, to, attrs)));
+	};
+
+	let offset = resume.offset(&to).await / super::PER_CHUNK * super::PER_CHUNK;
+	Ok((offset, super::copy_resumable_impl(from, to, attrs, offset, resume)))
+}
+
+/// Give up on a copy made with [`copy_resumable`], removing the partial data of
+/// `to` along with the progress recorded for it.
+pub async fn discard_resumable<U, V>(from: U, to: V) -> io::Result<()>
+where
+	U: AsUrl,
+	V: AsUrl,
+{
+	let (from, to) = (from.as_url(), to.as_url());
+	Resume::discard(from, to).await;
+	remove_file(to).await
+}
+
 pub async fn create<U>(url: U) -> io::Result<RwFile>
 where
 	U: AsUrl,
@@ -279,9 +326,13 @@ where
 	let url = url.into();
 	match url.as_url() {
 		Url::Regular(_) | Url::Search { .. } => yazi_fs::engine::local::try_absolute(url),
-		Url::Mount { .. } | Url::Hub { .. } | Url::Scope { .. } | Url::Sftp { .. } => {
-			super::try_absolute_impl(url)
-		}
+		Url::Mount { .. }
+		| Url::Hub { .. }
+		| Url::Scope { .. }
+		| Url::Sftp { .. }
+		| Url::Webdav { .. }
+		| Url::S3 { .. }
+		| Url::Ftp { .. } => super::try_absolute_impl(url),
 	}
 }
 
@@ -1,7 +1,7 @@
 use std::io;
 
 use tokio::sync::mpsc;
-use yazi_fs::{cha::Cha, engine::{Attrs, Capabilities, Engine}, file::File};
+use yazi_fs::{Digest, cha::Cha, engine::{Attrs, Capabilities, Engine}, file::File};
 use yazi_shared::{path::{DynPath, PathBufDyn}, strand::AsStrand, url::{Url, UrlBuf, UrlCow}};
 
 #[derive(Clone)]
@@ -9,6 +9,9 @@ pub(super) enum Engines<'a> {
 	Local(yazi_fs::engine::local::Local<'a>),
 	Lua(super::lua::Lua<'a>),
 	Sftp(super::sftp::Sftp<'a>),
+	Webdav(super::webdav::Webdav<'a>),
+	S3(super::s3::S3<'a>),
+	Ftp(super::ftp::Ftp<'a>),
 }
 
 impl<'a> Engine for Engines<'a> {
@@ -23,6 +26,9 @@ impl<'a> Engine for Engines<'a> {
 			Self::Local(p) => p.absolute().await,
 			Self::Lua(p) => p.absolute().await,
 			Self::Sftp(p) => p.absolute().await,
+			Self::Webdav(p) => p.absolute().await,
+			Self::S3(p) => p.absolute().await,
+			Self::Ftp(p) => p.absolute().await,
 		}
 	}
 
@@ -31,6 +37,9 @@ impl<'a> Engine for Engines<'a> {
 			Self::Local(p) => p.canonicalize().await,
 			Self::Lua(p) => p.canonicalize().await,
 			Self::Sftp(p) => p.canonicalize().await,
+			Self::Webdav(p) => p.canonicalize().await,
+			Self::S3(p) => p.canonicalize().await,
+			Self::Ftp(p) => p.canonicalize().await,
 		}
 	}
 
@@ -39,6 +48,9 @@ impl<'a> Engine for Engines<'a> {
 			Self::Local(p) => p.capabilities().await,
 			Self::Lua(p) => p.capabilities().await,
 			Self::Sftp(p) => p.capabilities().await,
+			Self::Webdav(p) => p.capabilities().await,
+			Self::S3(p) => p.capabilities().await,
+			Self::Ftp(p) => p.capabilities().await,
 		}
 	}
 
@@ -47,6 +59,20 @@ impl<'a> Engine for Engines<'a> {
 			Self::Local(p) => p.casefold().await,
 			Self::Lua(p) => p.casefold().await,
 			Self::Sftp(p) => p.casefold().await,
+			Self::Webdav(p) => p.casefold().await,
+			Self::S3(p) => p.casefold().await,
+			Self::Ftp(p) => p.casefold().await,
+		}
+	}
+
+	async fn checksum(&self, digest: Digest) -> io::Result<String> {
+		match self {
+			Self::Local(p) => p.checksum(digest).await,
+			Self::Lua(p) => p.checksum(digest).await,
+			Self::Sftp(p) => p.checksum(digest).await,
+			Self::Webdav(p) => p.checksum(digest).await,
+			Self::S3(p) => p.checksum(digest).await,
+			Self::Ftp(p) => p.checksum(digest).await,
 		}
 	}
 
@@ -58,6 +84,9 @@ impl<'a> Engine for Engines<'a> {
 			Self::Local(p) => p.copy(to, attrs).await,
 			Self::Lua(p) => p.copy(to, attrs).await,
 			Self::Sftp(p) => p.copy(to, attrs).await,
+			Self::Webdav(p) => p.copy(to, attrs).await,
+			Self::S3(p) => p.copy(to, attrs).await,
+			Self::Ftp(p) => p.copy(to, attrs).await,
 		}
 	}
 
@@ -70,6 +99,9 @@ impl<'a> Engine for Engines<'a> {
 			Self::Local(p) => p.copy_progressive(to, attrs),
 			Self::Lua(p) => p.copy_progressive(to, attrs),
 			Self::Sftp(p) => p.copy_progressive(to, attrs),
+			Self::Webdav(p) => p.copy_progressive(to, attrs),
+			Self::S3(p) => p.copy_progressive(to, attrs),
+			Self::Ftp(p) => p.copy_progressive(to, attrs),
 		}
 	}
 
@@ -79,6 +111,9 @@ impl<'a> Engine for Engines<'a> {
 			Self::Local(p) => (p.create().await?, url.to_owned()).into(),
 			Self::Lua(p) => p.create().await?.into(),
 			Self::Sftp(p) => (p.create().await?, url.to_owned()).into(),
+			Self::Webdav(p) => (p.create().await?, url.to_owned()).into(),
+			Self::S3(p) => (p.create().await?, url.to_owned()).into(),
+			Self::Ftp(p) => (p.create().await?, url.to_owned()).into(),
 		})
 	}
 
@@ -87,6 +122,9 @@ impl<'a> Engine for Engines<'a> {
 			Self::Local(p) => p.create_dir().await,
 			Self::Lua(p) => p.create_dir().await,
 			Self::Sftp(p) => p.create_dir().await,
+			Self::Webdav(p) => p.create_dir().await,
+			Self::S3(p) => p.create_dir().await,
+			Self::Ftp(p) => p.create_dir().await,
 		}
 	}
 
@@ -95,6 +133,9 @@ impl<'a> Engine for Engines<'a> {
 			Self::Local(p) => p.create_dir_all().await,
 			Self::Lua(p) => p.create_dir_all().await,
 			Self::Sftp(p) => p.create_dir_all().await,
+			Self::Webdav(p) => p.create_dir_all().await,
+			Self::S3(p) => p.create_dir_all().await,
+			Self::Ftp(p) => p.create_dir_all().await,
 		}
 	}
 
@@ -104,6 +145,9 @@ impl<'a> Engine for Engines<'a> {
 			Self::Local(p) => (p.create_new().await?, url.to_owned()).into(),
 			Self::Lua(p) => p.create_new().await?.into(),
 			Self::Sftp(p) => (p.create_new().await?, url.to_owned()).into(),
+			Self::Webdav(p) => (p.create_new().await?, url.to_owned()).into(),
+			Self::S3(p) => (p.create_new().await?, url.to_owned()).into(),
+			Self::Ftp(p) => (p.create_new().await?, url.to_owned()).into(),
 		})
 	}
 
@@ -112,6 +156,9 @@ impl<'a> Engine for Engines<'a> {
 			Self::Local(p) => p.file().await,
 			Self::Lua(p) => p.file().await,
 			Self::Sftp(p) => p.file().await,
+			Self::Webdav(p) => p.file().await,
+			Self::S3(p) => p.file().await,
+			Self::Ftp(p) => p.file().await,
 		}
 	}
 
@@ -123,6 +170,9 @@ impl<'a> Engine for Engines<'a> {
 			Self::Local(p) => p.hard_link(to).await,
 			Self::Lua(p) => p.hard_link(to).await,
 			Self::Sftp(p) => p.hard_link(to).await,
+			Self::Webdav(p) => p.hard_link(to).await,
+			Self::S3(p) => p.hard_link(to).await,
+			Self::Ftp(p) => p.hard_link(to).await,
 		}
 	}
 
@@ -131,6 +181,9 @@ impl<'a> Engine for Engines<'a> {
 			Self::Local(p) => p.metadata().await,
 			Self::Lua(p) => p.metadata().await,
 			Self::Sftp(p) => p.metadata().await,
+			Self::Webdav(p) => p.metadata().await,
+			Self::S3(p) => p.metadata().await,
+			Self::Ftp(p) => p.metadata().await,
 		}
 	}
 
@@ -141,6 +194,9 @@ impl<'a> Engine for Engines<'a> {
 			K::Regular | K::Search => Self::Me::Local(yazi_fs::engine::local::Local::new(url).await?),
 			K::Mount | K::Hub | K::Scope => Self::Me::Lua(super::lua::Lua::new(url).await?),
 			K::Sftp => Self::Me::Sftp(super::sftp::Sftp::new(url).await?),
+			K::Webdav => Self::Me::Webdav(super::webdav::Webdav::new(url).await?),
+			K::S3 => Self::Me::S3(super::s3::S3::new(url).await?),
+			K::Ftp => Self::Me::Ftp(super::ftp::Ftp::new(url).await?),
 		})
 	}
 
@@ -150,6 +206,9 @@ impl<'a> Engine for Engines<'a> {
 			Self::Local(p) => (p.open().await?, url.to_owned()).into(),
 			Self::Lua(p) => p.open().await?.into(),
 			Self::Sftp(p) => (p.open().await?, url.to_owned()).into(),
+			Self::Webdav(p) => (p.open().await?, url.to_owned()).into(),
+			Self::S3(p) => (p.open().await?, url.to_owned()).into(),
+			Self::Ftp(p) => (p.open().await?, url.to_owned()).into(),
 		})
 	}
 
@@ -158,6 +217,9 @@ impl<'a> Engine for Engines<'a> {
 			Self::Local(p) => Self::ReadDir::Local(p.read_dir().await?),
 			Self::Lua(p) => Self::ReadDir::Lua(p.read_dir().await?),
 			Self::Sftp(p) => Self::ReadDir::Sftp(p.read_dir().await?),
+			Self::Webdav(p) => Self::ReadDir::Webdav(p.read_dir().await?),
+			Self::S3(p) => Self::ReadDir::S3(p.read_dir().await?),
+			Self::Ftp(p) => Self::ReadDir::Ftp(p.read_dir().await?),
 		})
 	}
 
@@ -166,6 +228,9 @@ impl<'a> Engine for Engines<'a> {
 			Self::Local(p) => p.read_link().await,
 			Self::Lua(p) => p.read_link().await,
 			Self::Sftp(p) => p.read_link().await,
+			Self::Webdav(p) => p.read_link().await,
+			Self::S3(p) => p.read_link().await,
+			Self::Ftp(p) => p.read_link().await,
 		}
 	}
 
@@ -174,6 +239,9 @@ impl<'a> Engine for Engines<'a> {
 			Self::Local(p) => p.revalidate(file).await,
 			Self::Lua(p) => p.revalidate(file).await,
 			Self::Sftp(p) => p.revalidate(file).await,
+			Self::Webdav(p) => p.revalidate(file).await,
+			Self::S3(p) => p.revalidate(file).await,
+			Self::Ftp(p) => p.revalidate(file).await,
 		}
 	}
 
@@ -182,6 +250,9 @@ impl<'a> Engine for Engines<'a> {
 			Self::Local(p) => p.remove_dir().await,
 			Self::Lua(p) => p.remove_dir().await,
 			Self::Sftp(p) => p.remove_dir().await,
+			Self::Webdav(p) => p.remove_dir().await,
+			Self::S3(p) => p.remove_dir().await,
+			Self::Ftp(p) => p.remove_dir().await,
 		}
 	}
 
@@ -190,6 +261,9 @@ impl<'a> Engine for Engines<'a> {
 			Self::Local(p) => p.remove_dir_all().await,
 			Self::Lua(p) => p.remove_dir_all().await,
 			Self::Sftp(p) => p.remove_dir_all().await,
+			Self::Webdav(p) => p.remove_dir_all().await,
+			Self::S3(p) => p.remove_dir_all().await,
+			Self::Ftp(p) => p.remove_dir_all().await,
 		}
 	}
 
@@ -198,6 +272,9 @@ impl<'a> Engine for Engines<'a> {
 			Self::Local(p) => p.remove_dir_clean().await,
 			Self::Lua(p) => p.remove_dir_clean().await,
 			Self::Sftp(p) => p.remove_dir_clean().await,
+			Self::Webdav(p) => p.remove_dir_clean().await,
+			Self::S3(p) => p.remove_dir_clean().await,
+			Self::Ftp(p) => p.remove_dir_clean().await,
 		}
 	}
 
@@ -206,6 +283,9 @@ impl<'a> Engine for Engines<'a> {
 			Self::Local(p) => p.remove_file().await,
 			Self::Lua(p) => p.remove_file().await,
 			Self::Sftp(p) => p.remove_file().await,
+			Self::Webdav(p) => p.remove_file().await,
+			Self::S3(p) => p.remove_file().await,
+			Self::Ftp(p) => p.remove_file().await,
 		}
 	}
 
@@ -217,6 +297,9 @@ impl<'a> Engine for Engines<'a> {
 			Self::Local(p) => p.rename(to).await,
 			Self::Lua(p) => p.rename(to).await,
 			Self::Sftp(p) => p.rename(to).await,
+			Self::Webdav(p) => p.rename(to).await,
+			Self::S3(p) => p.rename(to).await,
+			Self::Ftp(p) => p.rename(to).await,
 		}
 	}
 
@@ -225,6 +308,9 @@ impl<'a> Engine for Engines<'a> {
 			Self::Local(p) => p.set_attrs(attrs).await,
 			Self::Lua(p) => p.set_attrs(attrs).await,
 			Self::Sftp(p) => p.set_attrs(attrs).await,
+			Self::Webdav(p) => p.set_attrs(attrs).await,
+			Self::S3(p) => p.set_attrs(attrs).await,
+			Self::Ftp(p) => p.set_attrs(attrs).await,
 		}
 	}
 
@@ -237,6 +323,9 @@ impl<'a> Engine for Engines<'a> {
 			Self::Local(p) => p.symlink(original, is_dir).await,
 			Self::Lua(p) => p.symlink(original, is_dir).await,
 			Self::Sftp(p) => p.symlink(original, is_dir).await,
+			Self::Webdav(p) => p.symlink(original, is_dir).await,
+			Self::S3(p) => p.symlink(original, is_dir).await,
+			Self::Ftp(p) => p.symlink(original, is_dir).await,
 		}
 	}
 
@@ -248,6 +337,9 @@ impl<'a> Engine for Engines<'a> {
 			Self::Local(p) => p.symlink_dir(original).await,
 			Self::Lua(p) => p.symlink_dir(original).await,
 			Self::Sftp(p) => p.symlink_dir(original).await,
+			Self::Webdav(p) => p.symlink_dir(original).await,
+			Self::S3(p) => p.symlink_dir(original).await,
+			Self::Ftp(p) => p.symlink_dir(original).await,
 		}
 	}
 
@@ -259,6 +351,9 @@ impl<'a> Engine for Engines<'a> {
 			Self::Local(p) => p.symlink_file(original).await,
 			Self::Lua(p) => p.symlink_file(original).await,
 			Self::Sftp(p) => p.symlink_file(original).await,
+			Self::Webdav(p) => p.symlink_file(original).await,
+			Self::S3(p) => p.symlink_file(original).await,
+			Self::Ftp(p) => p.symlink_file(original).await,
 		}
 	}
 
@@ -267,6 +362,9 @@ impl<'a> Engine for Engines<'a> {
 			Self::Local(p) => p.symlink_metadata().await,
 			Self::Lua(p) => p.symlink_metadata().await,
 			Self::Sftp(p) => p.symlink_metadata().await,
+			Self::Webdav(p) => p.symlink_metadata().await,
+			Self::S3(p) => p.symlink_metadata().await,
+			Self::Ftp(p) => p.symlink_metadata().await,
 		}
 	}
 
@@ -275,6 +373,9 @@ impl<'a> Engine for Engines<'a> {
 			Self::Local(p) => p.trash().await,
 			Self::Lua(p) => p.trash().await,
 			Self::Sftp(p) => p.trash().await,
+			Self::Webdav(p) => p.trash().await,
+			Self::S3(p) => p.trash().await,
+			Self::Ftp(p) => p.trash().await,
 		}
 	}
 
@@ -283,6 +384,9 @@ impl<'a> Engine for Engines<'a> {
 			Self::Local(p) => p.url(),
 			Self::Lua(p) => p.url(),
 			Self::Sftp(p) => p.url(),
+			Self::Webdav(p) => p.url(),
+			Self::S3(p) => p.url(),
+			Self::Ftp(p) => p.url(),
 		}
 	}
 
@@ -294,6 +398,9 @@ impl<'a> Engine for Engines<'a> {
 			Self::Local(p) => p.write(contents).await,
 			Self::Lua(p) => p.write(contents).await,
 			Self::Sftp(p) => p.write(contents).await,
+			Self::Webdav(p) => p.write(contents).await,
+			Self::S3(p) => p.write(contents).await,
+			Self::Ftp(p) => p.write(contents).await,
 		}
 	}
 }
@@ -0,0 +1,445 @@
+use std::{io, net::IpAddr, time::{Duration, SystemTime}};
+
+use chrono::{DateTime, Utc};
+use tokio::{io::{AsyncBufRead, AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader}, net::{TcpListener, TcpStream}, time::timeout};
+use typed_path::UnixPath;
+use yazi_config::vfs::{FtpTls, ServiceFtp};
+
+use super::{Entry, Stream};
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+// --- Reply
+pub(super) struct Reply {
+	pub(super) code: u16,
+	/// The lines of the reply, without the code of the first and last ones.
+	pub(super) text: Vec<u8>,
+}
+
+impl Reply {
+	fn lines(&self) -> impl Iterator<Item = &[u8]> { self.text.split(|&b| b == b'\n') }
+}
+
+// --- Client
+/// A control connection to an FTP server.
+pub(super) struct Client {
+	config: &'static ServiceFtp,
+	ctrl:   BufReader<Stream>,
+	peer:   IpAddr,
+	local:  IpAddr,
+
+	epsv: bool,
+	mlst: bool,
+	prot: bool,
+
+	/// Whether the connection may be out of sync with the server, because a
+	/// command was interrupted or a transfer was left open, so it can't be
+	/// reused.
+	pub(super) broken: bool,
+}
+
+impl Client {
+	pub(super) async fn connect(config: &'static ServiceFtp) -> io::Result<Self> {
+		let tcp = timeout(TIMEOUT, TcpStream::connect((config.host.as_str(), config.port()))).await??;
+		let (peer, local) = (tcp.peer_addr()?.ip(), tcp.local_addr()?.ip());
+
+		let stream = match config.tls {
+			FtpTls::Implicit => Stream::Tls(Box::new(super::tls::connect(config, tcp).await?)),
+			FtpTls::None | FtpTls::Explicit => Stream::Tcp(tcp),
+		};
+
+		let mut me = Self {
+			config,
+			ctrl: BufReader::new(stream),
+			peer,
+			local,
+			epsv: true,
+			mlst: false,
+			prot: false,
+			broken: false,
+		};
+
+		let greeting = me.reply().await?;
+		if greeting.code != 220 {
+			return Err(Self::error("connect", &greeting));
+		} else if config.tls == FtpTls::Explicit {
+			me = me.upgrade().await?;
+		}
+
+		me.login().await?;
+		me.setup().await?;
+		Ok(me)
+	}
+
+	async fn upgrade(mut self) -> io::Result<Self> {
+		self.exec("AUTH", Some(b"TLS")).await?;
+
+		let Stream::Tcp(tcp) = self.ctrl.into_inner() else { unreachable!() };
+		self.ctrl = BufReader::new(Stream::Tls(Box::new(super::tls::connect(self.config, tcp).await?)));
+		Ok(self)
+	}
+
+	async fn login(&mut self) -> io::Result<()> {
+		let config = self.config;
+		if self.exec("USER", Some(config.user().as_bytes())).await?.code != 331 {
+			return Ok(());
+		}
+
+		// Anonymous logins conventionally take an email address as the password
+		let password = match &config.password {
+			Some(p) => p.as_str(),
+			None if config.user.is_empty() => "anonymous@",
+			None => "",
+		};
+		self.exec("PASS", Some(password.as_bytes())).await.map(|_| ())
+	}
+
+	async fn setup(&mut self) -> io::Result<()> {
+		if self.config.tls != FtpTls::None {
+			self.exec("PBSZ", Some(b"0")).await?;
+			self.exec("PROT", Some(b"P")).await?;
+			self.prot = true;
+		}
+
+		self.exec("TYPE", Some(b"I")).await?;
+
+		let mut utf8 = false;
+		if let Ok(feat) = self.exec("FEAT", None).await {
+			for line in feat.lines().map(|l| l.trim_ascii().to_ascii_uppercase()) {
+				self.mlst |= line.starts_with(b"MLST");
+				utf8 |= line == b"UTF8";
+			}
+		}
+		if utf8 {
+			self.exec("OPTS", Some(b"UTF8 ON")).await.ok();
+		}
+		Ok(())
+	}
+
+	/// Send a command and wait for its reply, which is an error if the command
+	/// failed.
+	pub(super) async fn exec(&mut self, verb: &str, arg: Option<&[u8]>) -> io::Result<Reply> {
+		if arg.is_some_and(|a| a.contains(&b'\r') || a.contains(&b'\n')) {
+			return Err(io::Error::new(io::ErrorKind::InvalidInput, "Line breaks are not allowed"));
+		}
+
+		let mut line = Vec::with_capacity(verb.len() + arg.map_or(0, |a| a.len() + 1) + 2);
+		line.extend_from_slice(verb.as_bytes());
+		if let Some(arg) = arg {
+			line.push(b' ');
+			line.extend_from_slice(arg);
+		}
+		line.extend_from_slice(b"\r\n");
+
+		// Stays set if this gets interrupted before the reply is read
+		self.broken = true;
+		self.ctrl.get_mut().write_all(&line).await?;
+		self.ctrl.get_mut().flush().await?;
+		let reply = self.reply().await?;
+		self.broken = reply.code == 421;
+
+		if reply.code >= 400 { Err(Self::error(verb, &reply)) } else { Ok(reply) }
+	}
+
+	async fn reply(&mut self) -> io::Result<Reply> { read_reply(&mut self.ctrl).await }
+
+	fn error(verb: &str, reply: &Reply) -> io::Error {
+		let text = String::from_utf8_lossy(reply.lines().next().unwrap_or_default());
+		let lower = text.to_ascii_lowercase();
+
+		let kind = match reply.code {
+			_ if lower.contains("not empty") => io::ErrorKind::DirectoryNotEmpty,
+			_ if lower.contains("permission") || lower.contains("denied") => {
+				io::ErrorKind::PermissionDenied
+			}
+			_ if lower.contains("exists") && !lower.contains("not exist") => io::ErrorKind::AlreadyExists,
+			421 | 425 | 426 => io::ErrorKind::ConnectionAborted,
+			450 => io::ErrorKind::ResourceBusy,
+			500 | 502 | 504 => io::ErrorKind::Unsupported,
+			501 => io::ErrorKind::InvalidInput,
+			530 | 532 => io::ErrorKind::PermissionDenied,
+			550 => io::ErrorKind::NotFound,
+			452 | 552 => io::ErrorKind::StorageFull,
+			_ => io::ErrorKind::Other,
+		};
+		io::Error::new(kind, format!("FTP {verb} failed: {} {text}", reply.code))
+	}
+
+	/// Metadata of the file at `path`, which isn't followed if it's a symlink.
+	pub(super) async fn stat(&mut self, path: &UnixPath) -> io::Result<Entry> {
+		if self.mlst {
+			let reply = self.exec("MLST", Some(path.as_bytes())).await?;
+			let entry = reply
+				.lines()
+				.filter_map(|l| l.strip_prefix(b" "))
+				.find_map(|l| Entry::mlsx(l.trim_ascii_end(), false));
+
+			if let Some(entry) = entry {
+				return Ok(entry);
+			}
+		}
+
+		let (Some(parent), Some(name)) = (path.parent(), path.file_name()) else {
+			return Ok(Entry::root());
+		};
+		let entries = self.list(parent).await?;
+		entries.into_iter().find(|e| e.name == name).ok_or_else(|| io::ErrorKind::NotFound.into())
+	}
+
+	pub(super) async fn list(&mut self, path: &UnixPath) -> io::Result<Vec<Entry>> {
+		let verb = if self.mlst { "MLSD" } else { "LIST" };
+		let mut data = self.transfer(verb, path.as_bytes(), 0).await?;
+
+		let mut buf = Vec::new();
+		match data.read_to_end(&mut buf).await {
+			// Not all servers close TLS data connections cleanly
+			Ok(_) => {}
+			Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => {}
+			Err(e) => return Err(e),
+		}
+
+		drop(data);
+		self.finish(verb).await?;
+		Ok(Entry::parse(&buf, self.mlst))
+	}
+
+	/// Start a transfer with `verb`, from `offset` if it's not zero, returning
+	/// the data connection. [`Self::finish`] has to be called once it's done.
+	pub(super) async fn transfer(
+		&mut self,
+		verb: &str,
+		arg: &[u8],
+		offset: u64,
+	) -> io::Result<Stream> {
+		enum Pending {
+			Passive(TcpStream),
+			Active(TcpListener),
+		}
+
+		let pending = if self.config.active {
+			Pending::Active(self.active().await?)
+		} else {
+			Pending::Passive(self.passive().await?)
+		};
+
+		if offset > 0 {
+			self.exec("REST", Some(offset.to_string().as_bytes())).await?;
+		}
+
+		let reply = self.exec(verb, Some(arg)).await?;
+		if reply.code >= 200 {
+			return Err(Self::error(verb, &reply));
+		}
+
+		// Until the transfer is finished
+		self.broken = true;
+		let tcp = match pending {
+			Pending::Passive(tcp) => tcp,
+			Pending::Active(listener) => timeout(TIMEOUT, listener.accept()).await??.0,
+		};
+
+		Ok(if self.prot {
+			Stream::Tls(Box::new(super::tls::connect(self.config, tcp).await?))
+		} else {
+			Stream::Tcp(tcp)
+		})
+	}
+
+	/// Wait for the server to confirm a transfer, after its data connection has
+	/// been closed.
+	pub(super) async fn finish(&mut self, verb: &str) -> io::Result<()> {
+		let reply = self.reply().await?;
+		self.broken = false;
+
+		if reply.code >= 400 { Err(Self::error(verb, &reply)) } else { Ok(()) }
+	}
+
+	async fn passive(&mut self) -> io::Result<TcpStream> {
+		let port = if self.epsv {
+			match self.exec("EPSV", None).await {
+				Ok(reply) => Some(parse_epsv(&reply.text)?),
+				Err(e) if e.kind() == io::ErrorKind::Unsupported => {
+					self.epsv = false;
+					None
+				}
+				Err(e) => return Err(e),
+			}
+		} else {
+			None
+		};
+
+		// The address in a `PASV` reply is often a private one behind NAT, so the
+		// one of the control connection is used instead
+		let port = match port {
+			Some(port) => port,
+			None => parse_pasv(&self.exec("PASV", None).await?.text)?,
+		};
+		timeout(TIMEOUT, TcpStream::connect((self.peer, port))).await?
+	}
+
+	async fn active(&mut self) -> io::Result<TcpListener> {
+		let listener = TcpListener::bind((self.local, 0)).await?;
+		let port = listener.local_addr()?.port();
+
+		let eprt = match self.local {
+			IpAddr::V4(ip) => format!("|1|{ip}|{port}|"),
+			IpAddr::V6(ip) => format!("|2|{ip}|{port}|"),
+		};
+		match self.exec("EPRT", Some(eprt.as_bytes())).await {
+			Ok(_) => {}
+			Err(e) if e.kind() == io::ErrorKind::Unsupported && self.local.is_ipv4() => {
+				let IpAddr::V4(ip) = self.local else { unreachable!() };
+				let [a, b, c, d] = ip.octets();
+				let arg = format!("{a},{b},{c},{d},{},{}", port >> 8, port & 0xff);
+				self.exec("PORT", Some(arg.as_bytes())).await?;
+			}
+			Err(e) => return Err(e),
+		}
+		Ok(listener)
+	}
+
+	pub(super) async fn noop(&mut self) -> io::Result<()> {
+		self.exec("NOOP", None).await.map(|_| ())
+	}
+
+	pub(super) async fn mkdir(&mut self, path: &UnixPath) -> io::Result<()> {
+		self.exec("MKD", Some(path.as_bytes())).await.map(|_| ())
+	}
+
+	pub(super) async fn rmdir(&mut self, path: &UnixPath) -> io::Result<()> {
+		self.exec("RMD", Some(path.as_bytes())).await.map(|_| ())
+	}
+
+	pub(super) async fn delete(&mut self, path: &UnixPath) -> io::Result<()> {
+		self.exec("DELE", Some(path.as_bytes())).await.map(|_| ())
+	}
+
+	pub(super) async fn rename(&mut self, from: &UnixPath, to: &UnixPath) -> io::Result<()> {
+		self.exec("RNFR", Some(from.as_bytes())).await?;
+		self.exec("RNTO", Some(to.as_bytes())).await.map(|_| ())
+	}
+
+	pub(super) async fn chmod(&mut self, path: &UnixPath, mode: u16) -> io::Result<()> {
+		let mut arg = format!("CHMOD {mode:o} ").into_bytes();
+		arg.extend_from_slice(path.as_bytes());
+		self.exec("SITE", Some(&arg)).await.map(|_| ())
+	}
+
+	pub(super) async fn set_mtime(&mut self, path: &UnixPath, mtime: SystemTime) -> io::Result<()> {
+		let mut arg = DateTime::<Utc>::from(mtime).format("%Y%m%d%H%M%S ").to_string().into_bytes();
+		arg.extend_from_slice(path.as_bytes());
+		self.exec("MFMT", Some(&arg)).await.map(|_| ())
+	}
+}
+
+/// Read a reply, joining the lines of a multi-line one, which ends with a line
+/// starting with the same code followed by a space.
+async fn read_reply<R>(r: &mut R) -> io::Result<Reply>
+where
+	R: AsyncBufRead + Unpin,
+{
+	let mut line = Vec::new();
+	let mut read_line = async |line: &mut Vec<u8>| {
+		line.clear();
+		if timeout(TIMEOUT, r.read_until(b'\n', line)).await?? == 0 {
+			return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+		}
+		while line.last().is_some_and(|&b| b == b'\r' || b == b'\n') {
+			line.pop();
+		}
+		Ok(())
+	};
+
+	read_line(&mut line).await?;
+	let code = line
+		.get(..3)
+		.and_then(|s| std::str::from_utf8(s).ok()?.parse().ok())
+		.ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "Malformed FTP reply"))?;
+
+	let mut text = line.get(4..).unwrap_or_default().to_vec();
+	if line.get(3) == Some(&b'-') {
+		let end = format!("{code} ");
+		loop {
+			read_line(&mut line).await?;
+			text.push(b'\n');
+			if let Some(rest) = line.strip_prefix(end.as_bytes()) {
+				text.extend_from_slice(rest);
+				break;
+			}
+			text.extend_from_slice(&line);
+		}
+	}
+
+	Ok(Reply { code, text })
+}
+
+/// `229 Entering Extended Passive Mode (|||6446|)`
+fn parse_epsv(text: &[u8]) -> io::Result<u16> {
+	let port = text.iter().position(|&b| b == b'(').and_then(|i| {
+		let s = std::str::from_utf8(&text[i + 1..]).ok()?;
+		let delim = s.chars().next()?;
+		s.split(delim).nth(3)?.parse().ok()
+	});
+	port.ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "Malformed EPSV reply"))
+}
+
+/// `227 Entering Passive Mode (192,168,1,2,25,46)`
+fn parse_pasv(text: &[u8]) -> io::Result<u16> {
+	let port = text.iter().position(u8::is_ascii_digit).and_then(|i| {
+		let s = std::str::from_utf8(&text[i..]).ok()?;
+		let mut nums = s.split(',').map(|n| n.trim_end_matches(|c: char| !c.is_ascii_digit()));
+		let (hi, lo) = (nums.nth(4)?.parse::<u16>().ok()?, nums.next()?.parse::<u16>().ok()?);
+		Some(hi << 8 | lo)
+	});
+	port.ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "Malformed PASV reply"))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	async fn reply(mut s: &[u8]) -> io::Result<(u16, String)> {
+		let reply = read_reply(&mut s).await?;
+		Ok((reply.code, String::from_utf8(reply.text).unwrap()))
+	}
+
+	#[tokio::test]
+	async fn test_reply() {
+		assert_eq!(reply(b"220 Welcome\r\n").await.unwrap(), (220, "Welcome".to_owned()));
+		assert_eq!(reply(b"200\r\n").await.unwrap(), (200, String::new()));
+
+		let feat = b"211-Features:\r\n MLST type*;size*;modify*;\r\n UTF8\r\n211 End\r\n226 Next\r\n";
+		assert_eq!(
+			reply(feat).await.unwrap(),
+			(211, "Features:\n MLST type*;size*;modify*;\n UTF8\nEnd".to_owned())
+		);
+
+		// Lines in between may start with a code too, as long as it's not followed by a
+		// space
+		let nested = b"150-Opening\n150-still opening\n226 Done\n150 Done\n";
+		assert_eq!(
+			reply(nested).await.unwrap(),
+			(150, "Opening\n150-still opening\n226 Done\nDone".to_owned())
+		);
+
+		assert_eq!(reply(b"oops\r\n").await.unwrap_err().kind(), io::ErrorKind::InvalidData);
+		assert_eq!(reply(b"").await.unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+		assert_eq!(
+			reply(b"211-Features:\r\n UTF8\r\n").await.unwrap_err().kind(),
+			io::ErrorKind::UnexpectedEof
+		);
+	}
+
+	#[test]
+	fn test_passive() {
+		assert_eq!(parse_epsv(b"Entering Extended Passive Mode (|||6446|)").unwrap(), 6446);
+		assert_eq!(parse_epsv(b"Entering Extended Passive Mode (!!!6446!)").unwrap(), 6446);
+		assert!(parse_epsv(b"Entering Extended Passive Mode").is_err());
+		assert!(parse_epsv(b"Entering Extended Passive Mode (|||port|)").is_err());
+
+		assert_eq!(parse_pasv(b"Entering Passive Mode (192,168,1,2,25,46)").unwrap(), 25 << 8 | 46);
+		assert_eq!(parse_pasv(b"Entering Passive Mode 192,168,1,2,25,46.").unwrap(), 6446);
+		assert!(parse_pasv(b"Entering Passive Mode (192,168,1,2)").is_err());
+		assert!(parse_pasv(b"Entering Passive Mode").is_err());
+	}
+}
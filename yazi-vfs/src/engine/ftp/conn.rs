@@ -0,0 +1,65 @@
+use std::{io, time::Duration};
+
+use yazi_config::vfs::ServiceFtp;
+
+use super::Client;
+
+#[derive(Clone, Copy)]
+pub(super) struct Conn {
+	pub(super) config: &'static ServiceFtp,
+}
+
+impl deadpool::managed::Manager for Conn {
+	type Error = io::Error;
+	type Type = Client;
+
+	async fn create(&self) -> Result<Self::Type, Self::Error> {
+		Client::connect(self.config).await.map_err(|e| {
+			io::Error::new(
+				io::ErrorKind::NotConnected,
+				format!("Failed to connect to FTP server `{}`: {e}", self.config.domain),
+			)
+		})
+	}
+
+	async fn recycle(
+		&self,
+		obj: &mut Self::Type,
+		metrics: &deadpool::managed::Metrics,
+	) -> deadpool::managed::RecycleResult<Self::Error> {
+		if obj.broken {
+			return Err(deadpool::managed::RecycleError::Message("Connection out of sync".into()));
+		}
+
+		// Servers drop idle connections after a while, so make sure it's still alive
+		if metrics.last_used() > Duration::from_secs(15) {
+			obj.noop().await?;
+		}
+		Ok(())
+	}
+}
+
+impl Conn {
+	pub(super) async fn roll(self) -> io::Result<deadpool::managed::Object<Self>> {
+		use deadpool::managed::PoolError;
+
+		let pool = *super::CONN.lock().entry(self.config).or_insert_with(|| {
+			Box::leak(Box::new(
+				deadpool::managed::Pool::builder(self)
+					.runtime(deadpool::Runtime::Tokio1)
+					.max_size(8)
+					.create_timeout(Some(Duration::from_secs(45)))
+					.build()
+					.unwrap(),
+			))
+		});
+
+		pool.get().await.map_err(|e| match e {
+			PoolError::Timeout(_) => io::Error::new(io::ErrorKind::TimedOut, e.to_string()),
+			PoolError::Backend(e) => e,
+			PoolError::Closed | PoolError::NoRuntimeSpecified | PoolError::PostCreateHook(_) => {
+				io::Error::other(e.to_string())
+			}
+		})
+	}
+}
@@ -0,0 +1,64 @@
+use std::io;
+
+use yazi_fs::engine::{Attrs, Engine, FileBuilder};
+use yazi_shared::url::AsUrl;
+
+use crate::engine::ftp::{File, Ftp};
+
+#[derive(Clone, Copy, Default)]
+pub struct Demand(yazi_fs::engine::Demand);
+
+impl FileBuilder for Demand {
+	type File = File;
+
+	fn append(&mut self, append: bool) -> &mut Self {
+		self.0.append = append;
+		self
+	}
+
+	fn attrs(&mut self, attrs: Attrs) -> &mut Self {
+		self.0.attrs = attrs;
+		self
+	}
+
+	fn create(&mut self, create: bool) -> &mut Self {
+		self.0.create = create;
+		self
+	}
+
+	fn create_new(&mut self, create_new: bool) -> &mut Self {
+		self.0.create_new = create_new;
+		self
+	}
+
+	async fn open<U>(&self, url: U) -> io::Result<Self::File>
+	where
+		U: AsUrl,
+	{
+		let engine = Ftp::new(url.as_url()).await?;
+		let Self(d) = *self;
+
+		// A transfer only happens once the file is read or written, so it's
+		// created or truncated here with an empty upload
+		let fresh = crate::engine::fresh_on_open(d, engine.symlink_metadata()).await?;
+		if fresh {
+			engine.write(b"").await?;
+		}
+		Ok(File::new(engine.config, engine.path.to_owned(), d.write || d.append, d.append))
+	}
+
+	fn read(&mut self, read: bool) -> &mut Self {
+		self.0.read = read;
+		self
+	}
+
+	fn truncate(&mut self, truncate: bool) -> &mut Self {
+		self.0.truncate = truncate;
+		self
+	}
+
+	fn write(&mut self, write: bool) -> &mut Self {
+		self.0.write = write;
+		self
+	}
+}
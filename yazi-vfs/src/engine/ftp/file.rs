@@ -0,0 +1,264 @@
+use std::{io::{self, SeekFrom}, pin::Pin, task::{Context, Poll, ready}};
+
+use deadpool::managed::Object;
+use tokio::{io::{AsyncRead, AsyncSeek, AsyncWrite, AsyncWriteExt, ReadBuf}, task::JoinHandle};
+use typed_path::UnixPathBuf;
+use yazi_config::vfs::ServiceFtp;
+
+use super::{Conn, Stream};
+
+/// A file on an FTP server, read with `RETR` and written with `STOR`, both
+/// restarted with `REST` at the current position when it's not zero.
+///
+/// Each transfer takes a connection of its own, which goes back to the pool
+/// once the transfer is over.
+pub struct File {
+	config: &'static ServiceFtp,
+	path:   UnixPathBuf,
+	write:  bool,
+	append: bool,
+
+	pos:   u64,
+	end:   Option<u64>,
+	state: State,
+}
+
+enum State {
+	Idle,
+	Opening(JoinHandle<io::Result<(Object<Conn>, Stream)>>),
+	Open(Box<(Object<Conn>, Stream)>),
+	Closing(JoinHandle<io::Result<()>>),
+	Closed,
+}
+
+impl File {
+	pub(super) fn new(
+		config: &'static ServiceFtp,
+		path: UnixPathBuf,
+		write: bool,
+		append: bool,
+	) -> Self {
+		Self { config, path, write, append, pos: 0, end: None, state: State::Idle }
+	}
+
+	pub fn set_read_end(&mut self, end: Option<u64>) { self.end = end; }
+
+	fn open(&self) -> JoinHandle<io::Result<(Object<Conn>, Stream)>> {
+		let (config, path, pos) = (self.config, self.path.clone(), self.pos);
+		let verb = self.verb();
+
+		tokio::spawn(async move {
+			let mut conn = Conn { config }.roll().await?;
+			let data = conn.transfer(verb, path.as_bytes(), if verb == "APPE" { 0 } else { pos }).await?;
+			Ok((conn, data))
+		})
+	}
+
+	fn verb(&self) -> &'static str {
+		match (self.write, self.append) {
+			(false, _) => "RETR",
+			(true, false) => "STOR",
+			(true, true) => "APPE",
+		}
+	}
+
+	/// Close the data connection of a transfer and wait for the server to
+	/// confirm it. For downloads that didn't reach the end, the confirmation is
+	/// an error saying it was aborted, which is only read to keep the
+	/// connection in sync.
+	async fn close(mut conn: Object<Conn>, mut data: Stream, verb: &'static str) -> io::Result<()> {
+		if verb != "RETR" {
+			data.shutdown().await?;
+		}
+		drop(data);
+		conn.finish(verb).await
+	}
+
+	/// Let an unfinished transfer wind down in the background, so its
+	/// connection can be reused.
+	fn detach(&mut self) {
+		if let State::Open(open) = std::mem::replace(&mut self.state, State::Idle) {
+			let (conn, data) = *open;
+			tokio::spawn(Self::close(conn, data, self.verb()));
+		}
+	}
+
+	fn poll_state(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+		loop {
+			match &mut self.state {
+				State::Idle => self.state = State::Opening(self.open()),
+				State::Opening(open) => match ready!(Pin::new(open).poll(cx)) {
+					Ok(Ok(open)) => self.state = State::Open(Box::new(open)),
+					Ok(Err(e)) => {
+						self.state = State::Idle;
+						return Poll::Ready(Err(e));
+					}
+					Err(e) => {
+						self.state = State::Idle;
+						return Poll::Ready(Err(io::Error::other(e)));
+					}
+				},
+				State::Open(..) => return Poll::Ready(Ok(())),
+				State::Closing(_) | State::Closed => {
+					return Poll::Ready(Err(io::ErrorKind::BrokenPipe.into()));
+				}
+			}
+		}
+	}
+
+	fn poll_close(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+		loop {
+			match std::mem::replace(&mut self.state, State::Closed) {
+				State::Open(open) => {
+					let (conn, data) = *open;
+					self.state = State::Closing(tokio::spawn(Self::close(conn, data, self.verb())));
+				}
+				State::Closing(mut close) => {
+					return match Pin::new(&mut close).poll(cx) {
+						Poll::Ready(r) => Poll::Ready(r.map_err(io::Error::other)?),
+						Poll::Pending => {
+							self.state = State::Closing(close);
+							Poll::Pending
+						}
+					};
+				}
+				State::Opening(open) => {
+					// Nothing has been transferred yet, so it's fine to give up on it
+					open.abort();
+					return Poll::Ready(Ok(()));
+				}
+				State::Idle | State::Closed => return Poll::Ready(Ok(())),
+			}
+		}
+	}
+}
+
+impl Drop for File {
+	fn drop(&mut self) { self.detach(); }
+}
+
+impl AsyncRead for File {
+	fn poll_read(
+		mut self: Pin<&mut Self>,
+		cx: &mut Context<'_>,
+		buf: &mut ReadBuf<'_>,
+	) -> Poll<io::Result<()>> {
+		if self.write {
+			return Poll::Ready(Err(io::Error::other("File not opened for reading")));
+		} else if buf.remaining() == 0 || matches!(self.state, State::Closed) {
+			return Poll::Ready(Ok(()));
+		}
+
+		let remaining = match self.end {
+			Some(end) if end <= self.pos => return Poll::Ready(Ok(())),
+			Some(end) => (end - self.pos).min(buf.remaining() as u64) as usize,
+			None => buf.remaining(),
+		};
+
+		ready!(self.poll_state(cx))?;
+		let me = &mut *self;
+		let State::Open(open) = &mut me.state else { unreachable!() };
+		let data = &mut open.1;
+
+		// Don't read past the end, as the next transfer would pick up from there
+		let result = if remaining < buf.remaining() {
+			let mut tmp = vec![0; remaining];
+			let mut tmp_buf = ReadBuf::new(&mut tmp);
+			let result = ready!(Pin::new(data).poll_read(cx, &mut tmp_buf));
+			buf.put_slice(tmp_buf.filled());
+			result.map(|()| tmp_buf.filled().len())
+		} else {
+			let before = buf.filled().len();
+			ready!(Pin::new(data).poll_read(cx, buf)).map(|()| buf.filled().len() - before)
+		};
+
+		match result {
+			Ok(0) => {}
+			Ok(n) => {
+				me.pos += n as u64;
+				return Poll::Ready(Ok(()));
+			}
+			// Not all servers close TLS data connections cleanly
+			Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => {}
+			Err(e) => {
+				me.state = State::Idle;
+				return Poll::Ready(Err(e));
+			}
+		}
+
+		// The server only tells whether the whole file was sent after it's done
+		me.poll_close(cx)
+	}
+}
+
+impl AsyncSeek for File {
+	fn start_seek(mut self: Pin<&mut Self>, position: SeekFrom) -> io::Result<()> {
+		let pos = match position {
+			SeekFrom::Start(n) => Some(n),
+			SeekFrom::Current(n) => self.pos.checked_add_signed(n),
+			SeekFrom::End(_) => {
+				Err(io::Error::new(io::ErrorKind::Unsupported, "Cannot seek from the end of an FTP file"))?
+			}
+		};
+
+		let Some(pos) = pos else {
+			return Err(io::Error::new(io::ErrorKind::InvalidInput, "Invalid seek position"));
+		};
+		if pos == self.pos {
+			return Ok(());
+		} else if self.append {
+			return Err(io::Error::new(io::ErrorKind::Unsupported, "Cannot seek in an FTP append"));
+		}
+
+		self.detach();
+		if let State::Opening(open) = std::mem::replace(&mut self.state, State::Idle) {
+			open.abort();
+		}
+
+		self.pos = pos;
+		Ok(())
+	}
+
+	fn poll_complete(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<u64>> {
+		Poll::Ready(Ok(self.pos))
+	}
+}
+
+impl AsyncWrite for File {
+	fn poll_write(
+		mut self: Pin<&mut Self>,
+		cx: &mut Context<'_>,
+		buf: &[u8],
+	) -> Poll<io::Result<usize>> {
+		if !self.write {
+			return Poll::Ready(Err(io::Error::other("File not opened for writing")));
+		}
+
+		ready!(self.poll_state(cx))?;
+		let me = &mut *self;
+		let State::Open(open) = &mut me.state else { unreachable!() };
+		let data = &mut open.1;
+
+		match ready!(Pin::new(data).poll_write(cx, buf)) {
+			Ok(n) => {
+				me.pos += n as u64;
+				Poll::Ready(Ok(n))
+			}
+			Err(e) => {
+				me.state = State::Idle;
+				Poll::Ready(Err(e))
+			}
+		}
+	}
+
+	fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+		match &mut self.state {
+			State::Open(open) => Pin::new(&mut open.1).poll_flush(cx),
+			_ => Poll::Ready(Ok(())),
+		}
+	}
+
+	fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+		self.poll_close(cx)
+	}
+}
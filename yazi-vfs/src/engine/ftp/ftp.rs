@@ -0,0 +1,203 @@
+use std::{io, sync::Arc};
+
+use deadpool::managed::Object;
+use tokio::{io::AsyncWriteExt, sync::mpsc::Receiver};
+use typed_path::{UnixPath, UnixPathBuf};
+use yazi_config::vfs::{ServiceFtp, Vfs};
+use yazi_fs::{cha::Cha, engine::{Capabilities, Engine}};
+use yazi_shared::{auth::AuthKind, loc::LocBuf, path::{DynPath, PathBufDyn}, strand::AsStrand, url::{AsUrl, Url, UrlBuf, UrlCow}};
+
+use super::{Conn, Entry};
+
+// Symlinks to symlinks are followed up to this many times
+const MAX_HOPS: usize = 8;
+
+#[derive(Clone)]
+pub struct Ftp<'a> {
+	url:             Url<'a>,
+	pub(super) path: &'a UnixPath,
+
+	pub(super) config: &'static ServiceFtp,
+}
+
+impl Ftp<'_> {
+	async fn conn(&self) -> io::Result<Object<Conn>> { Conn { config: self.config }.roll().await }
+
+	fn url_of(&self, path: UnixPathBuf) -> UrlBuf {
+		UrlBuf::Ftp {
+			loc:  LocBuf::<UnixPathBuf>::saturated(path, AuthKind::Ftp),
+			auth: self.config.auth.clone(),
+		}
+	}
+
+	/// The file at `path`, with symlinks followed as far as the server lets
+	/// their targets be known.
+	async fn follow(&self) -> io::Result<Entry> {
+		let mut conn = self.conn().await?;
+		let mut path = self.path.to_owned();
+		for _ in 0..MAX_HOPS {
+			let entry = conn.stat(&path).await?;
+			let Some(link) = entry.link.as_deref().filter(|_| entry.cha.is_link()) else {
+				return Ok(entry);
+			};
+
+			let parent = path.parent().unwrap_or(UnixPath::new("/")).to_owned();
+			path = parent.join(link).normalize();
+		}
+
+		Err(io::Error::other("Too many levels of symbolic links"))
+	}
+}
+
+impl<'a> Engine for Ftp<'a> {
+	type Demand = super::Demand;
+	type File = super::File;
+	type Me<'b> = Ftp<'b>;
+	type ReadDir = super::ReadDir;
+	type UrlCow = UrlCow<'a>;
+
+	async fn absolute(&self) -> io::Result<Self::UrlCow> {
+		Ok(if let Some(u) = crate::engine::try_absolute_impl(self.url) {
+			u
+		} else {
+			self.canonicalize().await?.into()
+		})
+	}
+
+	async fn canonicalize(&self) -> io::Result<UrlBuf> {
+		self.follow().await?;
+
+		let mut path = UnixPathBuf::from("/");
+		path.push(self.path);
+		Ok(self.url_of(path.normalize()))
+	}
+
+	async fn capabilities(&self) -> io::Result<Capabilities> {
+		Ok(Capabilities {
+			symlink: false,
+			hard_link: false,
+			copy_progressive: true,
+			..Default::default()
+		})
+	}
+
+	async fn casefold(&self) -> io::Result<UrlBuf> { crate::engine::casefold_impl(self.url).await }
+
+	/// FTP has no server-side copy, so the data goes through here.
+	async fn copy<P>(&self, to: P, attrs: yazi_fs::engine::Attrs) -> io::Result<u64>
+	where
+		P: DynPath,
+	{
+		let to = self.url_of(to.dyn_path().to_unix_owned()?);
+		crate::engine::copy_impl(self.url, to.as_url(), attrs).await
+	}
+
+	fn copy_progressive<P, A>(&self, to: P, attrs: A) -> io::Result<Receiver<io::Result<u64>>>
+	where
+		P: DynPath,
+		A: Into<yazi_fs::engine::Attrs>,
+	{
+		let to = self.url_of(to.dyn_path().to_unix_owned()?);
+		Ok(crate::engine::copy_progressive_impl(self.url.to_owned(), to, attrs.into()))
+	}
+
+	async fn create_dir(&self) -> io::Result<()> {
+		let mut conn = self.conn().await?;
+		match conn.mkdir(self.path).await {
+			Err(e) if e.kind() != io::ErrorKind::AlreadyExists && conn.stat(self.path).await.is_ok() => {
+				Err(io::ErrorKind::AlreadyExists.into())
+			}
+			r => r,
+		}
+	}
+
+	async fn hard_link<P>(&self, _to: P) -> io::Result<()>
+	where
+		P: DynPath,
+	{
+		Err(io::Error::new(io::ErrorKind::Unsupported, "FTP does not support hard links"))
+	}
+
+	async fn metadata(&self) -> io::Result<Cha> { Ok(self.follow().await?.cha) }
+
+	async fn new<'b>(url: Url<'b>) -> io::Result<Self::Me<'b>> {
+		let Url::Ftp { loc, auth } = url else {
+			return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("Not an FTP URL: {url:?}")));
+		};
+
+		let config = Vfs::service::<&ServiceFtp>(auth)?;
+		Ok(Self::Me { url, path: loc.as_inner(), config })
+	}
+
+	async fn read_dir(self) -> io::Result<Self::ReadDir> {
+		let entries = self.conn().await?.list(self.path).await?;
+		let entries: Vec<_> = entries.into_iter().map(|e| (self.path.join(&e.name), e.cha)).collect();
+
+		Ok(Self::ReadDir { dir: Arc::new(self.url.to_owned()), entries: entries.into_iter() })
+	}
+
+	async fn read_link(&self) -> io::Result<PathBufDyn> {
+		let entry = self.conn().await?.stat(self.path).await?;
+		match entry.link {
+			Some(link) if entry.cha.is_link() => Ok(UnixPathBuf::from(link).into()),
+			_ => Err(io::Error::new(io::ErrorKind::InvalidInput, "Not a symlink")),
+		}
+	}
+
+	async fn remove_dir(&self) -> io::Result<()> { self.conn().await?.rmdir(self.path).await }
+
+	async fn remove_file(&self) -> io::Result<()> { self.conn().await?.delete(self.path).await }
+
+	async fn rename<P>(&self, to: P) -> io::Result<()>
+	where
+		P: DynPath,
+	{
+		let to = to.dyn_path().as_unix()?;
+		self.conn().await?.rename(self.path, to).await
+	}
+
+	/// Permissions and the modification time are set with `SITE CHMOD` and
+	/// `MFMT`, if the server supports them.
+	async fn set_attrs(&self, attrs: yazi_fs::engine::Attrs) -> io::Result<()> {
+		let mut conn = self.conn().await?;
+		if let Some(mode) = attrs.mode {
+			conn.chmod(self.path, mode.bits() & 0o7777).await.ok();
+		}
+		if let Some(mtime) = attrs.mtime {
+			conn.set_mtime(self.path, mtime).await.ok();
+		}
+		Ok(())
+	}
+
+	async fn symlink<S, F>(&self, _original: S, _is_dir: F) -> io::Result<()>
+	where
+		S: AsStrand,
+		F: AsyncFnOnce() -> io::Result<bool>,
+	{
+		Err(io::Error::new(io::ErrorKind::Unsupported, "FTP does not support creating symlinks"))
+	}
+
+	async fn symlink_metadata(&self) -> io::Result<Cha> {
+		Ok(self.conn().await?.stat(self.path).await?.cha)
+	}
+
+	async fn trash(&self) -> io::Result<()> {
+		Err(io::Error::new(io::ErrorKind::Unsupported, "FTP does not support trash"))
+	}
+
+	#[inline]
+	fn url(&self) -> Url<'_> { self.url }
+
+	async fn write<C>(&self, contents: C) -> io::Result<()>
+	where
+		C: AsRef<[u8]>,
+	{
+		let mut conn = self.conn().await?;
+		let mut data = conn.transfer("STOR", self.path.as_bytes(), 0).await?;
+
+		data.write_all(contents.as_ref()).await?;
+		data.shutdown().await?;
+		drop(data);
+		conn.finish("STOR").await
+	}
+}
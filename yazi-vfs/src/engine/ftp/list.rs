@@ -0,0 +1,385 @@
+use std::time::SystemTime;
+
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use yazi_fs::cha::{Cha, ChaKind, ChaMode};
+
+const MONTHS: [&[u8]; 12] =
+	[b"jan", b"feb", b"mar", b"apr", b"may", b"jun", b"jul", b"aug", b"sep", b"oct", b"nov", b"dec"];
+
+// --- Entry
+pub(super) struct Entry {
+	pub(super) name: Vec<u8>,
+	pub(super) cha:  Cha,
+	pub(super) link: Option<Vec<u8>>,
+}
+
+impl Entry {
+	/// Parse a directory listing, either from `MLSD` or the free-form `LIST`,
+	/// leaving out the directory itself and its parent.
+	pub(super) fn parse(data: &[u8], mlsd: bool) -> Vec<Self> {
+		data
+			.split(|&b| b == b'\n')
+			.map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+			.filter(|line| !line.is_empty())
+			.filter_map(|line| if mlsd { Self::mlsx(line, true) } else { Self::list(line) })
+			.filter(|e| e.name != b"." && e.name != b"..")
+			.collect()
+	}
+
+	/// Parse a line of facts from `MLSD`, or `MLST` if not `listing`, like
+	/// `type=file;size=42;modify=20250101120000; name`.
+	pub(super) fn mlsx(line: &[u8], listing: bool) -> Option<Self> {
+		let sp = line.iter().position(|&b| b == b' ')?;
+		let (facts, name) = (&line[..sp], &line[sp + 1..]);
+
+		let (mut mode, mut perm, mut link) = (ChaMode::T_FILE, None, None);
+		let (mut len, mut mtime, mut uid, mut gid) = (0, None, 0, 0);
+		for fact in facts.split(|&b| b == b';').filter(|f| !f.is_empty()) {
+			let Some(eq) = fact.iter().position(|&b| b == b'=') else { continue };
+			let (key, value) = (fact[..eq].to_ascii_lowercase(), &fact[eq + 1..]);
+
+			match key.as_slice() {
+				b"type" => {
+					let lower = value.to_ascii_lowercase();
+					match lower.as_slice() {
+						b"file" => mode = ChaMode::T_FILE,
+						b"dir" => mode = ChaMode::T_DIR,
+						b"cdir" | b"pdir" if listing => return None,
+						b"cdir" | b"pdir" => mode = ChaMode::T_DIR,
+						_ if lower.starts_with(b"os.unix=slink") || lower.starts_with(b"os.unix=symlink") => {
+							mode = ChaMode::T_LINK;
+							link = value.iter().position(|&b| b == b':').map(|i| value[i + 1..].to_vec());
+						}
+						_ => {}
+					}
+				}
+				b"size" => len = parse_num(value, 10).unwrap_or(0),
+				b"modify" => mtime = parse_timestamp(value),
+				b"unix.mode" => perm = parse_num(value, 8).map(|n| n as u16 & 0o7777),
+				b"unix.uid" => uid = parse_num(value, 10).unwrap_or(0) as u32,
+				b"unix.gid" => gid = parse_num(value, 10).unwrap_or(0) as u32,
+				_ => {}
+			}
+		}
+
+		// `MLST` may give the full path rather than just the name
+		let name = if listing { name } else { name.rsplit(|&b| b == b'/').next().unwrap_or(name) };
+		Some(Self::new(name, mode, perm, len, mtime, uid, gid).with_link(link))
+	}
+
+	/// Parse a line of `LIST` output, in either the Unix `ls -l` format or the
+	/// one of Windows servers.
+	fn list(line: &[u8]) -> Option<Self> {
+		if line.first().is_some_and(u8::is_ascii_digit) { Self::dos(line) } else { Self::unix(line) }
+	}
+
+	/// `drwxr-xr-x 2 user group 4096 Jan  1 12:00 name`, where the group, and
+	/// for some servers the link count, may be missing.
+	fn unix(line: &[u8]) -> Option<Self> {
+		let tokens = tokens(line, 9);
+		let perms = line.get(tokens.first()?.clone())?;
+		if perms.len() < 10 {
+			return None;
+		}
+
+		// Everything between the permissions and the month is optional except the size
+		let month = (2..tokens.len().min(6)).find(|&i| month(&line[tokens[i].clone()]).is_some())?;
+		let (day, time) = (tokens.get(month + 1)?, tokens.get(month + 2)?);
+		let name = line.get(tokens.get(month + 3)?.start..)?;
+
+		let len = parse_num(&line[tokens[month - 1].clone()], 10).unwrap_or(0);
+		let mtime = parse_list_time(
+			month_of(&line[tokens[month].clone()])?,
+			parse_num(&line[day.clone()], 10)? as u32,
+			&line[time.clone()],
+		);
+
+		let (mode, perm) = parse_perms(perms);
+		let (name, link) = if mode == ChaMode::T_LINK {
+			match name.windows(4).position(|w| w == b" -> ") {
+				Some(i) => (&name[..i], Some(name[i + 4..].to_vec())),
+				None => (name, None),
+			}
+		} else {
+			(name, None)
+		};
+
+		let nlink = if month >= 4 { parse_num(&line[tokens[1].clone()], 10).unwrap_or(0) } else { 0 };
+		let mut entry = Self::new(name, mode, Some(perm), len, mtime, 0, 0).with_link(link);
+		entry.cha.nlink = nlink;
+		Some(entry)
+	}
+
+	/// `01-31-25  09:15PM       <DIR>          name`
+	fn dos(line: &[u8]) -> Option<Self> {
+		let tokens = tokens(line, 4);
+		let (date, time, size) = (&line[tokens.first()?.clone()], tokens.get(1)?, tokens.get(2)?);
+		let name = line.get(tokens.get(3)?.start..)?;
+
+		let mut parts = date.split(|&b| b == b'-' || b == b'/').map(|s| parse_num(s, 10));
+		let (m, d, y) = (parts.next()??, parts.next()??, parts.next()??);
+		let y = if y < 70 {
+			y + 2000
+		} else if y < 100 {
+			y + 1900
+		} else {
+			y
+		};
+
+		let time = std::str::from_utf8(&line[time.clone()]).ok()?;
+		let time = NaiveTime::parse_from_str(time, "%I:%M%p").unwrap_or_default();
+		let mtime = NaiveDate::from_ymd_opt(y as i32, m as u32, d as u32)
+			.map(|d| NaiveDateTime::new(d, time).and_utc().into());
+
+		let size = &line[size.clone()];
+		Some(if size.eq_ignore_ascii_case(b"<DIR>") {
+			Self::new(name, ChaMode::T_DIR, None, 0, mtime, 0, 0)
+		} else {
+			Self::new(name, ChaMode::T_FILE, None, parse_num(size, 10)?, mtime, 0, 0)
+		})
+	}
+
+	fn new(
+		name: &[u8],
+		mode: ChaMode,
+		perm: Option<u16>,
+		len: u64,
+		mtime: Option<SystemTime>,
+		uid: u32,
+		gid: u32,
+	) -> Self {
+		// Assume everything is accessible if the server doesn't say otherwise
+		let perm = perm.unwrap_or(if mode == ChaMode::T_DIR { 0o755 } else { 0o644 });
+
+		Self {
+			name: name.to_vec(),
+			cha:  Cha {
+				kind: if name.starts_with(b".") { ChaKind::HIDDEN } else { ChaKind::empty() },
+				mode: mode | ChaMode::from_bits_retain(perm),
+				len: if mode == ChaMode::T_DIR { 0 } else { len },
+				atime: None,
+				btime: None,
+				ctime: None,
+				mtime,
+				dev: 0,
+				uid,
+				gid,
+				nlink: 0,
+			},
+			link: None,
+		}
+	}
+
+	/// The root directory, which can't be found by listing its parent.
+	pub(super) fn root() -> Self { Self::new(b"", ChaMode::T_DIR, None, 0, None, 0, 0) }
+
+	fn with_link(mut self, link: Option<Vec<u8>>) -> Self {
+		self.link = link.filter(|l| !l.is_empty());
+		self
+	}
+}
+
+/// Byte ranges of the first `n` whitespace-separated tokens of `line`.
+fn tokens(line: &[u8], n: usize) -> Vec<std::ops::Range<usize>> {
+	let mut tokens = Vec::with_capacity(n);
+	let mut i = 0;
+	while tokens.len() < n {
+		while line.get(i).is_some_and(u8::is_ascii_whitespace) {
+			i += 1;
+		}
+		if i >= line.len() {
+			break;
+		}
+
+		let start = i;
+		while line.get(i).is_some_and(|b| !b.is_ascii_whitespace()) {
+			i += 1;
+		}
+		tokens.push(start..i);
+	}
+	tokens
+}
+
+fn parse_num(s: &[u8], radix: u32) -> Option<u64> {
+	u64::from_str_radix(std::str::from_utf8(s).ok()?, radix).ok()
+}
+
+/// `YYYYMMDDHHMMSS[.sss]` in UTC, as used by `MLSx` and `MDTM`.
+pub(super) fn parse_timestamp(s: &[u8]) -> Option<SystemTime> {
+	let s = std::str::from_utf8(s.get(..14)?).ok()?;
+	NaiveDateTime::parse_from_str(s, "%Y%m%d%H%M%S").ok().map(|t| t.and_utc().into())
+}
+
+fn month(s: &[u8]) -> Option<usize> { MONTHS.iter().position(|m| s.eq_ignore_ascii_case(m)) }
+
+fn month_of(s: &[u8]) -> Option<u32> { month(s).map(|i| i as u32 + 1) }
+
+/// Either `HH:MM` within the last half year, or the year for older files.
+fn parse_list_time(month: u32, day: u32, time: &[u8]) -> Option<SystemTime> {
+	let now = Utc::now().naive_utc();
+	let (year, time) = match std::str::from_utf8(time).ok()? {
+		t if t.contains(':') => (now.year(), NaiveTime::parse_from_str(t, "%H:%M").ok()?),
+		t => (t.parse().ok()?, NaiveTime::MIN),
+	};
+
+	let mut dt = NaiveDateTime::new(NaiveDate::from_ymd_opt(year, month, day)?, time);
+	if dt > now + chrono::Duration::days(1) {
+		dt = dt.with_year(year - 1)?;
+	}
+	Some(dt.and_utc().into())
+}
+
+fn parse_perms(s: &[u8]) -> (ChaMode, u16) {
+	let mode = match s[0] {
+		b'd' => ChaMode::T_DIR,
+		b'l' => ChaMode::T_LINK,
+		_ => ChaMode::T_FILE,
+	};
+
+	let mut perm = 0;
+	for (i, &c) in s[1..10].iter().enumerate() {
+		let bit = 1 << (8 - i);
+		perm |= match c {
+			b'-' | b'S' | b'T' => 0,
+			_ => bit,
+		};
+	}
+	for (i, special) in [(3, 0o4000), (6, 0o2000), (9, 0o1000)] {
+		if matches!(s[i], b's' | b'S' | b't' | b'T') {
+			perm |= special;
+		}
+	}
+	(mode, perm)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn utc(y: i32, m: u32, d: u32, h: u32, min: u32) -> Option<SystemTime> {
+		let date = NaiveDate::from_ymd_opt(y, m, d)?;
+		Some(NaiveDateTime::new(date, NaiveTime::from_hms_opt(h, min, 0)?).and_utc().into())
+	}
+
+	#[test]
+	fn test_mlsd() {
+		let data = b"type=cdir;modify=20250101120000; .\r\n\
+			type=pdir;modify=20250101120000; ..\r\n\
+			type=file;size=42;modify=20250102030405.678;UNIX.mode=0640;UNIX.uid=1000;UNIX.gid=100; a b.txt\r\n\
+			Type=DIR;Modify=20240229235959; .config\r\n\
+			type=OS.unix=slink:/etc/hosts;size=10; hosts\r\n\
+			\r\n\
+			no-facts-here\r\n";
+
+		let entries = Entry::parse(data, true);
+		assert_eq!(entries.len(), 3);
+
+		let (a, config, hosts) = (&entries[0], &entries[1], &entries[2]);
+		assert_eq!(a.name, b"a b.txt");
+		assert_eq!(a.cha.mode, ChaMode::T_FILE | ChaMode::from_bits_retain(0o640));
+		assert_eq!((a.cha.len, a.cha.uid, a.cha.gid), (42, 1000, 100));
+		assert_eq!(
+			a.cha.mtime,
+			SystemTime::UNIX_EPOCH.checked_add(std::time::Duration::from_secs(1735787045))
+		);
+
+		assert_eq!(config.name, b".config");
+		assert_eq!(config.cha.mode, ChaMode::T_DIR | ChaMode::from_bits_retain(0o755));
+		assert!(config.cha.kind.contains(ChaKind::HIDDEN));
+		assert_eq!(
+			config.cha.mtime,
+			utc(2024, 2, 29, 23, 59).map(|t| t + std::time::Duration::from_secs(59))
+		);
+
+		assert_eq!(hosts.cha.mode & ChaMode::T_MASK, ChaMode::T_LINK);
+		assert_eq!(hosts.link.as_deref(), Some(&b"/etc/hosts"[..]));
+	}
+
+	#[test]
+	fn test_mlst() {
+		let cdir = Entry::mlsx(b"type=cdir;modify=20250101120000; /home/user", false).unwrap();
+		assert_eq!(cdir.name, b"user");
+		assert_eq!(cdir.cha.mode & ChaMode::T_MASK, ChaMode::T_DIR);
+	}
+
+	#[test]
+	fn test_unix() {
+		let data = b"total 12\n\
+			drwxr-xr-x   2 user     group        4096 Jan 01  2020 .\n\
+			-rw-r--r--   1 user     group        1234 Mar 15  2021 notes.txt\n\
+			lrwxrwxrwx   1 user     group          11 Mar 15  2021 link -> target file\n\
+			-rwsr-sr-T   1 user     group         100 Dec 31  1999 odd name  with spaces\n\
+			drwxr-xr-x   folder       0 Feb 28  2022 no-nlink\n";
+
+		let entries = Entry::parse(data, false);
+		assert_eq!(entries.len(), 4);
+
+		let notes = &entries[0];
+		assert_eq!(notes.name, b"notes.txt");
+		assert_eq!(notes.cha.mode, ChaMode::T_FILE | ChaMode::from_bits_retain(0o644));
+		assert_eq!((notes.cha.len, notes.cha.nlink), (1234, 1));
+		assert_eq!(notes.cha.mtime, utc(2021, 3, 15, 0, 0));
+
+		let link = &entries[1];
+		assert_eq!(link.name, b"link");
+		assert_eq!(link.link.as_deref(), Some(&b"target file"[..]));
+
+		let odd = &entries[2];
+		assert_eq!(odd.name, b"odd name  with spaces");
+		assert_eq!(odd.cha.mode, ChaMode::T_FILE | ChaMode::from_bits_retain(0o7754));
+
+		let dir = &entries[3];
+		assert_eq!(dir.name, b"no-nlink");
+		assert_eq!(
+			(dir.cha.mode & ChaMode::T_MASK, dir.cha.len, dir.cha.nlink),
+			(ChaMode::T_DIR, 0, 0)
+		);
+	}
+
+	#[test]
+	fn test_dos() {
+		let data = b"01-31-25  09:15PM       <DIR>          Program Files\r\n\
+			12-01-99  12:00AM                 2048 old.dat\r\n\
+			2024/02/29  07:05AM              7 leap.txt\r\n";
+
+		let entries = Entry::parse(data, false);
+		assert_eq!(entries.len(), 3);
+
+		assert_eq!(entries[0].name, b"Program Files");
+		assert_eq!(entries[0].cha.mode & ChaMode::T_MASK, ChaMode::T_DIR);
+		assert_eq!(entries[0].cha.mtime, utc(2025, 1, 31, 21, 15));
+
+		assert_eq!((entries[1].cha.len, entries[1].cha.mtime), (2048, utc(1999, 12, 1, 0, 0)));
+		assert_eq!(entries[2].cha.mtime, None);
+	}
+
+	#[test]
+	fn test_list_time() {
+		assert_eq!(parse_list_time(3, 15, b"2021"), utc(2021, 3, 15, 0, 0));
+		assert_eq!(parse_list_time(2, 30, b"2021"), None);
+		assert_eq!(parse_list_time(1, 1, b"noon"), None);
+
+		// Without a year it's the latest such date that isn't in the future
+		let now = Utc::now();
+		let recent = parse_list_time(now.month(), now.day(), b"00:00").unwrap();
+		assert_eq!(recent, utc(now.year(), now.month(), now.day(), 0, 0).unwrap());
+
+		let mut ahead = now + chrono::Duration::days(3);
+		if (ahead.month(), ahead.day()) == (2, 29) {
+			// No counterpart last year
+			ahead += chrono::Duration::days(1);
+		}
+		let last = parse_list_time(ahead.month(), ahead.day(), b"12:30").unwrap();
+		assert!(last < SystemTime::now());
+		assert!(SystemTime::now().duration_since(last).unwrap().as_secs() > 300 * 86400);
+	}
+
+	#[test]
+	fn test_perms() {
+		assert_eq!(parse_perms(b"drwxr-x---"), (ChaMode::T_DIR, 0o750));
+		assert_eq!(parse_perms(b"lrwxrwxrwx"), (ChaMode::T_LINK, 0o777));
+		assert_eq!(parse_perms(b"-rwsr-xr-x"), (ChaMode::T_FILE, 0o4755));
+		assert_eq!(parse_perms(b"-rwSr-Sr-T"), (ChaMode::T_FILE, 0o7644));
+		assert_eq!(parse_perms(b"prw-rw-rwt+"), (ChaMode::T_FILE, 0o1667));
+	}
+}
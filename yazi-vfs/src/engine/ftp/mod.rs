@@ -0,0 +1,21 @@
+yazi_macro::mod_flat!(client conn demand file ftp list read_dir stream tls);
+
+static CONN: yazi_shim::cell::RoCell<
+	parking_lot::Mutex<
+		hashbrown::HashMap<
+			&'static yazi_config::vfs::ServiceFtp,
+			&'static deadpool::managed::Pool<Conn>,
+		>,
+	>,
+> = yazi_shim::cell::RoCell::new();
+
+static TLS: yazi_shim::cell::RoCell<
+	parking_lot::Mutex<
+		hashbrown::HashMap<&'static yazi_config::vfs::ServiceFtp, std::sync::Arc<rustls::ClientConfig>>,
+	>,
+> = yazi_shim::cell::RoCell::new();
+
+pub(super) fn init() {
+	CONN.init(Default::default());
+	TLS.init(Default::default());
+}
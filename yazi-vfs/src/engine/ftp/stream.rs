@@ -0,0 +1,50 @@
+use std::{io, pin::Pin, task::{Context, Poll}};
+
+use tokio::{io::{AsyncRead, AsyncWrite, ReadBuf}, net::TcpStream};
+use tokio_rustls::client::TlsStream;
+
+/// A control or data connection, which may have been secured with TLS.
+pub(super) enum Stream {
+	Tcp(TcpStream),
+	Tls(Box<TlsStream<TcpStream>>),
+}
+
+impl AsyncRead for Stream {
+	fn poll_read(
+		mut self: Pin<&mut Self>,
+		cx: &mut Context<'_>,
+		buf: &mut ReadBuf<'_>,
+	) -> Poll<io::Result<()>> {
+		match &mut *self {
+			Self::Tcp(s) => Pin::new(s).poll_read(cx, buf),
+			Self::Tls(s) => Pin::new(s).poll_read(cx, buf),
+		}
+	}
+}
+
+impl AsyncWrite for Stream {
+	fn poll_write(
+		mut self: Pin<&mut Self>,
+		cx: &mut Context<'_>,
+		buf: &[u8],
+	) -> Poll<io::Result<usize>> {
+		match &mut *self {
+			Self::Tcp(s) => Pin::new(s).poll_write(cx, buf),
+			Self::Tls(s) => Pin::new(s).poll_write(cx, buf),
+		}
+	}
+
+	fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+		match &mut *self {
+			Self::Tcp(s) => Pin::new(s).poll_flush(cx),
+			Self::Tls(s) => Pin::new(s).poll_flush(cx),
+		}
+	}
+
+	fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+		match &mut *self {
+			Self::Tcp(s) => Pin::new(s).poll_shutdown(cx),
+			Self::Tls(s) => Pin::new(s).poll_shutdown(cx),
+		}
+	}
+}
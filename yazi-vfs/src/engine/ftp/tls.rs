@@ -0,0 +1,93 @@
+use std::{io, sync::Arc};
+
+use rustls::{ClientConfig, DigitallySignedStruct, SignatureScheme, client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier}, crypto::CryptoProvider, pki_types::{CertificateDer, ServerName, UnixTime}};
+use rustls_platform_verifier::ConfigVerifierExt;
+use tokio::net::TcpStream;
+use tokio_rustls::{TlsConnector, client::TlsStream};
+use yazi_config::vfs::ServiceFtp;
+
+/// Secure `tcp` for the FTP service `config`.
+///
+/// All connections to the same service share a [`ClientConfig`], so data
+/// connections can resume the TLS session of the control connection, which
+/// many servers insist on.
+pub(super) async fn connect(
+	config: &'static ServiceFtp,
+	tcp: TcpStream,
+) -> io::Result<TlsStream<TcpStream>> {
+	let name = ServerName::try_from(config.host.clone())
+		.map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+	TlsConnector::from(client_config(config)?).connect(name, tcp).await
+}
+
+fn client_config(config: &'static ServiceFtp) -> io::Result<Arc<ClientConfig>> {
+	if let Some(c) = super::TLS.lock().get(config) {
+		return Ok(c.clone());
+	}
+
+	let c = Arc::new(if config.no_cert_verify {
+		let provider = CryptoProvider::get_default()
+			.cloned()
+			.ok_or_else(|| io::Error::other("No TLS crypto provider installed"))?;
+
+		ClientConfig::builder()
+			.dangerous()
+			.with_custom_certificate_verifier(Arc::new(NoVerify(provider)))
+			.with_no_client_auth()
+	} else {
+		ClientConfig::with_platform_verifier().map_err(io::Error::other)?
+	});
+
+	Ok(super::TLS.lock().entry(config).or_insert(c).clone())
+}
+
+// --- NoVerify
+/// Accepts any certificate, while still checking the handshake signatures.
+#[derive(Debug)]
+struct NoVerify(Arc<CryptoProvider>);
+
+impl ServerCertVerifier for NoVerify {
+	fn verify_server_cert(
+		&self,
+		_end_entity: &CertificateDer<'_>,
+		_intermediates: &[CertificateDer<'_>],
+		_server_name: &ServerName<'_>,
+		_ocsp_response: &[u8],
+		_now: UnixTime,
+	) -> Result<ServerCertVerified, rustls::Error> {
+		Ok(ServerCertVerified::assertion())
+	}
+
+	fn verify_tls12_signature(
+		&self,
+		message: &[u8],
+		cert: &CertificateDer<'_>,
+		dss: &DigitallySignedStruct,
+	) -> Result<HandshakeSignatureValid, rustls::Error> {
+		rustls::crypto::verify_tls12_signature(
+			message,
+			cert,
+			dss,
+			&self.0.signature_verification_algorithms,
+		)
+	}
+
+	fn verify_tls13_signature(
+		&self,
+		message: &[u8],
+		cert: &CertificateDer<'_>,
+		dss: &DigitallySignedStruct,
+	) -> Result<HandshakeSignatureValid, rustls::Error> {
+		rustls::crypto::verify_tls13_signature(
+			message,
+			cert,
+			dss,
+			&self.0.signature_verification_algorithms,
+		)
+	}
+
+	fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+		self.0.signature_verification_algorithms.supported_schemes()
+	}
+}
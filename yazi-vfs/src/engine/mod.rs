@@ -1,5 +1,11 @@
-yazi_macro::mod_pub!(lua sftp);
+yazi_macro::mod_pub!(ftp lua s3 sftp webdav);
 
-yazi_macro::mod_flat!(absolute calculator copier dir_entry demand engine engines read_dir rw_file);
+yazi_macro::mod_flat!(absolute calculator casefold copier dir_entry demand engine engines ranged read_dir rw_file throttle);
 
-pub(super) fn init() { sftp::init(); }
+pub(super) fn init() {
+	ftp::init();
+	s3::init();
+	sftp::init();
+	webdav::init();
+	throttle::init();
+}
@@ -0,0 +1,245 @@
+use std::{io::{self, SeekFrom}, pin::Pin, task::{Context, Poll, ready}};
+
+use bytes::Bytes;
+use futures::{Sink, channel::mpsc as chan};
+use reqwest::{Response, StatusCode, header::{self, HeaderMap, HeaderValue}};
+use tokio::{io::ReadBuf, sync::mpsc, task::JoinHandle};
+
+/// The position and state of a file on an HTTP-based service, which is read
+/// with ranged `GET`s and written by streaming the whole content to a request.
+///
+/// Since that request replaces the whole file, writes have to start at the
+/// beginning and be sequential.
+pub(super) struct Ranged {
+	pub(super) write: bool,
+	// Whether to send the request on shutdown even if nothing was written
+	fresh:            bool,
+
+	pub(super) pos: u64,
+	pub(super) end: Option<u64>,
+	state:          State,
+}
+
+enum State {
+	Idle,
+	Reading(mpsc::Receiver<io::Result<Bytes>>, Bytes),
+	Writing(chan::Sender<Bytes>, JoinHandle<io::Result<()>>),
+	Closing(JoinHandle<io::Result<()>>),
+	Closed,
+}
+
+impl Ranged {
+	pub(super) fn new(write: bool, fresh: bool) -> Self {
+		Self { write, fresh, pos: 0, end: None, state: State::Idle }
+	}
+
+	pub(super) fn is_idle(&self) -> bool { matches!(self.state, State::Idle) }
+
+	/// The `Range` header to read from the current position with.
+	pub(super) fn range(&self) -> HeaderMap {
+		let (pos, mut headers) = (self.pos, HeaderMap::new());
+		let range = match self.end {
+			Some(end) => format!("bytes={pos}-{}", end.saturating_sub(1)),
+			None if pos > 0 => format!("bytes={pos}-"),
+			None => return headers,
+		};
+
+		headers.insert(header::RANGE, HeaderValue::from_str(&range).unwrap());
+		headers
+	}
+
+	/// Stream the body of the response to a `GET` sent with [`Self::range`].
+	pub(super) fn get<F>(&self, resp: F) -> mpsc::Receiver<io::Result<Bytes>>
+	where
+		F: Future<Output = io::Result<Response>> + Send + 'static,
+	{
+		let (tx, rx) = mpsc::channel(4);
+		let pos = self.pos;
+
+		tokio::spawn(async move {
+			let mut resp = match resp.await {
+				Ok(resp) => resp,
+				// Reading past the end of the file
+				Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return,
+				Err(e) => {
+					tx.send(Err(e)).await.ok();
+					return;
+				}
+			};
+
+			// The server may ignore the range and send the whole file
+			let mut skip = if resp.status() == StatusCode::PARTIAL_CONTENT { 0 } else { pos };
+			loop {
+				let mut chunk = match resp.chunk().await {
+					Ok(Some(chunk)) => chunk,
+					Ok(None) => break,
+					Err(e) => {
+						tx.send(Err(io::Error::other(e))).await.ok();
+						return;
+					}
+				};
+
+				let n = skip.min(chunk.len() as u64);
+				(chunk, skip) = (chunk.split_off(n as usize), skip - n);
+				if !chunk.is_empty() && tx.send(Ok(chunk)).await.is_err() {
+					break;
+				}
+			}
+		});
+
+		rx
+	}
+
+	/// Read from the current position, starting a `GET` with `get` if there's
+	/// none in flight.
+	pub(super) fn poll_read(
+		&mut self,
+		cx: &mut Context<'_>,
+		buf: &mut ReadBuf<'_>,
+		get: impl FnOnce(&Self) -> mpsc::Receiver<io::Result<Bytes>>,
+	) -> Poll<io::Result<()>> {
+		if self.write {
+			return Poll::Ready(Err(io::Error::other("File not opened for reading")));
+		} else if self.end.is_some_and(|end| self.pos >= end) {
+			return Poll::Ready(Ok(()));
+		}
+
+		if self.is_idle() {
+			self.state = State::Reading(get(self), Bytes::new());
+		}
+
+		let State::Reading(rx, pending) = &mut self.state else {
+			return Poll::Ready(Err(io::Error::other("File is closed")));
+		};
+
+		while pending.is_empty() {
+			match ready!(rx.poll_recv(cx)) {
+				Some(Ok(chunk)) => *pending = chunk,
+				Some(Err(e)) => {
+					self.state = State::Idle;
+					return Poll::Ready(Err(e));
+				}
+				None => return Poll::Ready(Ok(())),
+			}
+		}
+
+		let n = pending.len().min(buf.remaining());
+		buf.put_slice(&pending.split_to(n));
+		self.pos += n as u64;
+		Poll::Ready(Ok(()))
+	}
+
+	/// Move the read position, `what` being the kind of file for the errors.
+	pub(super) fn start_seek(&mut self, position: SeekFrom, what: &str) -> io::Result<()> {
+		let pos = match position {
+			SeekFrom::Start(n) => Some(n),
+			SeekFrom::Current(n) => self.pos.checked_add_signed(n),
+			SeekFrom::End(_) => Err(io::Error::new(
+				io::ErrorKind::Unsupported,
+				format!("Cannot seek from the end of {what}"),
+			))?,
+		};
+
+		let Some(pos) = pos else {
+			return Err(io::Error::new(io::ErrorKind::InvalidInput, "Invalid seek position"));
+		};
+		if pos == self.pos {
+			return Ok(());
+		} else if self.write {
+			return Err(io::Error::new(io::ErrorKind::Unsupported, format!("Cannot seek in {what}")));
+		}
+
+		self.pos = pos;
+		self.state = State::Idle;
+		Ok(())
+	}
+
+	/// Queue `buf` for the request started with `start` on the first write.
+	pub(super) fn poll_write(
+		&mut self,
+		cx: &mut Context<'_>,
+		buf: &[u8],
+		start: impl FnOnce(chan::Receiver<Bytes>) -> JoinHandle<io::Result<()>>,
+	) -> Poll<io::Result<usize>> {
+		if !self.write {
+			return Poll::Ready(Err(io::Error::other("File not opened for writing")));
+		}
+
+		if self.is_idle() {
+			let (tx, rx) = chan::channel(4);
+			self.state = State::Writing(tx, start(rx));
+		}
+
+		loop {
+			match &mut self.state {
+				State::Writing(tx, _) => match ready!(Pin::new(&mut *tx).poll_ready(cx)) {
+					Ok(()) => {
+						Pin::new(tx).start_send(Bytes::copy_from_slice(buf)).ok();
+						self.pos += buf.len() as u64;
+						return Poll::Ready(Ok(buf.len()));
+					}
+					// The request is over, find out why
+					Err(_) => {
+						let State::Writing(_, task) = std::mem::replace(&mut self.state, State::Closed) else {
+							unreachable!()
+						};
+						self.state = State::Closing(task);
+					}
+				},
+				State::Closing(task) => {
+					let result = ready!(Self::poll_task(task, cx));
+					self.state = State::Closed;
+					result?;
+					return Poll::Ready(Err(io::ErrorKind::BrokenPipe.into()));
+				}
+				State::Idle | State::Reading(..) | State::Closed => {
+					return Poll::Ready(Err(io::ErrorKind::BrokenPipe.into()));
+				}
+			}
+		}
+	}
+
+	/// End the content and wait for the request to finish.
+	pub(super) fn poll_shutdown(
+		&mut self,
+		cx: &mut Context<'_>,
+		start: impl FnOnce(chan::Receiver<Bytes>) -> JoinHandle<io::Result<()>>,
+	) -> Poll<io::Result<()>> {
+		let mut start = Some(start);
+		loop {
+			match std::mem::replace(&mut self.state, State::Closed) {
+				// Nothing was written, but the file still has to be created
+				State::Idle if self.write && self.fresh => {
+					let (_, rx) = chan::channel(0);
+					self.state = State::Closing(start.take().unwrap()(rx));
+				}
+				// Dropping the sender ends the content
+				State::Writing(_, task) => self.state = State::Closing(task),
+				State::Closing(mut task) => {
+					let result = Self::poll_task(&mut task, cx);
+					if result.is_pending() {
+						self.state = State::Closing(task);
+					}
+					return result;
+				}
+				State::Idle | State::Reading(..) | State::Closed => return Poll::Ready(Ok(())),
+			}
+		}
+	}
+
+	fn poll_task(
+		task: &mut JoinHandle<io::Result<()>>,
+		cx: &mut Context<'_>,
+	) -> Poll<io::Result<()>> {
+		Poll::Ready(ready!(Pin::new(task).poll(cx)).map_err(io::Error::other)?)
+	}
+}
+
+impl Drop for Ranged {
+	// An unfinished write must not be completed with partial content
+	fn drop(&mut self) {
+		if let State::Writing(_, task) | State::Closing(task) = &self.state {
+			task.abort();
+		}
+	}
+}
@@ -6,6 +6,9 @@ pub enum ReadDir {
 	Local(yazi_fs::engine::local::ReadDir),
 	Lua(super::lua::ReadDir),
 	Sftp(super::sftp::ReadDir),
+	Webdav(super::webdav::ReadDir),
+	S3(super::s3::ReadDir),
+	Ftp(super::ftp::ReadDir),
 }
 
 impl DirReader for ReadDir {
@@ -16,6 +19,9 @@ impl DirReader for ReadDir {
 			Self::Local(reader) => reader.next().await?.map(Self::Entry::Local),
 			Self::Lua(reader) => reader.next().await?.map(Self::Entry::Lua),
 			Self::Sftp(reader) => reader.next().await?.map(Self::Entry::Sftp),
+			Self::Webdav(reader) => reader.next().await?.map(Self::Entry::Webdav),
+			Self::S3(reader) => reader.next().await?.map(Self::Entry::S3),
+			Self::Ftp(reader) => reader.next().await?.map(Self::Entry::Ftp),
 		})
 	}
 }
@@ -2,8 +2,8 @@ use std::{io, pin::Pin};
 
 use mlua::{IntoLuaMulti, LuaString, UserData, UserDataMethods, Value};
 use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncWrite, AsyncWriteExt};
-use yazi_fs::{engine::Attrs, file::File};
-use yazi_shared::url::{UrlBuf, UrlLike};
+use yazi_fs::{engine::{Attrs, Engine, Preserve}, file::File};
+use yazi_shared::url::{AsUrl, UrlBuf, UrlLike};
 use yazi_shim::fs::Error;
 
 use crate::VfsFile;
@@ -11,6 +11,9 @@ use crate::VfsFile;
 pub enum RwFile {
 	Tokio(tokio::fs::File, UrlBuf),
 	Sftp(Box<yazi_sftp::fs::File>, UrlBuf),
+	Webdav(Box<super::webdav::File>, UrlBuf),
+	S3(Box<super::s3::File>, UrlBuf),
+	Ftp(Box<super::ftp::File>, UrlBuf),
 	Lua(super::lua::File),
 }
 
@@ -22,6 +25,18 @@ impl From<(yazi_sftp::fs::File, UrlBuf)> for RwFile {
 	fn from((f, url): (yazi_sftp::fs::File, UrlBuf)) -> Self { Self::Sftp(Box::new(f), url) }
 }
 
+impl From<(super::webdav::File, UrlBuf)> for RwFile {
+	fn from((f, url): (super::webdav::File, UrlBuf)) -> Self { Self::Webdav(Box::new(f), url) }
+}
+
+impl From<(super::s3::File, UrlBuf)> for RwFile {
+	fn from((f, url): (super::s3::File, UrlBuf)) -> Self { Self::S3(Box::new(f), url) }
+}
+
+impl From<(super::ftp::File, UrlBuf)> for RwFile {
+	fn from((f, url): (super::ftp::File, UrlBuf)) -> Self { Self::Ftp(Box::new(f), url) }
+}
+
 impl From<super::lua::File> for RwFile {
 	fn from(f: super::lua::File) -> Self { Self::Lua(f) }
 }
@@ -36,13 +51,20 @@ impl RwFile {
 				let name = url.name().unwrap_or_default().encoded_bytes();
 				super::sftp::Cha::try_from((name, &f.fstat().await?))?.0
 			}
+			Self::Webdav(_, url) => super::webdav::Webdav::new(url.as_url()).await?.metadata().await?,
+			Self::S3(_, url) => super::s3::S3::new(url.as_url()).await?.metadata().await?,
+			Self::Ftp(_, url) => super::ftp::Ftp::new(url.as_url()).await?.metadata().await?,
 			Self::Lua(f) => f.metadata().await?,
 		})
 	}
 
 	pub async fn file(&self) -> io::Result<File> {
 		Ok(match self {
-			Self::Tokio(_, url) | Self::Sftp(_, url) => {
+			Self::Tokio(_, url)
+			| Self::Sftp(_, url)
+			| Self::Webdav(_, url)
+			| Self::S3(_, url)
+			| Self::Ftp(_, url) => {
 				let cha = self.metadata().await?;
 				File::from_follow(url.clone(), cha).await
 			}
@@ -57,7 +79,11 @@ impl RwFile {
 
 		let cha = self.metadata().await?;
 		Ok(match self {
-			Self::Tokio(_, url) | Self::Sftp(_, url) => File { url, cha, extra: Default::default() },
+			Self::Tokio(_, url)
+			| Self::Sftp(_, url)
+			| Self::Webdav(_, url)
+			| Self::S3(_, url)
+			| Self::Ftp(_, url) => File { url, cha, extra: Default::default() },
 			Self::Lua(_) => unreachable!(),
 		})
 	}
@@ -66,22 +92,42 @@ impl RwFile {
 		match self {
 			Self::Tokio(f, _) => {
 				let (perm, times) = (attrs.try_into(), attrs.try_into());
-				if perm.is_err() && times.is_err() {
+				let owner = attrs.uid.is_some() || attrs.gid.is_some();
+				if perm.is_err() && times.is_err() && !owner {
 					return Ok(());
 				}
 
 				let std = f.try_clone().await?.into_std().await;
 				tokio::task::spawn_blocking(move || {
+					#[cfg(unix)]
+					if owner && let Err(e) = std::os::unix::fs::fchown(&std, attrs.uid, attrs.gid) {
+						attrs.lapse(Preserve::OWNERSHIP, e);
+					}
 					perm.map(|p| std.set_permissions(p)).ok();
 					times.map(|t| std.set_times(t)).ok();
 				})
 				.await?;
 			}
 			Self::Sftp(f, _) => {
-				if let Ok(attrs) = super::sftp::Attrs(attrs).try_into() {
-					f.fsetstat(&attrs).await?;
+				let owner = attrs.uid.is_some() || attrs.gid.is_some();
+				if let Ok(a) = super::sftp::Attrs(attrs).try_into() {
+					match f.fsetstat(&a).await {
+						// Servers usually refuse to give files away, which shouldn't keep the
+						// rest of the attributes from being set
+						Err(e) if owner => {
+							let rest = Attrs { uid: None, gid: None, ..attrs };
+							if let Ok(a) = super::sftp::Attrs(rest).try_into() {
+								f.fsetstat(&a).await?;
+							}
+							attrs.lapse(Preserve::OWNERSHIP, e.into());
+						}
+						r => r?,
+					}
 				}
 			}
+			Self::Webdav(..) | Self::S3(..) => {}
+			// The upload may still be in flight, which would undo a new mtime
+			Self::Ftp(..) => {}
 			Self::Lua(f) => f.set_attrs(attrs).await?,
 		}
 
@@ -94,9 +140,25 @@ impl RwFile {
 			Self::Sftp(f, _) => {
 				f.fsetstat(&yazi_sftp::fs::Attrs { size: Some(size), ..Default::default() }).await?
 			}
+			Self::Webdav(f, _) => f.set_len(size)?,
+			Self::S3(f, _) => f.set_len(size)?,
+			// Uploads can't be resized up front, and files grow as they're written
+			Self::Ftp(..) => {}
 			Self::Lua(f) => f.set_len(size).await?,
 		})
 	}
+
+	/// Hint that nothing past `end` will be read, so remote files don't read
+	/// ahead beyond it.
+	pub fn set_read_end(&mut self, end: Option<u64>) {
+		match self {
+			Self::Tokio(..) | Self::Lua(_) => {}
+			Self::Sftp(f, _) => f.set_read_end(end),
+			Self::Webdav(f, _) => f.set_read_end(end),
+			Self::S3(f, _) => f.set_read_end(end),
+			Self::Ftp(f, _) => f.set_read_end(end),
+		}
+	}
 }
 
 impl AsyncRead for RwFile {
@@ -109,6 +171,9 @@ impl AsyncRead for RwFile {
 		match &mut *self {
 			RwFile::Tokio(f, _) => Pin::new(f).poll_read(cx, buf),
 			RwFile::Sftp(f, _) => Pin::new(f).poll_read(cx, buf),
+			RwFile::Webdav(f, _) => Pin::new(f).poll_read(cx, buf),
+			RwFile::S3(f, _) => Pin::new(f).poll_read(cx, buf),
+			RwFile::Ftp(f, _) => Pin::new(f).poll_read(cx, buf),
 			RwFile::Lua(f) => Pin::new(f).poll_read(cx, buf),
 		}
 	}
@@ -120,6 +185,9 @@ impl AsyncSeek for RwFile {
 		match &mut *self {
 			RwFile::Tokio(f, _) => Pin::new(f).start_seek(position),
 			RwFile::Sftp(f, _) => Pin::new(f).start_seek(position),
+			RwFile::Webdav(f, _) => Pin::new(f).start_seek(position),
+			RwFile::S3(f, _) => Pin::new(f).start_seek(position),
+			RwFile::Ftp(f, _) => Pin::new(f).start_seek(position),
 			RwFile::Lua(f) => Pin::new(f).start_seek(position),
 		}
 	}
@@ -132,6 +200,9 @@ impl AsyncSeek for RwFile {
 		match &mut *self {
 			RwFile::Tokio(f, _) => Pin::new(f).poll_complete(cx),
 			RwFile::Sftp(f, _) => Pin::new(f).poll_complete(cx),
+			RwFile::Webdav(f, _) => Pin::new(f).poll_complete(cx),
+			RwFile::S3(f, _) => Pin::new(f).poll_complete(cx),
+			RwFile::Ftp(f, _) => Pin::new(f).poll_complete(cx),
 			RwFile::Lua(f) => Pin::new(f).poll_complete(cx),
 		}
 	}
@@ -147,6 +218,9 @@ impl AsyncWrite for RwFile {
 		match &mut *self {
 			RwFile::Tokio(f, _) => Pin::new(f).poll_write(cx, buf),
 			RwFile::Sftp(f, _) => Pin::new(f).poll_write(cx, buf),
+			RwFile::Webdav(f, _) => Pin::new(f).poll_write(cx, buf),
+			RwFile::S3(f, _) => Pin::new(f).poll_write(cx, buf),
+			RwFile::Ftp(f, _) => Pin::new(f).poll_write(cx, buf),
 			RwFile::Lua(f) => Pin::new(f).poll_write(cx, buf),
 		}
 	}
@@ -159,6 +233,9 @@ impl AsyncWrite for RwFile {
 		match &mut *self {
 			RwFile::Tokio(f, _) => Pin::new(f).poll_flush(cx),
 			RwFile::Sftp(f, _) => Pin::new(f).poll_flush(cx),
+			RwFile::Webdav(f, _) => Pin::new(f).poll_flush(cx),
+			RwFile::S3(f, _) => Pin::new(f).poll_flush(cx),
+			RwFile::Ftp(f, _) => Pin::new(f).poll_flush(cx),
 			RwFile::Lua(f) => Pin::new(f).poll_flush(cx),
 		}
 	}
@@ -171,6 +248,9 @@ impl AsyncWrite for RwFile {
 		match &mut *self {
 			RwFile::Tokio(f, _) => Pin::new(f).poll_shutdown(cx),
 			RwFile::Sftp(f, _) => Pin::new(f).poll_shutdown(cx),
+			RwFile::Webdav(f, _) => Pin::new(f).poll_shutdown(cx),
+			RwFile::S3(f, _) => Pin::new(f).poll_shutdown(cx),
+			RwFile::Ftp(f, _) => Pin::new(f).poll_shutdown(cx),
 			RwFile::Lua(f) => Pin::new(f).poll_shutdown(cx),
 		}
 	}
@@ -184,6 +264,9 @@ impl AsyncWrite for RwFile {
 		match &mut *self {
 			RwFile::Tokio(f, _) => Pin::new(f).poll_write_vectored(cx, bufs),
 			RwFile::Sftp(f, _) => Pin::new(f).poll_write_vectored(cx, bufs),
+			RwFile::Webdav(f, _) => Pin::new(f).poll_write_vectored(cx, bufs),
+			RwFile::S3(f, _) => Pin::new(f).poll_write_vectored(cx, bufs),
+			RwFile::Ftp(f, _) => Pin::new(f).poll_write_vectored(cx, bufs),
 			RwFile::Lua(f) => Pin::new(f).poll_write_vectored(cx, bufs),
 		}
 	}
@@ -193,6 +276,9 @@ impl AsyncWrite for RwFile {
 		match self {
 			RwFile::Tokio(f, _) => f.is_write_vectored(),
 			RwFile::Sftp(f, _) => f.is_write_vectored(),
+			RwFile::Webdav(f, _) => f.is_write_vectored(),
+			RwFile::S3(f, _) => f.is_write_vectored(),
+			RwFile::Ftp(f, _) => f.is_write_vectored(),
 			RwFile::Lua(f) => f.is_write_vectored(),
 		}
 	}
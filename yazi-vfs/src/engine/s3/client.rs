@@ -0,0 +1,254 @@
+use std::{fmt::Write, io};
+
+use bytes::Bytes;
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, percent_encode};
+use reqwest::{Method, Response, StatusCode, header::{self, HeaderMap, HeaderValue}};
+use typed_path::UnixPath;
+use yazi_config::vfs::ServiceS3;
+
+use super::{Credentials, Listing, Object, list::text_of};
+
+// Everything but unreserved characters is escaped in query strings, and paths
+// additionally keep their separators
+const QUERY: &AsciiSet = &NON_ALPHANUMERIC.remove(b'-').remove(b'.').remove(b'_').remove(b'~');
+const PATH: &AsciiSet = &QUERY.remove(b'/');
+
+pub(super) struct Client {
+	config: &'static ServiceS3,
+	http:   reqwest::Client,
+	creds:  Option<Credentials>,
+
+	// `scheme://authority` the requests are sent to
+	origin: String,
+	// What the `Host` header of the requests will be
+	host:   String,
+	// Path of the bucket, which is empty for virtual-hosted buckets
+	root:   String,
+}
+
+impl Client {
+	pub(super) fn get(config: &'static ServiceS3) -> io::Result<&'static Self> {
+		if let Some(&client) = super::CLIENT.lock().get(config) {
+			return Ok(client);
+		}
+
+		let http = reqwest::Client::builder()
+			.danger_accept_invalid_certs(config.no_cert_verify)
+			.build()
+			.map_err(io::Error::other)?;
+
+		let (scheme, rest) = config.endpoint.split_once("://").unwrap_or(("https", &config.endpoint));
+		let (authority, path) = rest.find('/').map_or((rest, ""), |i| rest.split_at(i));
+
+		// Default ports are left out of the `Host` header, so they have to be
+		// left out of the signature too
+		let authority = match scheme {
+			"http" => authority.strip_suffix(":80"),
+			_ => authority.strip_suffix(":443"),
+		}
+		.unwrap_or(authority);
+
+		let (host, root) = if config.virtual_host {
+			(format!("{}.{authority}", config.bucket), path.to_owned())
+		} else {
+			(authority.to_owned(), format!("{path}/{}", percent_encode(config.bucket.as_bytes(), QUERY)))
+		};
+
+		let client = Self {
+			config,
+			http,
+			creds: Credentials::resolve(config)?,
+			origin: format!("{scheme}://{host}"),
+			host,
+			root,
+		};
+		Ok(*super::CLIENT.lock().entry(config).or_insert_with(|| Box::leak(Box::new(client))))
+	}
+
+	pub(super) async fn send(
+		&self,
+		method: Method,
+		key: &str,
+		query: &[(&str, &str)],
+		mut headers: HeaderMap,
+		body: impl Into<Bytes>,
+	) -> io::Result<Response> {
+		let body = body.into();
+		let path = format!("{}/{}", self.root, percent_encode(key.as_bytes(), PATH));
+
+		let mut query: Vec<_> = query
+			.iter()
+			.map(|&(k, v)| {
+				format!("{}={}", percent_encode(k.as_bytes(), QUERY), percent_encode(v.as_bytes(), QUERY))
+			})
+			.collect();
+		query.sort_unstable();
+		let query = query.join("&");
+
+		if let Some(creds) = &self.creds {
+			let region = &self.config.region;
+			super::sign(creds, region, &method, (&self.host, &path, &query), &mut headers, &body);
+		}
+
+		let url = if query.is_empty() {
+			format!("{}{path}", self.origin)
+		} else {
+			format!("{}{path}?{query}", self.origin)
+		};
+
+		let req = self.http.request(method.clone(), url).headers(headers).body(body);
+		Self::check(&method, key, req.send().await.map_err(io::Error::other)?).await
+	}
+
+	pub(super) async fn head(&self, key: &str) -> io::Result<Object> {
+		let resp = self.send(Method::HEAD, key, &[], HeaderMap::new(), Bytes::new()).await?;
+		let header = |name| resp.headers().get(name).and_then(|v| v.to_str().ok());
+
+		Ok(Object::new(
+			key.to_owned(),
+			header(header::CONTENT_LENGTH).and_then(|s| s.parse().ok()).unwrap_or(0),
+			header(header::LAST_MODIFIED)
+				.and_then(|s| chrono::DateTime::parse_from_rfc2822(s).ok())
+				.map(Into::into),
+			header(header::ETAG).map(ToOwned::to_owned),
+		))
+	}
+
+	/// List the objects under `prefix`, and with `delimit`, group the ones in
+	/// subdirectories into common prefixes instead. `max` stops the listing
+	/// early once that many keys have been seen.
+	pub(super) async fn list(
+		&self,
+		prefix: &str,
+		delimit: bool,
+		max: Option<usize>,
+	) -> io::Result<Listing> {
+		let mut listing = Listing::default();
+		let max_keys = max.map(|n| n.to_string());
+
+		loop {
+			let mut query = vec![("list-type", "2"), ("prefix", prefix)];
+			if delimit {
+				query.push(("delimiter", "/"));
+			}
+			if let Some(n) = &max_keys {
+				query.push(("max-keys", n));
+			}
+			if let Some(token) = &listing.next {
+				query.push(("continuation-token", token));
+			}
+
+			let resp = self.send(Method::GET, "", &query, HeaderMap::new(), Bytes::new()).await?;
+			let page = Listing::parse(&resp.bytes().await.map_err(io::Error::other)?)?;
+
+			listing.objects.extend(page.objects);
+			listing.prefixes.extend(page.prefixes);
+			listing.next = page.next;
+
+			let seen = listing.objects.len() + listing.prefixes.len();
+			if listing.next.is_none() || max.is_some_and(|n| seen >= n) {
+				break Ok(listing);
+			}
+		}
+	}
+
+	/// Server-side `CopyObject` within the bucket.
+	pub(super) async fn copy(&self, from: &str, to: &str) -> io::Result<()> {
+		let source = format!("/{}/{}", self.config.bucket, percent_encode(from.as_bytes(), PATH));
+		let mut headers = HeaderMap::new();
+		headers.insert("x-amz-copy-source", HeaderValue::from_str(&source).map_err(io::Error::other)?);
+
+		let resp = self.send(Method::PUT, to, &[], headers, Bytes::new()).await?;
+		Self::check_body(&Method::PUT, to, resp).await
+	}
+
+	pub(super) async fn initiate(&self, key: &str) -> io::Result<String> {
+		let resp =
+			self.send(Method::POST, key, &[("uploads", "")], HeaderMap::new(), Bytes::new()).await?;
+		let body = resp.bytes().await.map_err(io::Error::other)?;
+
+		text_of(&body, &["InitiateMultipartUploadResult", "UploadId"])
+			.ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "No upload ID in the response"))
+	}
+
+	/// Upload the `n`th part of a multipart upload, returning its ETag.
+	pub(super) async fn upload_part(
+		&self,
+		key: &str,
+		id: &str,
+		n: usize,
+		body: Bytes,
+	) -> io::Result<String> {
+		let query = [("partNumber", n.to_string()), ("uploadId", id.to_owned())];
+		let query: Vec<_> = query.iter().map(|(k, v)| (*k, v.as_str())).collect();
+
+		let resp = self.send(Method::PUT, key, &query, HeaderMap::new(), body).await?;
+		resp
+			.headers()
+			.get(header::ETAG)
+			.and_then(|v| v.to_str().ok())
+			.map(ToOwned::to_owned)
+			.ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "No ETag for the uploaded part"))
+	}
+
+	pub(super) async fn complete(&self, key: &str, id: &str, etags: &[String]) -> io::Result<()> {
+		let mut xml = String::from("<CompleteMultipartUpload>");
+		for (i, etag) in etags.iter().enumerate() {
+			let etag = quick_xml::escape::escape(etag);
+			write!(xml, "<Part><PartNumber>{}</PartNumber><ETag>{etag}</ETag></Part>", i + 1).ok();
+		}
+		xml.push_str("</CompleteMultipartUpload>");
+
+		let resp = self.send(Method::POST, key, &[("uploadId", id)], HeaderMap::new(), xml).await?;
+		Self::check_body(&Method::POST, key, resp).await
+	}
+
+	pub(super) async fn abort(&self, key: &str, id: &str) -> io::Result<()> {
+		self.send(Method::DELETE, key, &[("uploadId", id)], HeaderMap::new(), Bytes::new()).await?;
+		Ok(())
+	}
+
+	async fn check(method: &Method, key: &str, resp: Response) -> io::Result<Response> {
+		let status = resp.status();
+		if status.is_success() || status == StatusCode::NOT_MODIFIED {
+			return Ok(resp);
+		}
+
+		let kind = match status {
+			StatusCode::NOT_FOUND => io::ErrorKind::NotFound,
+			StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => io::ErrorKind::PermissionDenied,
+			StatusCode::PRECONDITION_FAILED => io::ErrorKind::AlreadyExists,
+			StatusCode::RANGE_NOT_SATISFIABLE => io::ErrorKind::UnexpectedEof,
+			StatusCode::SERVICE_UNAVAILABLE => io::ErrorKind::ResourceBusy,
+			_ => io::ErrorKind::Other,
+		};
+
+		let body = resp.bytes().await.unwrap_or_default();
+		let reason = text_of(&body, &["Error", "Code"]).unwrap_or_else(|| status.to_string());
+		Err(io::Error::new(kind, format!("{method} {key} failed: {reason}")))
+	}
+
+	/// `CopyObject` and `CompleteMultipartUpload` can fail after they've
+	/// responded with `200 OK`, in which case the error is in the body.
+	async fn check_body(method: &Method, key: &str, resp: Response) -> io::Result<()> {
+		let body = resp.bytes().await.map_err(io::Error::other)?;
+		match text_of(&body, &["Error", "Code"]) {
+			Some(code) => Err(io::Error::other(format!("{method} {key} failed: {code}"))),
+			None => Ok(()),
+		}
+	}
+}
+
+/// The object key of `path`, which is always absolute in the URL.
+pub(super) fn key(path: &UnixPath) -> io::Result<String> {
+	let path = path.normalize();
+	let key = path.as_bytes().strip_prefix(b"/").unwrap_or(path.as_bytes());
+
+	String::from_utf8(key.to_owned())
+		.map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "S3 keys must be valid UTF-8"))
+}
+
+/// The prefix the objects in the directory `key` start with.
+pub(super) fn prefix(key: &str) -> String {
+	if key.is_empty() { String::new() } else { format!("{key}/") }
+}
@@ -0,0 +1,125 @@
+use std::{env, io, path::PathBuf};
+
+use yazi_config::vfs::ServiceS3;
+
+/// The keys requests to an S3 service are signed with.
+pub(super) struct Credentials {
+	pub(super) access_key:    String,
+	pub(super) secret_key:    String,
+	pub(super) session_token: Option<String>,
+}
+
+impl Credentials {
+	/// Look for credentials in the service config, then in its profile, then in
+	/// the `AWS_*` environment variables, and finally in the default profile.
+	///
+	/// `None` means there are none, and requests are sent anonymously.
+	pub(super) fn resolve(config: &ServiceS3) -> io::Result<Option<Self>> {
+		if let (Some(access_key), Some(secret_key)) = (&config.access_key, &config.secret_key) {
+			return Ok(Some(Self {
+				access_key:    access_key.clone(),
+				secret_key:    secret_key.clone(),
+				session_token: config.session_token.clone(),
+			}));
+		}
+
+		if let Some(profile) = &config.profile {
+			return Self::from_profile(profile)?.map(Some).ok_or_else(|| {
+				io::Error::new(io::ErrorKind::NotFound, format!("AWS profile `{profile}` not found"))
+			});
+		}
+
+		if let Some(creds) = Self::from_env() {
+			return Ok(Some(creds));
+		}
+		Self::from_profile(&env::var("AWS_PROFILE").unwrap_or_else(|_| "default".to_owned()))
+	}
+
+	fn from_env() -> Option<Self> {
+		Some(Self {
+			access_key:    env::var("AWS_ACCESS_KEY_ID").ok().filter(|s| !s.is_empty())?,
+			secret_key:    env::var("AWS_SECRET_ACCESS_KEY").ok().filter(|s| !s.is_empty())?,
+			session_token: env::var("AWS_SESSION_TOKEN").ok().filter(|s| !s.is_empty()),
+		})
+	}
+
+	fn from_profile(profile: &str) -> io::Result<Option<Self>> {
+		let path = env::var_os("AWS_SHARED_CREDENTIALS_FILE")
+			.map(PathBuf::from)
+			.or_else(|| dirs::home_dir().map(|h| h.join(".aws/credentials")));
+
+		let content = match path.map(std::fs::read_to_string) {
+			Some(Ok(s)) => s,
+			Some(Err(e)) if e.kind() != io::ErrorKind::NotFound => return Err(e),
+			_ => return Ok(None),
+		};
+
+		Ok(Self::parse(&content, profile))
+	}
+
+	/// Parse the section named `profile` out of an INI-style credentials file.
+	fn parse(content: &str, profile: &str) -> Option<Self> {
+		let (mut section, mut access_key, mut secret_key, mut session_token) = ("", None, None, None);
+		for line in content.lines().map(str::trim) {
+			if line.is_empty() || line.starts_with(['#', ';']) {
+				continue;
+			} else if let Some(name) = line.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+				section = name.trim();
+			} else if section == profile
+				&& let Some((key, value)) = line.split_once('=')
+			{
+				let value = Some(value.trim().to_owned());
+				match key.trim() {
+					"aws_access_key_id" => access_key = value,
+					"aws_secret_access_key" => secret_key = value,
+					"aws_session_token" => session_token = value,
+					_ => {}
+				}
+			}
+		}
+
+		access_key.zip(secret_key).map(|(access_key, secret_key)| Self {
+			access_key,
+			secret_key,
+			session_token,
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const FILE: &str = r#"
+# A comment
+[default]
+aws_access_key_id = AKIDDEFAULT
+aws_secret_access_key = secret/default
+
+[work]
+; Another comment
+aws_access_key_id=AKIDWORK
+aws_secret_access_key=secret=work
+aws_session_token = token
+region = eu-west-1
+
+[partial]
+aws_access_key_id = AKIDPARTIAL
+"#;
+
+	#[test]
+	fn test_parse() {
+		let creds = Credentials::parse(FILE, "default").unwrap();
+		assert_eq!(creds.access_key, "AKIDDEFAULT");
+		assert_eq!(creds.secret_key, "secret/default");
+		assert_eq!(creds.session_token, None);
+
+		let creds = Credentials::parse(FILE, "work").unwrap();
+		assert_eq!(creds.access_key, "AKIDWORK");
+		assert_eq!(creds.secret_key, "secret=work");
+		assert_eq!(creds.session_token.as_deref(), Some("token"));
+
+		assert!(Credentials::parse(FILE, "partial").is_none());
+		assert!(Credentials::parse(FILE, "missing").is_none());
+	}
+}
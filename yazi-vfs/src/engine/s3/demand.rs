@@ -0,0 +1,66 @@
+use std::io;
+
+use yazi_fs::engine::{Attrs, Engine, FileBuilder};
+use yazi_shared::url::AsUrl;
+
+use crate::engine::s3::{File, S3};
+
+#[derive(Clone, Copy, Default)]
+pub struct Demand(yazi_fs::engine::Demand);
+
+impl FileBuilder for Demand {
+	type File = File;
+
+	fn append(&mut self, append: bool) -> &mut Self {
+		self.0.append = append;
+		self
+	}
+
+	fn attrs(&mut self, attrs: Attrs) -> &mut Self {
+		self.0.attrs = attrs;
+		self
+	}
+
+	fn create(&mut self, create: bool) -> &mut Self {
+		self.0.create = create;
+		self
+	}
+
+	fn create_new(&mut self, create_new: bool) -> &mut Self {
+		self.0.create_new = create_new;
+		self
+	}
+
+	async fn open<U>(&self, url: U) -> io::Result<Self::File>
+	where
+		U: AsUrl,
+	{
+		let engine = S3::new(url.as_url()).await?;
+		let Self(d) = *self;
+		if d.append {
+			return Err(io::Error::new(io::ErrorKind::Unsupported, "S3 objects cannot be appended to"));
+		}
+
+		// Objects are only written when the file is shut down, so whether it's
+		// created even if nothing is written to it is decided here
+		let key = engine.key()?;
+		let fresh = crate::engine::fresh_on_open(d, engine.client.head(&key)).await?;
+
+		Ok(File::new(engine.client, key, d.write || fresh, fresh))
+	}
+
+	fn read(&mut self, read: bool) -> &mut Self {
+		self.0.read = read;
+		self
+	}
+
+	fn truncate(&mut self, truncate: bool) -> &mut Self {
+		self.0.truncate = truncate;
+		self
+	}
+
+	fn write(&mut self, write: bool) -> &mut Self {
+		self.0.write = write;
+		self
+	}
+}
@@ -0,0 +1,118 @@
+use std::{io::{self, SeekFrom}, pin::Pin, sync::atomic::{AtomicU64, Ordering}, task::{Context, Poll}};
+
+use bytes::Bytes;
+use futures::{StreamExt, channel::mpsc as chan};
+use reqwest::Method;
+use tokio::{io::{AsyncRead, AsyncSeek, AsyncWrite, ReadBuf}, task::JoinHandle};
+
+use super::{Client, Upload};
+use crate::engine::Ranged;
+
+/// An object in an S3 bucket, read with ranged `GET`s and written with a
+/// multipart upload.
+///
+/// Since objects are immutable, writes replace the whole object, so they have
+/// to start at the beginning and be sequential. The object only changes once
+/// the file is shut down.
+pub struct File {
+	client: &'static Client,
+	key:    String,
+	len:    AtomicU64,
+	ranged: Ranged,
+}
+
+impl File {
+	pub(super) fn new(client: &'static Client, key: String, write: bool, fresh: bool) -> Self {
+		Self { client, key, len: AtomicU64::new(0), ranged: Ranged::new(write, fresh) }
+	}
+
+	/// Objects can't be resized, so this only declares the size of the upcoming
+	/// upload, which is used to pick a part size large enough for it.
+	pub fn set_len(&self, size: u64) -> io::Result<()> {
+		if !self.ranged.write || !self.ranged.is_idle() {
+			return Err(io::Error::new(io::ErrorKind::Unsupported, "Cannot resize an S3 object"));
+		}
+
+		self.len.store(size, Ordering::Relaxed);
+		Ok(())
+	}
+
+	pub fn set_read_end(&mut self, end: Option<u64>) { self.ranged.end = end; }
+
+	fn upload(
+		client: &'static Client,
+		key: String,
+		len: u64,
+		mut rx: chan::Receiver<Bytes>,
+	) -> JoinHandle<io::Result<()>> {
+		let mut upload = Upload::new(client, key, len);
+
+		tokio::spawn(async move {
+			let mut result = Ok(());
+			while let Some(data) = rx.next().await {
+				result = upload.write(data).await;
+				if result.is_err() {
+					break;
+				}
+			}
+
+			if result.is_ok() {
+				result = upload.finish().await;
+			}
+			if result.is_err() {
+				upload.abort().await;
+			}
+			result
+		})
+	}
+}
+
+impl AsyncRead for File {
+	fn poll_read(
+		mut self: Pin<&mut Self>,
+		cx: &mut Context<'_>,
+		buf: &mut ReadBuf<'_>,
+	) -> Poll<io::Result<()>> {
+		let me = &mut *self;
+		let (client, key) = (me.client, &me.key);
+		me.ranged.poll_read(cx, buf, |r| {
+			let (key, headers) = (key.clone(), r.range());
+			r.get(async move { client.send(Method::GET, &key, &[], headers, Bytes::new()).await })
+		})
+	}
+}
+
+impl AsyncSeek for File {
+	fn start_seek(mut self: Pin<&mut Self>, position: SeekFrom) -> io::Result<()> {
+		let what = if self.ranged.write { "an S3 upload" } else { "an S3 object" };
+		self.ranged.start_seek(position, what)
+	}
+
+	fn poll_complete(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<u64>> {
+		Poll::Ready(Ok(self.ranged.pos))
+	}
+}
+
+impl AsyncWrite for File {
+	fn poll_write(
+		mut self: Pin<&mut Self>,
+		cx: &mut Context<'_>,
+		buf: &[u8],
+	) -> Poll<io::Result<usize>> {
+		let me = &mut *self;
+		let (client, key, len) = (me.client, &me.key, &me.len);
+		me.ranged
+			.poll_write(cx, buf, |rx| Self::upload(client, key.clone(), len.load(Ordering::Relaxed), rx))
+	}
+
+	fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+		Poll::Ready(Ok(()))
+	}
+
+	fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+		let me = &mut *self;
+		let (client, key, len) = (me.client, &me.key, &me.len);
+		me.ranged
+			.poll_shutdown(cx, |rx| Self::upload(client, key.clone(), len.load(Ordering::Relaxed), rx))
+	}
+}
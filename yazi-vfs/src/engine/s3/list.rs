@@ -0,0 +1,212 @@
+use std::{io, time::SystemTime};
+
+use chrono::DateTime;
+use quick_xml::{Reader, events::Event};
+use yazi_fs::cha::{Cha, ChaKind, ChaMode};
+
+// --- Object
+#[derive(Default)]
+pub(super) struct Object {
+	pub(super) key:  String,
+	pub(super) len:  u64,
+	mtime:           Option<SystemTime>,
+	pub(super) etag: Option<String>,
+}
+
+impl Object {
+	pub(super) fn new(
+		key: String,
+		len: u64,
+		mtime: Option<SystemTime>,
+		etag: Option<String>,
+	) -> Self {
+		Self { key, len, mtime, etag }
+	}
+
+	pub(super) fn cha(&self) -> Cha {
+		let name = self.key.rsplit('/').next().unwrap_or_default();
+		Cha {
+			// Objects have no notion of permissions, so assume they're all accessible
+			mode: ChaMode::T_FILE | ChaMode::from_bits_retain(0o644),
+			len: self.len,
+			mtime: self.mtime,
+			..dir(name)
+		}
+	}
+}
+
+/// The [`Cha`] of a prefix, which is what stands for a directory in S3.
+pub(super) fn dir(name: &str) -> Cha {
+	Cha {
+		kind:  if name.starts_with('.') { ChaKind::HIDDEN } else { ChaKind::empty() },
+		mode:  ChaMode::T_DIR | ChaMode::from_bits_retain(0o755),
+		len:   0,
+		atime: None,
+		btime: None,
+		ctime: None,
+		mtime: None,
+		dev:   0,
+		uid:   0,
+		gid:   0,
+		nlink: 0,
+	}
+}
+
+// --- Listing
+/// A page of a `ListObjectsV2` response.
+#[derive(Default)]
+pub(super) struct Listing {
+	pub(super) objects:  Vec<Object>,
+	pub(super) prefixes: Vec<String>,
+	pub(super) next:     Option<String>,
+}
+
+impl Listing {
+	pub(super) fn parse(xml: &[u8]) -> io::Result<Self> {
+		let mut listing = Self::default();
+		let mut object = None::<Object>;
+
+		walk(xml, |path, value| match path {
+			["ListBucketResult", "Contents", tag] => {
+				walk_object(object.get_or_insert_default(), tag, value)
+			}
+			["ListBucketResult", "Contents"] => listing.objects.extend(object.take()),
+			["ListBucketResult", "CommonPrefixes", "Prefix"] => listing.prefixes.push(value.to_owned()),
+			["ListBucketResult", "NextContinuationToken"] => {
+				listing.next = Some(value.to_owned()).filter(|s| !s.is_empty());
+			}
+			_ => {}
+		})?;
+
+		Ok(listing)
+	}
+}
+
+fn walk_object(o: &mut Object, tag: &str, value: &str) {
+	match tag {
+		"Key" => o.key = value.to_owned(),
+		"Size" => o.len = value.parse().unwrap_or(0),
+		"LastModified" => o.mtime = DateTime::parse_from_rfc3339(value).ok().map(Into::into),
+		"ETag" => o.etag = Some(value.to_owned()),
+		_ => {}
+	}
+}
+
+/// The text of the first element whose path matches `path`, e.g. `UploadId`
+/// of an `InitiateMultipartUploadResult`.
+pub(super) fn text_of(xml: &[u8], path: &[&str]) -> Option<String> {
+	let mut found = None;
+	walk(xml, |p, value| {
+		if found.is_none() && p == path {
+			found = Some(value.to_owned());
+		}
+	})
+	.ok()?;
+	found
+}
+
+/// Call `f` with the path of each element and its text when it ends.
+fn walk(xml: &[u8], mut f: impl FnMut(&[&str], &str)) -> io::Result<()> {
+	let mut reader = Reader::from_reader(xml);
+	let (mut stack, mut text) = (Vec::<String>::new(), String::new());
+
+	loop {
+		match reader.read_event().map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))? {
+			Event::Start(e) => {
+				text.clear();
+				stack.push(String::from_utf8_lossy(e.local_name().as_ref()).into_owned());
+			}
+			Event::Text(e) => text.push_str(&e.decode().unwrap_or_default()),
+			Event::CData(e) => text.push_str(&e.decode().unwrap_or_default()),
+			Event::GeneralRef(e) => {
+				text.push('&');
+				text.push_str(&e.decode().unwrap_or_default());
+				text.push(';');
+			}
+			Event::End(_) => {
+				let value = quick_xml::escape::unescape(text.trim()).unwrap_or_default();
+				f(&stack.iter().map(String::as_str).collect::<Vec<_>>(), &value);
+				stack.pop();
+				text.clear();
+			}
+			Event::Eof => break,
+			_ => {}
+		}
+	}
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_listing() {
+		let xml = br#"<?xml version="1.0" encoding="UTF-8"?>
+<ListBucketResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+	<Name>bucket</Name>
+	<Prefix>photos/</Prefix>
+	<KeyCount>3</KeyCount>
+	<IsTruncated>true</IsTruncated>
+	<NextContinuationToken>1ueGcxLPRx1Tr/XYExHnhbYLgveDs2J/wm36Hy4vbOwM=</NextContinuationToken>
+	<Contents>
+		<Key>photos/a&amp;b.jpg</Key>
+		<LastModified>2009-10-12T17:50:30.000Z</LastModified>
+		<ETag>&quot;fba9dede5f27731c9771645a39863328&quot;</ETag>
+		<Size>434234</Size>
+	</Contents>
+	<Contents>
+		<Key>photos/.hidden</Key>
+		<Size>0</Size>
+	</Contents>
+	<CommonPrefixes>
+		<Prefix>photos/2006/</Prefix>
+	</CommonPrefixes>
+</ListBucketResult>"#;
+
+		let listing = Listing::parse(xml).unwrap();
+		assert_eq!(listing.objects.len(), 2);
+		assert_eq!(listing.prefixes, ["photos/2006/"]);
+		assert_eq!(listing.next.as_deref(), Some("1ueGcxLPRx1Tr/XYExHnhbYLgveDs2J/wm36Hy4vbOwM="));
+
+		let a = &listing.objects[0];
+		assert_eq!(a.key, "photos/a&b.jpg");
+		assert_eq!(a.len, 434234);
+		assert_eq!(a.etag.as_deref(), Some("\"fba9dede5f27731c9771645a39863328\""));
+		assert_eq!(a.mtime, Some(DateTime::parse_from_rfc3339("2009-10-12T17:50:30Z").unwrap().into()));
+
+		let b = listing.objects[1].cha();
+		assert!(b.kind.contains(ChaKind::HIDDEN));
+		assert_eq!(b.mtime, None);
+	}
+
+	#[test]
+	fn test_listing_last_page() {
+		let xml =
+			b"<ListBucketResult><NextContinuationToken></NextContinuationToken></ListBucketResult>";
+		let listing = Listing::parse(xml).unwrap();
+		assert!(listing.objects.is_empty() && listing.next.is_none());
+
+		assert!(Listing::parse(b"<ListBucketResult><Contents></ListBucketResult>").is_err());
+	}
+
+	#[test]
+	fn test_text_of() {
+		let xml = br#"<InitiateMultipartUploadResult>
+	<Bucket>bucket</Bucket>
+	<Key>a.txt</Key>
+	<UploadId>VXBsb2FkIElE</UploadId>
+</InitiateMultipartUploadResult>"#;
+
+		assert_eq!(
+			text_of(xml, &["InitiateMultipartUploadResult", "UploadId"]).as_deref(),
+			Some("VXBsb2FkIElE")
+		);
+		assert_eq!(text_of(xml, &["UploadId"]), None);
+		assert_eq!(
+			text_of(b"<Error><Code>NoSuchKey</Code></Error>", &["Error", "Code"]).as_deref(),
+			Some("NoSuchKey")
+		);
+	}
+}
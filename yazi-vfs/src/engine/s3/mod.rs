@@ -0,0 +1,7 @@
+yazi_macro::mod_flat!(client credentials demand file list read_dir s3 sign upload);
+
+static CLIENT: yazi_shim::cell::RoCell<
+	parking_lot::Mutex<hashbrown::HashMap<&'static yazi_config::vfs::ServiceS3, &'static Client>>,
+> = yazi_shim::cell::RoCell::new();
+
+pub(super) fn init() { CLIENT.init(Default::default()); }
@@ -0,0 +1,43 @@
+use std::{io, sync::Arc};
+
+use typed_path::UnixPathBuf;
+use yazi_fs::{cha::Cha, engine::{DirReader, FileHolder}, file::File};
+use yazi_shared::{path::PathBufDyn, strand::StrandCow, url::{UrlBuf, UrlLike}};
+
+use crate::VfsFile;
+
+pub struct ReadDir {
+	pub(super) dir:     Arc<UrlBuf>,
+	pub(super) entries: std::vec::IntoIter<(UnixPathBuf, Cha)>,
+}
+
+impl DirReader for ReadDir {
+	type Entry = DirEntry;
+
+	async fn next(&mut self) -> io::Result<Option<Self::Entry>> {
+		Ok(self.entries.next().map(|(path, cha)| DirEntry { dir: self.dir.clone(), path, cha }))
+	}
+}
+
+// --- Entry
+pub struct DirEntry {
+	dir:  Arc<UrlBuf>,
+	path: UnixPathBuf,
+	cha:  Cha,
+}
+
+impl FileHolder for DirEntry {
+	async fn file(&self) -> io::Result<File> { Ok(File::from_follow(self.url(), self.cha).await) }
+
+	async fn file_type(&self) -> io::Result<yazi_fs::cha::ChaType> { Ok(self.cha.mode.into()) }
+
+	async fn metadata(&self) -> io::Result<yazi_fs::cha::Cha> { Ok(self.cha) }
+
+	fn name(&self) -> StrandCow<'_> { self.path.file_name().unwrap_or_default().into() }
+
+	fn path(&self) -> PathBufDyn { self.path.clone().into() }
+
+	fn url(&self) -> UrlBuf {
+		self.dir.try_join(self.name()).expect("entry name is a valid component of the S3 URL")
+	}
+}
@@ -0,0 +1,261 @@
+use std::{io, sync::Arc};
+
+use bytes::Bytes;
+use reqwest::{Method, header::HeaderMap};
+use tokio::sync::mpsc::Receiver;
+use typed_path::{UnixPath, UnixPathBuf};
+use yazi_config::vfs::{ServiceS3, Vfs};
+use yazi_fs::{cha::Cha, engine::{Capabilities, Engine}};
+use yazi_shared::{path::{DynPath, PathBufDyn}, strand::AsStrand, url::{AsUrl, Url, UrlBuf, UrlCow}};
+
+use super::{Client, Listing, key, prefix};
+
+#[derive(Clone)]
+pub struct S3<'a> {
+	url:  Url<'a>,
+	path: &'a UnixPath,
+
+	pub(super) client: &'static Client,
+	config:            &'static ServiceS3,
+}
+
+impl S3<'_> {
+	pub(super) fn key(&self) -> io::Result<String> { key(self.path) }
+
+	/// All objects under the directory `key`, at any depth.
+	async fn descendants(&self, key: &str) -> io::Result<Listing> {
+		self.client.list(&prefix(key), false, None).await
+	}
+}
+
+impl<'a> Engine for S3<'a> {
+	type Demand = super::Demand;
+	type File = super::File;
+	type Me<'b> = S3<'b>;
+	type ReadDir = super::ReadDir;
+	type UrlCow = UrlCow<'a>;
+
+	async fn absolute(&self) -> io::Result<Self::UrlCow> {
+		Ok(if let Some(u) = crate::engine::try_absolute_impl(self.url) {
+			u
+		} else {
+			self.canonicalize().await?.into()
+		})
+	}
+
+	async fn canonicalize(&self) -> io::Result<UrlBuf> {
+		// There are no symlinks in S3, so it's only a matter of existence
+		self.symlink_metadata().await?;
+
+		let mut path = UnixPathBuf::from("/");
+		path.push(self.path);
+		Ok(UrlBuf::S3 { loc: path.normalize().into(), auth: self.config.auth.clone() })
+	}
+
+	async fn capabilities(&self) -> io::Result<Capabilities> {
+		Ok(Capabilities {
+			symlink: false,
+			hard_link: false,
+			copy_progressive: true,
+			server_copy: true,
+			..Default::default()
+		})
+	}
+
+	async fn casefold(&self) -> io::Result<UrlBuf> { crate::engine::casefold_impl(self.url).await }
+
+	async fn copy<P>(&self, to: P, _attrs: yazi_fs::engine::Attrs) -> io::Result<u64>
+	where
+		P: DynPath,
+	{
+		let (from, to) = (self.key()?, key(to.dyn_path().as_unix()?)?);
+		let len = self.client.head(&from).await?.len;
+
+		self.client.copy(&from, &to).await?;
+		Ok(len)
+	}
+
+	fn copy_progressive<P, A>(&self, to: P, attrs: A) -> io::Result<Receiver<io::Result<u64>>>
+	where
+		P: DynPath,
+		A: Into<yazi_fs::engine::Attrs>,
+	{
+		let to = to.dyn_path().to_unix_owned()?;
+		let (from, attrs) = (self.url.to_owned(), attrs.into());
+
+		Ok(crate::engine::copy_progressive_once(async move {
+			S3::new(from.as_url()).await?.copy(to, attrs).await
+		}))
+	}
+
+	async fn create_dir(&self) -> io::Result<()> {
+		match self.symlink_metadata().await {
+			Ok(_) => return Err(io::ErrorKind::AlreadyExists.into()),
+			Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+			Err(e) => return Err(e),
+		}
+
+		// Directories are only implied by the keys under them, so an empty
+		// marker object keeps an empty one around
+		let marker = prefix(&self.key()?);
+		self.client.send(Method::PUT, &marker, &[], HeaderMap::new(), Bytes::new()).await.map(|_| ())
+	}
+
+	async fn hard_link<P>(&self, _to: P) -> io::Result<()>
+	where
+		P: DynPath,
+	{
+		Err(io::Error::new(io::ErrorKind::Unsupported, "S3 does not support hard links"))
+	}
+
+	async fn metadata(&self) -> io::Result<Cha> { self.symlink_metadata().await }
+
+	async fn new<'b>(url: Url<'b>) -> io::Result<Self::Me<'b>> {
+		let Url::S3 { loc, auth } = url else {
+			return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("Not an S3 URL: {url:?}")));
+		};
+
+		let config = Vfs::service::<&ServiceS3>(auth)?;
+		Ok(Self::Me { url, path: loc.as_inner(), client: Client::get(config)?, config })
+	}
+
+	async fn read_dir(self) -> io::Result<Self::ReadDir> {
+		let key = self.key()?;
+		let prefix = prefix(&key);
+		let listing = self.client.list(&prefix, true, None).await?;
+
+		let mut dir = UnixPathBuf::from("/");
+		dir.push(&key);
+
+		let mut marked = false;
+		let mut entries = Vec::with_capacity(listing.objects.len() + listing.prefixes.len());
+		for p in &listing.prefixes {
+			let name = p[prefix.len()..].trim_end_matches('/');
+			if !name.is_empty() {
+				entries.push((dir.join(name), super::list::dir(name)));
+			}
+		}
+		for o in &listing.objects {
+			let name = &o.key[prefix.len()..];
+			if name.is_empty() {
+				marked = true;
+			} else {
+				entries.push((dir.join(name), o.cha()));
+			}
+		}
+
+		if entries.is_empty() && !marked && !key.is_empty() {
+			return Err(io::ErrorKind::NotFound.into());
+		}
+		Ok(Self::ReadDir { dir: Arc::new(self.url.to_owned()), entries: entries.into_iter() })
+	}
+
+	async fn read_link(&self) -> io::Result<PathBufDyn> {
+		Err(io::Error::new(io::ErrorKind::InvalidInput, "S3 has no symlinks"))
+	}
+
+	async fn remove_dir(&self) -> io::Result<()> {
+		let key = self.key()?;
+		let listing = self.client.list(&prefix(&key), false, Some(2)).await?;
+
+		let marker = prefix(&key);
+		if listing.objects.iter().any(|o| o.key != marker) {
+			return Err(io::ErrorKind::DirectoryNotEmpty.into());
+		} else if listing.objects.is_empty() {
+			return Err(io::ErrorKind::NotFound.into());
+		}
+		self.client.send(Method::DELETE, &marker, &[], HeaderMap::new(), Bytes::new()).await.map(|_| ())
+	}
+
+	async fn remove_dir_all(&self) -> io::Result<()> {
+		for o in self.descendants(&self.key()?).await?.objects {
+			self.client.send(Method::DELETE, &o.key, &[], HeaderMap::new(), Bytes::new()).await?;
+		}
+		Ok(())
+	}
+
+	async fn remove_file(&self) -> io::Result<()> {
+		// Deleting a nonexistent key succeeds, so make sure there's something to delete
+		let key = self.key()?;
+		self.client.head(&key).await?;
+		self.client.send(Method::DELETE, &key, &[], HeaderMap::new(), Bytes::new()).await.map(|_| ())
+	}
+
+	/// S3 has no rename, so objects are copied to their new keys and deleted,
+	/// one by one for directories.
+	async fn rename<P>(&self, to: P) -> io::Result<()>
+	where
+		P: DynPath,
+	{
+		let (from, to) = (self.key()?, key(to.dyn_path().as_unix()?)?);
+		let objects = match self.client.head(&from).await {
+			Ok(o) => vec![(o.key, to)],
+			Err(e) if e.kind() == io::ErrorKind::NotFound => {
+				let (from, to) = (prefix(&from), prefix(&to));
+				let objects = self.client.list(&from, false, None).await?.objects;
+				if objects.is_empty() {
+					return Err(e);
+				}
+				objects
+					.into_iter()
+					.map(|o| (o.key.clone(), format!("{to}{}", &o.key[from.len()..])))
+					.collect()
+			}
+			Err(e) => return Err(e),
+		};
+
+		for (from, to) in objects {
+			self.client.copy(&from, &to).await?;
+			self.client.send(Method::DELETE, &from, &[], HeaderMap::new(), Bytes::new()).await?;
+		}
+		Ok(())
+	}
+
+	/// Objects have no permissions, ownership or times that can be changed.
+	async fn set_attrs(&self, _attrs: yazi_fs::engine::Attrs) -> io::Result<()> { Ok(()) }
+
+	async fn symlink<S, F>(&self, _original: S, _is_dir: F) -> io::Result<()>
+	where
+		S: AsStrand,
+		F: AsyncFnOnce() -> io::Result<bool>,
+	{
+		Err(io::Error::new(io::ErrorKind::Unsupported, "S3 does not support symlinks"))
+	}
+
+	async fn symlink_metadata(&self) -> io::Result<Cha> {
+		let key = self.key()?;
+		if key.is_empty() {
+			return Ok(super::list::dir(""));
+		}
+
+		let e = match self.client.head(&key).await {
+			Ok(o) => return Ok(o.cha()),
+			Err(e) if e.kind() == io::ErrorKind::NotFound => e,
+			Err(e) => return Err(e),
+		};
+
+		// Not an object, but it might still be a prefix
+		let listing = self.client.list(&prefix(&key), false, Some(1)).await?;
+		if listing.objects.is_empty() {
+			return Err(e);
+		}
+		Ok(super::list::dir(
+			self.path.file_name().map(String::from_utf8_lossy).unwrap_or_default().as_ref(),
+		))
+	}
+
+	async fn trash(&self) -> io::Result<()> {
+		Err(io::Error::new(io::ErrorKind::Unsupported, "S3 does not support trash"))
+	}
+
+	#[inline]
+	fn url(&self) -> Url<'_> { self.url }
+
+	async fn write<C>(&self, contents: C) -> io::Result<()>
+	where
+		C: AsRef<[u8]>,
+	{
+		let body = Bytes::copy_from_slice(contents.as_ref());
+		self.client.send(Method::PUT, &self.key()?, &[], HeaderMap::new(), body).await.map(|_| ())
+	}
+}
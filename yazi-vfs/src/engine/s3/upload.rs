@@ -0,0 +1,73 @@
+use std::io;
+
+use bytes::{Bytes, BytesMut};
+use reqwest::{Method, header::HeaderMap};
+
+use super::Client;
+
+// S3 requires parts to be at least 5 MiB, except for the last one
+const PART_SIZE: usize = 8 << 20;
+
+// And there can't be more than this many of them
+const MAX_PARTS: u64 = 10000;
+
+/// An upload that stays a single `PutObject` for small files, and turns into
+/// a multipart upload once there's more than a part's worth of data.
+pub(super) struct Upload {
+	client: &'static Client,
+	key:    String,
+
+	id:        Option<String>,
+	etags:     Vec<String>,
+	buf:       BytesMut,
+	part_size: usize,
+}
+
+impl Upload {
+	pub(super) fn new(client: &'static Client, key: String, len: u64) -> Self {
+		let part_size = (len.div_ceil(MAX_PARTS) as usize).max(PART_SIZE);
+		Self { client, key, id: None, etags: vec![], buf: BytesMut::new(), part_size }
+	}
+
+	pub(super) async fn write(&mut self, data: Bytes) -> io::Result<()> {
+		self.buf.extend_from_slice(&data);
+		while self.buf.len() >= self.part_size {
+			let part = self.buf.split_to(self.part_size).freeze();
+			self.part(part).await?;
+		}
+		Ok(())
+	}
+
+	pub(super) async fn finish(&mut self) -> io::Result<()> {
+		let rest = self.buf.split().freeze();
+		let Some(id) = self.id.clone() else {
+			return self
+				.client
+				.send(Method::PUT, &self.key, &[], HeaderMap::new(), rest)
+				.await
+				.map(|_| ());
+		};
+
+		if !rest.is_empty() {
+			self.etags.push(self.client.upload_part(&self.key, &id, self.etags.len() + 1, rest).await?);
+		}
+		self.client.complete(&self.key, &id, &self.etags).await
+	}
+
+	/// Discard the parts uploaded so far, so they don't linger in the bucket.
+	pub(super) async fn abort(&mut self) {
+		if let Some(id) = self.id.take() {
+			self.client.abort(&self.key, &id).await.ok();
+		}
+	}
+
+	async fn part(&mut self, body: Bytes) -> io::Result<()> {
+		let id = match &self.id {
+			Some(id) => id.clone(),
+			None => self.id.insert(self.client.initiate(&self.key).await?).clone(),
+		};
+
+		self.etags.push(self.client.upload_part(&self.key, &id, self.etags.len() + 1, body).await?);
+		Ok(())
+	}
+}
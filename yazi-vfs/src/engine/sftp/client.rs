@@ -0,0 +1,66 @@
+use std::{io, ops::Deref};
+
+use russh::{ChannelMsg, client::Handle};
+use yazi_sftp::Operator;
+
+use super::Conn;
+
+/// An SFTP session, along with the SSH connection it runs on, which can be used
+/// to open further channels.
+pub(super) struct Client {
+	pub(super) op:  Operator,
+	pub(super) ssh: Handle<Conn>,
+}
+
+impl Deref for Client {
+	type Target = Operator;
+
+	fn deref(&self) -> &Self::Target { &self.op }
+}
+
+impl Client {
+	pub(super) fn is_closed(&self) -> bool { self.op.is_closed() || self.ssh.is_closed() }
+
+	/// Run `command` on the remote host through an exec channel, returning its
+	/// stdout, or an error carrying its stderr if it exits with a non-zero
+	/// status.
+	pub(super) async fn exec(&self, command: &[u8]) -> io::Result<Vec<u8>> {
+		let mut channel = self.ssh.channel_open_session().await.map_err(io::Error::other)?;
+		channel.exec(true, command).await.map_err(io::Error::other)?;
+
+		let (mut stdout, mut stderr, mut code) = (vec![], vec![], None);
+		while let Some(msg) = channel.wait().await {
+			match msg {
+				ChannelMsg::Data { data } => stdout.extend_from_slice(&data),
+				ChannelMsg::ExtendedData { data, ext: 1 } => stderr.extend_from_slice(&data),
+				ChannelMsg::ExitStatus { exit_status } => code = Some(exit_status),
+				ChannelMsg::Failure => Err(io::Error::other("Exec request rejected by the server"))?,
+				_ => {}
+			}
+		}
+
+		match code {
+			Some(0) => Ok(stdout),
+			Some(n) => Err(io::Error::other(format!(
+				"Remote command exited with status {n}: {}",
+				String::from_utf8_lossy(&stderr).trim()
+			))),
+			None => Err(io::Error::other("Remote command terminated without an exit status")),
+		}
+	}
+}
+
+/// Quote `s` as a single POSIX shell word.
+pub(super) fn shell_quote(s: &[u8]) -> Vec<u8> {
+	let mut out = Vec::with_capacity(s.len() + 2);
+	out.push(b'\'');
+	for &b in s {
+		if b == b'\'' {
+			out.extend_from_slice(b"'\\''");
+		} else {
+			out.push(b);
+		}
+	}
+	out.push(b'\'');
+	out
+}
@@ -5,6 +5,8 @@ use russh::keys::{PrivateKeyWithHashAlg, PublicKeyOrCertificate, agent::AgentIde
 use yazi_config::vfs::ServiceSftp;
 use yazi_fs::engine::local::Local;
 
+use super::Client;
+
 #[derive(Clone, Copy)]
 pub(super) struct Conn {
 	pub(super) config: &'static ServiceSftp,
@@ -29,16 +31,21 @@ impl russh::client::Handler for Conn {
 
 impl deadpool::managed::Manager for Conn {
 	type Error = io::Error;
-	type Type = yazi_sftp::Operator;
+	type Type = Client;
 
 	async fn create(&self) -> Result<Self::Type, Self::Error> {
-		let channel = self.connect().await.map_err(|e| {
-			io::Error::other(format!("Failed to connect to SFTP server `{}`: {e}", self.config.domain))
+		let (ssh, channel) = self.connect().await.map_err(|e| {
+			io::Error::new(
+				io::ErrorKind::NotConnected,
+				format!("Failed to connect to SFTP server `{}`: {e}", self.config.domain),
+			)
 		})?;
 
 		let mut op = yazi_sftp::Operator::make(channel.into_stream());
 		op.init().await?;
-		Ok(op)
+
+		super::COPY_DATA.lock().insert(self.config, op.supports("copy-data"));
+		Ok(Client { op, ssh })
 	}
 
 	async fn recycle(
@@ -78,7 +85,9 @@ impl Conn {
 		})
 	}
 
-	async fn connect(self) -> Result<russh::Channel<russh::client::Msg>, russh::Error> {
+	async fn connect(
+		self,
+	) -> Result<(russh::client::Handle<Self>, russh::Channel<russh::client::Msg>), russh::Error> {
 		let pref = Arc::new(russh::client::Config {
 			inactivity_timeout: Some(std::time::Duration::from_secs(60)),
 			keepalive_interval: Some(std::time::Duration::from_secs(10)),
@@ -101,7 +110,7 @@ impl Conn {
 
 		let channel = session.channel_open_session().await?;
 		channel.request_subsystem(true, "sftp").await?;
-		Ok(channel)
+		Ok((session, channel))
 	}
 
 	async fn connect_by_password(
@@ -11,8 +11,8 @@ impl TryFrom<Attrs> for yazi_sftp::fs::Attrs {
 	fn try_from(value: Attrs) -> Result<Self, Self::Error> {
 		let attrs = Self {
 			size:     None,
-			uid:      None,
-			gid:      None,
+			uid:      value.0.uid,
+			gid:      value.0.gid,
 			perm:     value.0.mode.map(|m| m.bits() as u32),
 			atime:    value.0.atime_dur().map(|d| d.as_secs() as u32),
 			mtime:    value.0.mtime_dur().map(|d| d.as_secs() as u32),
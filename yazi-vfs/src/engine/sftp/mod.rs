@@ -1,4 +1,4 @@
-yazi_macro::mod_flat!(conn demand metadata read_dir sftp);
+yazi_macro::mod_flat!(client conn demand metadata read_dir remote sftp);
 
 static CONN: yazi_shim::cell::RoCell<
 	parking_lot::Mutex<
@@ -9,4 +9,41 @@ static CONN: yazi_shim::cell::RoCell<
 	>,
 > = yazi_shim::cell::RoCell::new();
 
-pub(super) fn init() { CONN.init(Default::default()); }
+static TRASH: yazi_shim::cell::RoCell<
+	parking_lot::Mutex<
+		hashbrown::HashMap<&'static yazi_config::vfs::ServiceSftp, Vec<yazi_shared::url::UrlBuf>>,
+	>,
+> = yazi_shim::cell::RoCell::new();
+
+// Whether the server of each service supports the `copy-data` extension, which
+// is only known once connected
+static COPY_DATA: yazi_shim::cell::RoCell<
+	parking_lot::Mutex<hashbrown::HashMap<&'static yazi_config::vfs::ServiceSftp, bool>>,
+> = yazi_shim::cell::RoCell::new();
+
+pub(super) fn init() {
+	CONN.init(Default::default());
+	TRASH.init(Default::default());
+	COPY_DATA.init(Default::default());
+}
+
+/// Trash folders of all SFTP services, i.e. those configured for a domain and
+/// any connected to through a catch-all.
+pub(crate) async fn trash_roots() -> Vec<yazi_shared::url::UrlBuf> {
+	let mut services: hashbrown::HashSet<&'static yazi_config::vfs::ServiceSftp> =
+		yazi_config::VFS.authorities.services().into_iter().filter_map(|s| s.try_into().ok()).collect();
+	services.extend(CONN.lock().keys().copied());
+
+	let roots = futures::future::join_all(services.into_iter().map(async |config| {
+		match Sftp::root(config).trash_roots().await {
+			Ok(roots) => roots,
+			Err(e) => {
+				yazi_macro::debug!("Cannot resolve the trash folders of `{}`: {e}", config.domain);
+				vec![]
+			}
+		}
+	}))
+	.await;
+
+	roots.into_iter().flatten().collect()
+}
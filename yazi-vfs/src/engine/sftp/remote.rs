@@ -0,0 +1,86 @@
+use std::io;
+
+use russh::{Channel, ChannelMsg, client::Msg};
+use yazi_config::vfs::{ServiceSftp, Vfs};
+use yazi_shared::{strand::AsStrand, url::Url};
+
+use super::{Conn, shell_quote};
+
+/// A shell command running on the host of an SFTP URL, over an exec channel of
+/// the SSH session the SFTP connection runs on.
+pub struct RemoteProcess {
+	channel: Channel<Msg>,
+	// The SSH session goes away with its handle, so hold the connection for as
+	// long as the command runs.
+	_client: deadpool::managed::Object<Conn>,
+}
+
+#[derive(Debug)]
+pub enum RemoteOutput {
+	Stdout(Vec<u8>),
+	Stderr(Vec<u8>),
+	Exit(Option<u32>),
+}
+
+impl RemoteProcess {
+	/// Run `cmd` with `sh` in the remote directory `cwd`, attached to a
+	/// pseudo-terminal of `pty` columns and rows if given.
+	///
+	/// Fails unless the service has `allow_exec` enabled.
+	pub async fn spawn(cwd: Url<'_>, cmd: &[u8], pty: Option<(u16, u16)>) -> io::Result<Self> {
+		let Url::Sftp { loc, auth } = cwd else {
+			return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("Not a SFTP URL: {cwd:?}")));
+		};
+
+		let config = Vfs::service::<&ServiceSftp>(auth)?;
+		if !config.allow_exec {
+			return Err(io::Error::new(
+				io::ErrorKind::PermissionDenied,
+				format!("Running commands is not allowed on `{}`, see `allow_exec`", config.domain),
+			));
+		}
+
+		let client = Conn { config }.roll().await?;
+		let channel = client.ssh.channel_open_session().await.map_err(io::Error::other)?;
+
+		if let Some((cols, rows)) = pty {
+			let term = std::env::var("TERM").unwrap_or_else(|_| "xterm-256color".to_owned());
+			channel
+				.request_pty(true, &term, cols as u32, rows as u32, 0, 0, &[])
+				.await
+				.map_err(io::Error::other)?;
+		}
+
+		let mut line = b"cd -- ".to_vec();
+		line.extend(shell_quote(loc.as_strand().encoded_bytes()));
+		line.extend_from_slice(b" && exec sh -c ");
+		line.extend(shell_quote(cmd));
+
+		channel.exec(true, line).await.map_err(io::Error::other)?;
+		Ok(Self { channel, _client: client })
+	}
+
+	/// Wait for the next output of the command, returning `None` once the
+	/// channel is closed.
+	pub async fn next(&mut self) -> io::Result<Option<RemoteOutput>> {
+		while let Some(msg) = self.channel.wait().await {
+			return Ok(Some(match msg {
+				ChannelMsg::Data { data } => RemoteOutput::Stdout(data.to_vec()),
+				ChannelMsg::ExtendedData { data, ext: 1 } => RemoteOutput::Stderr(data.to_vec()),
+				ChannelMsg::ExitStatus { exit_status } => RemoteOutput::Exit(Some(exit_status)),
+				ChannelMsg::ExitSignal { .. } => RemoteOutput::Exit(None),
+				ChannelMsg::Failure => Err(io::Error::other("Exec request rejected by the server"))?,
+				_ => continue,
+			}));
+		}
+		Ok(None)
+	}
+
+	pub async fn write(&self, data: &[u8]) -> io::Result<()> {
+		self.channel.data(data).await.map_err(io::Error::other)
+	}
+
+	pub async fn resize(&self, cols: u16, rows: u16) -> io::Result<()> {
+		self.channel.window_change(cols as u32, rows as u32, 0, 0).await.map_err(io::Error::other)
+	}
+}
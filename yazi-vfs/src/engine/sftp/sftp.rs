@@ -1,13 +1,13 @@
 use std::{io, sync::Arc};
 
-use tokio::{io::{AsyncWriteExt, BufReader, BufWriter}, sync::mpsc::Receiver};
+use tokio::{io::{AsyncWriteExt, BufReader, BufWriter}, sync::{mpsc, mpsc::Receiver}};
 use yazi_config::vfs::{ServiceSftp, Vfs};
-use yazi_fs::engine::{Capabilities, DirReader, Engine, FileHolder};
+use yazi_fs::{Digest, engine::{Capabilities, Engine}};
 use yazi_sftp::fs::{Attrs, Flags};
-use yazi_shared::{auth::AuthKind, loc::LocBuf, path::{DynPath, PathBufDyn}, strand::AsStrand, url::{Url, UrlBuf, UrlCow, UrlLike}};
+use yazi_shared::{auth::AuthKind, loc::{Loc, LocBuf}, path::{DynPath, PathBufDyn}, strand::AsStrand, url::{AsUrl, Url, UrlBuf, UrlCow, UrlLike}};
 
 use super::Cha;
-use crate::engine::sftp::Conn;
+use crate::engine::sftp::{Conn, shell_quote};
 
 #[derive(Clone)]
 pub struct Sftp<'a> {
@@ -43,13 +43,18 @@ impl<'a> Engine for Sftp<'a> {
 		Ok(Capabilities {
 			symlink: true,
 			hard_link: true,
+			trash: true,
 			copy_progressive: true,
+			// Assumed until connected, copies fall back to streaming if it's not
+			server_copy: self.config.allow_exec
+				|| super::COPY_DATA.lock().get(self.config).copied().unwrap_or(true),
+			ownership: true,
 			..Default::default()
 		})
 	}
 
 	async fn casefold(&self) -> io::Result<UrlBuf> {
-		let Some((parent, name)) = self.url.parent().zip(self.url.name()) else {
+		let Some((parent, _)) = self.url.parent().zip(self.url.name()) else {
 			return Ok(self.url.to_owned());
 		};
 
@@ -60,22 +65,18 @@ impl<'a> Engine for Sftp<'a> {
 			});
 		}
 
-		let mut it = Self::new(parent).await?.read_dir().await?;
-		let mut similar = None;
-		while let Some(dent) = it.next().await? {
-			let s = dent.name();
-			if !name.eq_ignore_ascii_case(&s) {
-				continue;
-			} else if s == name {
-				return Ok(dent.url());
-			} else if similar.is_none() {
-				similar = Some(s.into_owned());
-			} else {
-				return Err(io::ErrorKind::NotFound.into());
+		crate::engine::casefold_impl(self.url).await
+	}
+
+	async fn checksum(&self, digest: Digest) -> io::Result<String> {
+		if let Some(algo) = digest.sftp_name() {
+			match self.op().await?.check_file(self.path, algo).await {
+				Ok((used, hash)) if used == algo => return Ok(Digest::encode(&hash)),
+				Ok(_) | Err(yazi_sftp::Error::Unsupported) => {}
+				Err(e) => Err(e)?,
 			}
 		}
-
-		similar.map(|n| parent.try_join(n)).transpose()?.ok_or(io::ErrorKind::NotFound.into())
+		digest.read(self.open().await?).await
 	}
 
 	async fn copy<P>(&self, to: P, attrs: yazi_fs::engine::Attrs) -> io::Result<u64>
@@ -83,8 +84,11 @@ impl<'a> Engine for Sftp<'a> {
 		P: DynPath,
 	{
 		let to = to.dyn_path().as_unix()?;
-		let attrs = super::Attrs(attrs).try_into().unwrap_or_default();
+		if let Some(written) = self.copy_remote(to, attrs).await? {
+			return Ok(written);
+		}
 
+		let attrs = super::Attrs(attrs).try_into().unwrap_or_default();
 		let op = self.op().await?;
 		let from = op.open(self.path, Flags::READ, &Attrs::default()).await?;
 		let to = op.open(to, Flags::WRITE | Flags::CREATE | Flags::TRUNCATE, &attrs).await?;
@@ -107,16 +111,40 @@ impl<'a> Engine for Sftp<'a> {
 		P: DynPath,
 		A: Into<yazi_fs::engine::Attrs>,
 	{
+		let path = to.dyn_path().to_unix_owned()?;
 		let to = UrlBuf::Sftp {
-			loc:  LocBuf::<typed_path::UnixPathBuf>::saturated(
-				to.dyn_path().to_unix_owned()?,
-				AuthKind::Sftp,
-			),
+			loc:  LocBuf::<typed_path::UnixPathBuf>::saturated(path.clone(), AuthKind::Sftp),
 			auth: self.config.auth.clone(),
 		};
-		let from = self.url.to_owned();
+		let (from, attrs) = (self.url.to_owned(), attrs.into());
+
+		let (tx, rx) = mpsc::channel(1);
+		tokio::spawn(async move {
+			let result = match Sftp::new(from.as_url()).await {
+				Ok(me) => me.copy_remote(&path, attrs).await,
+				Err(e) => Err(e),
+			};
+
+			match result {
+				Ok(Some(written)) => {
+					if written > 0 {
+						tx.send(Ok(written)).await.ok();
+					}
+					tx.send(Ok(0)).await.ok();
+				}
+				Ok(None) => {
+					let mut rx = crate::engine::copy_progressive_impl(from, to, attrs);
+					while let Some(r) = rx.recv().await {
+						if tx.send(r).await.is_err() {
+							break;
+						}
+					}
+				}
+				Err(e) => _ = tx.send(Err(e)).await,
+			}
+		});
 
-		Ok(crate::engine::copy_progressive_impl(from, to, attrs.into()))
+		Ok(rx)
 	}
 
 	async fn create_dir(&self) -> io::Result<()> {
@@ -184,7 +212,8 @@ impl<'a> Engine for Sftp<'a> {
 				match op.remove(&to).await.map_err(io::Error::from) {
 					Ok(()) => {}
 					Err(e) if e.kind() == io::ErrorKind::NotFound => {}
-					Err(e) => Err(e)?,
+					// Like `rename(2)`, an empty directory can be replaced too
+					Err(e) => op.rmdir(&to).await.map_err(|_| e)?,
 				}
 				op.rename(self.path, &to).await?;
 			}
@@ -217,7 +246,22 @@ impl<'a> Engine for Sftp<'a> {
 	}
 
 	async fn trash(&self) -> io::Result<()> {
-		Err(io::Error::new(io::ErrorKind::Unsupported, "Trash not supported"))
+		let home = self.trash_roots().await?.swap_remove(0);
+		let e = match crate::trash::VfsTrash::put(&home, self.url, None).await {
+			Err(e) if e.kind() != io::ErrorKind::NotFound => e,
+			r => return r,
+		};
+
+		// Files on another mount can't be renamed into the home trash
+		let Ok(Some((root, top))) = self.topdir_trash().await else { return Err(e) };
+		crate::trash::VfsTrash::put(&root, self.url, Some(&top)).await?;
+
+		let mut roots = super::TRASH.lock();
+		let roots = roots.entry(self.config).or_default();
+		if !roots.contains(&root) {
+			roots.push(root);
+		}
+		Ok(())
 	}
 
 	#[inline]
@@ -229,4 +273,172 @@ impl<'a> Sftp<'a> {
 	pub(super) async fn op(&self) -> io::Result<deadpool::managed::Object<Conn>> {
 		Conn { config: self.config }.roll().await
 	}
+
+	pub(super) fn root(config: &'static ServiceSftp) -> Sftp<'static> {
+		let root = typed_path::UnixPath::new("/");
+		Sftp {
+			url: Url::Sftp { loc: Loc::saturated(root, AuthKind::Sftp), auth: &config.auth },
+			path: root,
+			config,
+		}
+	}
+
+	/// Trash folders of the remote user, the home one first, followed by those
+	/// at the top of other mounts.
+	///
+	/// The latter are found with `df` if exec is allowed, otherwise only those
+	/// trashed into since connecting are known.
+	pub(super) async fn trash_roots(&self) -> io::Result<Vec<UrlBuf>> {
+		if let Some(roots) = super::TRASH.lock().get(self.config) {
+			return Ok(roots.clone());
+		}
+
+		let mut roots = vec![self.home_trash().await?];
+		if self.config.allow_exec {
+			const FIND: &[u8] = br#"uid=$(id -u) && df -P | awk 'NR > 1 { print $6 }' | while IFS= read -r m; do for t in "$m/.Trash/$uid" "$m/.Trash-$uid"; do [ -d "$t/info" ] && printf '%s\n' "$t"; done; done; true"#;
+			match self.op().await?.exec(FIND).await {
+				Ok(b) => roots.extend(
+					b.split(|&c| c == b'\n').filter(|l| l.starts_with(b"/")).map(|l| self.url_of(l.into())),
+				),
+				Err(e) => {
+					yazi_macro::debug!("Cannot find the trash folders on `{}`: {e}", self.config.domain)
+				}
+			}
+		}
+
+		Ok(super::TRASH.lock().entry(self.config).or_insert(roots).clone())
+	}
+
+	/// The home trash of the remote user, i.e. `$XDG_DATA_HOME/Trash`.
+	///
+	/// `$XDG_DATA_HOME` can only be read if exec is allowed, otherwise it's
+	/// assumed to be the default `~/.local/share`.
+	async fn home_trash(&self) -> io::Result<UrlBuf> {
+		let op = self.op().await?;
+		let mut data = None;
+		if self.config.allow_exec {
+			match op.exec(br#"printf %s "${XDG_DATA_HOME:-$HOME/.local/share}""#).await {
+				Ok(b) if b.starts_with(b"/") => data = Some(typed_path::UnixPathBuf::from(b)),
+				Ok(_) => {}
+				Err(e) => yazi_macro::debug!("Cannot read $XDG_DATA_HOME on `{}`: {e}", self.config.domain),
+			}
+		}
+
+		let data = match data {
+			Some(p) => p,
+			None => op.realpath(".").await?.join(".local/share"),
+		};
+		Ok(self.url_of(data.join("Trash")))
+	}
+
+	/// The trash folder at the top of the mount the file is on, for when it
+	/// can't be moved into the home trash, along with that top directory.
+	///
+	/// With exec, the mount is found with `df` and its trash is created if need
+	/// be. Otherwise, only an existing one in one of the ancestors is used.
+	async fn topdir_trash(&self) -> io::Result<Option<(UrlBuf, typed_path::UnixPathBuf)>> {
+		let op = self.op().await?;
+		let is_dir = |a: &Attrs| a.perm.is_some_and(|p| p & 0o170000 == 0o040000);
+
+		let (uid, tops) = if self.config.allow_exec {
+			let command = [
+				b"id -u && df -P -- ",
+				&*shell_quote(self.path.as_bytes()),
+				b" | awk 'NR == 2 { print $6 }'",
+			]
+			.concat();
+			let out = op.exec(&command).await?;
+			let mut lines = out.split(|&b| b == b'\n');
+			let uid = lines.next().unwrap_or_default().to_vec();
+			let top = lines.next().filter(|l| l.starts_with(b"/")).map(typed_path::UnixPathBuf::from);
+			(uid, top.into_iter().collect())
+		} else {
+			let uid = op.stat(&op.realpath(".").await?).await?.uid;
+			let uid = uid.ok_or_else(|| io::Error::other("Cannot get the remote user ID"))?;
+			let tops: Vec<_> = self.path.ancestors().skip(1).map(|p| p.to_owned()).collect();
+			(uid.to_string().into_bytes(), tops)
+		};
+
+		if uid.is_empty() || !uid.iter().all(u8::is_ascii_digit) {
+			return Err(io::Error::other("Cannot get the remote user ID"));
+		}
+
+		for top in tops {
+			// A shared `.Trash` must be a sticky directory rather than a symlink
+			let shared = top.join(".Trash");
+			if op.lstat(&shared).await.is_ok_and(|a| is_dir(&a) && a.perm.unwrap_or(0) & 0o1000 != 0)
+				&& (self.config.allow_exec || op.lstat(&shared.join(&uid)).await.is_ok_and(|a| is_dir(&a)))
+			{
+				return Ok(Some((self.url_of(shared.join(&uid)), top)));
+			}
+
+			let own = top.join([b".Trash-", &uid[..]].concat());
+			if self.config.allow_exec || op.lstat(&own).await.is_ok_and(|a| is_dir(&a)) {
+				return Ok(Some((self.url_of(own), top)));
+			}
+		}
+		Ok(None)
+	}
+
+	fn url_of(&self, path: typed_path::UnixPathBuf) -> UrlBuf {
+		UrlBuf::Sftp {
+			loc:  LocBuf::<typed_path::UnixPathBuf>::saturated(path, AuthKind::Sftp),
+			auth: self.config.auth.clone(),
+		}
+	}
+
+	/// Copy the file to `to` without transferring it through the client, using
+	/// the `copy-data` extension, or `cp` on the remote host if exec is allowed.
+	///
+	/// Returns `None` if neither is available or works, so the caller can fall
+	/// back to a regular copy.
+	async fn copy_remote(
+		&self,
+		to: &typed_path::UnixPath,
+		attrs: yazi_fs::engine::Attrs,
+	) -> io::Result<Option<u64>> {
+		let attrs: Attrs = super::Attrs(attrs).try_into().unwrap_or_default();
+		let op = self.op().await?;
+
+		if op.supports("copy-data") {
+			match self.copy_data(&op, to, &attrs).await {
+				Ok(written) => return Ok(Some(written)),
+				Err(e) => yazi_macro::debug!("`copy-data` of {:?} failed, falling back: {e}", self.url),
+			}
+		}
+
+		if !self.config.allow_exec {
+			return Ok(None);
+		}
+
+		let command =
+			[b"cp -- ", &*shell_quote(self.path.as_bytes()), b" ", &shell_quote(to.as_bytes())].concat();
+		if let Err(e) = op.exec(&command).await {
+			yazi_macro::debug!("Remote copy of {:?} failed, falling back: {e}", self.url);
+			return Ok(None);
+		}
+
+		if !attrs.is_empty() {
+			op.setstat(to, attrs).await.ok();
+		}
+		Ok(Some(op.stat(to).await?.size.unwrap_or(0)))
+	}
+
+	async fn copy_data(
+		&self,
+		op: &yazi_sftp::Operator,
+		to: &typed_path::UnixPath,
+		attrs: &Attrs,
+	) -> io::Result<u64> {
+		let from = op.open(self.path, Flags::READ, &Attrs::default()).await?;
+		let mut to = op.open(to, Flags::WRITE | Flags::CREATE | Flags::TRUNCATE, attrs).await?;
+
+		from.copy_to(&to).await?;
+		if !attrs.is_empty() {
+			to.fsetstat(attrs).await.ok();
+		}
+
+		to.shutdown().await.ok();
+		Ok(from.fstat().await?.size.unwrap_or(0))
+	}
 }
@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use hashbrown::HashMap;
+use parking_lot::Mutex;
+use yazi_config::VFS;
+use yazi_fs::engine::Bucket;
+use yazi_shared::{auth::Auth, url::Url};
+use yazi_shim::cell::RoCell;
+
+static BUCKETS: RoCell<Mutex<HashMap<Arc<Auth>, Arc<Bucket>>>> = RoCell::new();
+
+pub(super) fn init() { BUCKETS.init(Default::default()); }
+
+/// The bandwidth limit of the service `url` belongs to, if it has one.
+///
+/// Buckets are kept by service name, so they outlive a config reload, and pick
+/// up its new rate.
+pub(super) fn bucket(url: Url) -> Option<Arc<Bucket>> {
+	let auth = url.auth();
+	let service = VFS.authorities.service(&auth.scheme, &auth.domain)?;
+
+	let mut buckets = BUCKETS.lock();
+	let rate = service.throttle();
+	if rate == 0 {
+		buckets.remove(service.auth());
+		return None;
+	}
+
+	let bucket = buckets.entry(service.auth().clone()).or_insert_with(|| Arc::new(Bucket::new(rate)));
+	bucket.set_rate(rate);
+	Some(bucket.clone())
+}
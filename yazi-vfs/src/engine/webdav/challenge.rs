@@ -0,0 +1,237 @@
+use std::{fmt::Write, sync::atomic::{AtomicU32, Ordering}};
+
+use md5::Md5;
+use rand::{Rng, make_rng, rngs::SmallRng};
+use reqwest::{Method, RequestBuilder};
+use sha2::{Digest as _, Sha256};
+use yazi_config::vfs::ServiceWebdav;
+
+/// An authentication scheme the server asked for in `WWW-Authenticate`.
+pub(super) enum Challenge {
+	Basic,
+	Digest(Digest),
+}
+
+pub(super) struct Digest {
+	realm:     String,
+	nonce:     String,
+	opaque:    Option<String>,
+	algorithm: Algorithm,
+	qop:       bool,
+	nc:        AtomicU32,
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum Algorithm {
+	Md5,
+	Md5Sess,
+	Sha256,
+	Sha256Sess,
+}
+
+impl Challenge {
+	/// Pick the strongest scheme out of the `WWW-Authenticate` headers,
+	/// preferring Digest over Basic so the password isn't sent in the clear.
+	pub(super) fn parse<'a>(headers: impl IntoIterator<Item = &'a str>) -> Option<Self> {
+		let mut basic = false;
+		for header in headers {
+			let (scheme, params) = header.trim().split_once(' ').unwrap_or((header.trim(), ""));
+			if scheme.eq_ignore_ascii_case("basic") {
+				basic = true;
+			} else if scheme.eq_ignore_ascii_case("digest")
+				&& let Some(digest) = Digest::parse(params)
+			{
+				return Some(Self::Digest(digest));
+			}
+		}
+		basic.then_some(Self::Basic)
+	}
+
+	pub(super) fn apply(
+		&self,
+		req: RequestBuilder,
+		config: &ServiceWebdav,
+		method: &Method,
+		uri: &str,
+	) -> RequestBuilder {
+		match self {
+			Self::Basic => req.basic_auth(&config.user, config.password.as_deref()),
+			Self::Digest(digest) => {
+				req.header(reqwest::header::AUTHORIZATION, digest.authorize(config, method, uri))
+			}
+		}
+	}
+}
+
+impl Digest {
+	fn parse(s: &str) -> Option<Self> {
+		let (mut realm, mut nonce, mut opaque) = (None, None, None);
+		let (mut algorithm, mut qop) = (Algorithm::Md5, false);
+
+		for (k, v) in params(s) {
+			match k.to_ascii_lowercase().as_str() {
+				"realm" => realm = Some(v.to_owned()),
+				"nonce" => nonce = Some(v.to_owned()),
+				"opaque" => opaque = Some(v.to_owned()),
+				"qop" => qop = v.split(',').any(|q| q.trim().eq_ignore_ascii_case("auth")),
+				"algorithm" => {
+					algorithm = match v.to_ascii_uppercase().as_str() {
+						"MD5" => Algorithm::Md5,
+						"MD5-SESS" => Algorithm::Md5Sess,
+						"SHA-256" => Algorithm::Sha256,
+						"SHA-256-SESS" => Algorithm::Sha256Sess,
+						_ => return None,
+					}
+				}
+				_ => {}
+			}
+		}
+
+		Some(Self { realm: realm?, nonce: nonce?, opaque, algorithm, qop, nc: AtomicU32::new(0) })
+	}
+
+	fn authorize(&self, config: &ServiceWebdav, method: &Method, uri: &str) -> String {
+		let nc = format!("{:08x}", self.nc.fetch_add(1, Ordering::Relaxed) + 1);
+		let cnonce = format!("{:016x}", make_rng::<SmallRng>().next_u64());
+
+		let password = config.password.as_deref().unwrap_or_default();
+		let mut ha1 = self.hash(&format!("{}:{}:{password}", config.user, self.realm));
+		if matches!(self.algorithm, Algorithm::Md5Sess | Algorithm::Sha256Sess) {
+			ha1 = self.hash(&format!("{ha1}:{}:{cnonce}", self.nonce));
+		}
+
+		let ha2 = self.hash(&format!("{method}:{uri}"));
+		let response = if self.qop {
+			self.hash(&format!("{ha1}:{}:{nc}:{cnonce}:auth:{ha2}", self.nonce))
+		} else {
+			self.hash(&format!("{ha1}:{}:{ha2}", self.nonce))
+		};
+
+		let mut s = format!(
+			r#"Digest username="{}", realm="{}", nonce="{}", uri="{uri}", algorithm={}, response="{response}""#,
+			quote(&config.user),
+			quote(&self.realm),
+			quote(&self.nonce),
+			match self.algorithm {
+				Algorithm::Md5 => "MD5",
+				Algorithm::Md5Sess => "MD5-sess",
+				Algorithm::Sha256 => "SHA-256",
+				Algorithm::Sha256Sess => "SHA-256-sess",
+			}
+		);
+		if self.qop {
+			write!(s, r#", qop=auth, nc={nc}, cnonce="{cnonce}""#).ok();
+		}
+		if let Some(opaque) = &self.opaque {
+			write!(s, r#", opaque="{}""#, quote(opaque)).ok();
+		}
+		s
+	}
+
+	fn hash(&self, s: &str) -> String {
+		match self.algorithm {
+			Algorithm::Md5 | Algorithm::Md5Sess => yazi_fs::Digest::encode(&Md5::digest(s)),
+			Algorithm::Sha256 | Algorithm::Sha256Sess => yazi_fs::Digest::encode(&Sha256::digest(s)),
+		}
+	}
+}
+
+/// Split the `key=value` or `key="quoted value"` parameters of a challenge.
+fn params(s: &str) -> impl Iterator<Item = (&str, &str)> {
+	let mut rest = s;
+	std::iter::from_fn(move || {
+		rest = rest.trim_start_matches([' ', ',']);
+		let (key, after) = rest.split_once('=')?;
+		let (value, after) = match after.strip_prefix('"') {
+			Some(quoted) => {
+				let mut end = quoted.len();
+				let mut escaped = false;
+				for (i, c) in quoted.char_indices() {
+					match c {
+						'\\' if !escaped => escaped = true,
+						'"' if !escaped => {
+							end = i;
+							break;
+						}
+						_ => escaped = false,
+					}
+				}
+				(&quoted[..end], quoted.get(end + 1..).unwrap_or_default())
+			}
+			None => after.split_once(',').unwrap_or((after, "")),
+		};
+
+		rest = after;
+		Some((key.trim(), value.trim()))
+	})
+}
+
+fn quote(s: &str) -> String { s.replace('\\', "\\\\").replace('"', "\\\"") }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn config() -> ServiceWebdav {
+		ServiceWebdav {
+			auth:           Default::default(),
+			url:            "http://www.nowhere.org".to_owned(),
+			user:           "Mufasa".to_owned(),
+			password:       Some("Circle Of Life".to_owned()),
+			no_cert_verify: false,
+			throttle:       0,
+		}
+	}
+
+	#[test]
+	fn test_params() {
+		let parsed: Vec<_> = params(r#"realm="a, \"b\"", qop="auth,auth-int" ,stale=false"#).collect();
+		assert_eq!(parsed, [("realm", r#"a, \"b\""#), ("qop", "auth,auth-int"), ("stale", "false")]);
+
+		assert_eq!(params("").count(), 0);
+		assert_eq!(params(r#"realm="unterminated"#).collect::<Vec<_>>(), [("realm", "unterminated")]);
+	}
+
+	#[test]
+	fn test_parse() {
+		assert!(matches!(Challenge::parse([r#"Basic realm="x""#]), Some(Challenge::Basic)));
+		assert!(Challenge::parse([r#"Bearer realm="x""#]).is_none());
+
+		// Digest is preferred over Basic, wherever it comes
+		let headers = [r#"Basic realm="x""#, r#"Digest realm="x", nonce="n", algorithm=SHA-256"#];
+		let Some(Challenge::Digest(d)) = Challenge::parse(headers) else { panic!() };
+		assert!(d.algorithm == Algorithm::Sha256 && !d.qop);
+
+		// Unknown algorithms and missing nonces fall back to Basic
+		let headers = [r#"Digest realm="x", nonce="n", algorithm=SHA-512"#, "Basic"];
+		assert!(matches!(Challenge::parse(headers), Some(Challenge::Basic)));
+		assert!(Challenge::parse([r#"Digest realm="x""#]).is_none());
+	}
+
+	#[test]
+	fn test_authorize() {
+		// The inputs of the RFC 2069 example, whose printed response is an erratum
+		let d = Digest::parse(
+			r#"realm="testrealm@host.com", nonce="dcd98b7102dd2f0e8b11d0f600bfb0c093", opaque="5ccc069c403ebaf9f0171e9517f40e41""#,
+		)
+		.unwrap();
+		assert_eq!(
+			d.authorize(&config(), &Method::GET, "/dir/index.html"),
+			r#"Digest username="Mufasa", realm="testrealm@host.com", nonce="dcd98b7102dd2f0e8b11d0f600bfb0c093", uri="/dir/index.html", algorithm=MD5, response="670fd8c2df070c60b045671b8b24ff02", opaque="5ccc069c403ebaf9f0171e9517f40e41""#
+		);
+
+		// With `qop`, the client nonce and the count go into the response too
+		let d = Digest::parse(r#"realm="r", nonce="n", qop="auth,auth-int""#).unwrap();
+		let s = d.authorize(&config(), &Method::PUT, "/a");
+		let (_, cnonce) = s.split_once(r#"cnonce=""#).unwrap();
+		let cnonce = &cnonce[..16];
+
+		let md5 = |s: String| yazi_fs::Digest::encode(&Md5::digest(s));
+		let (ha1, ha2) = (md5("Mufasa:r:Circle Of Life".to_owned()), md5("PUT:/a".to_owned()));
+		let response = md5(format!("{ha1}:n:00000001:{cnonce}:auth:{ha2}"));
+		assert!(s.contains(&format!(r#"response="{response}", qop=auth, nc=00000001, "#)));
+
+		// The nonce count goes up with each request
+		assert!(d.authorize(&config(), &Method::GET, "/").contains(", nc=00000002, "));
+	}
+}
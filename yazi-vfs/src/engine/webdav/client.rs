@@ -0,0 +1,251 @@
+use std::{io, sync::Arc};
+
+use bytes::Bytes;
+use hashbrown::HashMap;
+use parking_lot::Mutex;
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, percent_decode_str, percent_encode};
+use reqwest::{Method, RequestBuilder, Response, StatusCode, header::{self, HeaderMap, HeaderValue}};
+use typed_path::{UnixPath, UnixPathBuf};
+use yazi_config::vfs::ServiceWebdav;
+
+use super::{Challenge, Entry};
+
+// Everything but unreserved characters and the path separator is escaped
+const PATH: &AsciiSet =
+	&NON_ALPHANUMERIC.remove(b'-').remove(b'.').remove(b'_').remove(b'~').remove(b'/');
+
+const PROPFIND: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<d:propfind xmlns:d="DAV:">
+	<d:prop>
+		<d:resourcetype/>
+		<d:getcontentlength/>
+		<d:getlastmodified/>
+		<d:creationdate/>
+		<d:getetag/>
+	</d:prop>
+</d:propfind>"#;
+
+// ETags are only kept around for revalidation, so old ones can be dropped
+const MAX_ETAGS: usize = 10000;
+
+pub(super) struct Client {
+	config:    &'static ServiceWebdav,
+	http:      reqwest::Client,
+	challenge: Mutex<Option<Arc<Challenge>>>,
+	etags:     Mutex<HashMap<UnixPathBuf, String>>,
+}
+
+impl Client {
+	pub(super) fn get(config: &'static ServiceWebdav) -> io::Result<&'static Self> {
+		if let Some(&client) = super::CLIENT.lock().get(config) {
+			return Ok(client);
+		}
+
+		let http = reqwest::Client::builder()
+			.danger_accept_invalid_certs(config.no_cert_verify)
+			.build()
+			.map_err(io::Error::other)?;
+
+		let client = Self { config, http, challenge: Default::default(), etags: Default::default() };
+		Ok(*super::CLIENT.lock().entry(config).or_insert_with(|| Box::leak(Box::new(client))))
+	}
+
+	pub(super) fn url(&self, path: &UnixPath) -> String {
+		format!("{}{}", self.config.url, percent_encode(path.as_bytes(), PATH))
+	}
+
+	/// Send a request, retrying it once with credentials if the server asks for
+	/// them.
+	pub(super) async fn send(
+		&self,
+		method: Method,
+		path: &UnixPath,
+		headers: HeaderMap,
+		body: impl Into<Bytes>,
+	) -> io::Result<Response> {
+		let (url, body) = (self.url(path), body.into());
+
+		let mut retried = false;
+		loop {
+			let req = self.build(&method, &url, headers.clone()).body(body.clone());
+			let resp = req.send().await.map_err(io::Error::other)?;
+
+			if resp.status() == StatusCode::UNAUTHORIZED && !retried && self.challenged(&resp) {
+				retried = true;
+			} else {
+				return Self::check(&method, &url, resp);
+			}
+		}
+	}
+
+	/// Like [`Self::send`], but the body is streamed, so it can't be replayed
+	/// after an authentication challenge. Requests that happened before are
+	/// relied on to have picked up the challenge.
+	pub(super) async fn send_streaming(
+		&self,
+		method: Method,
+		path: &UnixPath,
+		headers: HeaderMap,
+		body: reqwest::Body,
+	) -> io::Result<Response> {
+		let url = self.url(path);
+		let resp = self.build(&method, &url, headers).body(body).send().await;
+
+		Self::check(&method, &url, resp.map_err(io::Error::other)?)
+	}
+
+	pub(super) async fn propfind(&self, path: &UnixPath, depth: u16) -> io::Result<Vec<Entry>> {
+		let mut headers = HeaderMap::new();
+		headers.insert("Depth", depth.into());
+		headers
+			.insert(header::CONTENT_TYPE, HeaderValue::from_static("application/xml; charset=utf-8"));
+
+		let resp = self.send(Method::from_bytes(b"PROPFIND").unwrap(), path, headers, PROPFIND).await?;
+		let body = resp.bytes().await.map_err(io::Error::other)?;
+
+		let mut entries = vec![];
+		for prop in super::Prop::parse(&body)? {
+			let Some(path) = self.path_of(&prop.href) else { continue };
+			entries.push(Entry {
+				cha: prop.cha(path.file_name().unwrap_or_default()),
+				etag: prop.etag,
+				path,
+			});
+		}
+
+		let mut etags = self.etags.lock();
+		if etags.len() > MAX_ETAGS {
+			etags.clear();
+		}
+		for e in &entries {
+			match &e.etag {
+				Some(etag) => etags.insert(e.path.clone(), etag.clone()),
+				None => etags.remove(&e.path),
+			};
+		}
+
+		Ok(entries)
+	}
+
+	pub(super) fn etag(&self, path: &UnixPath) -> Option<String> {
+		self.etags.lock().get(path).cloned()
+	}
+
+	pub(super) fn destination(&self, to: &UnixPath) -> HeaderMap {
+		let mut headers = HeaderMap::new();
+		if let Ok(value) = HeaderValue::from_str(&self.url(to)) {
+			headers.insert("Destination", value);
+		}
+		headers.insert("Overwrite", HeaderValue::from_static("T"));
+		headers
+	}
+
+	fn build(&self, method: &Method, url: &str, headers: HeaderMap) -> RequestBuilder {
+		let req = self.http.request(method.clone(), url).headers(headers);
+		let Some(challenge) = self.challenge.lock().clone() else { return req };
+
+		let uri = url.split_once("://").and_then(|(_, s)| s.find('/').map(|i| &s[i..]));
+		challenge.apply(req, self.config, method, uri.unwrap_or("/"))
+	}
+
+	fn challenged(&self, resp: &Response) -> bool {
+		if self.config.user.is_empty() {
+			return false;
+		}
+
+		let headers = resp.headers().get_all(header::WWW_AUTHENTICATE);
+		let Some(challenge) = Challenge::parse(headers.iter().filter_map(|v| v.to_str().ok())) else {
+			return false;
+		};
+
+		*self.challenge.lock() = Some(Arc::new(challenge));
+		true
+	}
+
+	fn check(method: &Method, url: &str, resp: Response) -> io::Result<Response> {
+		let status = resp.status();
+		if status.is_success() || status == StatusCode::NOT_MODIFIED {
+			return Ok(resp);
+		}
+
+		let kind = match status {
+			StatusCode::NOT_FOUND | StatusCode::CONFLICT => io::ErrorKind::NotFound,
+			StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => io::ErrorKind::PermissionDenied,
+			StatusCode::METHOD_NOT_ALLOWED => io::ErrorKind::Unsupported,
+			StatusCode::PRECONDITION_FAILED => io::ErrorKind::AlreadyExists,
+			StatusCode::LOCKED => io::ErrorKind::ResourceBusy,
+			StatusCode::INSUFFICIENT_STORAGE => io::ErrorKind::StorageFull,
+			_ => io::ErrorKind::Other,
+		};
+		Err(io::Error::new(kind, format!("{method} {url} failed: {status}")))
+	}
+
+	/// Map an `href` in a response back to the path it refers to on the
+	/// service, which may be either a full URL or an absolute path.
+	fn path_of(&self, href: &str) -> Option<UnixPathBuf> {
+		fn strip_origin(s: &str) -> &str {
+			match s.split_once("://") {
+				Some((_, rest)) => rest.find('/').map_or("/", |i| &rest[i..]),
+				None => s,
+			}
+		}
+
+		let mut base: Vec<u8> = percent_decode_str(strip_origin(&self.config.url)).collect();
+		while base.last() == Some(&b'/') {
+			base.pop();
+		}
+
+		let href: Vec<u8> = percent_decode_str(strip_origin(href)).collect();
+		let rest = href.strip_prefix(base.as_slice())?;
+		if rest.is_empty() {
+			Some(UnixPathBuf::from("/"))
+		} else if rest.starts_with(b"/") {
+			Some(UnixPath::new(rest).normalize())
+		} else {
+			None
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn make(url: &str) -> Client {
+		rustls::crypto::ring::default_provider().install_default().ok();
+		let config = Box::leak(Box::new(ServiceWebdav {
+			auth:           Default::default(),
+			url:            url.to_owned(),
+			user:           String::new(),
+			password:       None,
+			no_cert_verify: false,
+			throttle:       0,
+		}));
+		Client {
+			config,
+			http: reqwest::Client::new(),
+			challenge: Default::default(),
+			etags: Default::default(),
+		}
+	}
+
+	#[test]
+	fn test_path_of() {
+		let client = make("https://example.com/remote.php/dav");
+		let path = |href| client.path_of(href).map(|p| p.to_string_lossy().into_owned());
+
+		assert_eq!(path("/remote.php/dav").as_deref(), Some("/"));
+		assert_eq!(path("/remote.php/dav/").as_deref(), Some("/"));
+		assert_eq!(path("/remote.php/dav/a%20b/c.txt").as_deref(), Some("/a b/c.txt"));
+		assert_eq!(path("https://other.host/remote.php/dav/x/").as_deref(), Some("/x"));
+
+		// Outside of the service
+		assert_eq!(path("/remote.php/davx"), None);
+		assert_eq!(path("/elsewhere/a"), None);
+
+		// A service at the root of the host
+		let client = make("http://example.com");
+		let path = |href| client.path_of(href).map(|p| p.to_string_lossy().into_owned());
+		assert_eq!(path("/a/./b/../c").as_deref(), Some("/a/c"));
+	}
+}
@@ -0,0 +1,74 @@
+use std::io;
+
+use bytes::Bytes;
+use reqwest::{Method, header::{self, HeaderMap, HeaderValue}};
+use yazi_fs::engine::{Attrs, Engine, FileBuilder};
+use yazi_shared::url::AsUrl;
+
+use crate::engine::webdav::{File, Webdav};
+
+#[derive(Clone, Copy, Default)]
+pub struct Demand(yazi_fs::engine::Demand);
+
+impl FileBuilder for Demand {
+	type File = File;
+
+	fn append(&mut self, append: bool) -> &mut Self {
+		self.0.append = append;
+		self
+	}
+
+	fn attrs(&mut self, attrs: Attrs) -> &mut Self {
+		self.0.attrs = attrs;
+		self
+	}
+
+	fn create(&mut self, create: bool) -> &mut Self {
+		self.0.create = create;
+		self
+	}
+
+	fn create_new(&mut self, create_new: bool) -> &mut Self {
+		self.0.create_new = create_new;
+		self
+	}
+
+	async fn open<U>(&self, url: U) -> io::Result<Self::File>
+	where
+		U: AsUrl,
+	{
+		let engine = Webdav::new(url.as_url()).await?;
+		let Self(d) = *self;
+		if d.append {
+			return Err(io::Error::new(io::ErrorKind::Unsupported, "WebDAV files cannot be appended to"));
+		}
+
+		// Files are created or truncated right away with an empty `PUT`, as the
+		// one carrying the content only starts with the first write
+		if !crate::engine::fresh_on_open(d, engine.symlink_metadata()).await? {
+			return Ok(File::new(engine.client, engine.path.to_owned(), d.write));
+		}
+
+		let mut headers = HeaderMap::new();
+		if d.create_new {
+			headers.insert(header::IF_NONE_MATCH, HeaderValue::from_static("*"));
+		}
+		engine.client.send(Method::PUT, engine.path, headers, Bytes::new()).await?;
+		Ok(File::new(engine.client, engine.path.to_owned(), true))
+	}
+
+	fn read(&mut self, read: bool) -> &mut Self {
+		self.0.read = read;
+		self
+	}
+
+	fn truncate(&mut self, truncate: bool) -> &mut Self {
+		self.0.truncate = truncate;
+		self
+	}
+
+	fn write(&mut self, write: bool) -> &mut Self {
+		self.0.write = write;
+		self
+	}
+}
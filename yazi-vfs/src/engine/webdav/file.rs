@@ -0,0 +1,130 @@
+use std::{convert::Infallible, io::{self, SeekFrom}, pin::Pin, sync::atomic::{AtomicU64, Ordering}, task::{Context, Poll}};
+
+use bytes::Bytes;
+use futures::{Stream, channel::mpsc as chan};
+use http_body::{Frame, SizeHint};
+use reqwest::{Method, header::HeaderMap};
+use tokio::{io::{AsyncRead, AsyncSeek, AsyncWrite, ReadBuf}, task::JoinHandle};
+use typed_path::UnixPathBuf;
+
+use super::Client;
+use crate::engine::Ranged;
+
+/// A file on a WebDAV server, read with ranged `GET`s and written with a
+/// streaming `PUT`.
+///
+/// Since a `PUT` always replaces the whole file, writes have to start at the
+/// beginning and be sequential.
+pub struct File {
+	client: &'static Client,
+	path:   UnixPathBuf,
+	len:    AtomicU64,
+	ranged: Ranged,
+}
+
+impl File {
+	pub(super) fn new(client: &'static Client, path: UnixPathBuf, write: bool) -> Self {
+		Self { client, path, len: AtomicU64::new(0), ranged: Ranged::new(write, false) }
+	}
+
+	/// WebDAV can't resize a file in place, so this only declares the size of
+	/// the upcoming upload, which lets it be sent with a `Content-Length`.
+	pub fn set_len(&self, size: u64) -> io::Result<()> {
+		if !self.ranged.write || !self.ranged.is_idle() {
+			return Err(io::Error::new(io::ErrorKind::Unsupported, "Cannot resize a WebDAV file"));
+		}
+
+		self.len.store(size, Ordering::Relaxed);
+		Ok(())
+	}
+
+	pub fn set_read_end(&mut self, end: Option<u64>) { self.ranged.end = end; }
+
+	fn put(
+		client: &'static Client,
+		path: UnixPathBuf,
+		len: u64,
+		rx: chan::Receiver<Bytes>,
+	) -> JoinHandle<io::Result<()>> {
+		let body = reqwest::Body::wrap(Body { rx, len: Some(len).filter(|&n| n > 0) });
+
+		tokio::spawn(async move {
+			client.send_streaming(Method::PUT, &path, HeaderMap::new(), body).await.map(|_| ())
+		})
+	}
+}
+
+impl AsyncRead for File {
+	fn poll_read(
+		mut self: Pin<&mut Self>,
+		cx: &mut Context<'_>,
+		buf: &mut ReadBuf<'_>,
+	) -> Poll<io::Result<()>> {
+		let me = &mut *self;
+		let (client, path) = (me.client, &me.path);
+		me.ranged.poll_read(cx, buf, |r| {
+			let (path, headers) = (path.clone(), r.range());
+			r.get(async move { client.send(Method::GET, &path, headers, Bytes::new()).await })
+		})
+	}
+}
+
+impl AsyncSeek for File {
+	fn start_seek(mut self: Pin<&mut Self>, position: SeekFrom) -> io::Result<()> {
+		let what = if self.ranged.write { "a WebDAV upload" } else { "a WebDAV file" };
+		self.ranged.start_seek(position, what)
+	}
+
+	fn poll_complete(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<u64>> {
+		Poll::Ready(Ok(self.ranged.pos))
+	}
+}
+
+impl AsyncWrite for File {
+	fn poll_write(
+		mut self: Pin<&mut Self>,
+		cx: &mut Context<'_>,
+		buf: &[u8],
+	) -> Poll<io::Result<usize>> {
+		let me = &mut *self;
+		let (client, path, len) = (me.client, &me.path, &me.len);
+		me.ranged
+			.poll_write(cx, buf, |rx| Self::put(client, path.clone(), len.load(Ordering::Relaxed), rx))
+	}
+
+	fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+		Poll::Ready(Ok(()))
+	}
+
+	fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+		let me = &mut *self;
+		let (client, path, len) = (me.client, &me.path, &me.len);
+		me.ranged
+			.poll_shutdown(cx, |rx| Self::put(client, path.clone(), len.load(Ordering::Relaxed), rx))
+	}
+}
+
+// --- Body
+struct Body {
+	rx:  chan::Receiver<Bytes>,
+	len: Option<u64>,
+}
+
+impl http_body::Body for Body {
+	type Data = Bytes;
+	type Error = Infallible;
+
+	fn poll_frame(
+		mut self: Pin<&mut Self>,
+		cx: &mut Context<'_>,
+	) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+		Pin::new(&mut self.rx).poll_next(cx).map(|o| o.map(|data| Ok(Frame::data(data))))
+	}
+
+	fn size_hint(&self) -> SizeHint {
+		match self.len {
+			Some(len) => SizeHint::with_exact(len),
+			None => SizeHint::default(),
+		}
+	}
+}
@@ -0,0 +1,163 @@
+use std::{io, time::SystemTime};
+
+use chrono::DateTime;
+use quick_xml::{Reader, events::Event};
+use typed_path::UnixPathBuf;
+use yazi_fs::cha::{Cha, ChaKind, ChaMode};
+
+// --- Entry
+pub(super) struct Entry {
+	pub(super) path: UnixPathBuf,
+	pub(super) cha:  Cha,
+	pub(super) etag: Option<String>,
+}
+
+// --- Prop
+/// The properties of a resource in a `PROPFIND` multistatus response.
+#[derive(Default)]
+pub(super) struct Prop {
+	pub(super) href: String,
+	pub(super) etag: Option<String>,
+	dir:             bool,
+	len:             u64,
+	mtime:           Option<SystemTime>,
+	btime:           Option<SystemTime>,
+}
+
+impl Prop {
+	pub(super) fn parse(xml: &[u8]) -> io::Result<Vec<Self>> {
+		let mut reader = Reader::from_reader(xml);
+		let (mut props, mut prop, mut text) = (vec![], None::<Self>, String::new());
+
+		loop {
+			match reader.read_event().map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))? {
+				Event::Start(e) => {
+					text.clear();
+					if e.local_name().as_ref() == b"response" {
+						prop = Some(Self::default());
+					}
+				}
+				Event::Empty(e) => {
+					if e.local_name().as_ref() == b"collection"
+						&& let Some(p) = &mut prop
+					{
+						p.dir = true;
+					}
+				}
+				Event::Text(e) => text.push_str(&e.decode().unwrap_or_default()),
+				Event::CData(e) => text.push_str(&e.decode().unwrap_or_default()),
+				Event::GeneralRef(e) => {
+					text.push('&');
+					text.push_str(&e.decode().unwrap_or_default());
+					text.push(';');
+				}
+				Event::End(e) => {
+					let Some(p) = &mut prop else { continue };
+					let value = quick_xml::escape::unescape(text.trim()).unwrap_or_default();
+
+					match e.local_name().as_ref() {
+						b"response" => props.extend(prop.take()),
+						b"collection" => p.dir = true,
+						// Properties the server doesn't have come back empty
+						_ if value.is_empty() => {}
+						b"href" if p.href.is_empty() => p.href = value.into_owned(),
+						b"getcontentlength" => p.len = value.parse().unwrap_or(0),
+						b"getlastmodified" => {
+							p.mtime = DateTime::parse_from_rfc2822(&value).ok().map(Into::into);
+						}
+						b"creationdate" => {
+							p.btime = DateTime::parse_from_rfc3339(&value).ok().map(Into::into);
+						}
+						b"getetag" => p.etag = Some(value.into_owned()),
+						_ => {}
+					}
+					text.clear();
+				}
+				Event::Eof => break,
+				_ => {}
+			}
+		}
+
+		Ok(props)
+	}
+
+	pub(super) fn cha(&self, name: &[u8]) -> Cha {
+		let kind = if name.starts_with(b".") { ChaKind::HIDDEN } else { ChaKind::empty() };
+
+		// WebDAV has no notion of permissions, so assume everything is accessible
+		let mode = if self.dir {
+			ChaMode::T_DIR | ChaMode::from_bits_retain(0o755)
+		} else {
+			ChaMode::T_FILE | ChaMode::from_bits_retain(0o644)
+		};
+
+		Cha {
+			kind,
+			mode,
+			len: if self.dir { 0 } else { self.len },
+			atime: None,
+			btime: self.btime,
+			ctime: None,
+			mtime: self.mtime,
+			dev: 0,
+			uid: 0,
+			gid: 0,
+			nlink: 0,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const MULTISTATUS: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<D:multistatus xmlns:D="DAV:">
+	<D:response>
+		<D:href>/dav/a%20dir/</D:href>
+		<D:propstat>
+			<D:prop>
+				<D:resourcetype><D:collection/></D:resourcetype>
+				<D:getlastmodified>Tue, 15 Nov 1994 12:45:26 GMT</D:getlastmodified>
+				<D:getetag/>
+			</D:prop>
+			<D:status>HTTP/1.1 200 OK</D:status>
+		</D:propstat>
+	</D:response>
+	<D:response>
+		<D:href>/dav/a%20dir/Tom &amp; Jerry.txt</D:href>
+		<D:propstat>
+			<D:prop>
+				<D:resourcetype/>
+				<D:getcontentlength>1024</D:getcontentlength>
+				<D:creationdate>1994-11-15T12:45:26Z</D:creationdate>
+				<D:getetag>"abc"</D:getetag>
+			</D:prop>
+		</D:propstat>
+		<D:propstat>
+			<D:prop><D:quota-used-bytes/></D:prop>
+			<D:status>HTTP/1.1 404 Not Found</D:status>
+		</D:propstat>
+	</D:response>
+</D:multistatus>"#;
+
+	#[test]
+	fn test_parse() {
+		let props = Prop::parse(MULTISTATUS.as_bytes()).unwrap();
+		assert_eq!(props.len(), 2);
+
+		let (dir, file) = (&props[0], &props[1]);
+		assert_eq!(dir.href, "/dav/a%20dir/");
+		assert!(dir.dir && dir.etag.is_none());
+		assert_eq!(dir.mtime, Some(SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(784903526)));
+
+		assert_eq!(file.href, "/dav/a%20dir/Tom & Jerry.txt");
+		assert_eq!(file.etag.as_deref(), Some(r#""abc""#));
+		assert!(!file.dir && file.len == 1024 && file.mtime.is_none());
+		assert_eq!(file.btime, dir.mtime);
+
+		let cha = file.cha(b"Tom & Jerry.txt");
+		assert!(cha.is_file() && cha.len == 1024);
+		assert!(Prop::parse(b"<D:multistatus><D:response></D:oops>").is_err());
+	}
+}
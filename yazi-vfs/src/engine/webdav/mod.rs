@@ -0,0 +1,7 @@
+yazi_macro::mod_flat!(challenge client demand file metadata read_dir webdav);
+
+static CLIENT: yazi_shim::cell::RoCell<
+	parking_lot::Mutex<hashbrown::HashMap<&'static yazi_config::vfs::ServiceWebdav, &'static Client>>,
+> = yazi_shim::cell::RoCell::new();
+
+pub(super) fn init() { CLIENT.init(Default::default()); }
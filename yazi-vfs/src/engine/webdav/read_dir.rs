@@ -0,0 +1,44 @@
+use std::{io, sync::Arc};
+
+use yazi_fs::{engine::{DirReader, FileHolder}, file::File};
+use yazi_shared::{path::PathBufDyn, strand::StrandCow, url::{UrlBuf, UrlLike}};
+
+use super::Entry;
+use crate::VfsFile;
+
+pub struct ReadDir {
+	pub(super) dir:     Arc<UrlBuf>,
+	pub(super) entries: std::vec::IntoIter<Entry>,
+}
+
+impl DirReader for ReadDir {
+	type Entry = DirEntry;
+
+	async fn next(&mut self) -> io::Result<Option<Self::Entry>> {
+		Ok(self.entries.next().map(|entry| DirEntry { dir: self.dir.clone(), entry }))
+	}
+}
+
+// --- Entry
+pub struct DirEntry {
+	dir:   Arc<UrlBuf>,
+	entry: Entry,
+}
+
+impl FileHolder for DirEntry {
+	async fn file(&self) -> io::Result<File> {
+		Ok(File::from_follow(self.url(), self.entry.cha).await)
+	}
+
+	async fn file_type(&self) -> io::Result<yazi_fs::cha::ChaType> { Ok(self.entry.cha.mode.into()) }
+
+	async fn metadata(&self) -> io::Result<yazi_fs::cha::Cha> { Ok(self.entry.cha) }
+
+	fn name(&self) -> StrandCow<'_> { self.entry.path.file_name().unwrap_or_default().into() }
+
+	fn path(&self) -> PathBufDyn { self.entry.path.clone().into() }
+
+	fn url(&self) -> UrlBuf {
+		self.dir.try_join(self.name()).expect("entry name is a valid component of the WebDAV URL")
+	}
+}
@@ -0,0 +1,207 @@
+use std::{io, sync::Arc};
+
+use bytes::Bytes;
+use reqwest::{Method, StatusCode, header::{self, HeaderMap, HeaderValue}};
+use tokio::sync::mpsc::Receiver;
+use yazi_config::vfs::{ServiceWebdav, Vfs};
+use yazi_fs::{cha::Cha, engine::{Capabilities, DirReader, Engine}, file::File};
+use yazi_shared::{path::{DynPath, PathBufDyn}, strand::AsStrand, url::{AsUrl, Url, UrlBuf, UrlCow}};
+
+use super::Client;
+
+#[derive(Clone)]
+pub struct Webdav<'a> {
+	url:             Url<'a>,
+	pub(super) path: &'a typed_path::UnixPath,
+
+	pub(super) client: &'static Client,
+	config:            &'static ServiceWebdav,
+}
+
+impl<'a> Engine for Webdav<'a> {
+	type Demand = super::Demand;
+	type File = super::File;
+	type Me<'b> = Webdav<'b>;
+	type ReadDir = super::ReadDir;
+	type UrlCow = UrlCow<'a>;
+
+	async fn absolute(&self) -> io::Result<Self::UrlCow> {
+		Ok(if let Some(u) = crate::engine::try_absolute_impl(self.url) {
+			u
+		} else {
+			self.canonicalize().await?.into()
+		})
+	}
+
+	async fn canonicalize(&self) -> io::Result<UrlBuf> {
+		// There are no symlinks in WebDAV, so it's only a matter of existence
+		self.symlink_metadata().await?;
+
+		let mut path = typed_path::UnixPathBuf::from("/");
+		path.push(self.path);
+		Ok(UrlBuf::Webdav { loc: path.normalize().into(), auth: self.config.auth.clone() })
+	}
+
+	async fn capabilities(&self) -> io::Result<Capabilities> {
+		Ok(Capabilities { copy_progressive: true, server_copy: true, ..Default::default() })
+	}
+
+	async fn casefold(&self) -> io::Result<UrlBuf> { crate::engine::casefold_impl(self.url).await }
+
+	async fn copy<P>(&self, to: P, _attrs: yazi_fs::engine::Attrs) -> io::Result<u64>
+	where
+		P: DynPath,
+	{
+		let to = to.dyn_path().as_unix()?;
+		let mut headers = self.client.destination(to);
+		headers.insert("Depth", HeaderValue::from_static("0"));
+
+		self
+			.client
+			.send(Method::from_bytes(b"COPY").unwrap(), self.path, headers, Bytes::new())
+			.await?;
+		Ok(self.symlink_metadata().await?.len)
+	}
+
+	fn copy_progressive<P, A>(&self, to: P, attrs: A) -> io::Result<Receiver<io::Result<u64>>>
+	where
+		P: DynPath,
+		A: Into<yazi_fs::engine::Attrs>,
+	{
+		let to = to.dyn_path().to_unix_owned()?;
+		let (from, attrs) = (self.url.to_owned(), attrs.into());
+
+		Ok(crate::engine::copy_progressive_once(async move {
+			Webdav::new(from.as_url()).await?.copy(to, attrs).await
+		}))
+	}
+
+	async fn create_dir(&self) -> io::Result<()> {
+		let result = self
+			.client
+			.send(Method::from_bytes(b"MKCOL").unwrap(), self.path, HeaderMap::new(), Bytes::new())
+			.await;
+
+		match result {
+			// `405 Method Not Allowed` is what MKCOL gets for existing resources
+			Err(e) if e.kind() == io::ErrorKind::Unsupported => Err(io::ErrorKind::AlreadyExists.into()),
+			r => r.map(|_| ()),
+		}
+	}
+
+	async fn hard_link<P>(&self, _to: P) -> io::Result<()>
+	where
+		P: DynPath,
+	{
+		Err(io::Error::new(io::ErrorKind::Unsupported, "WebDAV does not support hard links"))
+	}
+
+	async fn metadata(&self) -> io::Result<Cha> { self.symlink_metadata().await }
+
+	async fn new<'b>(url: Url<'b>) -> io::Result<Self::Me<'b>> {
+		let Url::Webdav { loc, auth } = url else {
+			return Err(io::Error::new(
+				io::ErrorKind::InvalidInput,
+				format!("Not a WebDAV URL: {url:?}"),
+			));
+		};
+
+		let config = Vfs::service::<&ServiceWebdav>(auth)?;
+		Ok(Self::Me { url, path: loc.as_inner(), client: Client::get(config)?, config })
+	}
+
+	async fn read_dir(self) -> io::Result<Self::ReadDir> {
+		let mut entries = self.client.propfind(self.path, 1).await?;
+		entries.retain(|e| e.path.file_name().is_some() && e.path != self.path.normalize());
+
+		Ok(Self::ReadDir { dir: Arc::new(self.url.to_owned()), entries: entries.into_iter() })
+	}
+
+	async fn read_link(&self) -> io::Result<PathBufDyn> {
+		Err(io::Error::new(io::ErrorKind::InvalidInput, "WebDAV has no symlinks"))
+	}
+
+	/// Ask the server whether the file has changed since its ETag was last
+	/// seen, and only fetch its metadata again if so.
+	async fn revalidate(&self, file: File) -> io::Result<Option<File>> {
+		if let Some(etag) = self.client.etag(self.path)
+			&& let Ok(value) = HeaderValue::from_str(&etag)
+		{
+			let mut headers = HeaderMap::new();
+			headers.insert(header::IF_NONE_MATCH, value);
+
+			let resp = self.client.send(Method::HEAD, self.path, headers, Bytes::new()).await?;
+			if resp.status() == StatusCode::NOT_MODIFIED {
+				return Ok(None);
+			}
+		}
+
+		let new = File {
+			url:   self.url.to_owned(),
+			cha:   self.symlink_metadata().await?,
+			extra: Default::default(),
+		};
+		if new.cha.hits(file.cha) { Ok(None) } else { Ok(Some(new)) }
+	}
+
+	async fn remove_dir(&self) -> io::Result<()> {
+		// `DELETE` on a collection is always recursive
+		if self.clone().read_dir().await?.next().await?.is_some() {
+			return Err(io::ErrorKind::DirectoryNotEmpty.into());
+		}
+		self.remove_dir_all().await
+	}
+
+	async fn remove_dir_all(&self) -> io::Result<()> {
+		self.client.send(Method::DELETE, self.path, HeaderMap::new(), Bytes::new()).await.map(|_| ())
+	}
+
+	async fn remove_file(&self) -> io::Result<()> {
+		self.client.send(Method::DELETE, self.path, HeaderMap::new(), Bytes::new()).await.map(|_| ())
+	}
+
+	async fn rename<P>(&self, to: P) -> io::Result<()>
+	where
+		P: DynPath,
+	{
+		let headers = self.client.destination(to.dyn_path().as_unix()?);
+		let method = Method::from_bytes(b"MOVE").unwrap();
+
+		self.client.send(method, self.path, headers, Bytes::new()).await.map(|_| ())
+	}
+
+	/// WebDAV has no portable way to change permissions, ownership or times, so
+	/// they're left to the server.
+	async fn set_attrs(&self, _attrs: yazi_fs::engine::Attrs) -> io::Result<()> { Ok(()) }
+
+	async fn symlink<S, F>(&self, _original: S, _is_dir: F) -> io::Result<()>
+	where
+		S: AsStrand,
+		F: AsyncFnOnce() -> io::Result<bool>,
+	{
+		Err(io::Error::new(io::ErrorKind::Unsupported, "WebDAV does not support symlinks"))
+	}
+
+	async fn symlink_metadata(&self) -> io::Result<Cha> {
+		let entries = self.client.propfind(self.path, 0).await?;
+		match entries.into_iter().next() {
+			Some(entry) => Ok(entry.cha),
+			None => Err(io::Error::new(io::ErrorKind::InvalidData, "Empty PROPFIND response")),
+		}
+	}
+
+	async fn trash(&self) -> io::Result<()> {
+		Err(io::Error::new(io::ErrorKind::Unsupported, "WebDAV does not support trash"))
+	}
+
+	#[inline]
+	fn url(&self) -> Url<'_> { self.url }
+
+	async fn write<C>(&self, contents: C) -> io::Result<()>
+	where
+		C: AsRef<[u8]>,
+	{
+		let body = Bytes::copy_from_slice(contents.as_ref());
+		self.client.send(Method::PUT, self.path, HeaderMap::new(), body).await.map(|_| ())
+	}
+}
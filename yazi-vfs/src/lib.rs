@@ -1,5 +1,5 @@
-yazi_macro::mod_pub!(engine);
+yazi_macro::mod_pub!(engine trash);
 
-yazi_macro::mod_flat!(cha entries file fns http stamp);
+yazi_macro::mod_flat!(cha entries file fns http resume stamp walker);
 
 pub fn init() { engine::init(); }
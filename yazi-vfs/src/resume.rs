@@ -0,0 +1,109 @@
+use std::{io, path::PathBuf, str};
+
+use yazi_fs::{FsAuth, FsHash128, cha::Cha, engine::{Engine, local::Local}};
+use yazi_shared::url::{Url, UrlBuf};
+
+/// Progress of an interrupted transfer, kept in the stamp root of the remote
+/// side, so a retry can continue from where it left off as long as the source
+/// hasn't changed.
+#[derive(Clone, Debug)]
+pub struct Resume {
+	path: PathBuf,
+	sig:  String,
+}
+
+impl Resume {
+	const SIG_LEN: usize = 26;
+
+	pub fn new(from: Url, to: Url, cha: Cha) -> Option<Self> {
+		Some(Self {
+			path: Self::path(from, to)?,
+			sig:  cha.hash_u128_str(&mut [0; Self::SIG_LEN]).to_owned(),
+		})
+	}
+
+	/// The offset to continue the transfer at, or 0 if there's nothing to resume,
+	/// either because no progress was recorded, the source has changed, or the
+	/// partial file `to` is gone.
+	pub async fn offset(&self, to: &UrlBuf) -> u64 {
+		let Ok(data) = Local::regular(&self.path).read().await else { return 0 };
+		match self.parse(&data) {
+			0 => 0,
+			offset if crate::engine::metadata(to).await.is_ok() => offset,
+			_ => 0,
+		}
+	}
+
+	pub async fn save(&self, offset: u64) -> io::Result<()> {
+		Local::regular(&self.path)
+			.write(self.format(offset))
+			.await
+			.map_err(|e| io::Error::new(e.kind(), format!("Cannot write resume stamp: {e}")))
+	}
+
+	pub async fn clear(&self) { Local::regular(&self.path).remove_file().await.ok(); }
+
+	/// Forget the progress of the transfer from `from` to `to`, whatever state
+	/// its source is in.
+	pub async fn discard(from: Url<'_>, to: Url<'_>) {
+		if let Some(path) = Self::path(from, to) {
+			Local::regular(&path).remove_file().await.ok();
+		}
+	}
+
+	fn path(from: Url, to: Url) -> Option<PathBuf> {
+		let mut path = to.auth().stamp_root().or_else(|| from.auth().stamp_root())?;
+
+		let (mut a, mut b) = ([0; Self::SIG_LEN], [0; Self::SIG_LEN]);
+		path.push(format!("%part_{}{}", from.hash_u128_str(&mut a), to.hash_u128_str(&mut b)));
+		Some(path)
+	}
+
+	fn parse(&self, data: &[u8]) -> u64 {
+		match data.split_at_checked(Self::SIG_LEN) {
+			Some((sig, offset)) if sig == self.sig.as_bytes() => {
+				str::from_utf8(offset).ok().and_then(|s| s.parse().ok()).unwrap_or(0)
+			}
+			_ => 0,
+		}
+	}
+
+	fn format(&self, offset: u64) -> Vec<u8> {
+		let mut data = Vec::with_capacity(Self::SIG_LEN + 20);
+		data.extend_from_slice(self.sig.as_bytes());
+		data.extend_from_slice(offset.to_string().as_bytes());
+		data
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use yazi_shared::url::{AsUrl, UrlCow};
+
+	use super::*;
+
+	fn resume(len: u64) -> Resume {
+		yazi_shared::init_tests();
+		let (from, to) =
+			(UrlCow::try_from("sftp://vps//a").unwrap(), UrlCow::try_from("/tmp/a").unwrap());
+		Resume::new(from.as_url(), to.as_url(), Cha { len, ..Default::default() }).unwrap()
+	}
+
+	#[test]
+	fn test_parse() {
+		let a = resume(100);
+		assert_eq!(a.parse(&a.format(8 << 20)), 8 << 20);
+		assert_eq!(a.parse(b""), 0);
+		assert_eq!(a.parse(&a.format(8 << 20)[..Resume::SIG_LEN]), 0);
+
+		// Progress recorded for a different source is ignored
+		assert_eq!(resume(200).parse(&a.format(8 << 20)), 0);
+	}
+
+	#[test]
+	fn test_path() {
+		let a = resume(100);
+		assert_eq!(a.path, resume(200).path);
+		assert!(a.path.file_name().unwrap().to_str().unwrap().starts_with("%part_"));
+	}
+}
@@ -0,0 +1,102 @@
+use std::{borrow::Cow, io};
+
+use mlua::{AnyUserData, FromLua, Lua, UserData, UserDataFields, Value};
+use typed_path::{UnixComponent, UnixPathBuf};
+use yazi_fs::{cha::Cha, file::{File, FileExtra}};
+use yazi_shared::{path::PathBufDyn, url::{UrlBuf, UrlLike}};
+use yazi_shim::mlua::UserDataFieldsExt;
+
+use super::TrashInfo;
+use crate::engine;
+
+/// An item in a trash folder on a virtual file system, either a top-level one
+/// that has a `.trashinfo`, or a file inside a trashed directory.
+#[derive(Clone, Debug)]
+pub(crate) struct VfsTrashEntry {
+	pub(super) top:      UrlBuf,
+	pub(super) rel:      UnixPathBuf,
+	pub(super) cha:      Cha,
+	pub(super) lcha:     Cha,
+	pub(super) original: UrlBuf,
+	pub(super) link_to:  Option<PathBufDyn>,
+	pub(super) backing:  UrlBuf,
+}
+
+impl VfsTrashEntry {
+	pub(super) async fn new(top: UrlBuf, rel: UnixPathBuf, info: TrashInfo) -> io::Result<Self> {
+		let (backing, original) = if rel.as_bytes().is_empty() {
+			(info.backing, info.original)
+		} else {
+			(info.backing.try_join(&*rel)?, info.original.try_join(&*rel)?)
+		};
+		Self::with(top, rel, backing, original).await
+	}
+
+	async fn with(
+		top: UrlBuf,
+		rel: UnixPathBuf,
+		backing: UrlBuf,
+		original: UrlBuf,
+	) -> io::Result<Self> {
+		let lcha = engine::symlink_metadata(&backing).await?;
+		let (cha, link_to) = if lcha.is_link() {
+			(lcha.follow(engine::metadata(&backing).await.ok()), engine::read_link(&backing).await.ok())
+		} else {
+			(lcha, None)
+		};
+
+		Ok(Self { top, rel, cha, lcha, original, link_to, backing })
+	}
+
+	pub(super) async fn child(&self, name: &[u8]) -> io::Result<Self> {
+		let rel = self.rel.join(name);
+		if !rel.is_relative() || rel.components().any(|c| c == UnixComponent::ParentDir) {
+			return Err(io::Error::new(io::ErrorKind::InvalidInput, "invalid trash entry path"));
+		}
+
+		let (backing, original) = (self.backing.try_join(name)?, self.original.try_join(name)?);
+		Self::with(self.top.clone(), rel, backing, original).await
+	}
+
+	pub(super) fn has_rel(&self) -> bool { !self.rel.as_bytes().is_empty() }
+
+	pub(super) fn key(&self) -> Cow<'_, [u8]> {
+		match self.rel.file_name() {
+			Some(name) => name.into(),
+			None => format!("{:?}", self.top).into_bytes().into(),
+		}
+	}
+
+	pub(super) fn name(&self) -> &[u8] {
+		self
+			.rel
+			.file_name()
+			.or_else(|| self.original.name().map(|n| n.encoded_bytes()))
+			.expect("trash entry must have a name")
+	}
+
+	pub(super) fn into_file(self, url: UrlBuf) -> File {
+		File { url, cha: self.cha, extra: FileExtra::new(self.link_to, None) }
+	}
+}
+
+impl FromLua for VfsTrashEntry {
+	fn from_lua(value: Value, lua: &Lua) -> mlua::Result<Self> {
+		AnyUserData::from_lua(value, lua)?.take()
+	}
+}
+
+impl UserData for VfsTrashEntry {
+	fn add_fields<F: UserDataFields<Self>>(fields: &mut F) {
+		fields.add_cached_field("key", |lua, me| lua.create_string(me.key()));
+		fields.add_cached_field("top", |lua, me| lua.create_string(format!("{:?}", me.top)));
+		fields.add_cached_field("rel", |_, me| Ok(PathBufDyn::Unix(me.rel.clone())));
+		fields.add_cached_field("name", |lua, me| lua.create_string(me.name()));
+		fields.add_cached_field("cha", |_, me| Ok(me.cha));
+		fields.add_cached_field("lcha", |_, me| Ok(me.lcha));
+		fields.add_cached_field("original", |_, me| Ok(me.original.clone()));
+		fields.add_cached_field("link_to", |_, me| Ok(me.link_to.clone()));
+		// Not a local path, so previews go through the URL of the entry instead
+		fields.add_field("backing", Value::Nil);
+	}
+}
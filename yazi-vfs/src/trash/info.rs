@@ -0,0 +1,184 @@
+use std::io;
+
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, percent_decode, percent_encode};
+use tokio::io::AsyncReadExt;
+use yazi_shared::url::{UrlBuf, UrlLike};
+
+use crate::engine;
+
+const PATH: &AsciiSet =
+	&NON_ALPHANUMERIC.remove(b'/').remove(b'-').remove(b'_').remove(b'.').remove(b'~');
+
+pub(super) struct TrashInfo {
+	pub(super) backing:  UrlBuf,
+	pub(super) original: UrlBuf,
+}
+
+impl TrashInfo {
+	// Parses from a trashinfo URL, e.g.:
+	//   sftp://vps//home/alice/.local/share/Trash/info/cat.jpg.trashinfo
+	pub(super) async fn parse(info: &UrlBuf) -> io::Result<Self> {
+		let root = Self::root(info)?;
+
+		// cat.jpg
+		let stem = info
+			.name()
+			.and_then(|n| n.encoded_bytes().strip_suffix(b".trashinfo"))
+			.filter(|s| !s.is_empty())
+			.ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid trash info path"))?;
+
+		let mut buf = Vec::new();
+		engine::open(info).await?.read_to_end(&mut buf).await?;
+
+		Ok(Self {
+			backing:  root.try_join("files")?.try_join(stem)?,
+			original: Self::parse_original(&buf, &root)?,
+		})
+	}
+
+	// /home/alice/.local/share/Trash/info/cat.jpg.trashinfo  =>
+	// /home/alice/.local/share/Trash
+	pub(super) fn root(info: &UrlBuf) -> io::Result<UrlBuf> {
+		info
+			.parent()
+			.filter(|p| p.name().is_some_and(|n| n.encoded_bytes() == b"info"))
+			.and_then(|p| p.parent())
+			.map(|p| p.to_owned())
+			.ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid trash info path"))
+	}
+
+	pub(super) fn format(original: &[u8]) -> String {
+		format!(
+			"[Trash Info]\nPath={}\nDeletionDate={}\n",
+			percent_encode(original, PATH),
+			chrono::Local::now().format("%Y-%m-%dT%H:%M:%S")
+		)
+	}
+
+	/// The path to put in the `.trashinfo` of a trash at the top of a mount,
+	/// which is relative to the top directory `top`.
+	pub(super) fn relative<'a>(path: &'a [u8], top: &[u8]) -> io::Result<&'a [u8]> {
+		let top = top.strip_suffix(b"/").unwrap_or(top);
+		path.strip_prefix(top).and_then(|p| p.strip_prefix(b"/")).filter(|p| !p.is_empty()).ok_or_else(
+			|| io::Error::new(io::ErrorKind::InvalidInput, "path is not under the top directory"),
+		)
+	}
+
+	fn parse_original(buf: &[u8], root: &UrlBuf) -> io::Result<UrlBuf> {
+		let mut lines = buf.split(|&b| b == b'\n').map(|l| l.strip_suffix(b"\r").unwrap_or(l));
+		if lines.next() != Some(b"[Trash Info]") {
+			return Err(io::Error::new(io::ErrorKind::InvalidData, "invalid trash info header"));
+		}
+
+		let value = lines
+			.find_map(|l| l.strip_prefix(b"Path="))
+			.ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "trash info has no Path"))?;
+
+		let path: Vec<u8> = percent_decode(value).collect();
+		if path.is_empty() || path[0] != b'/' && path.split(|&b| b == b'/').any(|c| c == b"..") {
+			return Err(io::Error::new(io::ErrorKind::InvalidData, "invalid original trash path"));
+		}
+
+		let original = if path[0] == b'/' {
+			root.try_join(&*path)?
+		} else {
+			Self::mount_point(root)?.try_join(&*path)?
+		};
+		if original.name().is_none() {
+			return Err(io::Error::new(io::ErrorKind::InvalidData, "invalid original trash path"));
+		}
+		Ok(original)
+	}
+
+	// /mnt/disk/.Trash/1000           =>  /mnt/disk
+	// /mnt/disk/.Trash-1000           =>  /mnt/disk
+	// /home/alice/.local/share/Trash  =>  /home/alice/.local/share
+	fn mount_point(root: &UrlBuf) -> io::Result<UrlBuf> {
+		let parent = root.parent();
+		if let Some(p) = parent
+			&& p.name().is_some_and(|n| n.encoded_bytes() == b".Trash")
+		{
+			p.parent()
+		} else {
+			parent
+		}
+		.map(|p| p.to_owned())
+		.ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid trash mount point"))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use yazi_shared::url::UrlCow;
+
+	use super::*;
+
+	fn url(s: &str) -> UrlBuf { UrlCow::try_from(s).unwrap().into_owned() }
+
+	fn original(buf: &str, root: &str) -> io::Result<String> {
+		TrashInfo::parse_original(buf.as_bytes(), &url(root)).map(|u| format!("{u:?}"))
+	}
+
+	#[test]
+	fn test_root() {
+		yazi_shared::init_tests();
+
+		let info = url("sftp://vps//home/alice/.local/share/Trash/info/cat.jpg.trashinfo");
+		assert_eq!(TrashInfo::root(&info).unwrap(), url("sftp://vps//home/alice/.local/share/Trash"));
+		assert!(TrashInfo::root(&url("sftp://vps//home/alice/Trash/files/cat.jpg")).is_err());
+	}
+
+	#[test]
+	fn test_parse_original() {
+		yazi_shared::init_tests();
+
+		let home = "sftp://vps//home/alice/.local/share/Trash";
+		let info = "[Trash Info]\r\nPath=/home/alice/My%20Photos/cat%25.jpg\r\nDeletionDate=2025-01-02T03:04:05\r\n";
+		assert_eq!(
+			original(info, home).unwrap(),
+			format!("{:?}", url("sftp://vps//home/alice/My Photos/cat%.jpg"))
+		);
+
+		// Relative to the top of the mount
+		let info = "[Trash Info]\nDeletionDate=2025-01-02T03:04:05\nPath=photos/cat.jpg\n";
+		let expected = format!("{:?}", url("sftp://vps//mnt/disk/photos/cat.jpg"));
+		assert_eq!(original(info, "sftp://vps//mnt/disk/.Trash-1000").unwrap(), expected);
+		assert_eq!(original(info, "sftp://vps//mnt/disk/.Trash/1000").unwrap(), expected);
+
+		for bad in [
+			"Path=/home/alice/cat.jpg\n",
+			"[Trash Info]\nDeletionDate=2025-01-02T03:04:05\n",
+			"[Trash Info]\nPath=\n",
+			"[Trash Info]\nPath=../../etc/passwd\n",
+			"[Trash Info]\nPath=/\n",
+		] {
+			assert_eq!(original(bad, home).unwrap_err().kind(), io::ErrorKind::InvalidData, "{bad:?}");
+		}
+	}
+
+	#[test]
+	fn test_format() {
+		yazi_shared::init_tests();
+
+		let formatted = TrashInfo::format(b"/home/alice/a b/\xe7\x8c\xab~.jpg");
+		let mut lines = formatted.lines();
+		assert_eq!(lines.next(), Some("[Trash Info]"));
+		assert_eq!(lines.next(), Some("Path=/home/alice/a%20b/%E7%8C%AB~.jpg"));
+		assert!(lines.next().is_some_and(|l| l.starts_with("DeletionDate=")));
+
+		let root = "sftp://vps//home/alice/.local/share/Trash";
+		assert_eq!(
+			original(&formatted, root).unwrap(),
+			format!("{:?}", url("sftp://vps//home/alice/a b/猫~.jpg"))
+		);
+	}
+
+	#[test]
+	fn test_relative() {
+		assert_eq!(TrashInfo::relative(b"/mnt/disk/a/b", b"/mnt/disk").unwrap(), b"a/b");
+		assert_eq!(TrashInfo::relative(b"/mnt/disk/a", b"/mnt/disk/").unwrap(), b"a");
+		assert_eq!(TrashInfo::relative(b"/a", b"/").unwrap(), b"a");
+		assert!(TrashInfo::relative(b"/mnt/diskette/a", b"/mnt/disk").is_err());
+		assert!(TrashInfo::relative(b"/mnt/disk", b"/mnt/disk").is_err());
+	}
+}
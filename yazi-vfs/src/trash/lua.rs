@@ -0,0 +1,81 @@
+use mlua::{ExternalError, IntoLuaMulti, LuaString, Table, UserData, UserDataMethods, Value};
+use typed_path::{UnixComponent, UnixPathBuf};
+use yazi_fs::file::File;
+use yazi_shared::{path::PathBufDyn, url::{UrlBuf, UrlCow}};
+use yazi_shim::fs::Error;
+
+use super::{VfsTrash, VfsTrashEntry};
+
+impl UserData for VfsTrash {
+	fn add_methods<M: UserDataMethods<Self>>(methods: &mut M) {
+		methods.add_async_function("empty", |lua, ()| async move {
+			match VfsTrash.empty().await {
+				Ok(()) => true.into_lua_multi(&lua),
+				Err(e) => (false, Error::from(e)).into_lua_multi(&lua),
+			}
+		});
+
+		methods.add_async_function("entry", |lua, value: Value| async move {
+			let (top, rel) = match value {
+				Value::UserData(ud) => return ud.borrow::<VfsTrashEntry>()?.clone().into_lua_multi(&lua),
+				Value::Table(t) => parse_id(t)?,
+				_ => Err("expected a trash entry or an ID table".into_lua_err())?,
+			};
+
+			match VfsTrash.entry(top, rel).await {
+				Ok(entry) => entry.into_lua_multi(&lua),
+				Err(e) => (Value::Nil, Error::from(e)).into_lua_multi(&lua),
+			}
+		});
+
+		methods.add_async_function("list", |lua, entry: Option<VfsTrashEntry>| async move {
+			match VfsTrash.list(entry.as_ref()).await {
+				Ok(items) => lua.create_sequence_from(items)?.into_lua_multi(&lua),
+				Err(e) => (Value::Nil, Error::from(e)).into_lua_multi(&lua),
+			}
+		});
+
+		methods.add_function("metadata", |_, (entry, follow): (VfsTrashEntry, bool)| {
+			Ok(if follow { entry.cha } else { entry.lcha })
+		});
+
+		methods.add_async_function(
+			"remove",
+			|lua, (kind, entry): (LuaString, VfsTrashEntry)| async move {
+				let result = match &*kind.as_bytes() {
+					b"file" => VfsTrash.remove_file(&entry).await,
+					b"dir" => VfsTrash.remove_dir(&entry).await,
+					_ => Err("Removal type must be 'file' or 'dir'".into_lua_err())?,
+				};
+
+				match result {
+					Ok(()) => true.into_lua_multi(&lua),
+					Err(e) => (false, Error::from(e)).into_lua_multi(&lua),
+				}
+			},
+		);
+
+		methods.add_async_function("restore", |lua, entries: Vec<VfsTrashEntry>| async move {
+			match VfsTrash.restore(entries).await {
+				Ok(()) => true.into_lua_multi(&lua),
+				Err(e) => (false, Error::from(e)).into_lua_multi(&lua),
+			}
+		});
+
+		methods.add_function("revalidate", |_, (entry, file): (VfsTrashEntry, File)| {
+			Ok(VfsTrash.revalidate(entry, &file))
+		});
+
+		methods.add_async_function("changed", |_, ()| async move { Ok(VfsTrash.changed().await) });
+	}
+}
+
+fn parse_id(t: Table) -> mlua::Result<(UrlBuf, UnixPathBuf)> {
+	let top = UrlCow::try_from(&*t.raw_get::<LuaString>("top")?.as_bytes())?.into_owned();
+
+	let rel = UnixPathBuf::from(t.raw_get::<PathBufDyn>("rel")?.into_encoded_bytes());
+	if !rel.is_relative() || rel.components().any(|c| c == UnixComponent::ParentDir) {
+		Err("invalid trash entry path".into_lua_err())?;
+	}
+	Ok((top, rel))
+}
@@ -0,0 +1,218 @@
+use std::{hash::Hash, io, sync::atomic::{AtomicU64, Ordering}};
+
+use tokio::io::AsyncWriteExt;
+use typed_path::{UnixPath, UnixPathBuf};
+use yazi_fs::{cha::ChaSig, engine::{DirReader, FileHolder}, file::File};
+use yazi_shared::{strand::{AsStrand, StrandLike}, url::{Url, UrlBuf, UrlLike}};
+use yazi_shim::Twox128;
+
+use super::{TrashInfo, VfsTrashEntry};
+use crate::{engine, maybe_exists};
+
+/// The [`VfsTrash::signature`] of when the trash folders were last listed.
+static SEEN: AtomicU64 = AtomicU64::new(0);
+
+/// A freedesktop.org trash on a virtual file system, operated through the
+/// engine of the service it lives on.
+pub struct VfsTrash;
+
+impl VfsTrash {
+	/// Move `url` into the trash folder at `root`, which is at the top of the
+	/// mount at `topdir` if it's not the home trash.
+	pub(crate) async fn put(
+		root: &UrlBuf,
+		url: Url<'_>,
+		topdir: Option<&UnixPath>,
+	) -> io::Result<()> {
+		let name = url
+			.name()
+			.map(|n| n.encoded_bytes().to_owned())
+			.ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "Cannot trash the root"))?;
+
+		let (files, info) = (root.try_join("files")?, root.try_join("info")?);
+		engine::create_dir_all(&files).await?;
+		engine::create_dir_all(&info).await?;
+
+		let loc = url.loc();
+		let path = loc.as_strand().encoded_bytes();
+		let contents = TrashInfo::format(match topdir {
+			Some(top) => TrashInfo::relative(path, top.as_bytes())?,
+			None => path,
+		});
+		for n in 1u32.. {
+			let stem =
+				if n == 1 { name.clone() } else { [&name[..], format!(".{n}").as_bytes()].concat() };
+			let (backing, info) =
+				(files.try_join(&*stem)?, info.try_join(&*[&stem[..], b".trashinfo"].concat())?);
+
+			if maybe_exists(&backing).await {
+				continue;
+			}
+
+			// The `.trashinfo` is created atomically first to claim the name
+			let mut f = match engine::create_new(&info).await {
+				Ok(f) => f,
+				Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+				Err(e) => return Err(e),
+			};
+
+			let result = async {
+				f.write_all(contents.as_bytes()).await?;
+				f.shutdown().await?;
+				engine::rename(url, &backing).await
+			};
+			if let Err(e) = result.await {
+				engine::remove_file(&info).await.ok();
+				return Err(e);
+			}
+			break;
+		}
+		Ok(())
+	}
+
+	pub(crate) async fn list(&self, entry: Option<&VfsTrashEntry>) -> io::Result<Vec<VfsTrashEntry>> {
+		let Some(entry) = entry else {
+			SEEN.store(self.signature().await, Ordering::Relaxed);
+			return Ok(self.tops().await);
+		};
+
+		if !entry.lcha.is_dir() {
+			return Err(io::Error::new(io::ErrorKind::InvalidInput, "trash item is not a directory"));
+		}
+
+		let mut it = engine::read_dir(&entry.backing).await?;
+		let mut items = vec![];
+		while let Some(dent) = it.next().await? {
+			items.push(entry.child(dent.name().encoded_bytes()).await?);
+		}
+		Ok(items)
+	}
+
+	pub(crate) async fn entry(&self, top: UrlBuf, rel: UnixPathBuf) -> io::Result<VfsTrashEntry> {
+		let root = TrashInfo::root(&top)?;
+		if !Self::roots().await.contains(&root) {
+			return Err(io::Error::new(io::ErrorKind::NotFound, "trash item outside of trash folders"));
+		}
+
+		let info = TrashInfo::parse(&top).await?;
+		if !rel.as_bytes().is_empty() && !engine::symlink_metadata(&info.backing).await?.is_dir() {
+			return Err(io::Error::new(io::ErrorKind::InvalidInput, "trash item is not a directory"));
+		}
+
+		VfsTrashEntry::new(top, rel, info).await
+	}
+
+	pub(crate) fn revalidate(&self, entry: VfsTrashEntry, current: &File) -> Option<File> {
+		let latest = entry.into_file(current.url.clone());
+		let changed =
+			!latest.cha.hits(current.cha) || latest.extra.link_to() != current.extra.link_to();
+
+		changed.then_some(latest)
+	}
+
+	/// Whether items were added to or removed from any of the trash folders
+	/// since they were last listed.
+	pub(crate) async fn changed(&self) -> bool {
+		self.signature().await != SEEN.load(Ordering::Relaxed)
+	}
+
+	/// A hash of the state of all trash folders, which changes whenever items
+	/// are added to or removed from any of them.
+	async fn signature(&self) -> u64 {
+		let mut h = Twox128::default();
+		for root in Self::roots().await {
+			let Ok(info) = root.try_join("info") else { continue };
+			let Ok(cha) = engine::metadata(&info).await else { continue };
+
+			root.hash(&mut h);
+			ChaSig(cha).hash(&mut h);
+		}
+
+		let hash = h.finish_128();
+		hash as u64 ^ (hash >> 64) as u64
+	}
+
+	pub(crate) async fn remove_file(&self, entry: &VfsTrashEntry) -> io::Result<()> {
+		engine::remove_file(&entry.backing).await?;
+		if !entry.has_rel() {
+			engine::remove_file(&entry.top).await?;
+		}
+		Ok(())
+	}
+
+	pub(crate) async fn remove_dir(&self, entry: &VfsTrashEntry) -> io::Result<()> {
+		engine::remove_dir(&entry.backing).await?;
+		if !entry.has_rel() {
+			engine::remove_file(&entry.top).await?;
+		}
+		Ok(())
+	}
+
+	pub(crate) async fn restore(&self, mut entries: Vec<VfsTrashEntry>) -> io::Result<()> {
+		entries.sort_unstable_by_key(|entry| entry.rel.components().count());
+
+		let mut seen: Vec<(UrlBuf, UnixPathBuf)> = Vec::with_capacity(entries.len());
+		for entry in entries {
+			if seen.iter().any(|(top, rel)| *top == entry.top && entry.rel.starts_with(rel)) {
+				continue;
+			}
+
+			Self::restore_item(&entry.backing, &entry.original).await?;
+			if !entry.has_rel() {
+				engine::remove_file(&entry.top).await?;
+			}
+			seen.push((entry.top, entry.rel));
+		}
+		Ok(())
+	}
+
+	pub(crate) async fn empty(&self) -> io::Result<()> {
+		for entry in self.tops().await {
+			if entry.lcha.is_dir() {
+				engine::remove_dir_all(&entry.backing).await?;
+			} else {
+				engine::remove_file(&entry.backing).await?;
+			}
+			engine::remove_file(&entry.top).await?;
+		}
+		Ok(())
+	}
+
+	async fn tops(&self) -> Vec<VfsTrashEntry> {
+		let mut tops = Vec::new();
+		for root in Self::roots().await {
+			let Ok(dir) = root.try_join("info") else { continue };
+			let Ok(mut it) = engine::read_dir(&dir).await else { continue };
+
+			while let Ok(Some(dent)) = it.next().await {
+				let top = dent.url();
+				let Ok(info) = TrashInfo::parse(&top).await else { continue };
+				if let Ok(entry) = VfsTrashEntry::new(top, UnixPathBuf::new(), info).await {
+					tops.push(entry);
+				}
+			}
+		}
+		tops
+	}
+
+	async fn roots() -> Vec<UrlBuf> { engine::sftp::trash_roots().await }
+
+	async fn restore_item(from: &UrlBuf, to: &UrlBuf) -> io::Result<()> {
+		let is_dir = engine::symlink_metadata(from).await?.is_dir();
+		if let Some(parent) = to.parent() {
+			engine::create_dir_all(parent).await?;
+		}
+
+		let result =
+			if is_dir { engine::create_dir(to).await } else { engine::create_new(to).await.map(|_| ()) };
+
+		match result {
+			Ok(()) => engine::rename(from, to).await,
+			Err(e) if e.kind() == io::ErrorKind::AlreadyExists => Err(io::Error::new(
+				io::ErrorKind::AlreadyExists,
+				format!("restore target already exists: {to:?}"),
+			)),
+			Err(e) => Err(e),
+		}
+	}
+}
@@ -0,0 +1,116 @@
+use std::{collections::VecDeque, path::PathBuf, sync::Arc};
+
+use tokio::{io::AsyncReadExt, sync::mpsc::{self, UnboundedReceiver}};
+use yazi_fs::{Ignore, engine::{DirReader, FileHolder}, file::File};
+use yazi_shared::url::{UrlBuf, UrlLike};
+
+use crate::engine;
+
+type Rules = Vec<Arc<(PathBuf, Ignore)>>;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Walker {
+	/// How many levels to descend, where `1` means only the direct children.
+	pub depth:  Option<usize>,
+	pub hidden: bool,
+	/// Whether to skip what `.gitignore` and `.ignore` files rule out.
+	pub ignore: bool,
+}
+
+impl Walker {
+	/// Walk the directory breadth first, sending every descendant found along
+	/// the way, directories included.
+	pub fn walk(self, root: UrlBuf) -> UnboundedReceiver<File> {
+		self.walk_within(root.clone(), root)
+	}
+
+	/// Walk `dir` as a part of a walk from `root`, so the ignore rules of the
+	/// directories in between apply to it as well.
+	pub fn walk_within(self, root: UrlBuf, dir: UrlBuf) -> UnboundedReceiver<File> {
+		let (tx, rx) = mpsc::unbounded_channel();
+
+		tokio::spawn(async move {
+			let rules = self.inherited(&root, &dir).await;
+			let mut queue = VecDeque::from([(dir, 0, rules)]);
+			while let Some((dir, level, mut rules)) = queue.pop_front() {
+				if self.ignore {
+					rules.extend(Self::rules(&dir).await.map(Arc::new));
+				}
+
+				let Ok(mut it) = engine::read_dir(&dir).await else { continue };
+				while let Ok(Some(dent)) = it.next().await {
+					let Ok(file) = dent.file().await else { continue };
+					if !self.admits(&rules, &file) {
+						continue;
+					}
+
+					if file.is_dir() && !file.is_link() && self.depth.is_none_or(|d| level + 1 < d) {
+						queue.push_back((file.url.clone(), level + 1, rules.clone()));
+					}
+					if tx.send(file).is_err() {
+						return;
+					}
+				}
+			}
+		});
+		rx
+	}
+
+	/// The check a walk from `root` applies to the files in `dir`, for the ones
+	/// showing up there after the walk.
+	pub async fn admission(self, root: &UrlBuf, dir: &UrlBuf) -> impl Fn(&File) -> bool + use<> {
+		let mut rules = self.inherited(root, dir).await;
+		if self.ignore {
+			rules.extend(Self::rules(dir).await.map(Arc::new));
+		}
+		move |file| self.admits(&rules, file)
+	}
+
+	/// The rules `dir` inherits from the directories above it, up to `root`.
+	async fn inherited(self, root: &UrlBuf, dir: &UrlBuf) -> Rules {
+		let mut rules = Rules::new();
+		let (true, Some(root), Some(dir)) = (self.ignore, root.as_local(), dir.as_local()) else {
+			return rules;
+		};
+
+		let mut ancestors: Vec<_> =
+			dir.ancestors().skip(1).take_while(|p| p.starts_with(root)).collect();
+		ancestors.reverse();
+		for p in ancestors {
+			rules.extend(Self::rules(&UrlBuf::from(p)).await.map(Arc::new));
+		}
+		rules
+	}
+
+	/// Whether the file found in a walk under the given rules is kept.
+	fn admits(self, rules: &Rules, file: &File) -> bool {
+		if !self.hidden && file.is_hidden() {
+			return false;
+		} else if !self.ignore {
+			return true;
+		} else if file.is_dir() && file.url.name().is_some_and(|n| n == ".git") {
+			return false;
+		}
+
+		let Some(path) = file.url.as_local() else { return true };
+		!rules
+			.iter()
+			.rev()
+			.find_map(|r| r.1.matched(path.strip_prefix(&r.0).ok()?, file.is_dir()))
+			.unwrap_or(false)
+	}
+
+	async fn rules(dir: &UrlBuf) -> Option<(PathBuf, Ignore)> {
+		let mut s = String::new();
+		for name in Ignore::FILES {
+			let Ok(url) = dir.try_join(name) else { continue };
+			if let Ok(mut f) = engine::open(url).await {
+				f.read_to_string(&mut s).await.ok();
+				s.push('\n');
+			}
+		}
+
+		let ignore = Ignore::parse(&s);
+		Some((dir.as_local()?.to_owned(), ignore)).filter(|(_, i)| !i.is_empty())
+	}
+}
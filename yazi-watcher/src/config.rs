@@ -0,0 +1,55 @@
+use std::time::Duration;
+
+use notify::{RecommendedWatcher, RecursiveMode, Result, Watcher};
+use tokio::{pin, sync::mpsc::{self, UnboundedReceiver}};
+use tokio_stream::{StreamExt, wrappers::UnboundedReceiverStream};
+use yazi_fs::Xdg;
+use yazi_macro::{emit, relay};
+
+/// Watches the config directory, reloading the config files that change in it.
+pub(crate) struct Config;
+
+impl Config {
+	pub(crate) fn serve() {
+		let (tx, rx) = mpsc::unbounded_channel();
+		let handler = move |res: Result<notify::Event>| {
+			let Ok(event) = res else { return };
+			if event.kind.is_access() {
+				return;
+			}
+
+			for path in event.paths {
+				// Whether it's the theme that changed
+				match path.file_name().and_then(|s| s.to_str()) {
+					Some("yazi.toml" | "keymap.toml" | "vfs.toml") => _ = tx.send(false),
+					Some("theme.toml") => _ = tx.send(true),
+					_ => {}
+				}
+			}
+		};
+
+		let dir = Xdg::config_dir();
+		let watcher = RecommendedWatcher::new(handler, notify::Config::default())
+			.and_then(|mut w| w.watch(dir, RecursiveMode::NonRecursive).map(|()| w));
+
+		match watcher {
+			Ok(watcher) => _ = tokio::spawn(Self::changed(rx, watcher)),
+			Err(e) => yazi_macro::warn!("Failed to watch config directory {dir:?}: {e:?}"),
+		}
+	}
+
+	async fn changed(rx: UnboundedReceiver<bool>, _watcher: RecommendedWatcher) {
+		// Editors often save a file with several writes or a rename
+		let rx = UnboundedReceiverStream::new(rx).chunks_timeout(100, Duration::from_millis(300));
+		pin!(rx);
+
+		while let Some(chunk) = rx.next().await {
+			if chunk.contains(&false) {
+				emit!(Call(relay!(app:reload)));
+			}
+			if chunk.contains(&true) {
+				emit!(Call(relay!(app:theme)));
+			}
+		}
+	}
+}
@@ -18,9 +18,13 @@ impl Reporter {
 		for url in urls.into_iter().map(Into::into) {
 			match url.as_url().kind() {
 				AuthKind::Regular | AuthKind::Search => self.report_local(url),
-				AuthKind::Mount | AuthKind::Hub | AuthKind::Scope | AuthKind::Sftp => {
-					self.report_virtual(url)
-				}
+				AuthKind::Mount
+				| AuthKind::Hub
+				| AuthKind::Scope
+				| AuthKind::Sftp
+				| AuthKind::Webdav
+				| AuthKind::S3
+				| AuthKind::Ftp => self.report_virtual(url),
 			}
 		}
 	}
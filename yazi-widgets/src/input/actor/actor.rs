@@ -19,6 +19,16 @@ impl Input {
 			};
 		}
 
+		// Backspace edits the query of an ongoing search, while moving the cursor or
+		// editing otherwise ends it, keeping the match
+		if self.history.search.is_some() {
+			match action.name.as_ref() {
+				"backspace" => return self.search_backspace(),
+				"move" | "feed" | "backward" | "forward" | "visual" | "kill" => self.search_accept(),
+				_ => {}
+			}
+		}
+
 		on!(r#move, "move");
 		on!(feed);
 		on!(backward);
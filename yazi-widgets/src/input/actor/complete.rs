@@ -2,7 +2,7 @@ use std::path::MAIN_SEPARATOR_STR;
 
 use anyhow::Result;
 use yazi_macro::{act, render, succ};
-use yazi_shared::data::Data;
+use yazi_shared::{data::Data, shell::LastWord, strand::StrandLike};
 use yazi_shim::path::CROSS_SEPARATOR;
 
 use crate::input::{Input, parser::CompleteOpt};
@@ -10,7 +10,17 @@ use crate::input::{Input, parser::CompleteOpt};
 impl Input {
 	pub fn complete(&mut self, opt: CompleteOpt) -> Result<Data> {
 		let (before, after) = self.partition();
-		let new = if let Some((prefix, _)) = before.rsplit_once(CROSS_SEPARATOR) {
+		let new = if self.shell {
+			let word = LastWord::parse(before);
+			let head =
+				before[word.start..].rfind(CROSS_SEPARATOR).map_or(word.start, |i| word.start + i + 1);
+			format!(
+				"{}{}{}{after}",
+				&before[..head],
+				word.escape(&opt.name.to_string_lossy()),
+				opt.slash()
+			)
+		} else if let Some((prefix, _)) = before.rsplit_once(CROSS_SEPARATOR) {
 			format!("{prefix}/{}{after}", opt.completable()).replace(CROSS_SEPARATOR, MAIN_SEPARATOR_STR)
 		} else {
 			format!("{}{after}", opt.completable()).replace(CROSS_SEPARATOR, MAIN_SEPARATOR_STR)
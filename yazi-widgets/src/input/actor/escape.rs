@@ -6,6 +6,10 @@ use crate::input::{Input, InputMode, op::InputOp};
 
 impl Input {
 	pub fn escape(&mut self, _: ()) -> Result<Data> {
+		if self.search_cancel()? {
+			succ!();
+		}
+
 		let snap = self.snap_mut();
 		match snap.mode {
 			InputMode::Normal => {
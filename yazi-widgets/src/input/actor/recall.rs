@@ -6,6 +6,7 @@ use crate::{Step, input::{Input, InputSnap}};
 
 impl Input {
 	pub fn recall(&mut self, items: &[String], step: Step) -> Result<Data> {
+		self.search_accept();
 		if items.is_empty() {
 			succ!();
 		}
@@ -26,7 +27,7 @@ impl Input {
 		self.recall_to(items[next].clone())
 	}
 
-	fn recall_to(&mut self, value: String) -> Result<Data> {
+	pub(super) fn recall_to(&mut self, value: String) -> Result<Data> {
 		let mode = self.mode();
 
 		let mut snap = InputSnap::new(value, self.obscure);
@@ -0,0 +1,69 @@
+use anyhow::Result;
+use yazi_macro::{render, succ};
+use yazi_shared::data::Data;
+
+use crate::input::{Input, InputSearch};
+
+impl Input {
+	/// Start a reverse search through the history `entries`, or move on to an
+	/// older match if one is already going on.
+	pub fn search(&mut self, entries: &[String]) -> Result<Data> {
+		if self.history.search.is_some() {
+			let end = self.history.at.unwrap_or(usize::MAX);
+			return self.search_from(end);
+		}
+
+		if self.history.at.is_none() {
+			self.history.draft = self.value().to_owned();
+		}
+
+		self.history.search = Some(InputSearch { query: String::new(), entries: entries.to_vec() });
+		succ!(render!());
+	}
+
+	/// Cancel the ongoing search, and bring back what was typed before it.
+	pub fn search_cancel(&mut self) -> Result<bool> {
+		if self.history.search.is_none() {
+			return Ok(false);
+		}
+
+		let draft = self.history.take();
+		self.recall_to(draft)?;
+		Ok(true)
+	}
+
+	pub(super) fn search_type(&mut self, text: &str) -> Result<Data> {
+		let Some(search) = &mut self.history.search else { succ!() };
+		search.query.push_str(text);
+
+		// Stay on the current match as long as it still matches
+		let end = self.history.at.map_or(usize::MAX, |i| i + 1);
+		self.search_from(end)
+	}
+
+	pub(super) fn search_backspace(&mut self) -> Result<Data> {
+		let Some(search) = &mut self.history.search else { succ!() };
+		if search.query.pop().is_none() {
+			succ!();
+		}
+
+		self.search_from(usize::MAX)
+	}
+
+	pub(super) fn search_accept(&mut self) { self.history.search = None; }
+
+	/// Recall the newest entry before `end` that contains the query, or stay on
+	/// the current one if there's none.
+	fn search_from(&mut self, end: usize) -> Result<Data> {
+		let Some(search) = &self.history.search else { succ!() };
+
+		let end = end.min(search.entries.len());
+		let Some(i) = search.entries[..end].iter().rposition(|e| e.contains(&search.query)) else {
+			succ!(render!());
+		};
+
+		let value = search.entries[i].clone();
+		self.history.at = Some(i);
+		self.recall_to(value)
+	}
+}
@@ -11,6 +11,9 @@ impl Input {
 		if self.mode() == InputMode::Normal {
 			Ok(false)
 		} else if let Some(text) = key.text(&mut buf) {
+			if self.history.search.is_some() {
+				return Ok(self.search_type(text).is_ok());
+			}
 			Ok(self.feed(text.into()).is_ok())
 		} else {
 			Ok(false)
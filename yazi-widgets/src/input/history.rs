@@ -4,8 +4,9 @@ use std::mem;
 pub struct InputHistory {
 	pub name: String,
 
-	pub(super) at:    Option<usize>,
-	pub(super) draft: String,
+	pub(super) at:     Option<usize>,
+	pub(super) draft:  String,
+	pub(super) search: Option<InputSearch>,
 }
 
 impl InputHistory {
@@ -13,6 +14,17 @@ impl InputHistory {
 
 	pub fn take(&mut self) -> String {
 		self.at = None;
+		self.search = None;
 		mem::take(&mut self.draft)
 	}
+
+	/// The query of the ongoing reverse search, if any.
+	pub fn query(&self) -> Option<&str> { self.search.as_ref().map(|s| s.query.as_str()) }
+}
+
+// --- InputSearch
+#[derive(Debug, Default)]
+pub(super) struct InputSearch {
+	pub(super) query:   String,
+	pub(super) entries: Vec<String>,
 }
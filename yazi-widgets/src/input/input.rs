@@ -19,6 +19,7 @@ pub struct Input {
 	pub obscure:    bool,
 	pub realtime:   bool,
 	pub completion: bool,
+	pub shell:      bool,
 
 	pub cb:     Option<Box<dyn InputCallback>>,
 	pub ticket: Ids,
@@ -33,6 +34,7 @@ impl Input {
 			obscure: opt.obscure,
 			realtime: opt.realtime,
 			completion: opt.completion,
+			shell: opt.shell,
 
 			cb: opt.cb,
 			..Default::default()
@@ -168,10 +170,16 @@ impl Input {
 		let snap = self.snap();
 		let idx = snap.idx(snap.cursor).unwrap();
 
-		if let Some(sep) = snap.value[idx..].find(CROSS_SEPARATOR).map(|i| idx + i) {
-			(&snap.value[..sep], &snap.value[sep + 1..])
-		} else {
-			(&snap.value, "")
+		let sep = snap.value[idx..].find(CROSS_SEPARATOR).map(|i| idx + i);
+		// A shell command is completed word by word, so its words end at whitespace
+		let ws = snap.value[idx..].find(char::is_whitespace).map(|i| idx + i).filter(|_| self.shell);
+
+		match (sep, ws) {
+			(Some(sep), ws) if ws.is_none_or(|ws| sep < ws) => {
+				(&snap.value[..sep], &snap.value[sep + 1..])
+			}
+			(_, Some(ws)) => snap.value.split_at(ws),
+			_ => (&snap.value, ""),
 		}
 	}
 
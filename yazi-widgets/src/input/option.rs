@@ -17,6 +17,7 @@ pub struct InputOpt {
 	pub position:   Position,
 	pub realtime:   bool,
 	pub completion: bool,
+	pub shell:      bool,
 	pub cb:         Option<Box<dyn InputCallback>>,
 }
 
@@ -55,6 +56,7 @@ impl TryFrom<&Table> for InputOpt {
 			position:   t.raw_get::<Position>("pos").unwrap_or_default().with_height(3),
 			realtime:   t.raw_get("realtime")?,
 			completion: false,
+			shell:      false,
 			cb:         None,
 		})
 	}
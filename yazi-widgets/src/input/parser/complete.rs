@@ -32,6 +32,8 @@ impl IntoLua for CompleteOpt {
 
 impl CompleteOpt {
 	pub(crate) fn completable(&self) -> String {
-		format!("{}{}", self.name.to_string_lossy(), if self.is_dir { MAIN_SEPARATOR_STR } else { "" })
+		format!("{}{}", self.name.to_string_lossy(), self.slash())
 	}
+
+	pub(crate) fn slash(&self) -> &'static str { if self.is_dir { MAIN_SEPARATOR_STR } else { "" } }
 }